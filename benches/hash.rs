@@ -0,0 +1,76 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use inertia_core::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Count the heap allocations made from Rust, to check that hashing does not
+// allocate.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn hash_of<T: Hash>(x: &T) -> u64 {
+    let mut state = DefaultHasher::new();
+    x.hash(&mut state);
+    state.finish()
+}
+
+// Hash `x` once, panicking if that allocates through Rust or FLINT.
+fn assert_no_alloc<T: Hash>(x: &T) {
+    let before = (ALLOCATIONS.load(Ordering::Relaxed), flint_memory_stats().allocations);
+    hash_of(x);
+    let after = (ALLOCATIONS.load(Ordering::Relaxed), flint_memory_stats().allocations);
+    assert_eq!(before, after, "Hashing allocated.");
+}
+
+fn hashing(c: &mut Criterion) {
+    unsafe { enable_memory_tracking(); }
+    let mut rng = FlintRand::with_seed(5);
+    let ctx = IntModCtx::new(Integer::from(2).pow(200u32) + 297u32);
+    let m = IntModMat::randtest(1000, 1000, &ctx, &mut rng);
+    let x = IntMod::randm(&ctx, &mut rng);
+    assert_no_alloc(&m);
+    assert_no_alloc(&x);
+
+    let mut group = c.benchmark_group("hash");
+    group.sample_size(20);
+    group.bench_function("intmodmat_1000x1000", |b| b.iter(|| hash_of(&m)));
+    group.bench_function("intmod", |b| b.iter(|| hash_of(&x)));
+    group.finish();
+}
+
+criterion_group!(benches, hashing);
+criterion_main!(benches);
@@ -0,0 +1,99 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use inertia_core::*;
+
+const BITS: [u64; 5] = [64, 256, 4096, 65536, 1 << 20];
+
+fn operands(bits: u64, rng: &mut FlintRand) -> (Integer, Integer) {
+    (Integer::randbits(bits, rng), Integer::randbits(bits, rng))
+}
+
+fn arithmetic(c: &mut Criterion) {
+    let mut rng = FlintRand::with_seed(1);
+    let mut group = c.benchmark_group("integer");
+    for bits in BITS {
+        let (x, y) = operands(bits, &mut rng);
+        group.bench_with_input(BenchmarkId::new("mul", bits), &bits, |b, _| {
+            b.iter(|| &x * &y)
+        });
+        let z = &x * &y;
+        group.bench_with_input(BenchmarkId::new("fdiv_q", bits), &bits, |b, _| {
+            b.iter(|| z.fdiv_q(&y))
+        });
+        group.bench_with_input(BenchmarkId::new("gcd", bits), &bits, |b, _| {
+            b.iter(|| x.gcd(&y))
+        });
+    }
+    group.finish();
+}
+
+fn powm(c: &mut Criterion) {
+    let mut rng = FlintRand::with_seed(2);
+    let mut group = c.benchmark_group("integer_powm");
+    for bits in [64u64, 256, 1024, 4096] {
+        let (x, e) = operands(bits, &mut rng);
+        let m = Integer::randbits(bits, &mut rng) + 1;
+        group.bench_with_input(BenchmarkId::from_parameter(bits), &bits, |b, _| {
+            b.iter(|| x.powm(&e, &m))
+        });
+    }
+    group.finish();
+}
+
+fn fixed_base(c: &mut Criterion) {
+    let mut rng = FlintRand::with_seed(5);
+    let zn = IntModCtx::new(Integer::randbits(256u64, &mut rng) + 1);
+    let g = zn.element(Integer::randbits(256u64, &mut rng));
+    let exps: Vec<Integer> = (0..64).map(|_| Integer::randbits(256u64, &mut rng)).collect();
+    let mut group = c.benchmark_group("fixed_base_pow");
+    group.bench_function("plain", |b| {
+        b.iter(|| exps.iter().map(|e| g.clone().pow(e)).count())
+    });
+    for w in [2u64, 3, 4, 5, 6, 8] {
+        let fixed = FixedBasePow::with_window(&g, 256, w);
+        group.bench_with_input(BenchmarkId::new("window", w), &w, |b, _| {
+            b.iter(|| exps.iter().map(|e| fixed.pow(e)).count())
+        });
+    }
+    group.finish();
+}
+
+fn multi_exp(c: &mut Criterion) {
+    let mut rng = FlintRand::with_seed(6);
+    let zn = IntModCtx::new(Integer::randbits(256u64, &mut rng) + 1);
+    let mut group = c.benchmark_group("multi_exp");
+    group.sample_size(20);
+    for n in [2usize, 8, 32, 128] {
+        let bases: Vec<IntMod> = (0..n)
+            .map(|_| zn.element(Integer::randbits(256u64, &mut rng)))
+            .collect();
+        let exps: Vec<Integer> = (0..n).map(|_| Integer::randbits(256u64, &mut rng)).collect();
+        for (name, cutoff) in [("straus", u64::MAX), ("pippenger", 0)] {
+            group.bench_with_input(BenchmarkId::new(name, n), &n, |b, _| {
+                tuning::set_multi_exp_pippenger_cutoff(cutoff);
+                b.iter(|| IntMod::multi_exp(&bases, &exps))
+            });
+        }
+    }
+    tuning::set_multi_exp_pippenger_cutoff(tuning::DEFAULT_MULTI_EXP_PIPPENGER_CUTOFF);
+    group.finish();
+}
+
+criterion_group!(benches, arithmetic, powm, fixed_base, multi_exp);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use inertia_core::*;
+
+const DIMS: [i64; 5] = [4, 8, 16, 32, 64];
+const ENTRY_BITS: u64 = 16;
+
+fn scaling(c: &mut Criterion) {
+    let mut rng = FlintRand::with_seed(3);
+    let mut group = c.benchmark_group("intmat");
+    group.sample_size(20);
+    for n in DIMS {
+        let a = IntMat::randbits(n, n, ENTRY_BITS, &mut rng);
+        group.bench_with_input(BenchmarkId::new("det", n), &n, |b, _| {
+            b.iter(|| a.det())
+        });
+        group.bench_with_input(BenchmarkId::new("hnf", n), &n, |b, _| {
+            b.iter(|| a.hnf())
+        });
+        group.bench_with_input(BenchmarkId::new("lll", n), &n, |b, _| {
+            b.iter(|| a.lll())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, scaling);
+criterion_main!(benches);
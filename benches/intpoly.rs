@@ -0,0 +1,91 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// Polynomial multiplication at increasing lengths, both through `Mul` and 
+// through the individual FLINT algorithms, to locate the crossover points 
+// between the classical, Karatsuba and Kronecker substitution methods, and 
+// multipoint evaluation with and without the subproduct tree, which sets 
+// `tuning::multipoint_eval_cutoff`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use flint_sys::fmpz_poly;
+use inertia_core::*;
+
+const LENGTHS: [usize; 7] = [4, 16, 32, 64, 256, 1024, 4096];
+const COEFF_BITS: u64 = 64;
+
+type MulFn = unsafe extern "C" fn(
+    *mut fmpz_poly::fmpz_poly_struct,
+    *const fmpz_poly::fmpz_poly_struct,
+    *const fmpz_poly::fmpz_poly_struct,
+);
+
+fn mul_with(f: MulFn, x: &IntPoly, y: &IntPoly) -> IntPoly {
+    let mut res = IntPoly::zero();
+    unsafe { f(res.as_mut_ptr(), x.as_ptr(), y.as_ptr()); }
+    res
+}
+
+fn crossover(c: &mut Criterion) {
+    let mut rng = FlintRand::with_seed(4);
+    let mut group = c.benchmark_group("intpoly_mul");
+    let algorithms: [(&str, MulFn); 3] = [
+        ("classical", fmpz_poly::fmpz_poly_mul_classical),
+        ("karatsuba", fmpz_poly::fmpz_poly_mul_karatsuba),
+        ("KS", fmpz_poly::fmpz_poly_mul_KS),
+    ];
+    for len in LENGTHS {
+        let x = IntPoly::randtest(len, COEFF_BITS, &mut rng);
+        let y = IntPoly::randtest(len, COEFF_BITS, &mut rng);
+        group.bench_with_input(BenchmarkId::new("default", len), &len, |b, _| {
+            b.iter(|| &x * &y)
+        });
+        for (name, f) in algorithms {
+            // the quadratic methods are not worth timing at large lengths
+            if name != "KS" && len > 1024 {
+                continue;
+            }
+            group.bench_with_input(BenchmarkId::new(name, len), &len, |b, _| {
+                b.iter(|| mul_with(f, &x, &y))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn multipoint(c: &mut Criterion) {
+    let mut rng = FlintRand::with_seed(4);
+    let mut group = c.benchmark_group("intpoly_evaluate_vec");
+    for n in [4, 8, 16, 64, 256] {
+        let f = IntPoly::randtest(n, COEFF_BITS, &mut rng);
+        let xs: Vec<Integer> = (0..n as i64).map(Integer::from).collect();
+
+        tuning::set_multipoint_eval_cutoff(u64::MAX);
+        group.bench_with_input(BenchmarkId::new("pointwise", n), &n, |b, _| {
+            b.iter(|| f.evaluate_vec(&xs))
+        });
+        tuning::set_multipoint_eval_cutoff(0);
+        group.bench_with_input(BenchmarkId::new("tree", n), &n, |b, _| {
+            b.iter(|| f.evaluate_vec(&xs))
+        });
+    }
+    tuning::set_multipoint_eval_cutoff(tuning::DEFAULT_MULTIPOINT_EVAL_CUTOFF);
+    group.finish();
+}
+
+criterion_group!(benches, crossover, multipoint);
+criterion_main!(benches);
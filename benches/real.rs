@@ -0,0 +1,61 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// Arb constant evaluation against the crate's binary splitting, and the sweep 
+// used to pick `tuning::DEFAULT_BINSPLIT_BASECASE`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use inertia_core::*;
+
+const PRECS: [u64; 4] = [1 << 10, 1 << 13, 1 << 16, 1 << 18];
+
+fn constants(c: &mut Criterion) {
+    let mut group = c.benchmark_group("real_constants");
+    group.sample_size(10);
+    for prec in PRECS {
+        let field = RealField::init(prec);
+        group.bench_with_input(BenchmarkId::new("arb_pi", prec), &prec, |b, _| {
+            b.iter(|| field.pi())
+        });
+        group.bench_with_input(BenchmarkId::new("pi_chudnovsky", prec), &prec, |b, &p| {
+            b.iter(|| pi_chudnovsky(p))
+        });
+        group.bench_with_input(BenchmarkId::new("e", prec), &prec, |b, &p| {
+            b.iter(|| e_binary_splitting(p))
+        });
+        group.bench_with_input(BenchmarkId::new("zeta3", prec), &prec, |b, &p| {
+            b.iter(|| zeta3_binary_splitting(p))
+        });
+    }
+    group.finish();
+}
+
+fn binsplit_basecase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("binsplit_basecase");
+    group.sample_size(10);
+    for n in [1u64, 4, 8, 16, 32, 64] {
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            tuning::set_binsplit_basecase(n);
+            b.iter(|| e_binary_splitting(1 << 16))
+        });
+    }
+    tuning::set_binsplit_basecase(tuning::DEFAULT_BINSPLIT_BASECASE);
+    group.finish();
+}
+
+criterion_group!(benches, constants, binsplit_basecase);
+criterion_main!(benches);
@@ -16,7 +16,14 @@
  */
 
 //mod ops;
+mod cm;
 mod conv;
+mod tables;
+mod units;
+
+pub use cm::*;
+pub use tables::*;
+pub use units::*;
 
 use crate::{New, Integer};
 use flint_sys::fmpz::fmpz_set;
@@ -27,7 +34,6 @@ use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
-#[derive(Debug)]
 pub struct BinQuadForm {
     pub(crate) inner: qfb,
 }
@@ -60,6 +66,12 @@ impl Default for BinQuadForm {
     }
 }
 
+impl fmt::Debug for BinQuadForm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BinQuadForm").field(&format_args!("{}", self)).finish()
+    }
+}
+
 impl fmt::Display for BinQuadForm {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -83,6 +95,15 @@ impl Drop for BinQuadForm {
     }
 }
 
+impl Eq for BinQuadForm {}
+
+impl PartialEq for BinQuadForm {
+    #[inline]
+    fn eq(&self, rhs: &BinQuadForm) -> bool {
+        self.get_coeffs() == rhs.get_coeffs()
+    }
+}
+
 // TODO
 impl Hash for BinQuadForm {
     #[inline]
@@ -150,4 +171,201 @@ impl BinQuadForm {
         }
         [a, b, c]
     }
+    /// Return the principal form of discriminant `d`, that is, the identity 
+    /// element of the form class group. Panics unless `d` is congruent to 0 or 
+    /// 1 modulo 4.
+    pub fn principal<T: AsRef<Integer>>(d: T) -> BinQuadForm {
+        let mut d = d.as_ref().clone();
+        assert!(d.fdiv_r(Integer::from(4)) <= 1);
+        let mut res = BinQuadForm::default();
+        unsafe { qfb_principal_form(res.as_mut_ptr(), d.as_mut_ptr()); }
+        res
+    }
+
+    /// Return the discriminant `b^2 - 4ac` of the form `(a, b, c)`.
+    #[inline]
+    pub fn discriminant(&self) -> Integer {
+        let mut f = self.clone();
+        let mut res = Integer::default();
+        unsafe { qfb_discriminant(res.as_mut_ptr(), f.as_mut_ptr()); }
+        res
+    }
+
+    /// Return true if the form is primitive, that is, the gcd of its 
+    /// coefficients is one.
+    #[inline]
+    pub fn is_primitive(&self) -> bool {
+        let mut f = self.clone();
+        unsafe { qfb_is_primitive(f.as_mut_ptr()) == 1 }
+    }
+    
+    /// Return true if the positive definite form `(a, b, c)` is reduced, that 
+    /// is, `|b| <= a <= c` and `b >= 0` if either `|b| = a` or `a = c`.
+    #[inline]
+    pub fn is_reduced(&self) -> bool {
+        let mut f = self.clone();
+        unsafe { qfb_is_reduced(f.as_mut_ptr()) == 1 }
+    }
+    
+    /// Return the unique reduced form equivalent to a positive definite form.
+    #[inline]
+    pub fn reduce(&self) -> BinQuadForm {
+        let mut res = self.clone();
+        res.reduce_assign();
+        res
+    }
+    
+    /// Reduce a positive definite form in place.
+    pub fn reduce_assign(&mut self) {
+        self.check_definite();
+        let mut d = self.discriminant();
+        let mut f = self.clone();
+        unsafe { qfb_reduce(self.as_mut_ptr(), f.as_mut_ptr(), d.as_mut_ptr()); }
+    }
+
+    /// Return true if two positive definite forms are properly equivalent, 
+    /// that is, they reduce to the same form.
+    #[inline]
+    pub fn is_equivalent<T: AsRef<BinQuadForm>>(&self, other: T) -> bool {
+        let other = other.as_ref();
+        self.discriminant() == other.discriminant() 
+            && self.reduce() == other.reduce()
+    }
+
+    /// Compose two positive definite forms of the same discriminant using 
+    /// NUCOMP. The result is reduced.
+    pub fn compose<T: AsRef<BinQuadForm>>(&self, other: T) -> BinQuadForm {
+        let other = other.as_ref();
+        self.check_definite();
+        let mut d = self.discriminant();
+        assert_eq!(d, other.discriminant());
+
+        let mut l = d.abs().root(4);
+        let mut res = BinQuadForm::default();
+        unsafe {
+            qfb_nucomp(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(), 
+                d.as_mut_ptr(), 
+                l.as_mut_ptr()
+            );
+        }
+        res.reduce_assign();
+        res
+    }
+
+    /// Return the inverse of the form class of a positive definite form, that 
+    /// is, the reduction of `(a, -b, c)`.
+    #[inline]
+    pub fn inverse(&self) -> BinQuadForm {
+        let mut f = self.clone();
+        let mut res = BinQuadForm::default();
+        unsafe { qfb_inverse(res.as_mut_ptr(), f.as_mut_ptr()); }
+        res.reduce_assign();
+        res
+    }
+    
+    /// Raise a positive definite form to the power `e` under composition. 
+    /// Negative exponents compose with the inverse.
+    pub fn pow<T: AsRef<Integer>>(&self, e: T) -> BinQuadForm {
+        let e = e.as_ref();
+        self.check_definite();
+        let mut d = self.discriminant();
+        let mut f = if e.sign() < 0 { self.inverse() } else { self.clone() };
+        let mut e = e.abs();
+        let mut res = BinQuadForm::default();
+        unsafe { 
+            qfb_pow(res.as_mut_ptr(), f.as_mut_ptr(), d.as_mut_ptr(), e.as_mut_ptr()); 
+        }
+        res
+    }
+    
+    /// Return the order of the class of a primitive positive definite form in 
+    /// the form class group. Panics if the form is not primitive, since the 
+    /// powers of a non-primitive form never reach the principal form.
+    pub fn order(&self) -> u64 {
+        assert!(self.is_primitive(), "The form must be primitive.");
+        let one = BinQuadForm::principal(self.discriminant());
+        let g = self.reduce();
+        let mut h = g.clone();
+        let mut k = 1;
+        while h != one {
+            h = h.compose(&g);
+            k += 1;
+        }
+        k
+    }
+
+    #[inline]
+    fn check_definite(&self) {
+        assert!(
+            self.discriminant() < 0,
+            "Only positive definite forms are supported."
+        );
+    }
+}
+
+/// Compute the form class group of primitive positive definite forms of 
+/// negative discriminant `d`. Returns the class number together with a set of 
+/// reduced forms whose classes generate the group. The generators are chosen 
+/// greedily and need not be independent.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let (h, gens) = class_group(-23);
+/// assert_eq!(h, 3);
+/// assert_eq!(gens.len(), 1);
+/// assert_eq!(gens[0].order(), 3);
+/// ```
+pub fn class_group(d: i64) -> (u64, Vec<BinQuadForm>) {
+    assert!(d < 0 && (d.rem_euclid(4) == 0 || d.rem_euclid(4) == 1));
+    trace_span!("class_group", d);
+
+    let forms = reduced_forms(d);
+    let h = forms.len();
+
+    // grow the subgroup generated so far until it is everything
+    let mut subgroup = vec![BinQuadForm::principal(Integer::from(d))];
+    let mut gens = vec![];
+    for f in forms.iter() {
+        if subgroup.len() == h {
+            break;
+        }
+        if subgroup.contains(f) {
+            continue;
+        }
+
+        let mut coset = subgroup.clone();
+        let mut new = subgroup.clone();
+        loop {
+            coset = coset.iter().map(|g| g.compose(f)).collect();
+            if subgroup.contains(&coset[0]) {
+                break;
+            }
+            new.extend(coset.iter().cloned());
+        }
+        subgroup = new;
+        gens.push(f.clone());
+    }
+    (h as u64, gens)
+}
+
+// The reduced primitive forms of negative discriminant `d`, one in each class.
+pub(crate) fn reduced_forms(d: i64) -> Vec<BinQuadForm> {
+    let mut forms = Vec::new();
+    unsafe {
+        let mut arr = std::ptr::null_mut();
+        let num = qfb_reduced_forms(&mut arr, d);
+        for i in 0..num.try_into().expect("Cannot convert signed long to usize.") {
+            let mut f = BinQuadForm::default();
+            qfb_set(f.as_mut_ptr(), arr.add(i));
+            if f.is_primitive() {
+                forms.push(f);
+            }
+        }
+        qfb_array_clear(&mut arr, num);
+    }
+    forms
 }
@@ -0,0 +1,127 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::reduced_forms;
+use crate::*;
+use arb_sys::acb_modular::*;
+use arb_sys::arb::*;
+
+/// Return true if `d` is one of the nine Heegner discriminants `-3, -4, -7, 
+/// -8, -11, -19, -43, -67, -163`, the negative fundamental discriminants of 
+/// class number one. For these the j-invariant of `(d + sqrt(d))/2` is an 
+/// integer, which explains the near-integrality of `exp(pi sqrt(163))`.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// assert!(is_heegner_discriminant(-163));
+/// assert!(!is_heegner_discriminant(-15));
+/// assert!(!is_heegner_discriminant(-12));
+/// ```
+pub fn is_heegner_discriminant(d: i64) -> bool {
+    d < 0 && is_fundamental_discriminant(d) && class_number(d) == 1
+}
+
+impl IntPoly {
+    /// Return the Hilbert class polynomial `H_d` of the negative discriminant
+    /// `d`, whose roots are the j-invariants of the elliptic curves with 
+    /// complex multiplication by the order of discriminant `d`. Computed by 
+    /// Arb from numerical values of the j-function.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(IntPoly::hilbert_class_polynomial(-4), IntPoly::from([-1728, 1]));
+    /// assert_eq!(IntPoly::hilbert_class_polynomial(-23).degree(), 3);
+    /// ```
+    pub fn hilbert_class_polynomial(d: i64) -> IntPoly {
+        assert!(d < 0 && (d.rem_euclid(4) == 0 || d.rem_euclid(4) == 1));
+        let mut res = IntPoly::zero();
+        unsafe { acb_modular_hilbert_class_poly(res.as_mut_ptr(), d); }
+        res
+    }
+}
+
+/// The CM j-invariants of a negative discriminant `d`, see 
+/// [cm_j_invariants].
+#[derive(Clone, Debug)]
+pub struct CmJInvariants {
+    /// The discriminant.
+    pub discriminant: i64,
+    /// The Hilbert class polynomial `H_d`, the minimal polynomial of the 
+    /// j-invariants.
+    pub hilbert_poly: IntPoly,
+    /// For each reduced form `(a, b, c)` of discriminant `d`, one per class,
+    /// the value `j(tau)` at its root `tau = (-b + sqrt(d))/(2a)` in the 
+    /// upper half plane.
+    pub values: Vec<(BinQuadForm, Complex)>,
+}
+
+impl CmJInvariants {
+    /// Return the number field `Q(j) = Q[x]/H_d` generated by one of the 
+    /// j-invariants. Its Galois closure over `Q(sqrt(d))` is the ring class 
+    /// field of the order of discriminant `d`.
+    #[inline]
+    pub fn field(&self) -> NumFldCtx {
+        NumFldCtx::new(RatPoly::from(&self.hilbert_poly))
+    }
+}
+
+/// Compute the j-invariants of the elliptic curves with complex 
+/// multiplication by the order of negative discriminant `d`: the Hilbert 
+/// class polynomial, and its complex roots to `prec` bits, indexed by the 
+/// classes of binary quadratic forms of discriminant `d`.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let cm = cm_j_invariants(-163, 128);
+/// let j = -Integer::from(640320).pow(3u64);
+/// assert_eq!(cm.hilbert_poly, IntPoly::from([-&j, Integer::one()]));
+/// assert!(cm.values[0].1.re().contains(&Real::from(j)));
+///
+/// let cm = cm_j_invariants(-23, 128);
+/// assert_eq!(cm.values.len(), 3);
+/// assert_eq!(cm.field().degree(), 3);
+/// ```
+pub fn cm_j_invariants(d: i64, prec: u64) -> CmJInvariants {
+    let hilbert_poly = IntPoly::hilbert_class_polynomial(d);
+    let cc = ComplexField::init(prec);
+    let prec_si = prec.try_into().expect("Cannot convert precision to a signed long.");
+    let values = reduced_forms(d)
+        .into_iter()
+        .map(|f| {
+            let [a, b, _] = f.get_coeffs();
+            let two_a = &a + &a;
+            let mut re = Real::default();
+            let mut im = Real::default();
+            let mut j = Complex::default();
+            unsafe {
+                arb_set_fmpz(re.as_mut_ptr(), b.as_ptr());
+                arb_neg(re.as_mut_ptr(), re.as_ptr());
+                arb_div_fmpz(re.as_mut_ptr(), re.as_ptr(), two_a.as_ptr(), prec_si);
+                arb_sqrt_ui(im.as_mut_ptr(), d.unsigned_abs(), prec_si);
+                arb_div_fmpz(im.as_mut_ptr(), im.as_ptr(), two_a.as_ptr(), prec_si);
+                let tau = cc.from_parts(&re, &im);
+                acb_modular_j(j.as_mut_ptr(), tau.as_ptr(), prec_si);
+            }
+            j.set_prec(prec);
+            (f, j)
+        })
+        .collect();
+    CmJInvariants { discriminant: d, hilbert_poly, values }
+}
@@ -0,0 +1,143 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::reduced_forms;
+use crate::{BinQuadForm, Integer};
+use std::ops::{Bound, RangeBounds};
+
+/// Return the class number `h(d)` of primitive positive definite forms of 
+/// negative discriminant `d`, the number of reduced primitive forms. This 
+/// is cheaper than [class_group](crate::class_group) when the group 
+/// structure is not needed.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// assert_eq!(class_number(-23), 3);
+/// assert_eq!(class_number(-163), 1);
+/// ```
+pub fn class_number(d: i64) -> u64 {
+    assert!(d < 0 && (d.rem_euclid(4) == 0 || d.rem_euclid(4) == 1));
+    // FLINT works with -d
+    assert!(d.checked_neg().is_some(), "The discriminant is out of range.");
+    reduced_forms(d).len() as u64
+}
+
+/// Return true if `d` is a fundamental discriminant, the discriminant of a 
+/// quadratic field: `d = 1 mod 4` squarefree, or `d = 4m` with `m = 2, 3 
+/// mod 4` squarefree.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// assert!(is_fundamental_discriminant(-4));
+/// assert!(is_fundamental_discriminant(-15));
+/// assert!(!is_fundamental_discriminant(-12));
+/// assert!(!is_fundamental_discriminant(1));
+/// ```
+pub fn is_fundamental_discriminant(d: i64) -> bool {
+    let squarefree = |n: i64| n != 0 && Integer::from(n).factor().iter().all(|(_, e)| *e == 1);
+    match d.rem_euclid(4) {
+        1 => d != 1 && squarefree(d),
+        0 => {
+            let m = d / 4;
+            matches!(m.rem_euclid(4), 2 | 3) && squarefree(m)
+        }
+        _ => false,
+    }
+}
+
+/// An iterator over the negative fundamental discriminants in a range, in 
+/// order of increasing absolute value, together with their class numbers. 
+/// See [class_numbers].
+#[derive(Clone, Debug)]
+pub struct ClassNumbers {
+    // the next discriminant to try and the last one, with next >= last
+    next: i64,
+    last: i64,
+}
+
+impl Iterator for ClassNumbers {
+    type Item = (i64, u64);
+
+    fn next(&mut self) -> Option<(i64, u64)> {
+        while self.next >= self.last {
+            let d = self.next;
+            self.next -= 1;
+            if is_fundamental_discriminant(d) {
+                return Some((d, class_number(d)));
+            }
+        }
+        None
+    }
+}
+
+/// Iterate over the negative fundamental discriminants in `range`, in order
+/// of increasing absolute value, with their class numbers. Positive values in
+/// the range are skipped.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let table: Vec<(i64, u64)> = class_numbers(-24..0).collect();
+/// assert_eq!(table, [
+///     (-3, 1), (-4, 1), (-7, 1), (-8, 1), (-11, 1), 
+///     (-15, 2), (-19, 1), (-20, 2), (-23, 3), (-24, 2)
+/// ]);
+/// assert_eq!(class_numbers(..=i64::MIN).count(), 0);
+/// ```
+pub fn class_numbers<R: RangeBounds<i64>>(range: R) -> ClassNumbers {
+    let last = match range.start_bound() {
+        Bound::Included(&a) => a,
+        Bound::Excluded(&a) => a.saturating_add(1),
+        Bound::Unbounded => i64::MIN,
+    };
+    let next = match range.end_bound() {
+        Bound::Included(&b) => b,
+        Bound::Excluded(&b) => b.saturating_sub(1),
+        Bound::Unbounded => -1,
+    };
+    // -2^63 is not fundamental, and excluding it keeps `next` from 
+    // overflowing below the range
+    ClassNumbers { next: next.min(-1), last: last.max(i64::MIN + 1) }
+}
+
+impl BinQuadForm {
+    /// Search the negative fundamental discriminants in `range` for those of
+    /// class number `h`. Returns each such discriminant, in order of 
+    /// increasing absolute value, with its reduced primitive forms, one in 
+    /// each class.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // the imaginary quadratic fields of class number one
+    /// let found = BinQuadForm::forms_with_class_number(1, -200..0);
+    /// let ds: Vec<i64> = found.iter().map(|(d, _)| *d).collect();
+    /// assert_eq!(ds, [-3, -4, -7, -8, -11, -19, -43, -67, -163]);
+    /// assert_eq!(found[0].1, vec![BinQuadForm::principal(Integer::from(-3))]);
+    /// ```
+    pub fn forms_with_class_number<R: RangeBounds<i64>>(
+        h: u64, 
+        range: R
+    ) -> Vec<(i64, Vec<BinQuadForm>)> {
+        class_numbers(range)
+            .filter(|&(_, hd)| hd == h)
+            .map(|(d, _)| (d, reduced_forms(d)))
+            .collect()
+    }
+}
@@ -0,0 +1,149 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use arb_sys::arb::*;
+
+// Expand the quadratic irrational (p0 + sqrt(d))/q0, where q0 > 0 divides
+// d - p0^2, as a continued fraction until the first complete quotient with
+// denominator q0 again. Returns the last convergent p/q and the sign s such
+// that (q0 p - p0 q)^2 - d q^2 = s q0^2.
+fn cf_period(d: &Integer, p0: i64, q0: i64) -> (Integer, Integer, i32) {
+    let s = d.sqrt();
+    let (mut p, mut q) = (Integer::from(p0), Integer::from(q0));
+    // convergents h_k/k_k, starting from h_{-1}/k_{-1} = 1/0 and h_{-2}/k_{-2} = 0/1
+    let (mut h, mut h1) = (Integer::one(), Integer::zero());
+    let (mut k, mut k1) = (Integer::zero(), Integer::one());
+    let mut sign = 1;
+    loop {
+        check_interrupt();
+        let a = (&p + &s).fdiv_q(&q);
+        let hn = &a * &h + &h1;
+        let kn = &a * &k + &k1;
+        h1 = h;
+        k1 = k;
+        h = hn;
+        k = kn;
+        sign = -sign;
+        p = &a * &q - p;
+        q = (d - &p * &p).fdiv_q(&q);
+        if q == q0 {
+            return (h, k, sign);
+        }
+    }
+}
+
+fn check_real_discriminant(d: i64) {
+    assert!(
+        d > 0 && (d.rem_euclid(4) == 0 || d.rem_euclid(4) == 1),
+        "The discriminant must be positive and congruent to 0 or 1 mod 4."
+    );
+    assert!(!Integer::from(d).is_square(), "The discriminant must not be a square.");
+}
+
+/// Return the fundamental solution `(x, y)` of the Pell equation 
+/// `x^2 - d y^2 = 1`, the solution in positive integers with `x` minimal,
+/// found from the period of the continued fraction of `sqrt(d)`. Panics 
+/// unless `d` is positive and not a square.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let (x, y) = pell_solve(61);
+/// assert_eq!(x, 1766319049);
+/// assert_eq!(y, 226153980);
+/// ```
+pub fn pell_solve(d: i64) -> (Integer, Integer) {
+    assert!(d > 0 && !Integer::from(d).is_square(), "d must be positive and not a square.");
+    let d = Integer::from(d);
+    let (x, y, sign) = cf_period(&d, 0, 1);
+    if sign == 1 {
+        (x, y)
+    } else {
+        // square the solution of the negative equation
+        (&x * &x + &d * &y * &y, Integer::from(2) * &x * &y)
+    }
+}
+
+/// Return the fundamental solution `(x, y)` of the negative Pell equation
+/// `x^2 - d y^2 = -1`, or `None` if it has no solutions. Panics unless `d` 
+/// is positive and not a square.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// assert_eq!(pell_solve_negative(13), Some((Integer::from(18), Integer::from(5))));
+/// assert_eq!(pell_solve_negative(3), None);
+/// ```
+pub fn pell_solve_negative(d: i64) -> Option<(Integer, Integer)> {
+    assert!(d > 0 && !Integer::from(d).is_square(), "d must be positive and not a square.");
+    let (x, y, sign) = cf_period(&Integer::from(d), 0, 1);
+    if sign == -1 {
+        Some((x, y))
+    } else {
+        None
+    }
+}
+
+/// Return the fundamental unit `e = (t + u sqrt(d))/2 > 1` of the real 
+/// quadratic order of discriminant `d` as the pair `(t, u)`. The unit has 
+/// norm `(t^2 - d u^2)/4 = 1` or `-1`. Panics unless `d` is a positive 
+/// non-square discriminant, that is, congruent to 0 or 1 mod 4.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// // the golden ratio (1 + sqrt(5))/2, of norm -1
+/// assert_eq!(fundamental_unit(5), (Integer::from(1), Integer::from(1)));
+/// // 2 + sqrt(3) = (4 + sqrt(12))/2
+/// assert_eq!(fundamental_unit(12), (Integer::from(4), Integer::from(1)));
+/// ```
+pub fn fundamental_unit(d: i64) -> (Integer, Integer) {
+    check_real_discriminant(d);
+    // the order is Z[w] with w = (r + sqrt(d))/2
+    let r = d.rem_euclid(2);
+    let (p, q, _) = cf_period(&Integer::from(d), r, 2);
+    (Integer::from(2) * &p - Integer::from(r) * &q, q)
+}
+
+/// Return the regulator `log(e)` of the real quadratic order of 
+/// discriminant `d`, where `e` is its [fundamental_unit], as a ball computed
+/// to `prec` bits.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let r = regulator(5, 64);
+/// // log((1 + sqrt(5))/2) = 0.4812118250596...
+/// let approx = Real::from_str_prec("0.4812118250596 +/- 1e-13", 64).unwrap();
+/// assert!(approx.contains(&r));
+/// ```
+pub fn regulator(d: i64, prec: u64) -> Real {
+    let (t, u) = fundamental_unit(d);
+    let prec_si = prec.try_into().expect("Cannot convert precision to a signed long.");
+    let mut res = Real::default();
+    unsafe {
+        let mut x = Real::default();
+        arb_sqrt_ui(x.as_mut_ptr(), d.unsigned_abs(), prec_si);
+        arb_mul_fmpz(x.as_mut_ptr(), x.as_ptr(), u.as_ptr(), prec_si);
+        arb_add_fmpz(x.as_mut_ptr(), x.as_ptr(), t.as_ptr(), prec_si);
+        arb_mul_2exp_si(x.as_mut_ptr(), x.as_ptr(), -1);
+        arb_log(res.as_mut_ptr(), x.as_ptr(), prec_si);
+    }
+    res.set_prec(prec);
+    res
+}
@@ -0,0 +1,516 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! A C ABI over opaque handles, for using the crate from languages with a C 
+//! FFI (Python via `ctypes`, Julia via `ccall`, ...). Enabled by the `capi` 
+//! feature; build a `cdylib` depending on this crate with the feature 
+//! enabled to get a shared library exporting these symbols.
+//!
+//! Every object is returned as an owned pointer that must be released with 
+//! the matching `*_free` function, and strings returned by `*_to_str` must be
+//! released with [inertia_string_free]. Functions never take ownership of 
+//! their pointer arguments. On invalid input (a null pointer, a malformed 
+//! string, mismatched dimensions) the functions return a null pointer 
+//! instead of unwinding into the caller.
+//!
+//! ```c
+//! inertia_integer_t *a = inertia_integer_from_str("-123456789012345678901234567890");
+//! inertia_integer_t *b = inertia_integer_from_si(3);
+//! inertia_integer_t *c = inertia_integer_mul(a, b);
+//! char *s = inertia_integer_to_str(c);
+//! printf("%s\n", s);
+//! inertia_string_free(s);
+//! inertia_integer_free(a);
+//! inertia_integer_free(b);
+//! inertia_integer_free(c);
+//! ```
+
+use crate::{Integer, IntMat, IntPoly};
+use libc::c_char;
+use std::ffi::{CStr, CString};
+use std::panic::{self, UnwindSafe};
+use std::ptr;
+
+/// The factorization of an [IntPoly], see [inertia_intpoly_factor].
+pub struct IntPolyFactors {
+    content: Integer,
+    factors: Vec<(IntPoly, u64)>,
+}
+
+// Run `f`, returning a null pointer if it panics.
+fn guard<T, F>(f: F) -> *mut T
+where
+    F: FnOnce() -> Option<T> + UnwindSafe
+{
+    match panic::catch_unwind(f) {
+        Ok(Some(x)) => Box::into_raw(Box::new(x)),
+        _ => ptr::null_mut(),
+    }
+}
+
+unsafe fn free<T>(x: *mut T) {
+    if !x.is_null() {
+        drop(Box::from_raw(x));
+    }
+}
+
+// Borrow a handle, or `None` if it is null.
+unsafe fn borrow<'a, T>(x: *const T) -> Option<&'a T> {
+    x.as_ref()
+}
+
+fn to_c_string(s: String) -> *mut c_char {
+    match CString::new(s) {
+        Ok(s) => s.into_raw(),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Free a string returned by one of the `*_to_str` functions.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by this module that has not been 
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Return the integer `x`.
+#[no_mangle]
+pub extern "C" fn inertia_integer_from_si(x: i64) -> *mut Integer {
+    guard(|| Some(Integer::from(x)))
+}
+
+/// Parse a decimal integer with an optional leading `-`. Returns null if the 
+/// string is not a valid integer.
+///
+/// # Safety
+///
+/// `s` must be null or a valid nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_integer_from_str(s: *const c_char) -> *mut Integer {
+    if s.is_null() {
+        return ptr::null_mut();
+    }
+    let s = CStr::from_ptr(s);
+    guard(|| {
+        let s = s.to_str().ok()?;
+        match s.strip_prefix('-') {
+            Some(digits) => digits.parse::<Integer>().ok().map(|x| -x),
+            None => s.parse().ok(),
+        }
+    })
+}
+
+/// Free an integer.
+///
+/// # Safety
+///
+/// `x` must be null or an integer returned by this module that has not been 
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_integer_free(x: *mut Integer) {
+    free(x)
+}
+
+/// Return the decimal representation of an integer.
+///
+/// # Safety
+///
+/// `x` must be null or a valid integer handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_integer_to_str(x: *const Integer) -> *mut c_char {
+    match borrow(x) {
+        Some(x) => to_c_string(x.to_string()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Compare two integers, returning -1, 0 or 1. Null handles compare as 
+/// equal.
+///
+/// # Safety
+///
+/// `x` and `y` must be null or valid integer handles.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_integer_cmp(x: *const Integer, y: *const Integer) -> i32 {
+    match (borrow(x), borrow(y)) {
+        (Some(x), Some(y)) => x.cmp(y) as i32,
+        _ => 0,
+    }
+}
+
+macro_rules! capi_integer_binop {
+    ($($name:ident, $op:tt;)+) => {
+        $(
+            /// Return a new integer holding the result of the operation.
+            ///
+            /// # Safety
+            ///
+            /// `x` and `y` must be null or valid integer handles.
+            #[no_mangle]
+            pub unsafe extern "C" fn $name(
+                x: *const Integer, 
+                y: *const Integer
+            ) -> *mut Integer {
+                let (x, y) = match (borrow(x), borrow(y)) {
+                    (Some(x), Some(y)) => (x, y),
+                    _ => return ptr::null_mut(),
+                };
+                guard(|| Some(x $op y))
+            }
+        )+
+    }
+}
+
+capi_integer_binop! {
+    inertia_integer_add, +;
+    inertia_integer_sub, -;
+    inertia_integer_mul, *;
+}
+
+/// Return the floor of `x / y`, or null if `y` is zero.
+///
+/// # Safety
+///
+/// `x` and `y` must be null or valid integer handles.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_integer_fdiv_q(
+    x: *const Integer, 
+    y: *const Integer
+) -> *mut Integer {
+    let (x, y) = match (borrow(x), borrow(y)) {
+        (Some(x), Some(y)) => (x, y),
+        _ => return ptr::null_mut(),
+    };
+    guard(|| if y.is_zero() { None } else { Some(x.fdiv_q(y)) })
+}
+
+/// Return the `nrows` by `ncols` integer matrix with the given entries in 
+/// row-major order.
+///
+/// # Safety
+///
+/// `entries` must point to `nrows * ncols` values, or may be null if the 
+/// matrix is empty.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_from_si(
+    entries: *const i64, 
+    nrows: i64, 
+    ncols: i64
+) -> *mut IntMat {
+    if nrows < 0 || ncols < 0 {
+        return ptr::null_mut();
+    }
+    let len = match nrows.checked_mul(ncols).and_then(|n| usize::try_from(n).ok()) {
+        Some(len) => len,
+        None => return ptr::null_mut(),
+    };
+    if len > 0 && entries.is_null() {
+        return ptr::null_mut();
+    }
+    let entries = if len == 0 { &[] } else { std::slice::from_raw_parts(entries, len) };
+    guard(|| Some(IntMat::new(entries, nrows, ncols)))
+}
+
+/// Free an integer matrix.
+///
+/// # Safety
+///
+/// `m` must be null or a matrix returned by this module that has not been 
+/// freed.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_free(m: *mut IntMat) {
+    free(m)
+}
+
+/// Return the number of rows of a matrix, or -1 for a null handle.
+///
+/// # Safety
+///
+/// `m` must be null or a valid matrix handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_nrows(m: *const IntMat) -> i64 {
+    borrow(m).map_or(-1, |m| m.nrows_si())
+}
+
+/// Return the number of columns of a matrix, or -1 for a null handle.
+///
+/// # Safety
+///
+/// `m` must be null or a valid matrix handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_ncols(m: *const IntMat) -> i64 {
+    borrow(m).map_or(-1, |m| m.ncols_si())
+}
+
+/// Return a copy of the `(i, j)`-th entry of a matrix, or null if the index 
+/// is out of range.
+///
+/// # Safety
+///
+/// `m` must be null or a valid matrix handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_get_entry(
+    m: *const IntMat, 
+    i: usize, 
+    j: usize
+) -> *mut Integer {
+    match borrow(m) {
+        Some(m) if i < m.nrows() && j < m.ncols() => guard(|| Some(m.get_entry(i, j))),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Return the product of two matrices, or null if the dimensions do not 
+/// match.
+///
+/// # Safety
+///
+/// `a` and `b` must be null or valid matrix handles.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_mul(a: *const IntMat, b: *const IntMat) -> *mut IntMat {
+    match (borrow(a), borrow(b)) {
+        (Some(a), Some(b)) if a.ncols() == b.nrows() => guard(|| Some(a * b)),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Return the determinant of a square matrix, or null if it is not square.
+///
+/// # Safety
+///
+/// `m` must be null or a valid matrix handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_det(m: *const IntMat) -> *mut Integer {
+    match borrow(m) {
+        Some(m) if m.is_square() => guard(|| Some(m.det())),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Return the Hermite normal form of a matrix.
+///
+/// # Safety
+///
+/// `m` must be null or a valid matrix handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_hnf(m: *const IntMat) -> *mut IntMat {
+    match borrow(m) {
+        Some(m) => guard(|| Some(m.hnf())),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Return a string representation of a matrix.
+///
+/// # Safety
+///
+/// `m` must be null or a valid matrix handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intmat_to_str(m: *const IntMat) -> *mut c_char {
+    match borrow(m) {
+        Some(m) => to_c_string(m.to_string()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Return the polynomial with the given `len` coefficients, constant term 
+/// first.
+///
+/// # Safety
+///
+/// `coeffs` must point to `len` values, or may be null if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_from_si(coeffs: *const i64, len: usize) -> *mut IntPoly {
+    if len > 0 && coeffs.is_null() {
+        return ptr::null_mut();
+    }
+    let coeffs = if len == 0 { &[] } else { std::slice::from_raw_parts(coeffs, len) };
+    guard(|| Some(IntPoly::from(coeffs)))
+}
+
+/// Free a polynomial.
+///
+/// # Safety
+///
+/// `f` must be null or a polynomial returned by this module that has not 
+/// been freed.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_free(f: *mut IntPoly) {
+    free(f)
+}
+
+/// Return a string representation of a polynomial.
+///
+/// # Safety
+///
+/// `f` must be null or a valid polynomial handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_to_str(f: *const IntPoly) -> *mut c_char {
+    match borrow(f) {
+        Some(f) => to_c_string(f.to_string()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Return the product of two polynomials.
+///
+/// # Safety
+///
+/// `f` and `g` must be null or valid polynomial handles.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_mul(f: *const IntPoly, g: *const IntPoly) -> *mut IntPoly {
+    match (borrow(f), borrow(g)) {
+        (Some(f), Some(g)) => guard(|| Some(f * g)),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Factor a polynomial over the integers, see [IntPoly::factor]. The result 
+/// is read with the `inertia_intpoly_factors_*` functions.
+///
+/// # Safety
+///
+/// `f` must be null or a valid polynomial handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_factor(f: *const IntPoly) -> *mut IntPolyFactors {
+    match borrow(f) {
+        Some(f) => guard(|| {
+            let (content, factors) = f.factor();
+            Some(IntPolyFactors { content, factors })
+        }),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Free a factorization.
+///
+/// # Safety
+///
+/// `fac` must be null or a factorization returned by this module that has 
+/// not been freed.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_factors_free(fac: *mut IntPolyFactors) {
+    free(fac)
+}
+
+/// Return the number of distinct irreducible factors, or zero for a null 
+/// handle.
+///
+/// # Safety
+///
+/// `fac` must be null or a valid factorization handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_factors_len(fac: *const IntPolyFactors) -> usize {
+    borrow(fac).map_or(0, |fac| fac.factors.len())
+}
+
+/// Return a copy of the signed content of the factored polynomial.
+///
+/// # Safety
+///
+/// `fac` must be null or a valid factorization handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_factors_content(
+    fac: *const IntPolyFactors
+) -> *mut Integer {
+    match borrow(fac) {
+        Some(fac) => guard(|| Some(fac.content.clone())),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Return a copy of the `i`-th irreducible factor, or null if `i` is out of 
+/// range.
+///
+/// # Safety
+///
+/// `fac` must be null or a valid factorization handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_factors_get(
+    fac: *const IntPolyFactors, 
+    i: usize
+) -> *mut IntPoly {
+    match borrow(fac).and_then(|fac| fac.factors.get(i)) {
+        Some((f, _)) => guard(|| Some(f.clone())),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Return the multiplicity of the `i`-th irreducible factor, or zero if `i`
+/// is out of range.
+///
+/// # Safety
+///
+/// `fac` must be null or a valid factorization handle.
+#[no_mangle]
+pub unsafe extern "C" fn inertia_intpoly_factors_exp(
+    fac: *const IntPolyFactors, 
+    i: usize
+) -> u64 {
+    borrow(fac).and_then(|fac| fac.factors.get(i)).map_or(0, |(_, e)| *e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capi_roundtrip() {
+        unsafe {
+            let s = CString::new("-123456789012345678901234567890").unwrap();
+            let a = inertia_integer_from_str(s.as_ptr());
+            let b = inertia_integer_from_si(-3);
+            let c = inertia_integer_mul(a, b);
+            let out = inertia_integer_to_str(c);
+            assert_eq!(
+                CStr::from_ptr(out).to_str().unwrap(), 
+                "370370367037037036703703703670"
+            );
+            inertia_string_free(out);
+            let zero = inertia_integer_from_si(0);
+            assert!(inertia_integer_fdiv_q(a, zero).is_null());
+            let bad = CString::new("12a").unwrap();
+            assert!(inertia_integer_from_str(bad.as_ptr()).is_null());
+            for x in [a, b, c, zero] {
+                inertia_integer_free(x);
+            }
+
+            let m = inertia_intmat_from_si([2, 4, 1, 3].as_ptr(), 2, 2);
+            let d = inertia_intmat_det(m);
+            assert_eq!(*d, 2);
+            let n = inertia_intmat_from_si([1].as_ptr(), 1, 1);
+            assert!(inertia_intmat_mul(m, n).is_null());
+            inertia_integer_free(d);
+            inertia_intmat_free(m);
+            inertia_intmat_free(n);
+
+            let f = inertia_intpoly_from_si([-1, 0, 1].as_ptr(), 3);
+            let fac = inertia_intpoly_factor(f);
+            assert_eq!(inertia_intpoly_factors_len(fac), 2);
+            assert_eq!(inertia_intpoly_factors_exp(fac, 0), 1);
+            assert!(inertia_intpoly_factors_get(fac, 2).is_null());
+            inertia_intpoly_factors_free(fac);
+            inertia_intpoly_free(f);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// Checked variants of operations that panic on invalid input, see [Error].
+
+use crate::*;
+use crate::Error::*;
+
+impl Integer {
+    /// Return the quotient `self/other` as a rational number, or an error if
+    /// `other` is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Integer::from(3);
+    /// assert_eq!(x.try_div(6).unwrap(), Rational::from([1, 2]));
+    /// assert!(matches!(x.try_div(0), Err(Error::DivisionError(_))));
+    /// ```
+    pub fn try_div<T: Into<Integer>>(&self, other: T) -> Result<Rational> {
+        let other = other.into();
+        if other.is_zero() {
+            return Err(DivisionError("Division by zero.".to_string()));
+        }
+        Ok(self / other)
+    }
+}
+
+impl Rational {
+    /// Return the quotient `self/other`, or an error if `other` is zero.
+    pub fn try_div<T: Into<Rational>>(&self, other: T) -> Result<Rational> {
+        let other = other.into();
+        if other.is_zero() {
+            return Err(DivisionError("Division by zero.".to_string()));
+        }
+        Ok(self / other)
+    }
+}
+
+impl IntMod {
+    /// Return the inverse, or an error if the element is not a unit.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = IntModCtx::new(10);
+    /// assert_eq!(IntMod::new(3, &zn).try_inverse().unwrap(), 7);
+    /// assert!(matches!(IntMod::new(4, &zn).try_inverse(), Err(Error::NotInvertibleError(_))));
+    /// ```
+    pub fn try_inverse(&self) -> Result<IntMod> {
+        if !Integer::from(self).gcd(self.modulus()).is_one() {
+            return Err(NotInvertibleError(format!("{} modulo {}", self, self.modulus())));
+        }
+        Ok(self.inv())
+    }
+
+    /// Return the quotient `self/other`, or an error if the contexts differ or
+    /// `other` is not a unit.
+    pub fn try_div<T: AsRef<IntMod>>(&self, other: T) -> Result<IntMod> {
+        let other = other.as_ref();
+        if self.context() != other.context() {
+            return Err(ContextError(format!(
+                "Cannot divide an element modulo {} by an element modulo {}.",
+                self.modulus(),
+                other.modulus()
+            )));
+        }
+        Ok(self * other.try_inverse()?)
+    }
+}
+
+impl Nmod {
+    /// Return the inverse, or an error if the element is not a unit.
+    pub fn try_inverse(&self) -> Result<Nmod> {
+        self.inv().ok_or_else(|| {
+            NotInvertibleError(format!("{} modulo {}", self, self.context().modulus()))
+        })
+    }
+
+    /// Return the quotient `self/other`, or an error if the contexts differ or
+    /// `other` is not a unit.
+    pub fn try_div(&self, other: Nmod) -> Result<Nmod> {
+        if self.context() != other.context() {
+            return Err(ContextError(format!(
+                "Cannot divide an element modulo {} by an element modulo {}.",
+                self.context().modulus(),
+                other.context().modulus()
+            )));
+        }
+        Ok(*self * other.try_inverse()?)
+    }
+}
+
+impl IntModCtx {
+    /// Return the context of the integers modulo `modulus`, or an error if
+    /// the modulus is not positive.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert!(IntModCtx::try_new(7).is_ok());
+    /// assert!(matches!(IntModCtx::try_new(-7), Err(Error::RingError(_))));
+    /// ```
+    pub fn try_new<T: Into<Integer>>(modulus: T) -> Result<IntModCtx> {
+        let modulus = modulus.into();
+        if modulus.sign() <= 0 {
+            return Err(RingError(format!("The modulus {} is not positive.", modulus)));
+        }
+        Ok(IntModCtx::new(modulus))
+    }
+}
+
+impl NmodRing {
+    /// Return the ring of integers modulo `n`, or an error if `n` is zero.
+    pub fn try_new(n: u64) -> Result<NmodRing> {
+        if n == 0 {
+            return Err(RingError("The modulus is zero.".to_string()));
+        }
+        Ok(NmodRing::new(n))
+    }
+}
+
+impl FinFldCtx {
+    /// Return the context of the finite field of order `p^k`, or an error if
+    /// `p` is not prime or `k` is not positive.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(FinFldCtx::try_new(5, 2).unwrap().degree(), 2);
+    /// assert!(matches!(FinFldCtx::try_new(6, 2), Err(Error::RingError(_))));
+    /// assert!(matches!(FinFldCtx::try_new(5, 0), Err(Error::RingError(_))));
+    /// ```
+    pub fn try_new<P: Into<Integer>>(p: P, k: i64) -> Result<FinFldCtx> {
+        let p = p.into();
+        if !p.is_prime() {
+            return Err(RingError(format!("The characteristic {} is not prime.", p)));
+        }
+        if k < 1 {
+            return Err(RingError(format!("The degree {} is not positive.", k)));
+        }
+        Ok(FinFldCtx::new(p, k))
+    }
+}
+
+// Checked matrix arithmetic. The second form also compares the contexts.
+macro_rules! impl_try_matrix_ops {
+    ($t:ident) => {
+        impl_try_matrix_ops!(@impl $t, |_a: &$t, _b: &$t| Ok(()));
+    };
+    ($t:ident, ctx) => {
+        impl_try_matrix_ops!(@impl $t, |a: &$t, b: &$t| {
+            if a.context() == b.context() {
+                Ok(())
+            } else {
+                Err(ContextError("The matrices have different moduli.".to_string()))
+            }
+        });
+    };
+    (@impl $t:ident, $check_ctx:expr) => {
+        impl $t {
+            /// Return the sum of two matrices, or an error if their
+            /// dimensions or contexts differ.
+            pub fn try_add(&self, other: &$t) -> Result<$t> {
+                $check_ctx(self, other)?;
+                if self.nrows() != other.nrows() || self.ncols() != other.ncols() {
+                    return Err(DimensionError(format!(
+                        "Cannot add a {} by {} matrix and a {} by {} matrix.",
+                        self.nrows(), self.ncols(), other.nrows(), other.ncols()
+                    )));
+                }
+                Ok(self + other)
+            }
+
+            /// Return the difference of two matrices, or an error if their
+            /// dimensions or contexts differ.
+            pub fn try_sub(&self, other: &$t) -> Result<$t> {
+                $check_ctx(self, other)?;
+                if self.nrows() != other.nrows() || self.ncols() != other.ncols() {
+                    return Err(DimensionError(format!(
+                        "Cannot subtract a {} by {} matrix from a {} by {} matrix.",
+                        other.nrows(), other.ncols(), self.nrows(), self.ncols()
+                    )));
+                }
+                Ok(self - other)
+            }
+
+            /// Return the product of two matrices, or an error if the number
+            /// of columns of `self` is not the number of rows of `other` or
+            /// the contexts differ.
+            pub fn try_mul(&self, other: &$t) -> Result<$t> {
+                $check_ctx(self, other)?;
+                if self.ncols() != other.nrows() {
+                    return Err(DimensionError(format!(
+                        "Cannot multiply a {} by {} matrix by a {} by {} matrix.",
+                        self.nrows(), self.ncols(), other.nrows(), other.ncols()
+                    )));
+                }
+                Ok(self * other)
+            }
+        }
+    };
+}
+
+impl_try_matrix_ops!(IntMat);
+impl_try_matrix_ops!(RatMat);
+impl_try_matrix_ops!(IntModMat, ctx);
+impl_try_matrix_ops!(NmodMat, ctx);
+
+// Checked inverses of square matrices over fields.
+macro_rules! impl_try_inverse {
+    ($($t:ident)*) => ($(
+        impl $t {
+            /// Return the inverse of the matrix, or an error if it is not
+            /// square or is singular.
+            pub fn try_inverse(&self) -> Result<$t> {
+                if !self.is_square() {
+                    return Err(NotSquareError { nrows: self.nrows(), ncols: self.ncols() });
+                }
+                self.inv().ok_or_else(|| NotInvertibleError("The matrix is singular.".to_string()))
+            }
+        }
+    )*)
+}
+
+impl_try_inverse! {RatMat NmodMat FinFldMat}
+
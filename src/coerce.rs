@@ -0,0 +1,336 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Canonical maps between rings. An element is mapped into a ring by passing
+//! the parent of the target ring to [Coerce::coerce_into]: a context such as
+//! [IntModCtx] or [RealField], or one of the parent objects of the
+//! polynomial rings and matrix spaces.
+//!
+//! ```
+//! use inertia_core::*;
+//!
+//! let x = Integer::from(7);
+//! assert_eq!(x.coerce_into(&RationalField), Rational::from(7));
+//!
+//! let zn = IntModCtx::new(5);
+//! assert_eq!(x.coerce_into(&zn), 2);
+//!
+//! let r = RealField::init(64);
+//! let y = Rational::from([1, 3]).coerce_into(&r);
+//! assert_eq!(y.prec(), 64);
+//! assert!(y.overlaps(&Real::from(Rational::from([1, 3]))));
+//!
+//! let f = IntPoly::from([3, 6, 9]);
+//! assert_eq!(f.coerce_into(&IntModPolyRing::init(&zn)), IntModPoly::new([3, 1, 4], &zn));
+//! ```
+
+use crate::*;
+
+/// The canonical map from the parent of `Self` into the ring with parent
+/// `R`.
+///
+/// Most maps are defined on all elements. A map that is only defined under a
+/// compatibility condition on the target, such as reduction from `Z/nZ` to
+/// `Z/mZ` which requires `m` to divide `n`, panics if the condition fails.
+pub trait Coerce<R> {
+    type Output;
+
+    /// Return the image of `self` in the ring with parent `ring`.
+    fn coerce_into(&self, ring: &R) -> Self::Output;
+}
+
+// Integers
+
+impl Coerce<IntegerRing> for Integer {
+    type Output = Integer;
+    #[inline]
+    fn coerce_into(&self, _ring: &IntegerRing) -> Integer {
+        self.clone()
+    }
+}
+
+impl Coerce<RationalField> for Integer {
+    type Output = Rational;
+    #[inline]
+    fn coerce_into(&self, _ring: &RationalField) -> Rational {
+        Rational::from(self)
+    }
+}
+
+impl Coerce<RealField> for Integer {
+    type Output = Real;
+    #[inline]
+    fn coerce_into(&self, ring: &RealField) -> Real {
+        ring.new(self)
+    }
+}
+
+impl Coerce<ComplexField> for Integer {
+    type Output = Complex;
+    #[inline]
+    fn coerce_into(&self, ring: &ComplexField) -> Complex {
+        ring.new(self)
+    }
+}
+
+impl Coerce<IntModCtx> for Integer {
+    type Output = IntMod;
+    #[inline]
+    fn coerce_into(&self, ring: &IntModCtx) -> IntMod {
+        IntMod::new(self.clone(), ring)
+    }
+}
+
+impl Coerce<NmodRing> for Integer {
+    type Output = Nmod;
+    #[inline]
+    fn coerce_into(&self, ring: &NmodRing) -> Nmod {
+        Nmod::new(self.clone(), ring)
+    }
+}
+
+impl Coerce<FinFldCtx> for Integer {
+    type Output = FinFldElem;
+    #[inline]
+    fn coerce_into(&self, ring: &FinFldCtx) -> FinFldElem {
+        FinFldElem::new(&IntPoly::from(self), ring)
+    }
+}
+
+impl Coerce<NumFldCtx> for Integer {
+    type Output = NumFldElem;
+    #[inline]
+    fn coerce_into(&self, ring: &NumFldCtx) -> NumFldElem {
+        NumFldElem::new(&RatPoly::from(self), ring)
+    }
+}
+
+impl Coerce<IntPolyRing> for Integer {
+    type Output = IntPoly;
+    #[inline]
+    fn coerce_into(&self, _ring: &IntPolyRing) -> IntPoly {
+        IntPoly::from(self)
+    }
+}
+
+impl Coerce<RatPolyRing> for Integer {
+    type Output = RatPoly;
+    #[inline]
+    fn coerce_into(&self, _ring: &RatPolyRing) -> RatPoly {
+        RatPoly::from(self)
+    }
+}
+
+impl Coerce<IntModPolyRing> for Integer {
+    type Output = IntModPoly;
+    #[inline]
+    fn coerce_into(&self, ring: &IntModPolyRing) -> IntModPoly {
+        ring.new(IntPoly::from(self))
+    }
+}
+
+// Rationals
+
+impl Coerce<RationalField> for Rational {
+    type Output = Rational;
+    #[inline]
+    fn coerce_into(&self, _ring: &RationalField) -> Rational {
+        self.clone()
+    }
+}
+
+impl Coerce<RealField> for Rational {
+    type Output = Real;
+    /// Round to the precision of the field.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Rational::from([1, 3]).coerce_into(&RealField::init(1000));
+    /// assert!(x.rel_accuracy_bits() > 990);
+    /// ```
+    #[inline]
+    fn coerce_into(&self, ring: &RealField) -> Real {
+        self.to_real(ring.prec())
+    }
+}
+
+impl Coerce<ComplexField> for Rational {
+    type Output = Complex;
+    /// Round to the precision of the field.
+    #[inline]
+    fn coerce_into(&self, ring: &ComplexField) -> Complex {
+        ring.new(self.to_real(ring.prec()))
+    }
+}
+
+impl Coerce<NumFldCtx> for Rational {
+    type Output = NumFldElem;
+    #[inline]
+    fn coerce_into(&self, ring: &NumFldCtx) -> NumFldElem {
+        NumFldElem::new(&RatPoly::from(self), ring)
+    }
+}
+
+impl Coerce<RatPolyRing> for Rational {
+    type Output = RatPoly;
+    #[inline]
+    fn coerce_into(&self, _ring: &RatPolyRing) -> RatPoly {
+        RatPoly::from(self)
+    }
+}
+
+// Reals
+
+impl Coerce<RealField> for Real {
+    type Output = Real;
+    /// Round to the precision of the field.
+    #[inline]
+    fn coerce_into(&self, ring: &RealField) -> Real {
+        ring.new(self.clone())
+    }
+}
+
+impl Coerce<ComplexField> for Real {
+    type Output = Complex;
+    #[inline]
+    fn coerce_into(&self, ring: &ComplexField) -> Complex {
+        ring.new(self)
+    }
+}
+
+// Integers mod n
+
+impl Coerce<IntModCtx> for IntMod {
+    type Output = IntMod;
+    /// Reduce from `Z/nZ` to `Z/mZ`. Panics unless `m` divides `n`.
+    fn coerce_into(&self, ring: &IntModCtx) -> IntMod {
+        assert!(
+            self.modulus().divisible(ring.modulus()),
+            "No canonical map from Z/nZ to Z/mZ unless m divides n."
+        );
+        IntMod::new(Integer::from(self), ring)
+    }
+}
+
+// Polynomials
+
+impl Coerce<IntPolyRing> for IntPoly {
+    type Output = IntPoly;
+    #[inline]
+    fn coerce_into(&self, _ring: &IntPolyRing) -> IntPoly {
+        self.clone()
+    }
+}
+
+impl Coerce<RatPolyRing> for IntPoly {
+    type Output = RatPoly;
+    #[inline]
+    fn coerce_into(&self, _ring: &RatPolyRing) -> RatPoly {
+        RatPoly::from(self)
+    }
+}
+
+impl Coerce<IntModPolyRing> for IntPoly {
+    type Output = IntModPoly;
+    #[inline]
+    fn coerce_into(&self, ring: &IntModPolyRing) -> IntModPoly {
+        ring.new(self.clone())
+    }
+}
+
+impl Coerce<FinFldPolyRing> for IntPoly {
+    type Output = FinFldPoly;
+    /// Map each coefficient into the prime subfield.
+    fn coerce_into(&self, ring: &FinFldPolyRing) -> FinFldPoly {
+        let coeffs: Vec<FinFldElem> = self.get_coeffs()
+            .iter()
+            .map(|c| c.coerce_into(ring.context()))
+            .collect();
+        ring.new(&coeffs)
+    }
+}
+
+impl Coerce<RatPolyRing> for RatPoly {
+    type Output = RatPoly;
+    #[inline]
+    fn coerce_into(&self, _ring: &RatPolyRing) -> RatPoly {
+        self.clone()
+    }
+}
+
+impl Coerce<NumFldCtx> for RatPoly {
+    type Output = NumFldElem;
+    /// Map `x` to the generator of the number field, i.e. reduce modulo the
+    /// defining polynomial.
+    #[inline]
+    fn coerce_into(&self, ring: &NumFldCtx) -> NumFldElem {
+        NumFldElem::new(self, ring)
+    }
+}
+
+// Matrices
+
+impl Coerce<RatMatSpace> for IntMat {
+    type Output = RatMat;
+    /// Panics if the dimensions do not match the space.
+    fn coerce_into(&self, ring: &RatMatSpace) -> RatMat {
+        assert!(self.nrows() == ring.nrows() && self.ncols() == ring.ncols());
+        RatMat::from(self)
+    }
+}
+
+impl Coerce<IntModMatSpace> for IntMat {
+    type Output = IntModMat;
+    /// Panics if the dimensions do not match the space.
+    fn coerce_into(&self, ring: &IntModMatSpace) -> IntModMat {
+        assert!(self.nrows() == ring.nrows() && self.ncols() == ring.ncols());
+        self.reduce_mod(ring.context())
+    }
+}
+
+// Scalar matrices. The image of `c` in a square matrix space is `c` times the
+// identity, so these panic unless the space is square.
+
+macro_rules! impl_coerce_scalar_mat {
+    ($t:ident, $space:ident, $out:ident, $conv:expr) => {
+        impl Coerce<$space> for $t {
+            type Output = $out;
+            fn coerce_into(&self, ring: &$space) -> $out {
+                assert_eq!(ring.nrows(), ring.ncols(), "The matrix space must be square.");
+                let c = $conv(self, ring);
+                let mut res = ring.zero();
+                for i in 0..ring.nrows() {
+                    res.set_entry(i, i, &c);
+                }
+                res
+            }
+        }
+    };
+}
+
+impl_coerce_scalar_mat!(Integer, IntMatSpace, IntMat, |x: &Integer, _| x.clone());
+impl_coerce_scalar_mat!(Integer, RatMatSpace, RatMat, |x: &Integer, _| Rational::from(x));
+impl_coerce_scalar_mat!(Rational, RatMatSpace, RatMat, |x: &Rational, _| x.clone());
+impl_coerce_scalar_mat!(
+    Integer, IntModMatSpace, IntModMat,
+    |x: &Integer, ring: &IntModMatSpace| x.coerce_into(ring.context())
+);
+impl_coerce_scalar_mat!(
+    IntMod, IntModMatSpace, IntModMat,
+    |x: &IntMod, ring: &IntModMatSpace| x.coerce_into(ring.context())
+);
@@ -15,8 +15,10 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-//mod ops;
+mod ops;
 mod conv;
+mod elliptic;
+mod hypgeom;
 
 use crate::{New, Real};
 use arb_sys::acb::*;
@@ -26,9 +28,11 @@ use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
-#[derive(Debug)]
+/// A complex ball, a rectangle of real balls. Like [Real] it carries a 
+/// working precision, see [ComplexField].
 pub struct Acb {
     pub(crate) inner: acb_struct,
+    pub(crate) prec: u64,
 }
 
 pub type Complex = Acb;
@@ -46,6 +50,7 @@ impl Clone for Complex {
         unsafe {
             acb_set(res.as_mut_ptr(), self.as_ptr());
         }
+        res.prec = self.prec;
         res
     }
 }
@@ -62,6 +67,15 @@ impl Default for Complex {
 }
 
 // TODO
+impl fmt::Debug for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Complex")
+            .field("value", &format_args!("{}", self))
+            .field("prec", &self.prec)
+            .finish()
+    }
+}
+
 impl fmt::Display for Complex {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -112,6 +126,19 @@ impl Complex {
         res
     }
     
+    /// Return the imaginary unit `i`, the same as [Complex::onei].
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let i = Complex::i();
+    /// assert_eq!(&i * &i, -Complex::one());
+    /// ```
+    #[inline]
+    pub fn i() -> Self {
+        Complex::onei()
+    }
+
     #[inline]
     pub fn onei() -> Self {
         let mut res = Complex::default();
@@ -166,7 +193,7 @@ impl Complex {
 
     #[inline]
     pub const unsafe fn from_raw(inner: acb_struct) -> Self {
-        Complex { inner }
+        Complex { inner, prec: Real::DEFAULT_PREC }
     }
 
     #[inline]
@@ -176,12 +203,27 @@ impl Complex {
         ret
     }
 
+    /// Return the working precision in bits used for arithmetic with the 
+    /// ball. Operations on two balls use the larger of their precisions.
+    #[inline]
+    pub fn prec(&self) -> u64 {
+        self.prec
+    }
+
+    /// Set the working precision in bits. This does not round the ball.
+    #[inline]
+    pub fn set_prec(&mut self, prec: u64) {
+        assert!(prec >= 2, "The precision must be at least two bits.");
+        self.prec = prec;
+    }
+
     #[inline]
     pub fn re(&self) -> Real {
         let mut res = Real::default();
         unsafe {
             acb_get_real(res.as_mut_ptr(), self.as_ptr());
         }
+        res.set_prec(self.prec);
         res
     }
     
@@ -191,6 +233,77 @@ impl Complex {
         unsafe {
             acb_get_imag(res.as_mut_ptr(), self.as_ptr());
         }
+        res.set_prec(self.prec);
         res
     }
 }
+
+/// The complex numbers at a fixed working precision, the analogue of
+/// [RealField](crate::RealField) for [Complex] balls.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let cc = ComplexField::init(128);
+/// let i = cc.onei();
+/// assert_eq!(&i * &i, -cc.one());
+/// assert_eq!((&i * &i).prec(), 128);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ComplexField {
+    prec: u64,
+}
+
+impl Default for ComplexField {
+    #[inline]
+    fn default() -> Self {
+        ComplexField::init(Real::DEFAULT_PREC)
+    }
+}
+
+impl ComplexField {
+    /// Return the field of complex balls with `prec` bits of working 
+    /// precision.
+    #[inline]
+    pub fn init(prec: u64) -> Self {
+        assert!(prec >= 2, "The precision must be at least two bits.");
+        ComplexField { prec }
+    }
+
+    #[inline]
+    pub fn prec(&self) -> u64 {
+        self.prec
+    }
+
+    /// Return `x` rounded to the precision of the field.
+    pub fn new<T: Into<Complex>>(&self, x: T) -> Complex {
+        let mut res = x.into();
+        res.set_prec(self.prec);
+        let prec = self.prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe { acb_set_round(res.as_mut_ptr(), res.as_ptr(), prec); }
+        res
+    }
+
+    /// Return the complex number `re + im*i` rounded to the precision of the 
+    /// field.
+    pub fn from_parts<T: AsRef<Real>>(&self, re: T, im: T) -> Complex {
+        let mut res = Complex::default();
+        unsafe { acb_set_arb_arb(res.as_mut_ptr(), re.as_ref().as_ptr(), im.as_ref().as_ptr()); }
+        self.new(res)
+    }
+
+    #[inline]
+    pub fn zero(&self) -> Complex {
+        self.new(Complex::zero())
+    }
+
+    #[inline]
+    pub fn one(&self) -> Complex {
+        self.new(Complex::one())
+    }
+
+    #[inline]
+    pub fn onei(&self) -> Complex {
+        self.new(Complex::onei())
+    }
+}
@@ -83,3 +83,25 @@ impl_from_unsafe! {
     Complex, Real
     acb_set_arb
 }
+
+impl_from! {
+    Complex, Rational
+    {
+        fn from(src: &Rational) -> Complex {
+            let mut res = Complex::default();
+            let prec = res.prec.try_into().expect(
+                "Cannot convert precision to a signed long.");
+            unsafe { acb_set_fmpq(res.as_mut_ptr(), src.as_ptr(), prec); }
+            res
+        }
+    }
+}
+
+impl_from! {
+    Complex, IntMod
+    {
+        fn from(src: &IntMod) -> Complex {
+            Complex::from(Integer::from(src))
+        }
+    }
+}
@@ -0,0 +1,178 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use arb_sys::acb::acb_agm;
+use arb_sys::acb_elliptic::*;
+use arb_sys::acb_modular::acb_modular_theta;
+
+impl Complex {
+    /// Return the arithmetic-geometric mean of `self` and `other`, taking the
+    /// optimal branch of the square root at each step.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // K(m) = pi/(2 agm(1, sqrt(1 - m))), so K(3/4) agm(1, 1/2) = K(0)
+    /// let one = Complex::one();
+    /// let half = Complex::from(Rational::from([1, 2]));
+    /// let k = Complex::from(Rational::from([3, 4])).elliptic_k();
+    /// let lhs = k * one.agm(&half);
+    /// assert!(lhs.re().overlaps(&Complex::zero().elliptic_k().re()));
+    /// ```
+    pub fn agm(&self, other: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(other.prec), |res, prec| {
+            acb_agm(res.as_mut_ptr(), self.as_ptr(), other.as_ptr(), prec)
+        })
+    }
+
+    /// Return the complete elliptic integral of the first kind `K(m)` with
+    /// parameter `m = self`.
+    pub fn elliptic_k(&self) -> Complex {
+        eval_prec!(Complex, self.prec, |res, prec| {
+            acb_elliptic_k(res.as_mut_ptr(), self.as_ptr(), prec)
+        })
+    }
+
+    /// Return the complete elliptic integral of the second kind `E(m)` with
+    /// parameter `m = self`.
+    pub fn elliptic_e(&self) -> Complex {
+        eval_prec!(Complex, self.prec, |res, prec| {
+            acb_elliptic_e(res.as_mut_ptr(), self.as_ptr(), prec)
+        })
+    }
+
+    /// Return the complete elliptic integral of the third kind `Pi(n, m)` 
+    /// with characteristic `n = self` and parameter `m`.
+    pub fn elliptic_pi(&self, m: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(m.prec), |res, prec| {
+            acb_elliptic_pi(res.as_mut_ptr(), self.as_ptr(), m.as_ptr(), prec)
+        })
+    }
+
+    /// Return the incomplete elliptic integral of the first kind `F(phi, m)` 
+    /// with amplitude `phi = self` and parameter `m`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // F(phi, 0) = phi
+    /// let phi = Complex::from(Rational::from([1, 3]));
+    /// let f = phi.elliptic_f(&Complex::zero());
+    /// assert!(f.re().overlaps(&phi.re()));
+    /// ```
+    pub fn elliptic_f(&self, m: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(m.prec), |res, prec| {
+            acb_elliptic_f(res.as_mut_ptr(), self.as_ptr(), m.as_ptr(), 0, prec)
+        })
+    }
+
+    /// Return the incomplete elliptic integral of the second kind `E(phi, m)`
+    /// with amplitude `phi = self` and parameter `m`.
+    pub fn elliptic_e_inc(&self, m: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(m.prec), |res, prec| {
+            acb_elliptic_e_inc(res.as_mut_ptr(), self.as_ptr(), m.as_ptr(), 0, prec)
+        })
+    }
+
+    /// Return the incomplete elliptic integral of the third kind 
+    /// `Pi(n, phi, m)` with amplitude `phi = self`, characteristic `n` and 
+    /// parameter `m`.
+    pub fn elliptic_pi_inc(&self, n: &Complex, m: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(n.prec).max(m.prec), |res, prec| {
+            acb_elliptic_pi_inc(
+                res.as_mut_ptr(), 
+                n.as_ptr(), 
+                self.as_ptr(), 
+                m.as_ptr(), 
+                0, 
+                prec
+            )
+        })
+    }
+
+    /// Return the Jacobi theta functions `[theta_1, theta_2, theta_3, 
+    /// theta_4]` evaluated at `z = self` and the lattice parameter `tau` in 
+    /// the upper half plane, with the conventions of Arb's `acb_modular`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // Jacobi's identity theta_3^4 = theta_2^4 + theta_4^4 at z = 0
+    /// let [_, t2, t3, t4] = Complex::zero().jacobi_theta(&Complex::onei());
+    /// let fourth = |x: &Complex| { let y = x * x; &y * &y };
+    /// let lhs = fourth(&t3);
+    /// let rhs = fourth(&t2) + fourth(&t4);
+    /// assert!(lhs.re().overlaps(&rhs.re()));
+    /// ```
+    pub fn jacobi_theta(&self, tau: &Complex) -> [Complex; 4] {
+        let mut res: [Complex; 4] = Default::default();
+        let prec = self.prec.max(tau.prec);
+        for t in res.iter_mut() {
+            t.prec = prec;
+        }
+        let [t1, t2, t3, t4] = &mut res;
+        let prec_si = prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe {
+            acb_modular_theta(
+                t1.as_mut_ptr(), 
+                t2.as_mut_ptr(), 
+                t3.as_mut_ptr(), 
+                t4.as_mut_ptr(), 
+                self.as_ptr(), 
+                tau.as_ptr(), 
+                prec_si
+            );
+        }
+        res
+    }
+
+    /// Return the Weierstrass elliptic function `p(z)` at `z = self` for the
+    /// lattice `Z + tau Z`, where `tau` is in the upper half plane.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // the values at the half periods sum to zero
+    /// let tau = Complex::onei();
+    /// let half = Complex::from(Rational::from([1, 2]));
+    /// let e1 = half.weierstrass_p(&tau);
+    /// let e2 = (&tau * &half).weierstrass_p(&tau);
+    /// let e3 = ((&Complex::one() + &tau) * &half).weierstrass_p(&tau);
+    /// assert!((e1 + e2 + e3).re().contains(&Real::zero()));
+    /// ```
+    pub fn weierstrass_p(&self, tau: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(tau.prec), |res, prec| {
+            acb_elliptic_p(res.as_mut_ptr(), self.as_ptr(), tau.as_ptr(), prec)
+        })
+    }
+
+    /// Return the invariants `(g2, g3)` of the lattice `Z + tau Z` with 
+    /// `tau = self` in the upper half plane, so that the curve 
+    /// `y^2 = 4x^3 - g2 x - g3` is parametrized by `p` and its derivative.
+    pub fn weierstrass_invariants(&self) -> (Complex, Complex) {
+        let mut g2 = Complex::default();
+        let mut g3 = Complex::default();
+        g2.prec = self.prec;
+        g3.prec = self.prec;
+        let prec = self.prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe {
+            acb_elliptic_invariants(g2.as_mut_ptr(), g3.as_mut_ptr(), self.as_ptr(), prec);
+        }
+        (g2, g3)
+    }
+}
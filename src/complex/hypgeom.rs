@@ -0,0 +1,132 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::*;
+use arb_sys::acb_hypgeom::*;
+
+impl Complex {
+    /// Return the Gauss hypergeometric function `2F1(a, b; c; z)` at 
+    /// `z = self`, or the regularized function `2F1(a, b; c; z)/Gamma(c)` if
+    /// `regularized` is true. See [Real::hypgeom_2f1].
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // 2F1(1/2, 1/2; 3/2; z^2) = asin(z)/z, and asin(1/2) = pi/6
+    /// let half = Complex::from(0.5);
+    /// let f = Complex::from(0.25).hypgeom_2f1(&half, &half, &Complex::from(1.5), false);
+    /// let pi = Complex::from(Real::pi(128));
+    /// assert!(f.re().overlaps(&(pi / Complex::from(3)).re()));
+    /// ```
+    pub fn hypgeom_2f1(
+        &self,
+        a: &Complex,
+        b: &Complex,
+        c: &Complex,
+        regularized: bool
+    ) -> Complex {
+        let prec = self.prec.max(a.prec).max(b.prec).max(c.prec);
+        eval_prec!(Complex, prec, |res, prec| {
+            acb_hypgeom_2f1(
+                res.as_mut_ptr(),
+                a.as_ptr(),
+                b.as_ptr(),
+                c.as_ptr(),
+                self.as_ptr(),
+                regularized.into(),
+                prec
+            )
+        })
+    }
+
+    /// Return the Legendre function of the first kind `P_n^m(z)` at 
+    /// `z = self`. If `ferrers` is true this uses the Ferrers convention with 
+    /// a branch cut outside `(-1, 1)`, otherwise the convention with a branch 
+    /// cut on `(-inf, 1)`.
+    pub fn legendre_p(&self, n: &Complex, m: &Complex, ferrers: bool) -> Complex {
+        let kind = if ferrers { 0 } else { 1 };
+        eval_prec!(Complex, self.prec.max(n.prec).max(m.prec), |res, prec| {
+            acb_hypgeom_legendre_p(
+                res.as_mut_ptr(),
+                n.as_ptr(),
+                m.as_ptr(),
+                self.as_ptr(),
+                kind,
+                prec
+            )
+        })
+    }
+
+    /// Return the Legendre function of the second kind `Q_n^m(z)` at 
+    /// `z = self`, with the branch conventions of [Complex::legendre_p].
+    pub fn legendre_q(&self, n: &Complex, m: &Complex, ferrers: bool) -> Complex {
+        let kind = if ferrers { 0 } else { 1 };
+        eval_prec!(Complex, self.prec.max(n.prec).max(m.prec), |res, prec| {
+            acb_hypgeom_legendre_q(
+                res.as_mut_ptr(),
+                n.as_ptr(),
+                m.as_ptr(),
+                self.as_ptr(),
+                kind,
+                prec
+            )
+        })
+    }
+
+    /// Return the Hermite function `H_nu(z)` at `z = self`.
+    pub fn hermite_h(&self, nu: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(nu.prec), |res, prec| {
+            acb_hypgeom_hermite_h(res.as_mut_ptr(), nu.as_ptr(), self.as_ptr(), prec)
+        })
+    }
+
+    /// Return the generalized Laguerre function `L_n^m(z)` at `z = self`.
+    pub fn laguerre_l(&self, n: &Complex, m: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(n.prec).max(m.prec), |res, prec| {
+            acb_hypgeom_laguerre_l(
+                res.as_mut_ptr(),
+                n.as_ptr(),
+                m.as_ptr(),
+                self.as_ptr(),
+                prec
+            )
+        })
+    }
+
+    /// Return the Chebyshev function of the first kind `T_nu(z)` at 
+    /// `z = self`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // T_2(z) = 2z^2 - 1
+    /// let t = Complex::from(0.5).chebyshev_t(&Complex::from(2));
+    /// assert!(t.re().contains(&Real::from(-0.5)));
+    /// ```
+    pub fn chebyshev_t(&self, nu: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(nu.prec), |res, prec| {
+            acb_hypgeom_chebyshev_t(res.as_mut_ptr(), nu.as_ptr(), self.as_ptr(), prec)
+        })
+    }
+
+    /// Return the Chebyshev function of the second kind `U_nu(z)` at 
+    /// `z = self`.
+    pub fn chebyshev_u(&self, nu: &Complex) -> Complex {
+        eval_prec!(Complex, self.prec.max(nu.prec), |res, prec| {
+            acb_hypgeom_chebyshev_u(res.as_mut_ptr(), nu.as_ptr(), self.as_ptr(), prec)
+        })
+    }
+}
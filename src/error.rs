@@ -19,6 +19,44 @@ use thiserror::Error;
 
 // Including backtrace seems to require nightly as of 5/23.
 
+/// The error type of the crate.
+///
+/// Most arithmetic goes through the operator traits, which cannot fail, so
+/// invalid input to them panics: division by zero or by a non-invertible
+/// element, operands with different contexts, and matrices of incompatible
+/// dimensions. So do methods documented as requiring, for example, a square
+/// matrix or a prime modulus, and the `new` constructors of the rings.
+/// Checked variants returning an `Error` instead are available for the most
+/// common of these: [Integer::try_div], [Rational::try_div],
+/// [IntMod::try_div] and [IntMod::try_inverse], the same for [Nmod],
+/// `try_add`, `try_sub` and `try_mul` on [IntMat], [RatMat], [IntModMat] and
+/// [NmodMat], `try_inverse` on [RatMat], [NmodMat] and [FinFldMat], and
+/// [IntModCtx::try_new], [NmodRing::try_new] and [FinFldCtx::try_new].
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let a = IntMat::new([1, 2, 3, 4, 5, 6], 2, 3);
+/// assert!(matches!(a.try_mul(&a), Err(Error::DimensionError(_))));
+/// assert_eq!(a.try_mul(&a.transpose()).unwrap(), IntMat::new([14, 32, 32, 77], 2, 2));
+///
+/// let b = RatMat::from(&a);
+/// assert!(matches!(b.try_inverse(), Err(Error::NotSquareError { nrows: 2, ncols: 3 })));
+/// ```
+///
+/// [Integer::try_div]: crate::Integer::try_div
+/// [Rational::try_div]: crate::Rational::try_div
+/// [IntMod::try_div]: crate::IntMod::try_div
+/// [IntMod::try_inverse]: crate::IntMod::try_inverse
+/// [Nmod]: crate::Nmod
+/// [IntMat]: crate::IntMat
+/// [RatMat]: crate::RatMat
+/// [IntModMat]: crate::IntModMat
+/// [NmodMat]: crate::NmodMat
+/// [FinFldMat]: crate::FinFldMat
+/// [IntModCtx::try_new]: crate::IntModCtx::try_new
+/// [NmodRing::try_new]: crate::NmodRing::try_new
+/// [FinFldCtx::try_new]: crate::FinFldCtx::try_new
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("Unable to convert {val} of type {in_type} to type {out_type}.")]
@@ -29,6 +67,31 @@ pub enum Error {
     },
     #[error("Division error: {0}")]
     DivisionError(String),
+    #[error("Dimension mismatch: {0}")]
+    DimensionError(String),
+    #[error("Expected a square matrix, got a {nrows} by {ncols} matrix.")]
+    NotSquareError {
+        nrows: usize,
+        ncols: usize,
+    },
+    #[error("Not invertible: {0}")]
+    NotInvertibleError(String),
+    #[error("Context mismatch: {0}")]
+    ContextError(String),
+    #[error("Invalid ring: {0}")]
+    RingError(String),
+    #[error("Cannot parse integer: {0}")]
+    ParseIntegerError(String),
+    #[error("Cannot parse rational: {0}")]
+    ParseRationalError(String),
+    #[error("Cannot parse integer mod n: {0}")]
+    ParseIntModError(String),
+    #[error("Cannot parse polynomial: {0}")]
+    ParsePolyError(String),
+    #[error("Cannot parse matrix: {0}")]
+    ParseMatrixError(String),
+    #[error("Computation was interrupted.")]
+    Interrupted,
     // A generic error message.
     #[error("{0}")]
     Msg(String)
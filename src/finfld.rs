@@ -17,9 +17,15 @@
 
 mod ops;
 mod conv;
+mod dlog;
+mod pow;
+mod embed;
+
+pub use embed::FqEmbedding;
 
 use crate::*;
 use flint_sys::fq_default as fq;
+use flint_sys::fmpz_mod_poly;
 use std::ffi::CString;
 use std::fmt;
 use std::hash::{Hash, Hasher};
@@ -81,14 +87,39 @@ impl FqCtx {
         );
         FqCtx(ctx.assume_init())
     }
+    
+    pub fn with_modulus(modulus: &IntModPoly) -> Self {
+        assert!(modulus.modulus().is_prime());
+        assert!(modulus.degree() > 0);
+        assert!(modulus.is_irreducible());
+
+        let var = CString::new("o").unwrap();
+        let mut ctx = MaybeUninit::uninit();
+        unsafe {
+            fq::fq_default_ctx_init_modulus(
+                ctx.as_mut_ptr(), 
+                modulus.as_ptr(), 
+                modulus.ctx_as_ptr(),
+                var.as_ptr()
+            );
+            FqCtx(ctx.assume_init())
+        }
+    }
 }
 
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct FinFldCtx {
     inner: Rc<FqCtx>,
 }
 
+impl AsRef<FinFldCtx> for FinFldCtx {
+    #[inline]
+    fn as_ref(&self) -> &FinFldCtx {
+        self
+    }
+}
+
 impl Eq for FinFldCtx {}
 
 impl PartialEq for FinFldCtx {
@@ -97,6 +128,15 @@ impl PartialEq for FinFldCtx {
     }
 }
 
+impl fmt::Debug for FinFldCtx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FinFldCtx")
+            .field("order", &format_args!("{}^{}", self.prime(), self.degree()))
+            .field("modulus", &format_args!("{}", self.modulus()))
+            .finish()
+    }
+}
+
 impl fmt::Display for FinFldCtx {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -139,6 +179,23 @@ impl FinFldCtx {
         }
     }
 
+    /// Construct the finite field `F_p[o]/(f)` for an irreducible polynomial 
+    /// `f` over `Z/pZ`. Panics if `p` is not prime or `f` is not irreducible.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(5, 3);
+    /// let other = FinFldCtx::with_modulus(&ctx.modulus());
+    /// assert_eq!(ctx, other);
+    /// ```
+    #[inline]
+    pub fn with_modulus(modulus: &IntModPoly) -> Self {
+        FinFldCtx {
+            inner: Rc::new(FqCtx::with_modulus(modulus))
+        }
+    }
+
     #[inline]
     pub fn as_ptr(&self) -> &fq::fq_default_ctx_struct {
         &self.inner.0
@@ -209,6 +266,13 @@ impl AsRef<FinFldElem> for FinFldElem {
     }
 }
 
+impl AsRef<FinFldCtx> for FinFldElem {
+    #[inline]
+    fn as_ref(&self) -> &FinFldCtx {
+        self.context()
+    }
+}
+
 /*
 impl<'a, T> Assign<T> for FinFldElem
 where
@@ -238,7 +302,7 @@ impl Clone for FinFldElem {
 impl fmt::Debug for FinFldElem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("FinFldElem")
-            //.field("inner", &self.inner)
+            .field("value", &format_args!("{}", self))
             .field("ctx", &self.ctx)
             .finish()
     }
@@ -334,6 +398,16 @@ impl FinFldElem {
         unsafe { fq::fq_default_one(self.as_mut_ptr(), self.ctx_as_ptr()) }
     }
 
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { fq::fq_default_is_zero(self.as_ptr(), self.ctx_as_ptr()) != 0 }
+    }
+    
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        unsafe { fq::fq_default_is_one(self.as_ptr(), self.ctx_as_ptr()) != 0 }
+    }
+
     /// Returns a pointer to the inner [fq::fq_default_struct].
     #[inline]
     pub const fn as_ptr(&self) -> *const fq::fq_default_struct {
@@ -391,4 +465,22 @@ impl FinFldElem {
     pub fn order(&self) -> Integer {
         self.context().order()
     }
+
+    /// Return a random element of the finite field.
+    #[inline]
+    pub fn randtest(ctx: &FinFldCtx, rng: &mut FlintRand) -> FinFldElem {
+        let mut res = FinFldElem::zero(ctx);
+        unsafe { fq::fq_default_randtest(res.as_mut_ptr(), rng.as_mut_ptr(), ctx.as_ptr()); }
+        res
+    }
+    
+    /// Return a random nonzero element of the finite field.
+    #[inline]
+    pub fn randtest_not_zero(ctx: &FinFldCtx, rng: &mut FlintRand) -> FinFldElem {
+        let mut res = FinFldElem::zero(ctx);
+        unsafe { 
+            fq::fq_default_randtest_not_zero(res.as_mut_ptr(), rng.as_mut_ptr(), ctx.as_ptr()); 
+        }
+        res
+    }
 }
@@ -0,0 +1,145 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fq_default as fq;
+use std::collections::HashMap;
+
+impl FinFldElem {
+    /// Return the multiplicative order of a nonzero element, or `None` for 
+    /// zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // the generator of the default (Conway) modulus is primitive
+    /// let k = FinFldCtx::new(5, 2);
+    /// let x = FinFldElem::new(IntPoly::from([0, 1]), &k);
+    /// assert_eq!(x.multiplicative_order().unwrap(), 24);
+    /// assert_eq!((&x * &x).multiplicative_order().unwrap(), 12);
+    /// assert!(FinFldElem::zero(&k).multiplicative_order().is_none());
+    /// ```
+    pub fn multiplicative_order(&self) -> Option<Integer> {
+        if self.is_zero() {
+            return None;
+        }
+        let n = self.order() - 1;
+        Some(order_dividing(self, &n))
+    }
+
+    /// Return the smallest `x >= 0` with `base^x = self`, or `None` if there
+    /// is none. Uses Pohlig-Hellman reduction to the prime factors of the 
+    /// order of `base` and baby-step giant-step for each of them, so it is 
+    /// fast when that order has only small prime factors.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let k = FinFldCtx::new(5, 2);
+    /// let x = FinFldElem::new(IntPoly::from([0, 1]), &k);
+    /// let y = &x * &x * &x;
+    /// assert_eq!(y.dlog(&x).unwrap(), 3);
+    /// assert_eq!((&y * &y).dlog(&y).unwrap(), 2);
+    /// assert!(x.dlog(&y).is_none());
+    /// ```
+    pub fn dlog<T: AsRef<FinFldElem>>(&self, base: T) -> Option<Integer> {
+        let base = base.as_ref();
+        assert_eq!(self.context(), base.context());
+        if self.is_one() {
+            return Some(Integer::zero());
+        }
+        if base.is_zero() || self.is_zero() {
+            return if base.is_zero() && self.is_zero() { Some(Integer::one()) } else { None };
+        }
+
+        // the group of units is cyclic, so self lies in the subgroup 
+        // generated by base iff its order divides that of base
+        let n = order_dividing(base, &(self.order() - 1));
+        if !pow(self, &n).is_one() {
+            return None;
+        }
+
+        let mut x = Integer::zero();
+        let mut m = Integer::one();
+        for (p, e) in n.factor() {
+            let pe = p.pow(e);
+            let c = n.fdiv_q(&pe);
+            let xi = dlog_prime_power(&pow(base, &c), &pow(self, &c), &p, e);
+            // combine x mod m and xi mod p^e
+            let t = ((xi - &x) * m.invmod(&pe).unwrap()).fdiv_r(&pe);
+            x += &m * t;
+            m *= pe;
+        }
+        Some(x)
+    }
+}
+
+// Return x^e for a nonnegative integer exponent.
+fn pow(x: &FinFldElem, e: &Integer) -> FinFldElem {
+    let mut res = FinFldElem::zero(x.context());
+    unsafe { fq::fq_default_pow(res.as_mut_ptr(), x.as_ptr(), e.as_ptr(), x.ctx_as_ptr()); }
+    res
+}
+
+// Return the order of x, given that x^n = 1.
+fn order_dividing(x: &FinFldElem, n: &Integer) -> Integer {
+    let mut ord = n.clone();
+    for (q, _) in n.factor() {
+        while ord.divisible(&q) && pow(x, &ord.fdiv_q(&q)).is_one() {
+            ord = ord.fdiv_q(&q);
+        }
+    }
+    ord
+}
+
+// Return the logarithm of h to the base g of order p^e, one base p digit at 
+// a time.
+fn dlog_prime_power(g: &FinFldElem, h: &FinFldElem, p: &Integer, e: u64) -> Integer {
+    let pe = p.pow(e);
+    let gamma = pow(g, &pe.fdiv_q(p));
+    let mut x = Integer::zero();
+    let mut pk = Integer::one();
+    for k in 0..e {
+        // (g^-x h)^(p^(e-1-k)) lies in the subgroup of order p
+        let hk = pow(&(pow(g, &(&pe - &x)) * h), &p.pow(e - 1 - k));
+        x += bsgs(&gamma, &hk, p) * &pk;
+        pk *= p;
+    }
+    x
+}
+
+// Return the logarithm of h to the base g of prime order p.
+fn bsgs(g: &FinFldElem, h: &FinFldElem, p: &Integer) -> Integer {
+    let m = (p.sqrt() + 1).get_ui().expect("The subgroup order is too large.");
+    let mut table = HashMap::new();
+    let mut y = FinFldElem::one(g.context());
+    for j in 0..m {
+        table.entry(y.clone()).or_insert(j);
+        y *= g;
+    }
+    // g^-m
+    let step = pow(g, &(p - m).fdiv_r(p));
+    let mut y = h.clone();
+    for i in 0..m {
+        if let Some(j) = table.get(&y) {
+            return Integer::from(i) * m + *j;
+        }
+        check_interrupt();
+        y *= &step;
+    }
+    unreachable!("The element is not in the subgroup.")
+}
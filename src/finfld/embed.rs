@@ -0,0 +1,176 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+
+/// An embedding of the finite field `F_{p^m}` into `F_{p^n}` for `m | n`. It
+/// is determined by the image of the generator of the smaller field, a root
+/// of its defining polynomial in the larger one.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let k = FinFldCtx::new(3, 4);
+/// let e = k.subfield(2);
+/// let f = e.domain();
+///
+/// let a = FinFldElem::new(IntPoly::from([1, 2]), f);
+/// let b = FinFldElem::new(IntPoly::from([2, 1]), f);
+/// assert_eq!(e.apply(&(&a * &b)), e.apply(&a) * e.apply(&b));
+/// assert_eq!(e.preimage(&e.apply(&a)), Some(a));
+///
+/// // the generator of F_81 does not lie in F_9
+/// let x = FinFldElem::new(IntPoly::from([0, 1]), &k);
+/// assert_eq!(e.preimage(&x), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FqEmbedding {
+    domain: FinFldCtx,
+    codomain: FinFldCtx,
+    gen_image: FinFldElem,
+    // coordinates over F_p of the images of 1, g, ..., g^(m-1)
+    basis: Vec<Vec<Integer>>,
+}
+
+impl FqEmbedding {
+    /// Construct an embedding of `domain` into `codomain`. Panics unless the
+    /// fields have the same characteristic and the degree of `domain` divides
+    /// the degree of `codomain`.
+    pub fn new(domain: &FinFldCtx, codomain: &FinFldCtx) -> FqEmbedding {
+        assert_eq!(
+            domain.prime(), 
+            codomain.prime(), 
+            "The fields must have the same characteristic."
+        );
+        let m = domain.degree();
+        let n = codomain.degree();
+        assert!(n % m == 0, "The degree of the domain must divide the degree of the codomain.");
+
+        let mut f = FinFldPoly::zero(codomain);
+        for (i, c) in IntPoly::from(domain.modulus()).get_coeffs().into_iter().enumerate() {
+            f.set_coeff(i, FinFldElem::new(c, codomain));
+        }
+        // a polynomial of degree m irreducible over F_p splits over F_{p^n}
+        let gen_image = f.roots().swap_remove(0);
+
+        let mut basis = Vec::with_capacity(m as usize);
+        let mut pow = FinFldElem::one(codomain);
+        for _ in 0..m {
+            basis.push(coordinates(&pow));
+            pow *= &gen_image;
+        }
+        FqEmbedding { domain: domain.clone(), codomain: codomain.clone(), gen_image, basis }
+    }
+
+    /// The smaller field.
+    #[inline]
+    pub fn domain(&self) -> &FinFldCtx {
+        &self.domain
+    }
+
+    /// The larger field.
+    #[inline]
+    pub fn codomain(&self) -> &FinFldCtx {
+        &self.codomain
+    }
+
+    /// The image of the generator of the domain.
+    #[inline]
+    pub fn gen_image(&self) -> &FinFldElem {
+        &self.gen_image
+    }
+
+    /// Return the image of an element of the domain.
+    pub fn apply(&self, x: &FinFldElem) -> FinFldElem {
+        assert_eq!(x.context(), &self.domain);
+        let mut res = FinFldElem::zero(&self.codomain);
+        for c in IntPoly::from(x).get_coeffs().into_iter().rev() {
+            res *= &self.gen_image;
+            res += c;
+        }
+        res
+    }
+
+    /// Return the preimage of an element of the codomain, or `None` if it
+    /// does not lie in the image of the embedding.
+    pub fn preimage(&self, y: &FinFldElem) -> Option<FinFldElem> {
+        assert_eq!(y.context(), &self.codomain);
+        let x = solve_mod(&self.basis, &coordinates(y), &self.codomain.prime())?;
+        Some(FinFldElem::new(IntPoly::from(x.as_slice()), &self.domain))
+    }
+}
+
+impl FinFldCtx {
+    /// Return an embedding of the subfield of degree `m` over the prime
+    /// field, with its default defining polynomial. Panics unless `m` divides 
+    /// the degree of the field.
+    #[inline]
+    pub fn subfield(&self, m: i64) -> FqEmbedding {
+        FqEmbedding::new(&FinFldCtx::new(self.prime(), m), self)
+    }
+
+    /// Return embeddings of all subfields, one for every divisor of the
+    /// degree, in increasing order of degree.
+    pub fn subfields(&self) -> Vec<FqEmbedding> {
+        let n = self.degree();
+        (1..=n).filter(|m| n % m == 0).map(|m| self.subfield(m)).collect()
+    }
+}
+
+// The coordinates of `x` over F_p in the power basis of its field.
+fn coordinates(x: &FinFldElem) -> Vec<Integer> {
+    let n = x.degree() as usize;
+    let mut res = IntPoly::from(x).get_coeffs();
+    res.resize(n, Integer::zero());
+    res
+}
+
+// Solve `sum_j x_j cols[j] = b` over F_p by Gauss-Jordan elimination, assuming
+// the columns are linearly independent.
+fn solve_mod(cols: &[Vec<Integer>], b: &[Integer], p: &Integer) -> Option<Vec<Integer>> {
+    let m = cols.len();
+    let n = b.len();
+    let mut rows: Vec<Vec<Integer>> = (0..n)
+        .map(|i| {
+            let mut row: Vec<Integer> = cols.iter().map(|c| c[i].clone()).collect();
+            row.push(b[i].clone());
+            row
+        })
+        .collect();
+
+    for j in 0..m {
+        let k = (j..n).find(|&k| !rows[k][j].is_zero()).expect("The basis is not independent.");
+        rows.swap(j, k);
+        let inv = rows[j][j].invmod(p).unwrap();
+        for x in rows[j].iter_mut() {
+            *x = (&*x * &inv).fdiv_r(p);
+        }
+        for k in 0..n {
+            if k != j && !rows[k][j].is_zero() {
+                let c = rows[k][j].clone();
+                for l in j..=m {
+                    let t = (&rows[k][l] - &c * &rows[j][l]).fdiv_r(p);
+                    rows[k][l] = t;
+                }
+            }
+        }
+    }
+    if rows[m..].iter().any(|row| !row[m].is_zero()) {
+        return None;
+    }
+    Some(rows[..m].iter().map(|row| row[m].clone()).collect())
+}
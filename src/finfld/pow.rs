@@ -0,0 +1,55 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use crate::multiexp;
+
+impl FinFldElem {
+    /// Return `prod b_i^e_i` computed by simultaneous exponentiation, as for
+    /// [IntMod::multi_exp]. Bases with negative exponents must be nonzero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let k = FinFldCtx::new(7, 3);
+    /// let x = FinFldElem::new(IntPoly::from([0, 1]), &k);
+    /// let y = FinFldElem::new(IntPoly::from([3, 0, 2]), &k);
+    /// let exps = [Integer::from(1000), Integer::from(-17)];
+    /// let expected = x.clone().pow(1000u64) / y.clone().pow(17u64);
+    /// assert_eq!(FinFldElem::multi_exp(&[x, y], &exps), expected);
+    /// ```
+    pub fn multi_exp(bases: &[FinFldElem], exps: &[Integer]) -> FinFldElem {
+        assert_eq!(bases.len(), exps.len(), "The number of bases and exponents differ.");
+        assert!(!bases.is_empty(), "At least one base is required for the context.");
+        let ctx = bases[0].context();
+        let bases: Vec<FinFldElem> = bases
+            .iter()
+            .zip(exps)
+            .map(|(b, e)| {
+                assert_eq!(b.context(), ctx);
+                if e < &0 {
+                    assert!(!b.is_zero(), "Cannot invert zero.");
+                    b.inv()
+                } else {
+                    b.clone()
+                }
+            })
+            .collect();
+        let exps: Vec<Integer> = exps.iter().map(|e| e.abs()).collect();
+        multiexp::multi_exp(FinFldElem::one(ctx), &bases, &exps)
+    }
+}
@@ -18,14 +18,15 @@
 mod ops;
 mod conv;
 
-//#[cfg(feature = "serde")]
-//mod serde;
+#[cfg(feature = "serde")]
+mod serde;
 
 use crate::*;
 use flint_sys::{
     fq_default::fq_default_ctx_struct,
     fq_default_mat::*,
 };
+use crate::util::{perm_is_odd, DisplayRows};
 use std::fmt;
 //use std::hash::{Hash, Hasher};
 use std::mem::MaybeUninit;
@@ -42,6 +43,13 @@ impl AsRef<FinFldMat> for FinFldMat {
     }
 }
 
+impl AsRef<FinFldCtx> for FinFldMat {
+    #[inline]
+    fn as_ref(&self) -> &FinFldCtx {
+        self.context()
+    }
+}
+
 impl Clone for FinFldMat {
     fn clone(&self) -> Self {
         let mut z = MaybeUninit::uninit();
@@ -59,8 +67,11 @@ impl Clone for FinFldMat {
 // fq_default_mat_struct is a union so can't derive Debug
 impl fmt::Debug for FinFldMat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = self.get_entries();
         f.debug_struct("FinFldMat")
-            //.field("inner", &self.inner)
+            .field("nrows", &self.nrows())
+            .field("ncols", &self.ncols())
+            .field("entries", &DisplayRows(entries.chunks(self.ncols().max(1)).collect()))
             .field("ctx", &self.ctx)
             .finish()
     }
@@ -194,7 +205,6 @@ where
 */
 
 impl FinFldMat {
-    /*
     // private helper methods to convert usize indices to i64, emit consistent
     // messages on panic, and bounds check
     fn check_indices(&self, i: usize, j: usize) -> (i64, i64) {
@@ -212,7 +222,6 @@ impl FinFldMat {
         assert!(j < self.ncols_si());
         j
     }
-    */
     
     /*
     #[inline]
@@ -233,15 +242,19 @@ impl FinFldMat {
         }
     }
    
-    /*
     #[inline]
-    pub fn one(dim: i64) -> IntMat {
-        let mut res = IntMat::zero(dim, dim);
-        unsafe {
-            fmpz_mat::fmpz_mat_one(res.as_mut_ptr());
-        }
+    pub fn one(dim: i64, ctx: &FinFldCtx) -> FinFldMat {
+        let mut res = FinFldMat::zero(dim, dim, ctx);
+        unsafe { fq_default_mat_one(res.as_mut_ptr(), ctx.as_ptr()); }
         res
-    }*/
+    }
+
+    /// Return a random `nrows` by `ncols` matrix.
+    pub fn randtest(nrows: i64, ncols: i64, ctx: &FinFldCtx, rng: &mut FlintRand) -> FinFldMat {
+        let mut res = FinFldMat::zero(nrows, ncols, ctx);
+        unsafe { fq_default_mat_randtest(res.as_mut_ptr(), rng.as_mut_ptr(), ctx.as_ptr()); }
+        res
+    }
 
     #[inline]
     pub const fn as_ptr(&self) -> *const fq_default_mat_struct {
@@ -296,6 +309,309 @@ impl FinFldMat {
     pub fn ncols_si(&self) -> i64 {
         unsafe { fq_default_mat_ncols(self.as_ptr(), self.ctx_as_ptr())}
     }
+
+    /// Get the `(i, j)`-th entry of the matrix.
+    #[inline]
+    pub fn get_entry(&self, i: usize, j: usize) -> FinFldElem {
+        let (i, j) = self.check_indices(i, j);
+        let mut res = FinFldElem::zero(self.context());
+        unsafe {
+            fq_default_mat_entry(res.as_mut_ptr(), self.as_ptr(), i, j, self.ctx_as_ptr());
+        }
+        res
+    }
+
+    /// Set the `(i, j)`-th entry of the matrix.
+    #[inline]
+    pub fn set_entry<T: AsRef<FinFldElem>>(&mut self, i: usize, j: usize, e: T) {
+        let e = e.as_ref();
+        assert_eq!(self.context(), e.context());
+        let (i, j) = self.check_indices(i, j);
+        unsafe {
+            fq_default_mat_entry_set(
+                self.as_mut_ptr(), 
+                i, 
+                j, 
+                e.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+    }
+
+    /// Get a vector with all of the entries of the matrix.
+    pub fn get_entries(&self) -> Vec<FinFldElem> {
+        let r = self.nrows();
+        let c = self.ncols();
+        let mut out = Vec::with_capacity(r * c);
+
+        for i in 0..r {
+            for j in 0..c {
+                out.push(self.get_entry(i, j));
+            }
+        }
+        out
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        unsafe { fq_default_mat_is_empty(self.as_ptr(), self.ctx_as_ptr()) != 0 }
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        unsafe { fq_default_mat_is_square(self.as_ptr(), self.ctx_as_ptr()) != 0 }
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { fq_default_mat_is_zero(self.as_ptr(), self.ctx_as_ptr()) != 0 }
+    }
+
+    /// Return the rank and the reduced row echelon form of the matrix.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(5, 2);
+    /// let e = |c: [i32; 2]| FinFldElem::new(IntPoly::from(c), &ctx);
+    /// let m = FinFldMatSpace::init(2, 3, &ctx).new(&[
+    ///     e([1, 0]), e([2, 0]), e([0, 1]),
+    ///     e([2, 0]), e([4, 0]), e([3, 0]),
+    /// ]);
+    /// let (rank, r) = m.rref();
+    /// assert_eq!(rank, 2);
+    /// assert_eq!(r.get_entry(0, 1), e([2, 0]));
+    /// assert!(r.get_entry(0, 2).is_zero());
+    /// ```
+    pub fn rref(&self) -> (i64, FinFldMat) {
+        let mut res = self.clone();
+        let rank = unsafe { fq_default_mat_rref(res.as_mut_ptr(), self.ctx_as_ptr()) };
+        (rank, res)
+    }
+
+    /// Return the rank of the matrix.
+    #[inline]
+    pub fn rank(&self) -> i64 {
+        unsafe { fq_default_mat_rank(self.as_ptr(), self.ctx_as_ptr()) }
+    }
+
+    /// Return a matrix whose columns form a basis of the right nullspace of 
+    /// the matrix, that is, of the solutions of `Ax = 0`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(3, 2);
+    /// let e = |c: [i32; 2]| FinFldElem::new(IntPoly::from(c), &ctx);
+    /// let m = FinFldMatSpace::init(2, 3, &ctx).new(&[
+    ///     e([1, 0]), e([0, 1]), e([1, 1]),
+    ///     e([0, 1]), e([1, 1]), e([1, 2]),
+    /// ]);
+    /// // the second row is o times the first, as o^2 = o + 1
+    /// let x = m.nullspace();
+    /// assert_eq!(x.ncols(), 2);
+    /// assert!((&m * &x).is_zero());
+    /// ```
+    pub fn nullspace(&self) -> FinFldMat {
+        let n = self.ncols_si();
+        let mut basis = FinFldMat::zero(n, n, self.context());
+        let nullity = unsafe {
+            fq_default_mat_nullspace(basis.as_mut_ptr(), self.as_ptr(), self.ctx_as_ptr())
+        };
+        let mut res = FinFldMat::zero(n, nullity, self.context());
+        for i in 0..res.nrows() {
+            for j in 0..res.ncols() {
+                res.set_entry(i, j, basis.get_entry(i, j));
+            }
+        }
+        res
+    }
+
+    /// Return an LU decomposition with partial pivoting `(p, L, U)` of the 
+    /// matrix. Row `i` of `PA` is row `p[i]` of `A`, `L` is square unit lower 
+    /// triangular and `U` is in row echelon form with the shape of `A`, so 
+    /// that `PA = LU`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(7, 2);
+    /// let e = |c: [i32; 2]| FinFldElem::new(IntPoly::from(c), &ctx);
+    /// let m = FinFldMatSpace::init(3, 3, &ctx).new(&[
+    ///     e([0, 0]), e([1, 0]), e([2, 1]),
+    ///     e([3, 0]), e([0, 1]), e([1, 0]),
+    ///     e([1, 1]), e([0, 0]), e([5, 0]),
+    /// ]);
+    /// let (p, l, u) = m.plu();
+    /// let lu = &l * &u;
+    /// for i in 0..3 {
+    ///     for j in 0..3 {
+    ///         assert_eq!(lu.get_entry(i, j), m.get_entry(p[i], j));
+    ///     }
+    /// }
+    /// ```
+    pub fn plu(&self) -> (Vec<usize>, FinFldMat, FinFldMat) {
+        let (m, n) = (self.nrows(), self.ncols());
+        let mut lu = self.clone();
+        let mut perm: Vec<i64> = (0..self.nrows_si()).collect();
+        unsafe {
+            fq_default_mat_lu(perm.as_mut_ptr(), lu.as_mut_ptr(), 0, self.ctx_as_ptr());
+        }
+
+        // FLINT stores the multipliers strictly below the diagonal
+        let mut l = FinFldMat::one(self.nrows_si(), self.context());
+        let mut u = FinFldMat::zero(self.nrows_si(), self.ncols_si(), self.context());
+        for i in 0..m {
+            for j in 0..n {
+                if j < i {
+                    l.set_entry(i, j, lu.get_entry(i, j));
+                } else {
+                    u.set_entry(i, j, lu.get_entry(i, j));
+                }
+            }
+        }
+        let perm = perm.into_iter()
+            .map(|x| x.try_into().expect("Cannot convert signed long to usize."))
+            .collect();
+        (perm, l, u)
+    }
+
+    /// Return the determinant of a square matrix, computed from its LU 
+    /// decomposition.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(5, 2);
+    /// let e = |c: [i32; 2]| FinFldElem::new(IntPoly::from(c), &ctx);
+    /// let m = FinFldMatSpace::init(2, 2, &ctx).new(&[
+    ///     e([0, 1]), e([1, 0]), 
+    ///     e([1, 0]), e([0, 1]),
+    /// ]);
+    /// // o^2 - 1
+    /// let o = e([0, 1]);
+    /// assert_eq!(m.det(), &(&o * &o) - &e([1, 0]));
+    /// ```
+    pub fn det(&self) -> FinFldElem {
+        assert!(self.is_square());
+        let n = self.nrows();
+        let (perm, _, u) = self.plu();
+
+        let mut res = FinFldElem::one(self.context());
+        for i in 0..n {
+            res = &res * &u.get_entry(i, i);
+        }
+        if perm_is_odd(&perm) {
+            res = -res;
+        }
+        res
+    }
+
+    /// Return the inverse of the matrix, or `None` if it is singular. The 
+    /// matrix must be square.
+    pub fn inv(&self) -> Option<FinFldMat> {
+        assert!(self.is_square());
+        let mut res = FinFldMat::zero(self.nrows_si(), self.ncols_si(), self.context());
+        unsafe {
+            let x = fq_default_mat_inv(res.as_mut_ptr(), self.as_ptr(), self.ctx_as_ptr());
+            if x != 0 {
+                Some(res)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Solve `AX = B` for a square, invertible matrix `A`, returning `None` if 
+    /// `A` is singular. See [can_solve](FinFldMat::can_solve) for general 
+    /// systems.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(2, 3);
+    /// let mut rng = FlintRand::new();
+    /// let a = FinFldMat::randtest(4, 4, &ctx, &mut rng);
+    /// let b = FinFldMat::randtest(4, 2, &ctx, &mut rng);
+    /// if let Some(x) = a.solve(&b) {
+    ///     assert_eq!(&a * &x, b);
+    ///     assert!(a.inv().is_some());
+    /// }
+    /// ```
+    pub fn solve(&self, rhs: &FinFldMat) -> Option<FinFldMat> {
+        assert!(self.is_square());
+        assert_eq!(self.context(), rhs.context());
+        assert_eq!(self.nrows(), rhs.nrows());
+        let mut res = FinFldMat::zero(self.ncols_si(), rhs.ncols_si(), self.context());
+        unsafe {
+            let x = fq_default_mat_solve(
+                res.as_mut_ptr(),
+                self.as_ptr(),
+                rhs.as_ptr(),
+                self.ctx_as_ptr()
+            );
+            if x != 0 {
+                Some(res)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Return a solution `X` of `AX = B` for a matrix `A` of any shape, or 
+    /// `None` if the system is inconsistent. The solutions are `X` plus the 
+    /// span of the [nullspace](FinFldMat::nullspace).
+    pub fn can_solve(&self, rhs: &FinFldMat) -> Option<FinFldMat> {
+        assert_eq!(self.context(), rhs.context());
+        assert_eq!(self.nrows(), rhs.nrows());
+        let mut res = FinFldMat::zero(self.ncols_si(), rhs.ncols_si(), self.context());
+        unsafe {
+            let x = fq_default_mat_can_solve(
+                res.as_mut_ptr(),
+                self.as_ptr(),
+                rhs.as_ptr(),
+                self.ctx_as_ptr()
+            );
+            if x != 0 {
+                Some(res)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Return the characteristic polynomial of a square matrix.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(3, 2);
+    /// let m = FinFldMat::one(3, &ctx);
+    /// // (x - 1)^3 = x^3 - 1 in characteristic 3
+    /// let p = m.charpoly();
+    /// assert_eq!(p.degree(), 3);
+    /// assert_eq!(p.get_coeff(0), -FinFldElem::one(&ctx));
+    /// assert!(p.get_coeff(1).is_zero() && p.get_coeff(2).is_zero());
+    /// assert_eq!(m.minpoly().degree(), 1);
+    /// ```
+    pub fn charpoly(&self) -> FinFldPoly {
+        assert!(self.is_square());
+        let mut res = FinFldPoly::zero(self.context());
+        unsafe {
+            fq_default_mat_charpoly(res.as_mut_ptr(), self.as_ptr(), self.ctx_as_ptr());
+        }
+        res
+    }
+
+    /// Return the minimal polynomial of a square matrix.
+    pub fn minpoly(&self) -> FinFldPoly {
+        assert!(self.is_square());
+        let mut res = FinFldPoly::zero(self.context());
+        unsafe {
+            fq_default_mat_minpoly(res.as_mut_ptr(), self.as_ptr(), self.ctx_as_ptr());
+        }
+        res
+    }
     /*
 
     /// Set `self` to the zero matrix.
@@ -37,6 +37,22 @@ impl_cmp! {
     }
 }*/
 
+impl_cmp! {
+    eq
+    FinFldMat
+    {
+        fn eq(&self, rhs: &FinFldMat) -> bool {
+            unsafe {
+                self.context() == rhs.context() && fq_default_mat_equal(
+                    self.as_ptr(),
+                    rhs.as_ptr(),
+                    self.ctx_as_ptr()
+                ) != 0
+            }
+        }
+    }
+}
+
 impl_unop_unsafe! {
     matrix_ctx
     FinFldMat
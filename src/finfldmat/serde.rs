@@ -0,0 +1,135 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{
+    FinFldCtx, 
+    FinFldElem, 
+    FinFldMat, 
+    Integer, 
+    IntModCtx, 
+    IntModPoly, 
+    IntPoly, 
+    NewCtx
+};
+use crate::util::read_matrix_entries;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+use std::fmt;
+
+// The context is stored as the characteristic together with the lift of the 
+// defining polynomial, and entries as their polynomial representatives.
+impl Serialize for FinFldMat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let entries = self.get_entries();
+        let mut seq = serializer.serialize_seq(Some(entries.len() + 4))?;
+        seq.serialize_element(&self.context().prime())?;
+        seq.serialize_element(&IntPoly::from(self.modulus()))?;
+        seq.serialize_element(&self.nrows_si())?;
+        seq.serialize_element(&self.ncols_si())?;
+        for e in entries.iter() {
+            seq.serialize_element(&IntPoly::from(e))?;
+        }
+        seq.end()
+    }
+}
+
+struct FinFldMatVisitor {}
+
+impl FinFldMatVisitor {
+    fn new() -> Self {
+        FinFldMatVisitor {}
+    }
+}
+
+impl<'de> Visitor<'de> for FinFldMatVisitor {
+    type Value = FinFldMat;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a FinFldMat")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let p: Integer = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let modulus: IntPoly = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let nrows: i64 = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        let ncols: i64 = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+        if !p.is_prime() {
+            return Err(de::Error::custom("characteristic is not prime"));
+        }
+        let modulus = IntModPoly::new(modulus, &IntModCtx::new(p));
+        if modulus.degree() <= 0 || !modulus.is_irreducible() {
+            return Err(de::Error::custom("modulus is not irreducible"));
+        }
+        let entries: Vec<IntPoly> = 
+            read_matrix_entries(&mut access, nrows, ncols, 4, &self)?;
+
+        let ctx = FinFldCtx::with_modulus(&modulus);
+        let mut res = FinFldMat::zero(nrows, ncols, &ctx);
+        let c = res.ncols();
+        for (k, x) in entries.into_iter().enumerate() {
+            res.set_entry(k / c, k % c, FinFldElem::new(x, &ctx));
+        }
+        Ok(res)
+    }
+}
+
+impl<'de> Deserialize<'de> for FinFldMat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(FinFldMatVisitor::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn serde() {
+        let ctx = FinFldCtx::new(7, 3);
+        let mut x = FinFldMat::zero(2, 2, &ctx);
+        x.set_entry(0, 0, FinFldElem::new(IntPoly::from([1, 2, 3]), &ctx));
+        x.set_entry(1, 0, FinFldElem::new(IntPoly::from([0, 6]), &ctx));
+        x.set_entry(1, 1, FinFldElem::one(&ctx));
+        
+        let ser = bincode::serialize(&x).unwrap();
+        let y: FinFldMat = bincode::deserialize(&ser).unwrap();
+        assert_eq!(x.context(), y.context());
+        assert_eq!(x.get_entries(), y.get_entries());
+        
+        let ser = serde_json::to_string(&x).unwrap();
+        let y: FinFldMat = serde_json::from_str(&ser).unwrap();
+        assert_eq!(x.context(), y.context());
+        assert_eq!(x.get_entries(), y.get_entries());
+    }
+}
@@ -21,8 +21,10 @@ mod conv;
 use crate::*;
 use flint_sys::{
     fq_default::fq_default_ctx_struct,
-    fq_default_poly::*
+    fq_default_poly::*,
+    fq_default_poly_factor as fq_fac
 };
+use crate::util::DisplayList;
 use std::fmt;
 //use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
@@ -40,6 +42,13 @@ impl AsRef<FinFldPoly> for FinFldPoly {
     }
 }
 
+impl AsRef<FinFldCtx> for FinFldPoly {
+    #[inline]
+    fn as_ref(&self) -> &FinFldCtx {
+        self.context()
+    }
+}
+
 impl Clone for FinFldPoly {
     #[inline]
     fn clone(&self) -> Self {
@@ -59,7 +68,7 @@ impl Clone for FinFldPoly {
 impl fmt::Debug for FinFldPoly {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("FinFldPoly")
-            //.field("inner", &self.inner)
+            .field("coeffs", &DisplayList(&self.get_coeffs()))
             .field("ctx", &self.ctx)
             .finish()
     }
@@ -139,6 +148,20 @@ impl FinFldPoly {
         unsafe{ fq_default_poly_one(res.as_mut_ptr(), ctx.as_ptr()); }
         res
     }
+
+    /// Return a random polynomial of length at most `len`.
+    pub fn randtest(len: usize, ctx: &FinFldCtx, rng: &mut FlintRand) -> FinFldPoly {
+        let mut res = FinFldPoly::with_capacity(len, ctx);
+        unsafe {
+            fq_default_poly_randtest(
+                res.as_mut_ptr(), 
+                rng.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long."),
+                ctx.as_ptr()
+            );
+        }
+        res
+    }
     
     #[inline]
     pub const fn as_ptr(&self) -> *const fq_default_poly_struct {
@@ -193,60 +216,40 @@ impl FinFldPoly {
         self.context().modulus()
     }
 
-    /*
     #[inline]
     pub fn is_zero(&self) -> bool {
-        unsafe { 
-            fmpz_mod_poly::fmpz_mod_poly_is_zero(
-                self.as_ptr(), 
-                self.ctx_as_ptr()
-            ) == 1
-        }
+        unsafe { fq_default_poly_is_zero(self.as_ptr(), self.ctx_as_ptr()) == 1 }
     }
 
     #[inline]
     pub fn is_one(&self) -> bool {
-        unsafe { 
-            fmpz_mod_poly::fmpz_mod_poly_is_one(
-                self.as_ptr(), 
-                self.ctx_as_ptr()
-            ) == 1
-        }
+        unsafe { fq_default_poly_is_one(self.as_ptr(), self.ctx_as_ptr()) == 1 }
     }
 
     #[inline]
     pub fn is_gen(&self) -> bool {
-        unsafe { 
-            fmpz_mod_poly::fmpz_mod_poly_is_gen(
-                self.as_ptr(), 
-                self.ctx_as_ptr()
-            ) == 1
-        }
+        unsafe { fq_default_poly_is_gen(self.as_ptr(), self.ctx_as_ptr()) == 1 }
     }
 
-    
     #[inline]
     pub fn len(&self) -> usize {
         unsafe { 
-            fmpz_mod_poly::fmpz_mod_poly_length(
-                self.as_ptr(), 
-                self.ctx_as_ptr()
-            ).try_into().unwrap()
+            fq_default_poly_length(self.as_ptr(), self.ctx_as_ptr())
+                .try_into()
+                .expect("Cannot convert signed long to usize.")
         }
     }
 
     #[inline]
     pub fn degree(&self) -> i64 {
-        unsafe { 
-            fmpz_mod_poly::fmpz_mod_poly_degree(self.as_ptr(), self.ctx_as_ptr()) 
-        }
+        unsafe { fq_default_poly_degree(self.as_ptr(), self.ctx_as_ptr()) }
     }
     
-    pub fn get_coeff(&self, i: usize) -> IntMod {
+    pub fn get_coeff(&self, i: usize) -> FinFldElem {
         let ctx = self.context();
-        let mut res = IntMod::zero(&ctx);
+        let mut res = FinFldElem::zero(ctx);
         unsafe { 
-            fmpz_mod_poly::fmpz_mod_poly_get_coeff_fmpz(
+            fq_default_poly_get_coeff(
                 res.as_mut_ptr(), 
                 self.as_ptr(), 
                 i.try_into().expect("Cannot convert index to a signed long."),
@@ -256,26 +259,392 @@ impl FinFldPoly {
         res
     }
     
-    pub fn set_coeff<T: AsRef<IntMod>>(&mut self, i: usize, coeff: T) {
+    pub fn set_coeff<T: AsRef<FinFldElem>>(&mut self, i: usize, coeff: T) {
+        let coeff = coeff.as_ref();
+        assert_eq!(self.context(), coeff.context());
         unsafe {
-            fmpz_mod_poly::fmpz_mod_poly_set_coeff_fmpz(
+            fq_default_poly_set_coeff(
                 self.as_mut_ptr(),                                 
                 i.try_into().expect("Cannot convert index to a signed long."), 
-                coeff.as_ref().as_ptr(),
+                coeff.as_ptr(),
                 self.ctx_as_ptr()
             );
         }
     }
     
-    // TODO: anything better?
     #[inline]
-    pub fn get_coeffs(&self) -> Vec<IntMod> {
-        let mut res = Vec::with_capacity(self.len());
-        for i in 0..self.len() {
-            res.push(self.get_coeff(i))
+    pub fn get_coeffs(&self) -> Vec<FinFldElem> {
+        (0..self.len()).map(|i| self.get_coeff(i)).collect()
+    }
+
+    /// Lift a polynomial with coefficients in the prime field to the integer
+    /// polynomial with coefficients in `[0, p)`. Panics if a coefficient is
+    /// not in the prime field.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(5, 1);
+    /// let f = IntPoly::from([-1, 7, 1]).coerce_into(&FinFldPolyRing::init(&ctx));
+    /// assert_eq!(f.lift_to_intpoly(), IntPoly::from([4, 2, 1]));
+    /// ```
+    pub fn lift_to_intpoly(&self) -> IntPoly {
+        let mut res = IntPoly::zero();
+        for (i, c) in self.get_coeffs().iter().enumerate() {
+            let c = IntPoly::from(c);
+            assert!(c.degree() <= 0, "Coefficient is not in the prime field.");
+            res.set_coeff(i, c.get_coeff(0));
+        }
+        res
+    }
+
+    /// Return the resultant of `self` and `other`, computed with the 
+    /// Euclidean algorithm.
+    pub fn resultant<T: AsRef<FinFldPoly>>(&self, other: T) -> FinFldElem {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        let ctx = self.context();
+        if self.is_zero() || other.is_zero() {
+            return FinFldElem::zero(ctx);
+        }
+
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let mut res = FinFldElem::one(ctx);
+        loop {
+            check_interrupt();
+            let (n, m) = (a.degree() as u64, b.degree() as u64);
+            if m == 0 {
+                return res * b.get_coeff(0).pow(n);
+            }
+            let mut r = FinFldPoly::zero(ctx);
+            unsafe {
+                fq_default_poly_rem(r.as_mut_ptr(), a.as_ptr(), b.as_ptr(), ctx.as_ptr());
+            }
+            if r.is_zero() {
+                return FinFldElem::zero(ctx);
+            }
+            // res(a, b) = (-1)^(nm) lc(b)^(n - deg r) res(b, r)
+            let k = r.degree() as u64;
+            res *= b.get_coeff(m as usize).pow(n - k);
+            if n * m % 2 == 1 {
+                res = -res;
+            }
+            a = b;
+            b = r;
+        }
+    }
+
+    /// Return the discriminant of the polynomial. The discriminant of a 
+    /// constant polynomial is zero.
+    pub fn discriminant(&self) -> FinFldElem {
+        let ctx = self.context();
+        let n = self.degree();
+        if n < 1 {
+            return FinFldElem::zero(ctx);
+        }
+        let mut df = FinFldPoly::zero(ctx);
+        unsafe { fq_default_poly_derivative(df.as_mut_ptr(), self.as_ptr(), ctx.as_ptr()); }
+        if df.is_zero() {
+            return FinFldElem::zero(ctx);
+        }
+
+        // the derivative may drop in degree in positive characteristic, in 
+        // which case the resultant with formal degree n - 1 picks up extra 
+        // factors of the leading coefficient
+        let n = n as u64;
+        let m = df.degree() as u64;
+        let lc = self.get_coeff(n as usize);
+        let res = self.resultant(&df) * lc.clone().pow(n - 1 - m) / lc;
+        if (n * (n - 1) / 2) % 2 == 1 {
+            -res
+        } else {
+            res
+        }
+    }
+
+    /// Return the subresultant polynomial remainder sequence of `self` and 
+    /// `other`, computed as for [IntPoly::subresultants].
+    pub fn subresultants<T: AsRef<FinFldPoly>>(&self, other: T) -> Vec<FinFldPoly> {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        crate::prs::subresultants(self, other)
+    }
+
+    /// Return the monic greatest common divisor of `self` and `other`.
+    #[inline]
+    pub fn gcd<T: AsRef<FinFldPoly>>(&self, other: T) -> FinFldPoly {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        let mut res = FinFldPoly::zero(self.context());
+        unsafe {
+            fq_default_poly_gcd(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return `(g, s, t)` where `g` is the monic gcd of `self` and `other` and 
+    /// `s*self + t*other = g`.
+    pub fn xgcd<T: AsRef<FinFldPoly>>(&self, other: T) -> (FinFldPoly, FinFldPoly, FinFldPoly) {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        let ctx = self.context();
+        let mut d = FinFldPoly::zero(ctx);
+        let mut s = FinFldPoly::zero(ctx);
+        let mut t = FinFldPoly::zero(ctx);
+        unsafe {
+            fq_default_poly_xgcd(
+                d.as_mut_ptr(), 
+                s.as_mut_ptr(), 
+                t.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(),
+                ctx.as_ptr()
+            );
+        }
+        (d, s, t)
+    }
+
+    /// Return the inverse of `self` modulo `modulus`, or `None` if they are 
+    /// not coprime. Panics if `modulus` is constant.
+    pub fn invmod<T: AsRef<FinFldPoly>>(&self, modulus: T) -> Option<FinFldPoly> {
+        let modulus = modulus.as_ref();
+        assert!(modulus.degree() > 0, "Modulus must be nonconstant.");
+        let (d, s, _) = self.xgcd(modulus);
+        if d.is_one() {
+            Some(s.divrem(modulus).1)
+        } else {
+            None
+        }
+    }
+
+    /// Return `(q, r)` with `self = q*other + r` and `deg(r) < deg(other)`. 
+    /// Panics if `other` is zero.
+    pub fn divrem<T: AsRef<FinFldPoly>>(&self, other: T) -> (FinFldPoly, FinFldPoly) {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        assert!(!other.is_zero(), "Division by zero.");
+        let ctx = self.context();
+        let mut q = FinFldPoly::zero(ctx);
+        let mut r = FinFldPoly::zero(ctx);
+        unsafe {
+            fq_default_poly_divrem(
+                q.as_mut_ptr(), 
+                r.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(),
+                ctx.as_ptr()
+            );
+        }
+        (q, r)
+    }
+
+    /// Return the distinct roots of a nonzero polynomial in its field of
+    /// coefficients.
+    pub fn roots(&self) -> Vec<FinFldElem> {
+        assert!(!self.is_zero());
+        let ctx = self.context();
+        let mut lin = FinFldPoly::zero(ctx);
+        let mut res = Vec::new();
+        unsafe {
+            let mut fac = MaybeUninit::uninit();
+            fq_fac::fq_default_poly_factor_init(fac.as_mut_ptr(), ctx.as_ptr());
+            let mut fac = fac.assume_init();
+            fq_fac::fq_default_poly_roots(&mut fac, self.as_ptr(), 0, ctx.as_ptr());
+            let len = fq_fac::fq_default_poly_factor_length(&mut fac, ctx.as_ptr());
+            for i in 0..len {
+                // the factors are monic and linear, x - r
+                fq_fac::fq_default_poly_factor_get_poly(
+                    lin.as_mut_ptr(), 
+                    &mut fac, 
+                    i, 
+                    ctx.as_ptr()
+                );
+                res.push(-lin.get_coeff(0));
+            }
+            fq_fac::fq_default_poly_factor_clear(&mut fac, ctx.as_ptr());
+        }
+        res
+    }
+
+    /// Return `self^e` reduced modulo `modulus`, by binary exponentiation.
+    /// Panics if `modulus` is zero.
+    pub fn powmod<E, T>(&self, e: E, modulus: T) -> FinFldPoly
+    where
+        E: Into<Integer>,
+        T: AsRef<FinFldPoly>
+    {
+        let modulus = modulus.as_ref();
+        assert_eq!(self.context(), modulus.context());
+        assert!(!modulus.is_zero(), "Division by zero.");
+        let e = e.into();
+        assert!(e >= 0, "Exponent must be nonnegative.");
+
+        // the exponentiation requires a reduced base
+        let base = if self.len() >= modulus.len() {
+            self.divrem(modulus).1
+        } else {
+            self.clone()
+        };
+        let mut res = FinFldPoly::zero(self.context());
+        unsafe {
+            fq_default_poly_powmod_fmpz_binexp(
+                res.as_mut_ptr(), 
+                base.as_ptr(), 
+                e.as_ptr(), 
+                modulus.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return the inverse of the reversal of `self` as a power series to
+    /// precision `len(self)`, the precomputed inverse used by 
+    /// [FinFldPoly::powmod_preinv] and [FinFldPoly::x_powmod_preinv]. Panics 
+    /// if `self` is zero.
+    pub fn preinv(&self) -> FinFldPoly {
+        assert!(!self.is_zero(), "Division by zero.");
+        let len = self.len().try_into().expect("Cannot convert length to a signed long.");
+        let mut rev = FinFldPoly::zero(self.context());
+        let mut res = FinFldPoly::zero(self.context());
+        unsafe {
+            fq_default_poly_reverse(
+                rev.as_mut_ptr(), 
+                self.as_ptr(), 
+                len, 
+                self.ctx_as_ptr()
+            );
+            fq_default_poly_inv_series_newton(
+                res.as_mut_ptr(), 
+                rev.as_ptr(), 
+                len, 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return `self^e` reduced modulo `modulus`, given the precomputed 
+    /// inverse `finv = modulus.preinv()`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(5, 2);
+    /// let r = FinFldPolyRing::init(&ctx);
+    /// let x = r.gen();
+    /// let f = &x * &x * &x + &x + r.one();
+    /// let g = &x + r.one();
+    /// assert_eq!(g.powmod_preinv(1000, &f, &f.preinv()), g.powmod(1000, &f));
+    /// ```
+    pub fn powmod_preinv<E, T>(&self, e: E, modulus: T, finv: &FinFldPoly) -> FinFldPoly
+    where
+        E: Into<Integer>,
+        T: AsRef<FinFldPoly>
+    {
+        let modulus = modulus.as_ref();
+        assert_eq!(self.context(), modulus.context());
+        assert_eq!(self.context(), finv.context());
+        assert!(!modulus.is_zero(), "Division by zero.");
+        let e = e.into();
+        assert!(e >= 0, "Exponent must be nonnegative.");
+
+        // the exponentiation requires a reduced base
+        let base = if self.len() >= modulus.len() {
+            self.divrem(modulus).1
+        } else {
+            self.clone()
+        };
+        let mut res = FinFldPoly::zero(self.context());
+        unsafe {
+            fq_default_poly_powmod_fmpz_binexp_preinv(
+                res.as_mut_ptr(), 
+                base.as_ptr(), 
+                e.as_ptr(), 
+                modulus.as_ptr(), 
+                finv.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return `x^e` reduced modulo `modulus`, given the precomputed inverse 
+    /// `finv = modulus.preinv()`, by sliding window exponentiation 
+    /// specialized to the base `x`. Panics unless `modulus` is nonconstant.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = FinFldCtx::new(3, 1);
+    /// let r = FinFldPolyRing::init(&ctx);
+    /// let x = r.gen();
+    /// let f = &x * &x + r.one();
+    /// let y = FinFldPoly::x_powmod_preinv(81, &f, &f.preinv());
+    /// assert_eq!(y, x.powmod(81, &f));
+    /// ```
+    pub fn x_powmod_preinv<E, T>(e: E, modulus: T, finv: &FinFldPoly) -> FinFldPoly
+    where
+        E: Into<Integer>,
+        T: AsRef<FinFldPoly>
+    {
+        let modulus = modulus.as_ref();
+        assert_eq!(modulus.context(), finv.context());
+        assert!(modulus.degree() > 0, "Modulus must be nonconstant.");
+        let e = e.into();
+        assert!(e >= 0, "Exponent must be nonnegative.");
+        let mut res = FinFldPoly::zero(modulus.context());
+        unsafe {
+            fq_default_poly_powmod_x_fmpz_preinv(
+                res.as_mut_ptr(), 
+                e.as_ptr(), 
+                modulus.as_ptr(), 
+                finv.as_ptr(), 
+                modulus.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return the image `self^(q^k)` of `self` under the `k`-th power of the 
+    /// Frobenius map of `F_q[x]/(modulus)`, where `q` is the order of the 
+    /// coefficient field. For `self = x` this is `x^(q^k)`, which equals `x`
+    /// modulo every irreducible factor of `modulus` whose degree divides `k`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // x^2 + 1 is irreducible over F_3, and the Frobenius swaps its roots
+    /// let ctx = FinFldCtx::new(3, 1);
+    /// let r = FinFldPolyRing::init(&ctx);
+    /// let x = r.gen();
+    /// let f = &x * &x + r.one();
+    /// assert_eq!(x.frobenius(1, &f), -&x);
+    /// assert_eq!(x.frobenius(2, &f), x);
+    /// assert_eq!(x.frobenius(2, &f), x.powmod(Integer::from(9), &f));
+    ///
+    /// let y = &x + &r.one();
+    /// assert_eq!(y.frobenius(1, &f), y.powmod(Integer::from(3), &f));
+    /// assert_eq!(y.frobenius(1, &f), &r.one() - &x);
+    /// ```
+    pub fn frobenius<T: AsRef<FinFldPoly>>(&self, k: u64, modulus: T) -> FinFldPoly {
+        let modulus = modulus.as_ref();
+        let q = self.context().order();
+        let finv = modulus.preinv();
+        let mut res = if self.len() >= modulus.len() {
+            self.divrem(modulus).1
+        } else {
+            self.clone()
+        };
+        for _ in 0..k {
+            res = res.powmod_preinv(q.clone(), modulus, &finv);
         }
         res
     }
-    */
 }
 
@@ -21,15 +21,29 @@ mod conv;
 #[cfg(feature = "serde")]
 mod serde;
 
+#[cfg(feature = "num-bigint")]
+mod bigint;
+
+#[cfg(feature = "rug")]
+mod rug;
+
 pub mod macros;
 
-use crate::New;
-use flint_sys::fmpz;
+mod lit;
+pub use lit::IntegerLit;
+pub use conv::RoundMode;
+
+use crate::{New, Rational, FlintRand};
+use flint_sys::{fmpz, fmpq};
+use flint_sys::deps::mp_limb_t;
+use flint_sys::flint::FLINT_BITS;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
+use std::os::raw::c_int;
 
-#[derive(Debug)]
+// `repr(transparent)` lets matrix entries be borrowed as `&Integer`.
+#[repr(transparent)]
 pub struct Integer {
     inner: fmpz::fmpz,
 }
@@ -62,10 +76,20 @@ impl Default for Integer {
     }
 }
 
+impl fmt::Debug for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Integer").field(&format_args!("{}", self)).finish()
+    }
+}
+
 impl fmt::Display for Integer {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.to_str_radix(10))
+        let s = self.to_str_radix(10);
+        match s.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "", digits),
+            None => f.pad_integral(true, "", &s),
+        }
     }
 }
 
@@ -77,12 +101,34 @@ impl Drop for Integer {
 }
 
 impl Hash for Integer {
+    /// Hash the limbs of the absolute value as returned by 
+    /// [get_ui_vector](Integer::get_ui_vector), reading them in place.
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.get_ui_vector().hash(state);
+        if self.is_mpz() {
+            unsafe {
+                let z = &*self.as_mpz_ptr();
+                let n = z.size.unsigned_abs() as usize;
+                std::slice::from_raw_parts(z.limbs, n).hash(state);
+            }
+        } else if self.is_zero() {
+            <[mp_limb_t]>::hash(&[], state);
+        } else {
+            <[mp_limb_t]>::hash(&[self.inner.unsigned_abs() as mp_limb_t], state);
+        }
     }
 }
 
+// The layout of a GMP integer, which backs an fmpz that does not fit in 
+// `FLINT_BITS - 2` bits: the number of allocated limbs, the signed number of 
+// used limbs and the limbs, least significant first.
+#[repr(C)]
+struct MpzStruct {
+    alloc: c_int,
+    size: c_int,
+    limbs: *const mp_limb_t,
+}
+
 impl<T: Into<Integer>> New<T> for Integer {
     #[inline]
     fn new(src: T) -> Self {
@@ -311,6 +357,138 @@ impl Integer {
         }
     }
 
+    /// Return the digits of the absolute value of the `Integer` in base `base`, 
+    /// least significant digit first. The digits of zero are the empty vector.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let z = Integer::from(-1234);
+    /// assert_eq!(z.to_digits(10), vec![4, 3, 2, 1]);
+    /// assert_eq!(Integer::from_digits(&[4, 3, 2, 1], 10), 1234);
+    ///
+    /// let x = Integer::from(3).pow(100u8);
+    /// assert_eq!(Integer::from_digits(&x.to_digits(7), 7), x);
+    /// ```
+    pub fn to_digits(&self, base: u64) -> Vec<u64> {
+        assert!(base > 1);
+        let mut res = vec![];
+        if self.is_zero() {
+            return res;
+        }
+
+        // peel off k digits at a time using the largest power of the base 
+        // fitting in a limb
+        let (k, bk) = Self::digit_chunk(base);
+        let mut x = self.abs();
+        while !x.is_zero() {
+            let mut r = unsafe { 
+                let r = fmpz::fmpz_fdiv_ui(x.as_ptr(), bk);
+                fmpz::fmpz_fdiv_q_ui(x.as_mut_ptr(), x.as_ptr(), bk);
+                r
+            };
+            for _ in 0..k {
+                res.push(r % base);
+                r /= base;
+            }
+        }
+
+        while res.last() == Some(&0) {
+            res.pop();
+        }
+        res
+    }
+
+    /// Construct a nonnegative `Integer` from its digits in base `base`, least 
+    /// significant digit first. Panics if any digit is not less than `base`.
+    pub fn from_digits(digits: &[u64], base: u64) -> Integer {
+        assert!(base > 1);
+        assert!(digits.iter().all(|&d| d < base), "Digit out of range.");
+        
+        let (k, bk) = Self::digit_chunk(base);
+        let mut res = Integer::default();
+        for chunk in digits.rchunks(k) {
+            let val = chunk.iter().rev().fold(0u64, |acc, &d| acc * base + d);
+            let mul = if chunk.len() == k {
+                bk
+            } else {
+                base.pow(chunk.len() as u32)
+            };
+            unsafe {
+                fmpz::fmpz_mul_ui(res.as_mut_ptr(), res.as_ptr(), mul);
+                fmpz::fmpz_add_ui(res.as_mut_ptr(), res.as_ptr(), val);
+            }
+        }
+        res
+    }
+
+    // Return the largest `k` such that `base^k` fits in a limb, and `base^k`.
+    fn digit_chunk(base: u64) -> (usize, u64) {
+        let mut k = 1;
+        let mut bk = base;
+        while let Some(t) = bk.checked_mul(base) {
+            bk = t;
+            k += 1;
+        }
+        (k, bk)
+    }
+
+    /// Return the little-endian bytes of the absolute value of the `Integer`, 
+    /// with no trailing zero bytes. The bytes of zero are the empty vector.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let z = Integer::from(0x1234);
+    /// assert_eq!(z.to_bytes_le(), vec![0x34, 0x12]);
+    /// assert_eq!(z.to_bytes_be(), vec![0x12, 0x34]);
+    /// assert_eq!(Integer::from_bytes_le(&[0x34, 0x12]), z);
+    /// assert_eq!(Integer::from_bytes_be(&[0x00, 0x12, 0x34]), z);
+    /// ```
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut res: Vec<u8> = self.abs()
+            .get_ui_vector()
+            .into_iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect();
+        while res.last() == Some(&0) {
+            res.pop();
+        }
+        res
+    }
+
+    /// Return the big-endian bytes of the absolute value of the `Integer`, 
+    /// with no leading zero bytes.
+    #[inline]
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut res = self.to_bytes_le();
+        res.reverse();
+        res
+    }
+
+    /// Construct a nonnegative `Integer` from its little-endian bytes.
+    pub fn from_bytes_le(bytes: &[u8]) -> Integer {
+        let limbs = bytes
+            .chunks(8)
+            .map(|chunk| {
+                let mut buf = [0u8; 8];
+                buf[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(buf)
+            })
+            .collect();
+        let mut res = Integer::default();
+        res.set_ui_vector(limbs);
+        res
+    }
+
+    /// Construct a nonnegative `Integer` from its big-endian bytes.
+    #[inline]
+    pub fn from_bytes_be(bytes: &[u8]) -> Integer {
+        let mut le = bytes.to_vec();
+        le.reverse();
+        Integer::from_bytes_le(&le)
+    }
+
     /// Convert the `Integer` to a string in base `base`.
     ///
     /// ```
@@ -393,6 +571,41 @@ impl Integer {
     pub fn size(&self) -> i64 {
         unsafe { flint_sys::fmpz::fmpz_size(self.as_ptr()) }
     }
+
+    /// Returns the number of limbs allocated on the heap for the `Integer`. 
+    /// Values of at most `FLINT_BITS - 2` bits are stored inline and use no 
+    /// heap limbs, larger values are backed by a GMP integer whose allocation may exceed 
+    /// [size][Integer::size].
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::from(12345).allocated_limbs(), 0);
+    /// let z = Integer::from(2).pow(200u32);
+    /// assert!(z.allocated_limbs() >= 4);
+    /// ```
+    #[inline]
+    pub fn allocated_limbs(&self) -> usize {
+        if self.is_mpz() {
+            unsafe { (*self.as_mpz_ptr()).alloc as usize }
+        } else {
+            0
+        }
+    }
+
+    // COEFF_TO_PTR: an fmpz backed by a GMP integer stores the pointer 
+    // shifted right by two, which only makes sense if `is_mpz` holds.
+    #[inline]
+    fn as_mpz_ptr(&self) -> *const MpzStruct {
+        debug_assert!(self.is_mpz());
+        (self.inner << 2) as *const MpzStruct
+    }
+
+    // COEFF_IS_MPZ: the top two bits of an fmpz are 01 iff it is a pointer.
+    #[inline]
+    pub(crate) fn is_mpz(&self) -> bool {
+        (self.inner as mp_limb_t) >> (FLINT_BITS - 2) == 1
+    }
     
     /// Returns -1 if the `Integer` is negative, +1 if the `Integer`
     /// is positive, and 0 otherwise.
@@ -650,8 +863,8 @@ impl Integer {
         }
     }
     
-    /* TODO: Flint 3
-    /// Return the power of two `2^exp`.
+    /// Return the power of two `2^exp`. With the `flint3` feature this calls
+    /// `fmpz_one_2exp` directly.
     ///
     /// ```
     /// use inertia_core::Integer;
@@ -664,9 +877,7 @@ impl Integer {
         S: Into<u64>,
     {
         let mut res = Integer::default();
-        unsafe {
-            fmpz::fmpz_one_2exp(res.as_mut_ptr(), exp.into());
-        }
+        res.one_2exp_assign(exp);
         res
     }
     
@@ -675,7 +886,7 @@ impl Integer {
     /// ```
     /// use inertia_core::Integer;
     ///
-    /// let mut g = Integer::from(2);
+    /// let mut g = Integer::from(5);
     /// g.one_2exp_assign(3u32);
     /// assert_eq!(g, 8);
     /// ```
@@ -684,11 +895,16 @@ impl Integer {
     where
         S: Into<u64>,
     {
+        #[cfg(feature = "flint3")]
+        unsafe {
+            fmpz::fmpz_one_2exp(self.as_mut_ptr(), exp.into());
+        }
+        #[cfg(not(feature = "flint3"))]
         unsafe {
+            fmpz::fmpz_one(self.as_mut_ptr());
             fmpz::fmpz_mul_2exp(self.as_mut_ptr(), self.as_ptr(), exp.into());
         }
     }
-    */
 
     /// Return `self + (x * y)`.
     ///
@@ -1715,6 +1931,82 @@ impl Integer {
             fmpz::fmpz_flog_ui(self.as_ptr(), b)
         }
     }
+
+    /// Return `floor(log2(|self|))`, one less than the number of bits. Panics 
+    /// if `self` is zero.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::from(1024).ilog2(), 10);
+    /// assert_eq!(Integer::from(-1023).ilog2(), 9);
+    /// ```
+    #[inline]
+    pub fn ilog2(&self) -> u64 {
+        assert!(!self.is_zero(), "The logarithm of zero is undefined.");
+        self.bits() - 1
+    }
+
+    /// Return `floor(log10(|self|))`, one less than the number of decimal 
+    /// digits. Panics if `self` is zero.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::from(999).ilog10(), 2);
+    /// assert_eq!(Integer::from(-1000).ilog10(), 3);
+    /// ```
+    #[inline]
+    pub fn ilog10(&self) -> u64 {
+        assert!(!self.is_zero(), "The logarithm of zero is undefined.");
+        self.abs().flog_ui(10u64).try_into().expect("Cannot convert signed long to an unsigned long.")
+    }
+
+    /// Return the integer in scientific notation with `sig_digits` 
+    /// significant digits, rounding half away from zero, e.g. `-1.23e45`. 
+    /// Only the leading digits are converted to decimal, so this is cheap 
+    /// for huge integers.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// let x = Integer::from(123456789);
+    /// assert_eq!(x.to_scientific_notation(3), "1.23e8");
+    /// assert_eq!((-x).to_scientific_notation(1), "-1e8");
+    /// assert_eq!(Integer::from(99999).to_scientific_notation(2), "1.0e5");
+    /// assert_eq!(Integer::from(42).to_scientific_notation(4), "4.200e1");
+    /// assert_eq!(Integer::from(0).to_scientific_notation(2), "0.0e0");
+    /// ```
+    pub fn to_scientific_notation(&self, sig_digits: usize) -> String {
+        assert!(sig_digits > 0, "At least one significant digit is required.");
+        let sig = sig_digits as u64;
+        let (mut mantissa, mut exp) = if self.is_zero() {
+            (Integer::zero(), 0)
+        } else {
+            let abs = self.abs();
+            let exp = abs.ilog10();
+            if exp < sig {
+                (abs * Integer::from(10).pow(sig - 1 - exp), exp)
+            } else {
+                let scale = Integer::from(10).pow(exp + 1 - sig);
+                let half = scale.fdiv_q(Integer::from(2));
+                ((abs + half).fdiv_q(&scale), exp)
+            }
+        };
+        // rounding up may carry into a new digit, e.g. 9.99 -> 10.0
+        if mantissa.bits() > 0 && mantissa.ilog10() == sig {
+            mantissa = mantissa.fdiv_q(Integer::from(10));
+            exp += 1;
+        }
+
+        let digits = format!("{:0>width$}", mantissa.to_string(), width = sig_digits);
+        let sign = if self.sign() < 0 { "-" } else { "" };
+        if sig_digits == 1 {
+            format!("{}{}e{}", sign, digits, exp)
+        } else {
+            format!("{}{}.{}e{}", sign, &digits[..1], &digits[1..], exp)
+        }
+    }
     
     /// Return the integer part of the square root of `self`.
     ///
@@ -1905,6 +2197,103 @@ impl Integer {
         }
     }
 
+    /// Return the maximal `k` and the root `r` with `self = r^k`. If `self` is
+    /// not a perfect power this is `(self, 1)`, as it is for `0`, `1` and 
+    /// `-1`, which are `k`-th powers for every odd `k`.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::from(2).pow(12u8).is_perfect_power_full(), (Integer::from(2), 12));
+    /// assert_eq!(Integer::from(-216).is_perfect_power_full(), (Integer::from(-6), 3));
+    /// assert_eq!(Integer::from(12).is_perfect_power_full(), (Integer::from(12), 1));
+    /// ```
+    pub fn is_perfect_power_full(&self) -> (Integer, u64) {
+        let mut r = self.clone();
+        let mut k = 1u64;
+        // the roots of 0 and 1 are themselves and would loop forever
+        while r.abs() > 1 {
+            match r.is_perfect_power() {
+                Some((root, e)) => {
+                    r = root;
+                    k *= u64::try_from(e).expect("Exponents are positive.");
+                }
+                None => break,
+            }
+        }
+        (r, k)
+    }
+
+    /// Return the square root of `self` if it is a perfect square, otherwise
+    /// `None`.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::from(144).sqrt_exact().unwrap(), 12);
+    /// assert!(Integer::from(145).sqrt_exact().is_none());
+    /// assert!(Integer::from(-4).sqrt_exact().is_none());
+    /// ```
+    pub fn sqrt_exact(&self) -> Option<Integer> {
+        if self.sign() < 0 {
+            return None;
+        }
+        let (f, r) = self.sqrtrem();
+        if r.is_zero() { Some(f) } else { None }
+    }
+
+    /// Return the `n`-th root of `self` if it is an exact `n`-th power, 
+    /// otherwise `None`. Negative numbers have exact roots only for odd `n`.
+    /// Requires that `n > 0`.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::from(-125).nth_root_exact(3).unwrap(), -5);
+    /// assert!(Integer::from(-16).nth_root_exact(4).is_none());
+    /// assert!(Integer::from(17).nth_root_exact(4).is_none());
+    /// ```
+    pub fn nth_root_exact<S>(&self, n: S) -> Option<Integer>
+    where
+        S: Into<i64>
+    {
+        let n = n.into();
+        assert!(n > 0);
+        if n % 2 == 0 && self.sign() < 0 {
+            return None;
+        }
+        let (r, rem) = self.iroot_rem(n);
+        if rem.is_zero() { Some(r) } else { None }
+    }
+
+    /// Return the integer part `r` of the `n`-th root of `self`, rounded 
+    /// towards zero, and the remainder `self - r^n`, which has the sign of
+    /// `self`. Requires that `n > 0` and if `n` is even then `self` is 
+    /// non-negative.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// let (r, rem) = Integer::from(100).iroot_rem(3);
+    /// assert_eq!((r, rem), (Integer::from(4), Integer::from(36)));
+    /// let (r, rem) = Integer::from(-100).iroot_rem(3);
+    /// assert_eq!((r, rem), (Integer::from(-4), Integer::from(-36)));
+    /// ```
+    pub fn iroot_rem<S>(&self, n: S) -> (Integer, Integer)
+    where
+        S: Into<i64>
+    {
+        let n = n.into();
+        let r = self.root(n);
+        let e = u64::try_from(n).expect("The root degree must be positive.");
+        let mut pow = Integer::default();
+        unsafe {
+            fmpz::fmpz_pow_ui(pow.as_mut_ptr(), r.as_ptr(), e);
+        }
+        let rem = self - pow;
+        (r, rem)
+    }
+
     /// Return the factorial `n!` where `n` is an unsigned long.
     ///
     /// ```
@@ -1938,6 +2327,26 @@ impl Integer {
     {
         Integer::fac_ui(n)
     }
+
+    /// Return the primorial `n#`, the product of the primes up to `n`.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::primorial(10u32), 210);
+    /// assert_eq!(Integer::primorial(1u32), 1);
+    /// ```
+    #[inline]
+    pub fn primorial<S>(n: S) -> Integer 
+    where
+        S: Into<u64>
+    {
+        let mut res = Integer::default();
+        unsafe {
+            fmpz::fmpz_primorial(res.as_mut_ptr(), n.into());
+        }
+        res
+    }
     
     /// Return the Fibonacci number `F_n` where `n` is an unsigned long.
     ///
@@ -2231,42 +2640,261 @@ impl Integer {
     pub fn is_prime(&self) -> bool {
         unsafe { fmpz::fmpz_is_prime(self.as_ptr()) == 1 }
     }
+
+    /// Factor the absolute value of a nonzero integer into primes. Returns 
+    /// the primes in increasing order with their multiplicities; one has no 
+    /// prime factors.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let fac = Integer::from(-360).factor();
+    /// assert_eq!(fac, vec![(Integer::from(2), 3), (Integer::from(3), 2), (Integer::from(5), 1)]);
+    /// assert!(Integer::one().factor().is_empty());
+    /// ```
+    pub fn factor(&self) -> Vec<(Integer, u64)> {
+        assert!(!self.is_zero(), "Cannot factor zero.");
+        crate::check_interrupt();
+        let mut res = Vec::new();
+        unsafe {
+            let mut fac = MaybeUninit::uninit();
+            flint_sys::fmpz_factor::fmpz_factor_init(fac.as_mut_ptr());
+            let mut fac = fac.assume_init();
+            flint_sys::fmpz_factor::fmpz_factor(&mut fac, self.as_ptr());
+            for i in 0..fac.num.try_into().expect("Cannot convert signed long to usize.") {
+                let mut p = Integer::zero();
+                fmpz::fmpz_set(p.as_mut_ptr(), fac.p.add(i));
+                res.push((p, *fac.exp.add(i)));
+            }
+            flint_sys::fmpz_factor::fmpz_factor_clear(&mut fac);
+        }
+        res.sort();
+        res
+    }
+
+    /// Return Euler's totient `phi(n)`, the number of integers in `[1, n]` 
+    /// coprime to `n`. Panics unless `self` is positive.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::from(36).euler_phi(), 12);
+    /// assert_eq!(Integer::from(1).euler_phi(), 1);
+    /// ```
+    #[inline]
+    pub fn euler_phi(&self) -> Integer {
+        assert!(self > &0);
+        let mut res = Integer::default();
+        unsafe { fmpz::fmpz_euler_phi(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Return the Möbius function `mu(n)`: zero if `n` has a square factor, 
+    /// otherwise `(-1)^k` where `k` is the number of prime factors. Panics 
+    /// unless `self` is positive.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// assert_eq!(Integer::from(30).moebius_mu(), -1);
+    /// assert_eq!(Integer::from(12).moebius_mu(), 0);
+    /// assert_eq!(Integer::from(1).moebius_mu(), 1);
+    /// ```
+    #[inline]
+    pub fn moebius_mu(&self) -> i32 {
+        assert!(self > &0);
+        unsafe { fmpz::fmpz_moebius_mu(self.as_ptr()) }
+    }
+
+    /// Return the divisor function `sigma_k(n)`, the sum of the `k`-th powers 
+    /// of the positive divisors of `n`. In particular `sigma_0` counts the 
+    /// divisors. Panics unless `self` is positive.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// let n = Integer::from(12);
+    /// assert_eq!(n.divisor_sigma(0), 6);
+    /// assert_eq!(n.divisor_sigma(1), 28);
+    /// assert_eq!(n.divisor_sigma(2), 210);
+    /// ```
+    pub fn divisor_sigma(&self, k: u64) -> Integer {
+        assert!(self > &0);
+        let mut res = Integer::one();
+        for (p, e) in self.factor() {
+            // 1 + p^k + ... + p^(ek)
+            let pk = p.pow(k);
+            let mut term = Integer::one();
+            let mut sum = Integer::one();
+            for _ in 0..e {
+                term *= &pk;
+                sum += &term;
+            }
+            res *= sum;
+        }
+        res
+    }
+
+    /// Return the positive divisors of `|self|` in increasing order. Panics 
+    /// if `self` is zero.
+    ///
+    /// ```
+    /// use inertia_core::Integer;
+    ///
+    /// let d = Integer::from(-12).divisors();
+    /// assert_eq!(d, [1, 2, 3, 4, 6, 12].map(Integer::from));
+    /// ```
+    pub fn divisors(&self) -> Vec<Integer> {
+        let mut res = vec![Integer::one()];
+        for (p, e) in self.factor() {
+            let len = res.len();
+            let mut pj = Integer::one();
+            for _ in 0..e {
+                pj *= &p;
+                for i in 0..len {
+                    let d = &res[i] * &pj;
+                    res.push(d);
+                }
+            }
+        }
+        res.sort();
+        res
+    }
    
-    /*
+    /// Rational reconstruction: find a fraction `n/d` congruent to `self` 
+    /// modulo `modulus` with `|n|, d <= sqrt(modulus/2)`. Returns `None` if no 
+    /// such fraction exists. The modulus must be greater than one.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // 2/3 mod 101
+    /// let a = Integer::from(68);
+    /// assert_eq!(a.reconstruct(Integer::from(101)).unwrap(), Rational::from([2, 3]));
+    ///
+    /// // the input is reduced modulo 101 first
+    /// let b = Integer::from(-33);
+    /// assert_eq!(b.reconstruct(Integer::from(101)).unwrap(), Rational::from([2, 3]));
+    /// assert!(Integer::from(-1000).reconstruct(Integer::from(101)).is_none());
+    /// ```
     #[inline]
-    pub fn reconstruct(&self, modulus: T) -> Rational
+    pub fn reconstruct<T>(&self, modulus: T) -> Option<Rational>
     where
         T: AsRef<Integer>
     {
+        let modulus = modulus.as_ref();
+        assert!(*modulus > 1, "The modulus must be greater than one.");
+        let a = self.fdiv_r(modulus);
         let mut res = Rational::default();
         unsafe {
-            fmpq::fmpq_reconstruct_fmpz(
+            let r = fmpq::fmpq_reconstruct_fmpz(
                 res.as_mut_ptr(), 
-                self.as_ptr(), 
+                a.as_ptr(), 
                 modulus.as_ptr()
             );
+            if r == 0 {
+                None
+            } else {
+                Some(res)
+            }
         }
-        res
     }
     
-    #[inline]
-    pub fn reconstruct_2(&self, modulus: T, n: T, d: T) -> Rational 
+    /// Rational reconstruction with explicit bounds: find a fraction `n/d` 
+    /// congruent to `self` modulo `modulus` with `|n| <= num_bound` and 
+    /// `0 < d <= den_bound`. Returns `None` if no such fraction exists. The 
+    /// modulus must be greater than one.
+    #[inline]
+    pub fn reconstruct_2<T, N, D>(
+        &self, 
+        modulus: T, 
+        num_bound: N, 
+        den_bound: D
+    ) -> Option<Rational>
     where
-        T: AsRef<Integer>
+        T: AsRef<Integer>,
+        N: AsRef<Integer>,
+        D: AsRef<Integer>
     {
+        let modulus = modulus.as_ref();
+        assert!(*modulus > 1, "The modulus must be greater than one.");
+        let a = self.fdiv_r(modulus);
         let mut res = Rational::default();
         unsafe {
-            fmpq::fmpq_reconstruct_fmpz_2(
+            let r = fmpq::fmpq_reconstruct_fmpz_2(
                 res.as_mut_ptr(), 
-                self.as_ptr(), 
+                a.as_ptr(), 
                 modulus.as_ptr(),
-                n.as_ptr(),
-                d.as_ptr()
+                num_bound.as_ref().as_ptr(),
+                den_bound.as_ref().as_ptr()
             );
+            if r == 0 {
+                None
+            } else {
+                Some(res)
+            }
         }
+    }
+
+    // Random generation //
+
+    /// Return a random integer with up to `bits` bits, biased towards values 
+    /// that are likely to trigger corner cases.
+    #[inline]
+    pub fn randtest<S>(bits: S, rng: &mut FlintRand) -> Integer 
+    where
+        S: Into<u64>
+    {
+        let mut res = Integer::default();
+        unsafe { fmpz::fmpz_randtest(res.as_mut_ptr(), rng.as_mut_ptr(), bits.into()); }
+        res
+    }
+    
+    /// Return a random nonzero integer with up to `bits` bits.
+    #[inline]
+    pub fn randtest_not_zero<S>(bits: S, rng: &mut FlintRand) -> Integer 
+    where
+        S: Into<u64>
+    {
+        let bits = bits.into();
+        assert!(bits > 0);
+        let mut res = Integer::default();
+        unsafe { fmpz::fmpz_randtest_not_zero(res.as_mut_ptr(), rng.as_mut_ptr(), bits); }
+        res
+    }
+    
+    /// Return a random integer with exactly `bits` bits and random sign.
+    #[inline]
+    pub fn randbits<S>(bits: S, rng: &mut FlintRand) -> Integer 
+    where
+        S: Into<u64>
+    {
+        let mut res = Integer::default();
+        unsafe { fmpz::fmpz_randbits(res.as_mut_ptr(), rng.as_mut_ptr(), bits.into()); }
+        res
+    }
+    
+    /// Return a uniformly random integer in the range `[0, m)`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let mut rng = FlintRand::with_seed(1);
+    /// let m = Integer::from(10);
+    /// let x = Integer::randm(&m, &mut rng);
+    /// assert!(x >= 0 && x < m);
+    /// ```
+    #[inline]
+    pub fn randm<T>(m: T, rng: &mut FlintRand) -> Integer 
+    where
+        T: AsRef<Integer>
+    {
+        let m = m.as_ref();
+        assert!(m > &0);
+        let mut res = Integer::default();
+        unsafe { fmpz::fmpz_randm(res.as_mut_ptr(), rng.as_mut_ptr(), m.as_ptr()); }
         res
     }
-    */
 
     // Special functions //
 }
@@ -0,0 +1,83 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use num_bigint::{BigInt, BigUint, Sign};
+
+// FLINT limbs are 64 bits while BigUint::new expects 32 bit digits.
+impl_from! {
+    BigInt, Integer
+    {
+        fn from(src: &Integer) -> BigInt {
+            let sign = match src.sign() {
+                -1 => Sign::Minus,
+                0 => Sign::NoSign,
+                _ => Sign::Plus,
+            };
+            let digits: Vec<u32> = src.abs()
+                .get_ui_vector()
+                .into_iter()
+                .flat_map(|limb| [limb as u32, (limb >> 32) as u32])
+                .collect();
+            BigInt::from_biguint(sign, BigUint::new(digits))
+        }
+    }
+}
+
+impl_from! {
+    Integer, BigInt
+    {
+        fn from(src: &BigInt) -> Integer {
+            let (sign, limbs) = src.to_u64_digits();
+            let mut res = Integer::default();
+            res.set_ui_vector(limbs);
+            if sign == Sign::Minus {
+                res.neg_assign();
+            }
+            res
+        }
+    }
+}
+
+impl_from! {
+    Integer, BigUint
+    {
+        fn from(src: &BigUint) -> Integer {
+            let mut res = Integer::default();
+            res.set_ui_vector(src.to_u64_digits());
+            res
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Integer;
+    use num_bigint::BigInt;
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn bigint_roundtrip(limbs: Vec<u64>, neg: bool) -> bool {
+        let mut x = Integer::default();
+        x.set_ui_vector(limbs);
+        if neg {
+            x = -x;
+        }
+        let y = BigInt::from(&x);
+        x.to_string() == y.to_string() && Integer::from(&y) == x
+    }
+}
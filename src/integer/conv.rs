@@ -15,21 +15,72 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use crate::{util::is_digit, *};
+use crate::*;
 use crate::error::Error::*;
+use arb_sys::arf::*;
 use flint_sys::fmpz;
+use flint_sys::flint::FLINT_BITS;
 use std::ffi::CString;
 use std::str::FromStr;
 
 
-// FIXME: panics from negative sign...
 // FIXME: Valgrind sometimes complains about possibly lost bytes.
 // Probably false negative, how can we be sure?
 impl FromStr for Integer {
     type Err = Error;
+    /// Parse a decimal integer with an optional minus sign.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x: Integer = "-12345678901234567890".parse().unwrap();
+    /// assert_eq!(x.to_string().parse::<Integer>().unwrap(), x);
+    /// assert!(matches!("12a".parse::<Integer>(), Err(Error::ParseIntegerError(_))));
+    /// ```
+    #[inline]
     fn from_str(s: &str) -> Result<Self> {
-        if !s.chars().all(is_digit) {
-            return Err(Msg("Input is not an integer.".to_string()));
+        Integer::from_str_radix(s, 10)
+    }
+}
+
+// The value of a digit in base `base`, following GMP: for bases up to 36 
+// letters are case insensitive, above that upper case letters come first.
+fn digit_value(c: char, base: u8) -> Option<u8> {
+    let v = match c {
+        '0'..='9' => c as u8 - b'0',
+        'A'..='Z' => c as u8 - b'A' + 10,
+        'a'..='z' if base <= 36 => c as u8 - b'a' + 10,
+        'a'..='z' => c as u8 - b'a' + 36,
+        _ => return None,
+    };
+    if v < base { Some(v) } else { None }
+}
+
+impl Integer {
+    /// Parse an integer in base `base` with an optional minus sign. The base
+    /// can be between 2 and 62, inclusive, other bases are an error. Digits 
+    /// above 9 are letters as in [Integer::to_str_radix]: up to base 36 they 
+    /// are case insensitive, above that `A-Z` come before `a-z`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Integer::from_str_radix("-ff", 16).unwrap();
+    /// assert_eq!(x, -255);
+    /// assert_eq!(Integer::from_str_radix("FF", 16).unwrap(), 255);
+    /// assert_eq!(Integer::from_str_radix(&x.to_str_radix(36), 36).unwrap(), x);
+    /// assert!(Integer::from_str_radix("12", 2).is_err());
+    /// assert!(Integer::from_str_radix("12", 63).is_err());
+    /// ```
+    pub fn from_str_radix(s: &str, base: u8) -> Result<Integer> {
+        if !(2..=62).contains(&base) {
+            return Err(ParseIntegerError(format!(
+                "The base {} is not between 2 and 62.", base)));
+        }
+        let digits = s.strip_prefix('-').unwrap_or(s);
+        if digits.is_empty() || !digits.chars().all(|c| digit_value(c, base).is_some()) {
+            return Err(ParseIntegerError(format!(
+                "{:?} is not an integer in base {}.", s, base)));
         }
 
         if let Ok(c_str) = CString::new(s) {
@@ -38,16 +89,16 @@ impl FromStr for Integer {
                 let res = flint_sys::fmpz::fmpz_set_str(
                     z.as_mut_ptr(), 
                     c_str.as_ptr(), 
-                    10
+                    base.into()
                 );
                 if res == 0 {
                     Ok(z)
                 } else {
-                    Err(Msg("Error in conversion.".to_string()))
+                    Err(ParseIntegerError("Error in conversion.".to_string()))
                 }
             }
         } else {
-            Err(Msg("String contains 0 byte.".to_string()))
+            Err(ParseIntegerError("String contains 0 byte.".to_string()))
         }
     }
 }
@@ -92,3 +143,152 @@ impl TryFrom<Rational> for Integer {
         }
     }
 }
+
+fn conversion_error(src: &Integer, out_type: &str) -> Error {
+    ConversionError {
+        val: src.to_string(),
+        in_type: "Integer".to_string(),
+        out_type: out_type.to_string(),
+    }
+}
+
+macro_rules! impl_tryfrom_primitive {
+    ($get:ident; $($t:ident)*) => ($(
+        impl TryFrom<&Integer> for $t {
+            type Error = Error;
+            #[inline]
+            fn try_from(src: &Integer) -> Result<Self> {
+                src.$get()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| conversion_error(src, stringify!($t)))
+            }
+        }
+
+        impl TryFrom<Integer> for $t {
+            type Error = Error;
+            #[inline]
+            fn try_from(src: Integer) -> Result<Self> {
+                <$t>::try_from(&src)
+            }
+        }
+    )*)
+}
+
+impl_tryfrom_primitive! { get_ui; usize u64 u32 u16 u8 }
+impl_tryfrom_primitive! { get_si; isize i64 i32 i16 i8 }
+
+impl Integer {
+    // The absolute value as a u128, if it fits.
+    fn abs_get_u128(&self) -> Option<u128> {
+        if self.bits() > 128 {
+            return None;
+        }
+        // the limbs are least significant first
+        let v = self.abs().get_ui_vector();
+        Some(v.iter().rev().fold(0, |acc, &limb| (acc << FLINT_BITS) | limb as u128))
+    }
+}
+
+impl TryFrom<&Integer> for u128 {
+    type Error = Error;
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Integer::from(2).pow(100u8);
+    /// assert_eq!(u128::try_from(&x).unwrap(), 1u128 << 100);
+    /// assert!(u8::try_from(&x).is_err());
+    /// assert!(u128::try_from(Integer::from(-1)).is_err());
+    /// assert_eq!(i128::try_from(-x).unwrap(), -(1i128 << 100));
+    /// ```
+    fn try_from(src: &Integer) -> Result<Self> {
+        if src.sign() < 0 {
+            return Err(conversion_error(src, "u128"));
+        }
+        src.abs_get_u128().ok_or_else(|| conversion_error(src, "u128"))
+    }
+}
+
+impl TryFrom<Integer> for u128 {
+    type Error = Error;
+    #[inline]
+    fn try_from(src: Integer) -> Result<Self> {
+        u128::try_from(&src)
+    }
+}
+
+impl TryFrom<&Integer> for i128 {
+    type Error = Error;
+    fn try_from(src: &Integer) -> Result<Self> {
+        let m = src.abs_get_u128().ok_or_else(|| conversion_error(src, "i128"))?;
+        if src.sign() >= 0 {
+            i128::try_from(m).map_err(|_| conversion_error(src, "i128"))
+        } else if m <= 1u128 << 127 {
+            // -2^127 wraps to itself
+            Ok(0i128.wrapping_sub(m as i128))
+        } else {
+            Err(conversion_error(src, "i128"))
+        }
+    }
+}
+
+impl TryFrom<Integer> for i128 {
+    type Error = Error;
+    #[inline]
+    fn try_from(src: Integer) -> Result<Self> {
+        i128::try_from(&src)
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// Floating point
+///////////////////////////////////////////////////////////////////
+
+/// The rounding direction of a conversion to a floating point number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest value, with ties to even.
+    Nearest,
+}
+
+impl RoundMode {
+    pub(crate) fn as_arf_rnd(self) -> arf_rnd_t {
+        match self {
+            RoundMode::Down => ARF_RND_DOWN,
+            RoundMode::Up => ARF_RND_UP,
+            RoundMode::Floor => ARF_RND_FLOOR,
+            RoundMode::Ceil => ARF_RND_CEIL,
+            RoundMode::Nearest => ARF_RND_NEAR,
+        }
+    }
+}
+
+impl Integer {
+    /// Convert the integer to a double, rounding in the direction `rnd`. 
+    /// Values too large for a double round to infinity or to `f64::MAX` 
+    /// according to the direction.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Integer::from(2).pow(60u8) + 1;
+    /// assert_eq!(x.to_f64_rounded(RoundMode::Down), 2f64.powi(60));
+    /// assert_eq!(x.to_f64_rounded(RoundMode::Ceil), 2f64.powi(60) + 256.0);
+    /// assert_eq!((-x).to_f64_rounded(RoundMode::Floor), -(2f64.powi(60) + 256.0));
+    /// assert_eq!(Integer::from(2).pow(2000u16).to_f64_rounded(RoundMode::Nearest), f64::INFINITY);
+    /// ```
+    pub fn to_f64_rounded(&self, rnd: RoundMode) -> f64 {
+        let mut x = crate::arf::Arf::default();
+        unsafe {
+            arf_set_fmpz(x.as_mut_ptr(), self.as_ptr());
+            arf_get_d(x.as_ptr(), rnd.as_arf_rnd())
+        }
+    }
+}
@@ -0,0 +1,126 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{Integer, Rational};
+use std::hash::{Hash, Hasher};
+
+/// A small integer literal that can be constructed in a `const` context and 
+/// materialized as an [Integer] when needed. Decimal strings are validated at 
+/// compile time when used in a `const` item.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// const A: IntegerLit = IntegerLit::new(-7);
+/// const B: IntegerLit = IntegerLit::from_decimal("-123456789012345678901234567890");
+///
+/// assert_eq!(Integer::from(A), -7);
+/// assert_eq!(B.to_integer().to_string(), "-123456789012345678901234567890");
+/// assert_eq!(A, IntegerLit::from_decimal("-7"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct IntegerLit {
+    // private, so that decimal strings are always validated
+    inner: Lit,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Lit {
+    Small(i64),
+    Decimal(&'static str),
+}
+
+impl Eq for IntegerLit {}
+
+impl PartialEq for IntegerLit {
+    fn eq(&self, rhs: &IntegerLit) -> bool {
+        match (self.inner, rhs.inner) {
+            (Lit::Small(x), Lit::Small(y)) => x == y,
+            _ => self.to_integer() == rhs.to_integer(),
+        }
+    }
+}
+
+impl Hash for IntegerLit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_integer().hash(state)
+    }
+}
+
+impl IntegerLit {
+    #[inline]
+    pub const fn new(x: i64) -> Self {
+        IntegerLit { inner: Lit::Small(x) }
+    }
+    
+    /// Construct a literal from a decimal string with an optional leading 
+    /// minus sign. Panics if the string is not a valid decimal integer.
+    pub const fn from_decimal(s: &'static str) -> Self {
+        let bytes = s.as_bytes();
+        let start = if !bytes.is_empty() && bytes[0] == b'-' { 1 } else { 0 };
+        assert!(bytes.len() > start, "Empty integer literal.");
+        
+        let mut i = start;
+        while i < bytes.len() {
+            assert!(bytes[i].is_ascii_digit(), "Invalid integer literal.");
+            i += 1;
+        }
+        IntegerLit { inner: Lit::Decimal(s) }
+    }
+
+    /// Materialize the literal as an [Integer].
+    pub fn to_integer(&self) -> Integer {
+        match self.inner {
+            Lit::Small(x) => Integer::from(x),
+            Lit::Decimal(s) => {
+                if let Some(digits) = s.strip_prefix('-') {
+                    -digits.parse::<Integer>().unwrap()
+                } else {
+                    s.parse::<Integer>().unwrap()
+                }
+            }
+        }
+    }
+}
+
+impl From<IntegerLit> for Integer {
+    #[inline]
+    fn from(x: IntegerLit) -> Integer {
+        x.to_integer()
+    }
+}
+
+impl From<&IntegerLit> for Integer {
+    #[inline]
+    fn from(x: &IntegerLit) -> Integer {
+        x.to_integer()
+    }
+}
+
+impl From<IntegerLit> for Rational {
+    #[inline]
+    fn from(x: IntegerLit) -> Rational {
+        Rational::from(x.to_integer())
+    }
+}
+
+impl From<&IntegerLit> for Rational {
+    #[inline]
+    fn from(x: &IntegerLit) -> Rational {
+        Rational::from(x.to_integer())
+    }
+}
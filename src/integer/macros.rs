@@ -35,6 +35,111 @@ macro_rules! pow2 {
     };
 }
 
+/// Construct an [Integer](crate::Integer) from anything convertible to one.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// assert_eq!(integer!(), 0);
+/// assert_eq!(integer!(5u8), 5);
+/// ```
+#[macro_export]
+macro_rules! integer {
+    () => {
+        $crate::Integer::zero()
+    };
+    ($arg:expr) => {
+        $crate::Integer::from($arg)
+    };
+}
+
+/// Construct a [Rational](crate::Rational) from a fraction `a/b` or anything 
+/// convertible to one.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// assert_eq!(rational!(1/2), Rational::from([1, 2]));
+/// assert_eq!(rational!(-3/6), Rational::from([-1, 2]));
+/// assert_eq!(rational!(4), 4);
+/// ```
+#[macro_export]
+macro_rules! rational {
+    () => {
+        $crate::Rational::zero()
+    };
+    ($num:tt / $den:tt) => {
+        $crate::Rational::from([$num, $den])
+    };
+    (- $num:tt / $den:tt) => {
+        $crate::Rational::from([-$num, $den])
+    };
+    ($arg:expr) => {
+        $crate::Rational::from($arg)
+    };
+}
+
+/// Construct an [IntPoly](crate::IntPoly) from its coefficients in order of 
+/// increasing degree.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let f = intpoly!([1, 0, -2]);
+/// assert_eq!(f.degree(), 2);
+/// assert_eq!(f.get_coeff(2), -2);
+/// assert!(intpoly!([]).is_zero());
+/// ```
+#[macro_export]
+macro_rules! intpoly {
+    () => {
+        $crate::IntPoly::zero()
+    };
+    ([]) => {
+        $crate::IntPoly::zero()
+    };
+    ([$($c:expr),+ $(,)?]) => {
+        $crate::IntPoly::from([$($crate::Integer::from($c)),+])
+    };
+}
+
+/// Construct an [IntMat](crate::IntMat) from a list of rows. Panics if the rows 
+/// do not all have the same length.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let m = intmat!([[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(m, IntMat::new([1, 2, 3, 4, 5, 6], 2, 3));
+/// ```
+#[macro_export]
+macro_rules! intmat {
+    ([$([$($x:expr),* $(,)?]),* $(,)?]) => {{
+        let rows: Vec<Vec<$crate::Integer>> = vec![
+            $(vec![$($crate::Integer::from($x)),*]),*
+        ];
+        let nrows = rows.len();
+        let ncols = rows.first().map_or(0, |r| r.len());
+        assert!(
+            rows.iter().all(|r| r.len() == ncols), 
+            "Rows must have the same length."
+        );
+        let mut res = $crate::IntMat::zero(
+            nrows.try_into().expect("Cannot convert usize to a signed long."), 
+            ncols.try_into().expect("Cannot convert usize to a signed long.")
+        );
+        for (i, row) in rows.iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                res.set_entry(i, j, x);
+            }
+        }
+        res
+    }};
+}
 
 pub use int;
 pub use pow2;
+pub use integer;
+pub use rational;
+pub use intpoly;
+pub use intmat;
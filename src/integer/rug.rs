@@ -0,0 +1,66 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use ::rug::integer::Order;
+use ::rug::Integer as RugInteger;
+
+impl_from! {
+    RugInteger, Integer
+    {
+        fn from(src: &Integer) -> RugInteger {
+            let res = RugInteger::from_digits(&src.abs().get_ui_vector(), Order::Lsf);
+            if src.sign() < 0 {
+                -res
+            } else {
+                res
+            }
+        }
+    }
+}
+
+impl_from! {
+    Integer, RugInteger
+    {
+        fn from(src: &RugInteger) -> Integer {
+            let mut res = Integer::default();
+            res.set_ui_vector(src.to_digits::<u64>(Order::Lsf));
+            if src.is_negative() {
+                res.neg_assign();
+            }
+            res
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Integer;
+    use ::rug::Integer as RugInteger;
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn rug_roundtrip(limbs: Vec<u64>, neg: bool) -> bool {
+        let mut x = Integer::default();
+        x.set_ui_vector(limbs);
+        if neg {
+            x = -x;
+        }
+        let y = RugInteger::from(&x);
+        x.to_string() == y.to_string() && Integer::from(&y) == x
+    }
+}
@@ -0,0 +1,122 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{Error, Result};
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+thread_local! {
+    // The flag of the innermost active `with_interrupt_check` on this thread.
+    static FLAG: Cell<*const AtomicBool> = Cell::new(ptr::null());
+}
+
+// Panic payload used to unwind out of an interrupted computation.
+struct Interrupted;
+
+// Restores the enclosing flag when a `with_interrupt_check` scope ends, also
+// when unwinding.
+struct Restore(*const AtomicBool);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        FLAG.with(|f| f.set(self.0));
+    }
+}
+
+/// Run `f`, aborting it with [Error::Interrupted] once `flag` is set. The flag
+/// is typically set from another thread, e.g. a GUI cancel button or a server
+/// timeout.
+///
+/// Cancellation is cooperative: the flag is polled by [check_interrupt]
+/// between coarse-grained FLINT calls in the crate's Rust-driven loops (per
+/// prime in [ModularPrimes](crate::ModularPrimes), per evaluation point in
+/// implicitization, per recursion step in binary splitting, and so on), so a
+/// single long FLINT call runs to completion before the interrupt is seen.
+/// In particular LLL reduction ([IntMat::lll](crate::IntMat::lll)) and the 
+/// factorization of integers and integer polynomials are single FLINT calls, 
+/// which only check the flag before they start.
+/// Values created inside `f` are dropped normally when it is aborted, but
+/// values captured by mutable reference may be left partially updated.
+///
+/// Aborting relies on unwinding, so when the crate is built with 
+/// `panic = "abort"`, as is common for WebAssembly builds, the flag is 
+/// ignored and `f` always runs to completion.
+///
+/// ```
+/// use inertia_core::*;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// let flag = AtomicBool::new(false);
+/// let primes = with_interrupt_check(&flag, || {
+///     ModularPrimes::with_bits(20).take(3).count()
+/// });
+/// assert_eq!(primes.unwrap(), 3);
+///
+/// flag.store(true, Ordering::Relaxed);
+/// let res = with_interrupt_check(&flag, || ModularPrimes::with_bits(20).count());
+/// assert!(matches!(res, Err(Error::Interrupted)));
+/// ```
+pub fn with_interrupt_check<F, R>(flag: &AtomicBool, f: F) -> Result<R>
+where
+    F: FnOnce() -> R
+{
+    let prev = FLAG.with(|cell| cell.replace(flag));
+    let _restore = Restore(prev);
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(res) => Ok(res),
+        Err(payload) => {
+            if payload.is::<Interrupted>() {
+                Err(Error::Interrupted)
+            } else {
+                panic::resume_unwind(payload)
+            }
+        }
+    }
+}
+
+/// Poll the flag of the innermost enclosing [with_interrupt_check] and abort
+/// the computation if it is set. Does nothing outside of
+/// `with_interrupt_check`. Long-running loops built on top of the crate can
+/// call this to become cancellable in the same way.
+///
+/// Aborting unwinds, and unwinding across an `extern "C"` frame is undefined
+/// behavior, so this must not be called from a callback invoked by C code.
+/// Poll [interrupt_requested] there instead and return through the normal
+/// control flow of the C library.
+#[inline]
+pub fn check_interrupt() {
+    // unwinding would abort the whole process
+    if cfg!(panic = "abort") {
+        return;
+    }
+    if interrupt_requested() {
+        panic::resume_unwind(Box::new(Interrupted));
+    }
+}
+
+/// Whether the flag of the innermost enclosing [with_interrupt_check] is set,
+/// without aborting. Always `false` outside of `with_interrupt_check`.
+#[inline]
+pub fn interrupt_requested() -> bool {
+    FLAG.with(|cell| {
+        let flag = cell.get();
+        // the pointer is only set for the duration of the enclosing scope
+        !flag.is_null() && unsafe { (*flag).load(Ordering::Relaxed) }
+    })
+}
@@ -17,18 +17,29 @@
 
 mod ops;
 mod conv;
+mod window;
+mod lattice;
+mod lu;
 
-//#[cfg(feature = "serde")]
-//mod serde;
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(all(feature = "threads", not(target_os = "emscripten")))]
+mod parallel;
+
+pub use window::IntMatWindow;
+pub use lu::IntMatLU;
 
 use crate::*;
-use flint_sys::{fmpz, fmpz_mat};
+use flint_sys::{fmpz, fmpz_mat, fmpq_mat};
+use flint_sys::deps::mp_limb_t;
+use std::os::raw::c_int;
+use crate::util::{fmt_rows, DisplayRows};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::MaybeUninit;
 
 
-#[derive(Debug)]
 pub struct IntMat {
     inner: fmpz_mat::fmpz_mat_struct,
 }
@@ -50,29 +61,30 @@ impl Clone for IntMat {
     }
 }
 
+impl fmt::Debug for IntMat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntMat")
+            .field("nrows", &self.nrows())
+            .field("ncols", &self.ncols())
+            .field("entries", &DisplayRows(self.rows().collect()))
+            .finish()
+    }
+}
+
+/// Entries are right-aligned by column, or padded to the width option if
+/// one is given.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let m = IntMat::new([1, -2, 100, 4], 2, 2);
+/// assert_eq!(m.to_string(), "[   1  -2 ]\n[ 100   4 ]");
+/// assert_eq!(format!("{:<3}", m), "[ 1    -2  ]\n[ 100  4   ]");
+/// ```
 impl fmt::Display for IntMat {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let r = self.nrows().try_into().expect(
-            "Cannot convert signed long to usize.");
-        let c = self.ncols().try_into().expect(
-            "Cannot convert signed long to usize.");
-        let mut out = Vec::with_capacity(r);
-
-        for i in 0..r {
-            let mut row = Vec::with_capacity(c + 2);
-            row.push("[".to_string());
-            for j in 0..c {
-                row.push(format!(" {} ", self.get_entry(i, j)));
-            }
-            if i == r - 1 {
-                row.push("]".to_string());
-            } else {
-                row.push("]\n".to_string());
-            }
-            out.push(row.join(""));
-        }
-        write!(f, "{}", out.join(""))
+        fmt_rows(f, self.rows())
     }
 }
 
@@ -421,19 +433,41 @@ impl IntMat {
         }
     }
    
-    /* TODO: function missing from bindings
-    /// Swap two integer matrices by swapping the individual entries rather 
-    /// than swapping the contents of their structs.
-    #[inline]
+    /// Swap two integer matrices of the same dimensions by swapping the 
+    /// individual entries rather than swapping the contents of their structs,
+    /// so windows into either matrix see the swapped entries. With the 
+    /// `flint3` feature this calls `fmpz_mat_swap_entrywise`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let mut a = IntMat::new([1, 2, 3, 4], 2, 2);
+    /// let mut b = IntMat::new([5, 6, 7, 8], 2, 2);
+    /// a.swap_entrywise(&mut b);
+    /// assert_eq!(a, IntMat::new([5, 6, 7, 8], 2, 2));
+    /// assert_eq!(b, IntMat::new([1, 2, 3, 4], 2, 2));
+    /// ```
     pub fn swap_entrywise(&mut self, other: &mut IntMat) {
+        assert!(self.nrows() == other.nrows() && self.ncols() == other.ncols());
+        #[cfg(feature = "flint3")]
         unsafe { 
             fmpz_mat::fmpz_mat_swap_entrywise(
                 self.as_mut_ptr(), 
                 other.as_mut_ptr()
             ); 
         }
+        #[cfg(not(feature = "flint3"))]
+        unsafe {
+            for i in 0..self.nrows_si() {
+                for j in 0..self.ncols_si() {
+                    fmpz::fmpz_swap(
+                        fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j),
+                        fmpz_mat::fmpz_mat_entry(other.as_ptr(), i, j)
+                    );
+                }
+            }
+        }
     }
-    */
 
     /*
     /// Return true if the matrix is invertible.
@@ -457,6 +491,67 @@ impl IntMat {
         self.column(i).is_zero()
     }
 
+    /// Return true if the matrix is square and all entries off the main 
+    /// diagonal are zero.
+    pub fn is_diagonal(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        for i in 0..self.nrows() {
+            for j in 0..self.ncols() {
+                if i != j && !self.get_entry(i, j).is_zero() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+    
+    /// Return true if the matrix is a scalar multiple of the identity.
+    pub fn is_scalar(&self) -> bool {
+        if !self.is_diagonal() {
+            return false;
+        }
+        let n = self.nrows();
+        n == 0 || (1..n).all(|i| self.get_entry(i, i) == self.get_entry(0, 0))
+    }
+
+    /// Return true if the matrix is a permutation matrix, that is, a square 
+    /// matrix with exactly one entry equal to one in each row and column and 
+    /// all other entries zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert!(IntMat::new([0, 1, 1, 0], 2, 2).is_permutation());
+    /// assert!(!IntMat::new([0, 1, 1, 1], 2, 2).is_permutation());
+    /// ```
+    pub fn is_permutation(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        let n = self.nrows();
+        let mut seen = vec![false; n];
+        for i in 0..n {
+            let mut pos = None;
+            for j in 0..n {
+                let x = self.get_entry(i, j);
+                if x.is_zero() {
+                    continue;
+                }
+                if !x.is_one() || pos.is_some() {
+                    return false;
+                }
+                pos = Some(j);
+            }
+            match pos {
+                Some(j) if !seen[j] => seen[j] = true,
+                _ => return false,
+            }
+        }
+        true
+    }
+
     /// Return the transpose.
     #[inline]
     pub fn transpose(&self) -> IntMat {
@@ -505,7 +600,7 @@ impl IntMat {
 
         let mut res = IntMat::zero(self.nrows_si() + other.nrows_si(), ncols);
         unsafe {
-            fmpz_mat::fmpz_mat_concat_horizontal(
+            fmpz_mat::fmpz_mat_concat_vertical(
                 res.as_mut_ptr(), 
                 self.as_ptr(), 
                 other.as_ptr()
@@ -514,35 +609,12 @@ impl IntMat {
         res
     }
    
-    // TODO: 'window' version to avoid allocation
     /// Return a new matrix containing the `r2 - r1` by `c2 - c1` submatrix of 
     /// an integer matrix whose `(0, 0)` entry is the `(r1, c1)` entry of the input.
+    /// Use [window][IntMat::window] to avoid copying.
+    #[inline]
     pub fn submatrix(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> IntMat {
-        if r1 == r2 || c1 == c2 {
-            return IntMat::zero(0, 0)
-        }
-        
-        assert!(r1 <= r2);
-        assert!(c1 <= c2);
-        let (r1, c1) = self.check_indices(r1, c1);
-        let (r2, c2) = self.check_indices(r2, c2);
-
-        let mut res = IntMat::zero(r2 - r1, c2 - c1);
-        let mut win = MaybeUninit::uninit();
-        unsafe {
-            fmpz_mat::fmpz_mat_window_init(
-                win.as_mut_ptr(), 
-                self.as_ptr(),
-                r1,
-                c1,
-                r2,
-                c2
-            );
-            fmpz_mat::fmpz_mat_set(res.as_mut_ptr(), win.as_ptr());
-            fmpz_mat::fmpz_mat_window_clear(win.as_mut_ptr());
-        }
-        res
-
+        self.window(r1, c1, r2, c2).to_intmat()
     }
     
     /// Return row `i` as an integer matrix.
@@ -557,6 +629,112 @@ impl IntMat {
         self.submatrix(0, j, self.nrows(), j + 1)
     }
 
+    /// Borrow the `(i, j)`-th entry of the matrix without copying.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> &Integer {
+        let (i, j) = self.check_indices(i, j);
+        unsafe { &*(fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j) as *const Integer) }
+    }
+
+    /// Mutably borrow the `(i, j)`-th entry of the matrix.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let mut m = IntMat::zero(2, 2);
+    /// *m.entry_mut(1, 0) = Integer::from(3);
+    /// m.row_mut(0)[1] += Integer::from(2);
+    /// assert_eq!(m, IntMat::new([0, 2, 3, 0], 2, 2));
+    /// ```
+    #[inline]
+    pub fn entry_mut(&mut self, i: usize, j: usize) -> &mut Integer {
+        let (i, j) = self.check_indices(i, j);
+        unsafe { &mut *(fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j) as *mut Integer) }
+    }
+
+    /// Borrow row `i` of the matrix as a slice without copying.
+    #[inline]
+    pub fn row_slice(&self, i: usize) -> &[Integer] {
+        let i = self.check_row_index(i);
+        let c = self.ncols();
+        if c == 0 {
+            return &[];
+        }
+        unsafe {
+            let ptr = fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, 0) as *const Integer;
+            std::slice::from_raw_parts(ptr, c)
+        }
+    }
+
+    /// Mutably borrow row `i` of the matrix as a slice.
+    #[inline]
+    pub fn row_mut(&mut self, i: usize) -> &mut [Integer] {
+        let i = self.check_row_index(i);
+        let c = self.ncols();
+        if c == 0 {
+            return &mut [];
+        }
+        unsafe {
+            let ptr = fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, 0) as *mut Integer;
+            std::slice::from_raw_parts_mut(ptr, c)
+        }
+    }
+
+    /// Iterate over the rows of the matrix as borrowed slices.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let m = IntMat::new([1, 2, 3, 4, 5, 6], 2, 3);
+    /// let sums: Vec<Integer> = m.rows()
+    ///     .map(|r| r.iter().fold(Integer::zero(), |acc, x| acc + x))
+    ///     .collect();
+    /// assert_eq!(sums, vec![6, 15]);
+    ///
+    /// let col: Vec<&Integer> = m.cols().nth(1).unwrap().collect();
+    /// assert_eq!(col, vec![&Integer::from(2), &Integer::from(5)]);
+    /// assert_eq!(m.iter().count(), 6);
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[Integer]> + '_ {
+        (0..self.nrows()).map(move |i| self.row_slice(i))
+    }
+    
+    /// Iterate over the columns of the matrix, each given as an iterator over 
+    /// borrowed entries.
+    #[inline]
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &Integer> + '_> + '_ {
+        let r = self.nrows();
+        (0..self.ncols()).map(move |j| (0..r).map(move |i| self.entry(i, j)))
+    }
+    
+    /// Iterate over the entries of the matrix in row-major order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Integer> + '_ {
+        self.rows().flatten()
+    }
+
+    /// Reduce the entries of the matrix modulo the modulus of `ctx`. See
+    /// [IntModMat::lift] for the inverse.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = IntModCtx::new(5);
+    /// let m = IntMat::new([7, -1, 10, 3], 2, 2);
+    /// let r = m.reduce_mod(&zn);
+    /// assert_eq!(r.lift(), IntMat::new([2, 4, 0, 3], 2, 2));
+    /// ```
+    pub fn reduce_mod(&self, ctx: &IntModCtx) -> IntModMat {
+        let mut res = IntModMat::zero(self.nrows_si(), self.ncols_si(), ctx);
+        for (i, row) in self.rows().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                res.set_entry(i, j, IntMod::new(x.clone(), ctx));
+            }
+        }
+        res
+    }
+
     /// Square an integer matrix. The matrix must be square.
     #[inline]
     pub fn square(&self) -> Self {
@@ -596,6 +774,60 @@ impl IntMat {
         res
     }
     
+    /// Return a random `nrows` by `ncols` matrix with entries of up to `bits` 
+    /// bits, biased towards values likely to trigger corner cases.
+    pub fn randtest<S>(nrows: i64, ncols: i64, bits: S, rng: &mut FlintRand) -> IntMat 
+    where
+        S: Into<u64>
+    {
+        let mut res = IntMat::zero(nrows, ncols);
+        unsafe { 
+            fmpz_mat::fmpz_mat_randtest(res.as_mut_ptr(), rng.as_mut_ptr(), bits.into()); 
+        }
+        res
+    }
+    
+    /// Return a random `nrows` by `ncols` matrix with entries of exactly `bits` 
+    /// bits and random signs.
+    pub fn randbits<S>(nrows: i64, ncols: i64, bits: S, rng: &mut FlintRand) -> IntMat 
+    where
+        S: Into<u64>
+    {
+        let mut res = IntMat::zero(nrows, ncols);
+        unsafe { 
+            fmpz_mat::fmpz_mat_randbits(res.as_mut_ptr(), rng.as_mut_ptr(), bits.into()); 
+        }
+        res
+    }
+    
+    /// Return an estimate in bytes of the memory used by the matrix: the 
+    /// struct itself, the entry and row pointer arrays, and the heap storage of 
+    /// entries too large to be stored inline.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let mut m = IntMat::zero(2, 2);
+    /// let small = m.memory_footprint();
+    /// m.set_entry(0, 0, Integer::from(2).pow(1000u32));
+    /// assert!(m.memory_footprint() >= small + 1000/8);
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        let (r, c) = (self.nrows(), self.ncols());
+        let mut res = std::mem::size_of::<IntMat>()
+            + r * c * std::mem::size_of::<fmpz::fmpz>()
+            + r * std::mem::size_of::<*mut fmpz::fmpz>();
+        for x in self.iter() {
+            if x.is_mpz() {
+                // the GMP header plus its limbs
+                res += 2 * std::mem::size_of::<c_int>()
+                    + std::mem::size_of::<*const mp_limb_t>()
+                    + std::mem::size_of::<mp_limb_t>() * x.allocated_limbs();
+            }
+        }
+        res
+    }
+    
     /// Compute the trace of a square integer matrix.
     #[inline]
     pub fn trace(&self) -> Integer {
@@ -621,6 +853,7 @@ impl IntMat {
     /// Compute the determinant of the matrix.
     #[inline]
     pub fn det(&self) -> Integer {
+        trace_span!("IntMat::det", mat = self);
         assert!(self.is_square());
         let mut res = Integer::zero();
         unsafe { 
@@ -652,6 +885,144 @@ impl IntMat {
         }
         res
     }
+
+    // The maximum number of bits of the absolute value of an entry, recorded 
+    // in tracing spans.
+    #[cfg(feature = "trace")]
+    #[inline]
+    pub(crate) fn max_bits(&self) -> u64 {
+        unsafe { fmpz_mat::fmpz_mat_max_bits(self.as_ptr()).unsigned_abs() }
+    }
+
+    /// Return the largest absolute value of an entry of the matrix, or zero 
+    /// if the matrix is empty.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let m = IntMat::new([1, -5, 3, 2], 2, 2);
+    /// assert_eq!(m.max_abs_entry(), 5);
+    /// assert_eq!(m.frobenius_norm_squared(), 39);
+    /// assert_eq!(m.l1_norm(), 7);
+    /// assert_eq!(m.linf_norm(), 6);
+    /// ```
+    pub fn max_abs_entry(&self) -> Integer {
+        let mut res = Integer::zero();
+        unsafe {
+            for i in 0..self.nrows_si() {
+                for j in 0..self.ncols_si() {
+                    let x = fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j);
+                    if fmpz::fmpz_cmpabs(x, res.as_ptr()) > 0 {
+                        fmpz::fmpz_abs(res.as_mut_ptr(), x);
+                    }
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the sum of the squares of the entries of the matrix.
+    pub fn frobenius_norm_squared(&self) -> Integer {
+        let mut res = Integer::zero();
+        unsafe {
+            for i in 0..self.nrows_si() {
+                for j in 0..self.ncols_si() {
+                    let x = fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j);
+                    fmpz::fmpz_addmul(res.as_mut_ptr(), x, x);
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the operator 1-norm of the matrix, that is, the largest sum of 
+    /// the absolute values of the entries in a column.
+    pub fn l1_norm(&self) -> Integer {
+        let mut res = Integer::zero();
+        let mut sum = Integer::zero();
+        let mut t = Integer::zero();
+        unsafe {
+            for j in 0..self.ncols_si() {
+                sum.zero_assign();
+                for i in 0..self.nrows_si() {
+                    let x = fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j);
+                    fmpz::fmpz_abs(t.as_mut_ptr(), x);
+                    fmpz::fmpz_add(sum.as_mut_ptr(), sum.as_ptr(), t.as_ptr());
+                }
+                if sum > res {
+                    fmpz::fmpz_set(res.as_mut_ptr(), sum.as_ptr());
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the operator infinity-norm of the matrix, that is, the largest 
+    /// sum of the absolute values of the entries in a row.
+    pub fn linf_norm(&self) -> Integer {
+        let mut res = Integer::zero();
+        let mut sum = Integer::zero();
+        let mut t = Integer::zero();
+        unsafe {
+            for i in 0..self.nrows_si() {
+                sum.zero_assign();
+                for j in 0..self.ncols_si() {
+                    let x = fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j);
+                    fmpz::fmpz_abs(t.as_mut_ptr(), x);
+                    fmpz::fmpz_add(sum.as_mut_ptr(), sum.as_ptr(), t.as_ptr());
+                }
+                if sum > res {
+                    fmpz::fmpz_set(res.as_mut_ptr(), sum.as_ptr());
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the product of the squared Euclidean norms of the rows of a 
+    /// square matrix. By the Hadamard inequality this bounds the square of the 
+    /// determinant.
+    pub fn hadamard_bound_squared(&self) -> Integer {
+        assert!(self.is_square());
+        let mut res = Integer::one();
+        let mut sum = Integer::zero();
+        unsafe {
+            for i in 0..self.nrows_si() {
+                sum.zero_assign();
+                for j in 0..self.ncols_si() {
+                    let x = fmpz_mat::fmpz_mat_entry(self.as_ptr(), i, j);
+                    fmpz::fmpz_addmul(sum.as_mut_ptr(), x, x);
+                }
+                fmpz::fmpz_mul(res.as_mut_ptr(), res.as_ptr(), sum.as_ptr());
+            }
+        }
+        res
+    }
+    
+    /// Return the smallest integer bounding the absolute value of the 
+    /// determinant of a square matrix by the Hadamard inequality, i.e. the 
+    /// ceiling of the square root of [hadamard_bound_squared][IntMat::hadamard_bound_squared].
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let m = IntMat::new([3, 1, -1, 2], 2, 2);
+    /// assert_eq!(m.hadamard_bound_squared(), 50);
+    /// assert_eq!(m.hadamard_bound(), 8);
+    /// assert!(m.det().abs() <= m.hadamard_bound());
+    /// ```
+    pub fn hadamard_bound(&self) -> Integer {
+        let b = self.hadamard_bound_squared();
+        let mut s = Integer::zero();
+        let mut r = Integer::zero();
+        unsafe {
+            fmpz::fmpz_sqrtrem(s.as_mut_ptr(), r.as_mut_ptr(), b.as_ptr());
+            if !r.is_zero() {
+                fmpz::fmpz_add_ui(s.as_mut_ptr(), s.as_ptr(), 1);
+            }
+        }
+        s
+    }
     
     /// Applies a similarity transform to an `n` by `n` integer matrix. If `P` 
     /// is the identity matrix whose zero entries in row `r` have been replaced 
@@ -683,6 +1054,7 @@ impl IntMat {
     /// Return the characteristic polynomial of a square integer matrix.
     #[inline]
     pub fn charpoly(&self) -> IntPoly {
+        trace_span!("IntMat::charpoly", mat = self);
         assert!(self.is_square());
         let mut res = IntPoly::zero();
         unsafe { 
@@ -694,6 +1066,7 @@ impl IntMat {
     /// Return the minimal polynomial of a square integer matrix.
     #[inline]
     pub fn minpoly(&self) -> IntPoly {
+        trace_span!("IntMat::minpoly", mat = self);
         assert!(self.is_square());
         let mut res = IntPoly::zero();
         unsafe { 
@@ -707,9 +1080,237 @@ impl IntMat {
     /// row reducing a copy of the input matrix.
     #[inline]
     pub fn rank(&self) -> i64 {
+        trace_span!("IntMat::rank", mat = self);
         unsafe { fmpz_mat::fmpz_mat_rank(self.as_ptr()) }
     }
 
+    /// Return the Toeplitz matrix with first column `col` and first row `row`, 
+    /// that is, the matrix whose `(i, j)`-th entry only depends on `i - j`. 
+    /// Panics if the first entries of `col` and `row` do not agree.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let col = [Integer::from(1), Integer::from(2), Integer::from(3)];
+    /// let row = [Integer::from(1), Integer::from(4)];
+    /// let t = IntMat::toeplitz(&col, &row);
+    /// assert_eq!(t, IntMat::new([1, 4, 2, 1, 3, 2], 3, 2));
+    /// assert!(t.is_toeplitz());
+    /// ```
+    pub fn toeplitz<T: AsRef<Integer>>(col: &[T], row: &[T]) -> IntMat {
+        assert!(!col.is_empty() && !row.is_empty());
+        assert_eq!(col[0].as_ref(), row[0].as_ref());
+        
+        let nrows = col.len().try_into().expect(
+            "Cannot convert usize to a signed long.");
+        let ncols = row.len().try_into().expect(
+            "Cannot convert usize to a signed long.");
+        let mut res = IntMat::zero(nrows, ncols);
+        for i in 0..col.len() {
+            for j in 0..row.len() {
+                if i >= j {
+                    res.set_entry(i, j, col[i - j].as_ref());
+                } else {
+                    res.set_entry(i, j, row[j - i].as_ref());
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the Hankel matrix with first column `col` and last row `row`, 
+    /// that is, the matrix whose `(i, j)`-th entry only depends on `i + j`. 
+    /// Panics if the last entry of `col` and the first entry of `row` do not 
+    /// agree.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let col = [Integer::from(1), Integer::from(2)];
+    /// let row = [Integer::from(2), Integer::from(3), Integer::from(4)];
+    /// let h = IntMat::hankel(&col, &row);
+    /// assert_eq!(h, IntMat::new([1, 2, 3, 2, 3, 4], 2, 3));
+    /// assert!(h.is_hankel());
+    /// ```
+    pub fn hankel<T: AsRef<Integer>>(col: &[T], row: &[T]) -> IntMat {
+        assert!(!col.is_empty() && !row.is_empty());
+        assert_eq!(col[col.len() - 1].as_ref(), row[0].as_ref());
+        
+        let nrows = col.len().try_into().expect(
+            "Cannot convert usize to a signed long.");
+        let ncols = row.len().try_into().expect(
+            "Cannot convert usize to a signed long.");
+        let mut res = IntMat::zero(nrows, ncols);
+        for i in 0..col.len() {
+            for j in 0..row.len() {
+                let k = i + j;
+                if k < col.len() {
+                    res.set_entry(i, j, col[k].as_ref());
+                } else {
+                    res.set_entry(i, j, row[k + 1 - col.len()].as_ref());
+                }
+            }
+        }
+        res
+    }
+
+    /// Return true if the matrix is a Toeplitz matrix, that is, each of its 
+    /// diagonals is constant.
+    pub fn is_toeplitz(&self) -> bool {
+        for i in 1..self.nrows() {
+            for j in 1..self.ncols() {
+                if self.get_entry(i, j) != self.get_entry(i - 1, j - 1) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+    
+    /// Return true if the matrix is a Hankel matrix, that is, each of its 
+    /// anti-diagonals is constant.
+    pub fn is_hankel(&self) -> bool {
+        for i in 1..self.nrows() {
+            for j in 0..self.ncols().saturating_sub(1) {
+                if self.get_entry(i, j) != self.get_entry(i - 1, j + 1) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+    
+    /// Return the `n` by `n` adjacency matrix of the undirected multigraph on 
+    /// the vertices `0..n` with the given edges. Repeated edges are counted 
+    /// with multiplicity and a loop at `u` adds one to the `(u, u)`-th entry.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = IntMat::adjacency_from_edges(3, &[(0, 1), (1, 2)]);
+    /// assert_eq!(a, IntMat::new([0, 1, 0, 1, 0, 1, 0, 1, 0], 3, 3));
+    /// assert_eq!(a.laplacian(), IntMat::new([1, -1, 0, -1, 2, -1, 0, -1, 1], 3, 3));
+    /// ```
+    pub fn adjacency_from_edges(n: usize, edges: &[(usize, usize)]) -> IntMat {
+        let dim = n.try_into().expect("Cannot convert usize to a signed long.");
+        let mut res = IntMat::zero(dim, dim);
+        for &(u, v) in edges {
+            assert!(u < n && v < n, "Edge endpoint out of range.");
+            res.set_entry(u, v, res.get_entry(u, v) + 1);
+            if u != v {
+                res.set_entry(v, u, res.get_entry(v, u) + 1);
+            }
+        }
+        res
+    }
+
+    /// Return the `n` by `edges.len()` oriented incidence matrix of the graph 
+    /// on the vertices `0..n` with the given edges: the column of the edge 
+    /// `(u, v)` has `1` in row `u` and `-1` in row `v`, and is zero for a 
+    /// loop. Its product with its transpose is the Laplacian of the graph.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let edges = [(0, 1), (1, 2), (2, 0)];
+    /// let b = IntMat::incidence_matrix(3, &edges);
+    /// assert_eq!(&b * &b.transpose(), IntMat::adjacency_from_edges(3, &edges).laplacian());
+    /// ```
+    pub fn incidence_matrix(n: usize, edges: &[(usize, usize)]) -> IntMat {
+        let nrows = n.try_into().expect("Cannot convert usize to a signed long.");
+        let ncols = edges.len().try_into().expect("Cannot convert usize to a signed long.");
+        let mut res = IntMat::zero(nrows, ncols);
+        for (j, &(u, v)) in edges.iter().enumerate() {
+            assert!(u < n && v < n, "Edge endpoint out of range.");
+            if u != v {
+                res.set_entry(u, j, Integer::one());
+                res.set_entry(v, j, Integer::from(-1));
+            }
+        }
+        res
+    }
+
+    /// Return the Laplacian `D - A` of a square adjacency matrix `A`, where 
+    /// `D` is the diagonal matrix of row sums.
+    pub fn laplacian(&self) -> IntMat {
+        assert!(self.is_square());
+        let mut res = -self;
+        for i in 0..self.nrows() {
+            let mut deg = Integer::zero();
+            for j in 0..self.ncols() {
+                deg += self.get_entry(i, j);
+            }
+            res.set_entry(i, i, res.get_entry(i, i) + deg);
+        }
+        res
+    }
+
+    /// Return the number of spanning trees of the graph with square adjacency 
+    /// matrix `self`, computed by Kirchhoff's theorem as a cofactor of the 
+    /// Laplacian.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // Cayley's formula: the complete graph on n vertices has n^(n-2) 
+    /// // spanning trees
+    /// let k5 = IntMat::new([1; 25], 5, 5) - IntMat::one(5);
+    /// assert_eq!(k5.spanning_tree_count(), 125);
+    /// ```
+    pub fn spanning_tree_count(&self) -> Integer {
+        assert!(self.is_square() && !self.is_empty());
+        let n = self.nrows();
+        self.laplacian().submatrix(1, 1, n, n).det()
+    }
+
+    /// Return the matrix raised to the power `exp`. The matrix must be 
+    /// square.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // powers of the Fibonacci matrix
+    /// let f = IntMat::new([1, 1, 1, 0], 2, 2);
+    /// assert_eq!(f.pow(10), IntMat::new([89, 55, 55, 34], 2, 2));
+    /// assert_eq!(f.pow(0), IntMat::one(2));
+    /// ```
+    pub fn pow(&self, exp: u64) -> IntMat {
+        assert!(self.is_square());
+        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe { fmpz_mat::fmpz_mat_pow(res.as_mut_ptr(), self.as_ptr(), exp); }
+        res
+    }
+    
+    /// Solve `AX = B` over the rationals for a square Toeplitz matrix `A` using 
+    /// the Levinson recursion. See [RatMat::solve_toeplitz].
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = IntMat::new([2, 1, 0, 1, 2, 1, 0, 1, 2], 3, 3);
+    /// let b = IntMat::new([1, 0, 0], 3, 1);
+    /// let x = a.solve_toeplitz(&b).unwrap();
+    /// assert_eq!(x.get_entry(0, 0), Rational::from([3, 4]));
+    /// ```
+    pub fn solve_toeplitz<T: AsRef<IntMat>>(&self, rhs: T) -> Option<RatMat> {
+        self.to_ratmat().solve_toeplitz(rhs.as_ref().to_ratmat())
+    }
+    
+    /// Solve `AX = B` over the rationals for a square Hankel matrix `A`. See 
+    /// [RatMat::solve_hankel].
+    pub fn solve_hankel<T: AsRef<IntMat>>(&self, rhs: T) -> Option<RatMat> {
+        self.to_ratmat().solve_hankel(rhs.as_ref().to_ratmat())
+    }
+    
+    #[inline]
+    fn to_ratmat(&self) -> RatMat {
+        let mut res = RatMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe {
+            fmpq_mat::fmpq_mat_set_fmpz_mat(res.as_mut_ptr(), self.as_ptr());
+        }
+        res
+    }
+
     /*
     /// Solve `AX = B` for nonsingular `A`.
     pub fn solve<T>(&self, rhs: T) -> Option<RatMat> where 
@@ -896,24 +1497,8 @@ impl IntMat {
     }
     */
 
-    /// Return the rank and (A, den) a fraction-free LU decomposition of the input.
-    pub fn fflu(&self) -> (i64, IntMat, Integer) {
-        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
-        let mut den = Integer::zero();
-
-        unsafe {
-            let rank = fmpz_mat::fmpz_mat_fflu(
-                res.as_mut_ptr(), 
-                den.as_mut_ptr(), 
-                std::ptr::null(), 
-                self.as_ptr(), 
-                0
-            );
-            (rank, res, den)
-        }
-    }
-   
     pub fn rref(&self) -> (i64, IntMat, Integer) {
+        trace_span!("IntMat::rref", mat = self);
         let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
         let mut den = Integer::zero();
 
@@ -995,6 +1580,7 @@ impl IntMat {
 
     // FIXME: aliasing allowed? then do hnf_assign
     pub fn hnf(&self) -> IntMat {
+        trace_span!("IntMat::hnf", mat = self);
         let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
         unsafe { 
             fmpz_mat::fmpz_mat_hnf(res.as_mut_ptr(), self.as_ptr()); 
@@ -1003,6 +1589,7 @@ impl IntMat {
     }
     
     pub fn hnf_transform(&self) -> (IntMat, IntMat) {
+        trace_span!("IntMat::hnf_transform", mat = self);
         let mut h = IntMat::zero(self.nrows_si(), self.ncols_si());
         let mut u = IntMat::zero(self.nrows_si(), self.ncols_si());
         unsafe { 
@@ -1020,6 +1607,7 @@ impl IntMat {
     }
     
     pub fn snf(&self) -> IntMat {
+        trace_span!("IntMat::snf", mat = self);
         let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
         unsafe { fmpz_mat::fmpz_mat_snf(res.as_mut_ptr(), self.as_ptr()); }
         res
@@ -16,10 +16,37 @@
  */
 
 use crate::*;
+use crate::Error::ParseMatrixError;
+use std::str::FromStr;
 use flint_sys::fmpz_mat;
 use std::mem::MaybeUninit;
 
 
+impl FromStr for IntMat {
+    type Err = Error;
+    /// Parse a matrix given as nested lists `[[1, 2], [3, 4]]`, in Pari/GP 
+    /// syntax `[1, 2; 3, 4]`, or in the format of `Display`, with one 
+    /// bracketed row per line.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let m: IntMat = "[[1, -2], [30, 4]]".parse().unwrap();
+    /// assert_eq!(m, IntMat::new([1, -2, 30, 4], 2, 2));
+    /// assert_eq!("[1, -2; 30, 4]".parse::<IntMat>().unwrap(), m);
+    /// assert_eq!(m.to_string().parse::<IntMat>().unwrap(), m);
+    /// assert!(matches!("[[1, 2], [3]]".parse::<IntMat>(), Err(Error::ParseMatrixError(_))));
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let (r, c, entries) = parse::matrix(s).map_err(ParseMatrixError)?;
+        let entries = entries.into_iter()
+            .map(parse::integer)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(ParseMatrixError)?;
+        Ok(IntMat::new(entries.as_slice(), r as i64, c as i64))
+    }
+}
+
 impl_from! {
     IntMat, IntModMat
     {
@@ -0,0 +1,166 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{check_interrupt, Integer, IntMat, Rational};
+use flint_sys::{fmpz_lll, fmpz_mat};
+use std::mem::MaybeUninit;
+
+// Lattices are given by the rows of a matrix, following FLINT.
+impl IntMat {
+    /// Return the Gram matrix `A * A^T` of the rows of the matrix.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = IntMat::new([1, 2, 3, 4, 5, 6], 2, 3);
+    /// assert_eq!(a.gram(), IntMat::new([14, 32, 32, 77], 2, 2));
+    /// ```
+    pub fn gram(&self) -> IntMat {
+        let mut res = IntMat::zero(self.nrows_si(), self.nrows_si());
+        unsafe { fmpz_mat::fmpz_mat_gram(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Return an LLL-reduced basis of the lattice spanned by the rows of the
+    /// matrix, with FLINT's default parameters `delta = 0.99` and 
+    /// `eta = 0.51`. The rows need not be linearly independent; dependencies 
+    /// show up as zero rows at the top of the result.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = IntMat::new([1, 0, 0, 1000, 0, 1, 0, 2001, 0, 0, 1, 2999], 3, 4);
+    /// let b = a.lll();
+    /// assert_eq!(b.det_gram(), a.det_gram());
+    /// assert!(b.row_slice(0).iter().all(|x| x.abs() < 10));
+    /// ```
+    pub fn lll(&self) -> IntMat {
+        trace_span!("IntMat::lll", mat = self);
+        check_interrupt();
+        let mut res = self.clone();
+        unsafe {
+            let mut fl = MaybeUninit::uninit();
+            fmpz_lll::fmpz_lll_context_init_default(fl.as_mut_ptr());
+            fmpz_lll::fmpz_lll(res.as_mut_ptr(), std::ptr::null_mut(), fl.as_ptr());
+        }
+        res
+    }
+
+    /// LLL-reduce the rows of the matrix and remove the trailing vectors 
+    /// whose squared Gram-Schmidt norm exceeds `bound`, returning the 
+    /// remaining reduced vectors. This is the variant used in van Hoeij's 
+    /// factoring algorithm and in lattice attacks, where only short vectors 
+    /// are of interest.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = IntMat::new([1, 0, 0, 1000, 0, 1, 0, 2001, 0, 0, 1, 2999], 3, 4);
+    /// let b = a.lll_with_removals(100);
+    /// assert!(b.nrows() < 3);
+    /// assert!(b.rows().all(|r| r.iter().all(|x| x.abs() < 10)));
+    /// ```
+    pub fn lll_with_removals<T: Into<Integer>>(&self, bound: T) -> IntMat {
+        trace_span!("IntMat::lll_with_removals", mat = self);
+        check_interrupt();
+        let bound = bound.into();
+        let mut b = self.clone();
+        let d = unsafe {
+            let mut fl = MaybeUninit::uninit();
+            fmpz_lll::fmpz_lll_context_init_default(fl.as_mut_ptr());
+            fmpz_lll::fmpz_lll_with_removal(
+                b.as_mut_ptr(), 
+                std::ptr::null_mut(), 
+                bound.as_ptr(), 
+                fl.as_ptr()
+            )
+        };
+        let d = d.try_into().expect("Cannot convert signed long to usize.");
+        b.submatrix(0, 0, d, b.ncols())
+    }
+
+    /// Return the lattice vector, as a row vector, found by Babai's nearest 
+    /// plane algorithm for the row vector `target`. The result is a 
+    /// lattice vector close to `target`, within a factor `2^(n/2)` of the 
+    /// closest one when the basis is LLL-reduced, so the basis should 
+    /// usually be reduced with [IntMat::lll] first. The rows of the matrix 
+    /// must be linearly independent.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let basis = IntMat::new([2, 0, 1, 3], 2, 2).lll();
+    /// let v = basis.babai(&IntMat::new([5, 4], 1, 2));
+    /// assert_eq!(v, IntMat::new([5, 3], 1, 2));
+    /// ```
+    pub fn babai(&self, target: &IntMat) -> IntMat {
+        assert!(target.nrows() == 1 && target.ncols() == self.ncols());
+        let n = self.nrows();
+        let m = self.ncols();
+
+        // Gram-Schmidt orthogonalization of the rows over the rationals
+        let mut gs: Vec<Vec<Rational>> = Vec::with_capacity(n);
+        let mut norms: Vec<Rational> = Vec::with_capacity(n);
+        for i in 0..n {
+            let mut v: Vec<Rational> = self.row_slice(i).iter().map(Rational::from).collect();
+            for (u, nu) in gs.iter().zip(norms.iter()) {
+                let mu = dot(&v, u) / nu;
+                for k in 0..m {
+                    v[k] -= &mu * &u[k];
+                }
+            }
+            let nv = dot(&v, &v);
+            assert!(!nv.is_zero(), "The basis vectors are not linearly independent.");
+            gs.push(v);
+            norms.push(nv);
+        }
+
+        let mut t: Vec<Rational> = target.row_slice(0).iter().map(Rational::from).collect();
+        let half = Rational::from([1, 2]);
+        for i in (0..n).rev() {
+            let c = (dot(&t, &gs[i]) / &norms[i] + &half).floor();
+            if c.is_zero() {
+                continue;
+            }
+            for (k, x) in self.row_slice(i).iter().enumerate() {
+                t[k] -= Rational::from(&c * x);
+            }
+        }
+
+        let mut res = IntMat::zero(1, self.ncols_si());
+        for k in 0..m {
+            let x = Rational::from(target.entry(0, k)) - &t[k];
+            res.set_entry(0, k, x.numerator());
+        }
+        res
+    }
+
+    /// Return the determinant of the Gram matrix of the rows, the square of 
+    /// the volume of the lattice when the rows are linearly independent.
+    #[inline]
+    pub fn det_gram(&self) -> Integer {
+        self.gram().det()
+    }
+}
+
+fn dot(x: &[Rational], y: &[Rational]) -> Rational {
+    let mut res = Rational::zero();
+    for (a, b) in x.iter().zip(y) {
+        res += a * b;
+    }
+    res
+}
@@ -0,0 +1,153 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpz_mat;
+use crate::util::perm_is_odd;
+
+/// A fraction-free LU decomposition of an integer matrix, see [IntMat::fflu]. 
+/// The factorization is computed once and reused by [solve](IntMatLU::solve) 
+/// for any number of right hand sides.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let a = IntMat::new([2, 1, 1, 3], 2, 2);
+/// let lu = a.fflu();
+/// assert_eq!(lu.rank(), 2);
+/// assert_eq!(lu.det(), 5);
+///
+/// let x = lu.solve(&IntMat::new([1, 2], 2, 1)).unwrap();
+/// assert_eq!(x, RatMat::new([[1, 5], [3, 5]], 2, 1));
+/// let x = lu.solve(&IntMat::new([5, 0], 2, 1)).unwrap();
+/// assert_eq!(x, RatMat::new([3, -1], 2, 1));
+///
+/// let lu = IntMat::new([1, 2, 2, 4], 2, 2).fflu();
+/// assert_eq!(lu.rank(), 1);
+/// assert_eq!(lu.det(), 0);
+/// assert!(lu.solve(&IntMat::new([1, 2], 2, 1)).is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntMatLU {
+    lu: IntMat,
+    den: Integer,
+    // row `i` of the factored matrix is row `perm[i]` of the input
+    perm: Vec<i64>,
+    rank: usize,
+}
+
+impl IntMat {
+    /// Return a fraction-free LU decomposition of the matrix.
+    pub fn fflu(&self) -> IntMatLU {
+        trace_span!("IntMat::fflu", mat = self);
+        let mut lu = IntMat::zero(self.nrows_si(), self.ncols_si());
+        let mut den = Integer::zero();
+        let mut perm: Vec<i64> = (0..self.nrows_si()).collect();
+
+        let rank = unsafe {
+            fmpz_mat::fmpz_mat_fflu(
+                lu.as_mut_ptr(), 
+                den.as_mut_ptr(), 
+                perm.as_mut_ptr(), 
+                self.as_ptr(), 
+                0
+            )
+        };
+        let rank = rank.try_into().expect("Cannot convert signed long to usize.");
+        IntMatLU { lu, den, perm, rank }
+    }
+}
+
+impl IntMatLU {
+    /// Return the fraction-free LU matrix as computed by FLINT.
+    #[inline]
+    pub fn matrix(&self) -> &IntMat {
+        &self.lu
+    }
+
+    /// Return the denominator of the decomposition, the last nonzero pivot.
+    #[inline]
+    pub fn den(&self) -> &Integer {
+        &self.den
+    }
+
+    /// Return the row permutation: row `i` of the factored matrix is row 
+    /// `perm[i]` of the input.
+    #[inline]
+    pub fn perm(&self) -> &[i64] {
+        &self.perm
+    }
+
+    /// Return the rank of the factored matrix.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    #[inline]
+    fn is_nonsingular(&self) -> bool {
+        self.lu.is_square() && self.rank == self.lu.nrows()
+    }
+
+    // FLINT stores the row permutation as signed longs.
+    fn perm_usize(&self) -> Vec<usize> {
+        self.perm.iter().map(|&p| p as usize).collect()
+    }
+
+    /// Return the determinant of the factored square matrix.
+    pub fn det(&self) -> Integer {
+        assert!(self.lu.is_square());
+        if !self.is_nonsingular() {
+            Integer::zero()
+        } else if perm_is_odd(&self.perm_usize()) {
+            -&self.den
+        } else {
+            self.den.clone()
+        }
+    }
+
+    /// Solve `AX = B` fraction-free for a square, nonsingular matrix `A`, 
+    /// returning `(X, d)` with `AX = dB` and `d` the determinant of `A`, or 
+    /// `None` if `A` is singular.
+    pub fn solve_fraction_free(&self, rhs: &IntMat) -> Option<(IntMat, Integer)> {
+        assert_eq!(self.lu.nrows(), rhs.nrows());
+        if !self.is_nonsingular() {
+            return None;
+        }
+        let mut res = IntMat::zero(self.lu.ncols_si(), rhs.ncols_si());
+        unsafe {
+            fmpz_mat::fmpz_mat_solve_fflu_precomp(
+                res.as_mut_ptr(),
+                self.perm.as_ptr(),
+                self.lu.as_ptr(),
+                rhs.as_ptr()
+            );
+        }
+        if perm_is_odd(&self.perm_usize()) {
+            Some((-&res, -&self.den))
+        } else {
+            Some((res, self.den.clone()))
+        }
+    }
+
+    /// Solve `AX = B` for a square, nonsingular matrix `A`, returning `None` 
+    /// if `A` is singular.
+    #[inline]
+    pub fn solve(&self, rhs: &IntMat) -> Option<RatMat> {
+        self.solve_fraction_free(rhs).map(|(x, d)| &x / &d)
+    }
+}
@@ -16,8 +16,9 @@
  */
 
 use crate::*;
+use crate::util::fmpq_mat_swap_into;
 
-use flint_sys::{fmpz, fmpz_mat};
+use flint_sys::{fmpz, fmpq, fmpz_mat, fmpq_mat};
 use inertia_algebra::ops::*;
 
 use libc::{c_long, c_ulong};
@@ -82,19 +83,36 @@ impl_binop_unsafe! {
     fmpz_mat::fmpz_mat_scalar_mod_fmpz;
 }
 
-/*
 impl_binop_unsafe! {
     scalar_rhs
     IntMat, Integer, RatMat
 
     Div {div}
     AssignDiv {assign_div}
-    fmpq_mat::fmpq_mat_set_fmpz_mat_div_fmpz;
+    fmpz_mat_scalar_div_fmpz;
+}
+
+impl_binop_unsafe! {
+    scalar_rhs
+    IntMat, Rational, RatMat
+
+    Mul {mul}
+    AssignMul {assign_mul}
+    fmpz_mat_scalar_mul_fmpq;
 
-    Pow {div}
+    Div {div}
     AssignDiv {assign_div}
-    fmpq_mat::fmpq_mat_set_fmpz_mat_div_fmpz;
-}*/
+    fmpz_mat_scalar_div_fmpq;
+}
+
+impl_binop_unsafe! {
+    scalar_lhs
+    Rational, IntMat, RatMat
+
+    Mul {mul}
+    AssignMul {assign_mul}
+    fmpz_mat_fmpq_scalar_mul;
+}
 
 impl_binop_unsafe! {
     scalar_rhs
@@ -117,16 +135,14 @@ impl_binop_unsafe! {
     fmpz_mat::fmpz_mat_pow;
 }
 
-/* TODO: RatMat
 impl_binop_unsafe! {
     scalar_rhs
-    IntMat, Integer, RatMat
+    IntMat, u64 {u64 u32 u16 u8}, RatMat
 
     Div {div}
-    DivAssign {div_assign}
     AssignDiv {assign_div}
-    fmpz_mat_scalar_div_fmpz;
-}*/
+    fmpz_mat_scalar_div_ui;
+}
 
 impl_binop_unsafe! {
     scalar_rhs
@@ -144,21 +160,14 @@ impl_binop_unsafe! {
     fmpz_mat_scalar_mod_si;
 }
 
-/* TODO: RatMat
 impl_binop_unsafe! {
     scalar_rhs
-    IntMat, Integer, RatMat
+    IntMat, i64 {i64 i32 i16 i8}, RatMat
 
     Div {div}
-    DivAssign {div_assign}
     AssignDiv {assign_div}
-    fmpz_mat_scalar_div_fmpz;
-
-    Pow {div}
-    DivAssign {div_assign}
-    AssignDiv {assign_div}
-    fmpz_mat_scalar_div_fmpz;
-}*/
+    fmpz_mat_scalar_div_si;
+}
 
 impl_binop_unsafe! {
     scalar_lhs
@@ -243,3 +252,78 @@ unsafe fn fmpz_mat_scalar_mod_si(
     fmpz_mat::fmpz_mat_scalar_mod_fmpz(res, f, z.as_ptr());
     fmpz::fmpz_clear(z.as_mut_ptr());
 }
+
+// The results below are rational matrices computed in a temporary of the
+// right dimensions, since the destination of `AssignDiv` and `AssignMul` can
+// have any shape.
+
+#[inline]
+unsafe fn fmpz_mat_scalar_div_fmpz(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpz_mat::fmpz_mat_struct,
+    g: *const fmpz::fmpz,
+) {
+    assert!(fmpz::fmpz_is_zero(g) == 0, "Division by zero.");
+    fmpq_mat_swap_into(res, (*f).r, (*f).c, |z| {
+        fmpq_mat::fmpq_mat_set_fmpz_mat_div_fmpz(z, f, g)
+    });
+}
+
+#[inline]
+unsafe fn fmpz_mat_scalar_div_ui(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpz_mat::fmpz_mat_struct,
+    g: c_ulong,
+) {
+    let mut z = MaybeUninit::uninit();
+    fmpz::fmpz_init_set_ui(z.as_mut_ptr(), g);
+    fmpz_mat_scalar_div_fmpz(res, f, z.as_ptr());
+    fmpz::fmpz_clear(z.as_mut_ptr());
+}
+
+#[inline]
+unsafe fn fmpz_mat_scalar_div_si(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpz_mat::fmpz_mat_struct,
+    g: c_long,
+) {
+    let mut z = MaybeUninit::uninit();
+    fmpz::fmpz_init_set_si(z.as_mut_ptr(), g);
+    fmpz_mat_scalar_div_fmpz(res, f, z.as_ptr());
+    fmpz::fmpz_clear(z.as_mut_ptr());
+}
+
+#[inline]
+unsafe fn fmpz_mat_scalar_mul_fmpq(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpz_mat::fmpz_mat_struct,
+    g: *const fmpq::fmpq,
+) {
+    fmpq_mat_swap_into(res, (*f).r, (*f).c, |z| {
+        fmpq_mat::fmpq_mat_set_fmpz_mat(z, f);
+        fmpq_mat::fmpq_mat_scalar_mul_fmpq(z, z, g);
+    });
+}
+
+#[inline]
+unsafe fn fmpz_mat_scalar_div_fmpq(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpz_mat::fmpz_mat_struct,
+    g: *const fmpq::fmpq,
+) {
+    assert!(fmpq::fmpq_is_zero(g) == 0, "Division by zero.");
+    let mut z = MaybeUninit::uninit();
+    fmpq::fmpq_init(z.as_mut_ptr());
+    fmpq::fmpq_inv(z.as_mut_ptr(), g);
+    fmpz_mat_scalar_mul_fmpq(res, f, z.as_ptr());
+    fmpq::fmpq_clear(z.as_mut_ptr());
+}
+
+#[inline]
+unsafe fn fmpz_mat_fmpq_scalar_mul(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpq::fmpq,
+    g: *const fmpz_mat::fmpz_mat_struct,
+) {
+    fmpz_mat_scalar_mul_fmpq(res, g, f);
+}
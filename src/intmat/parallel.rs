@@ -0,0 +1,153 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::{fmpz, fmpz_mat};
+use rayon::prelude::*;
+
+// A matrix shared read-only with the worker threads. FLINT only reads the 
+// entries, so concurrent access is sound as long as the borrow it was created
+// from outlives the parallel section.
+#[derive(Clone, Copy)]
+struct Shared(*const IntMat);
+
+unsafe impl Send for Shared {}
+unsafe impl Sync for Shared {}
+
+impl Shared {
+    #[inline]
+    unsafe fn get(&self) -> &IntMat {
+        &*self.0
+    }
+}
+
+// The residues of a matrix modulo a prime, in row-major order. Plain words
+// can be sent between threads, unlike FLINT matrices.
+fn residues(m: &NmodMat) -> Vec<u64> {
+    m.rows().flatten().copied().collect()
+}
+
+fn from_residues(vals: &[u64], nrows: i64, ncols: i64, ctx: &NmodRing) -> NmodMat {
+    let mut res = NmodMat::zero(nrows, ncols, ctx);
+    let c = res.ncols();
+    for (k, &x) in vals.iter().enumerate() {
+        res.set_entry(k / c, k % c, unsafe { Nmod::from_raw(x, *ctx) });
+    }
+    res
+}
+
+impl IntMat {
+    /// Return the product `self * other`, computed modulo enough word-size 
+    /// primes to determine it and recombined with the Chinese remainder 
+    /// theorem. The products modulo each prime run in parallel on the rayon
+    /// thread pool, which pays off for large matrices with large entries.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = IntMat::new([1, -2, 3, 4, 5, -6], 2, 3);
+    /// let b = IntMat::new([7, 8, 9, 10, -11, 12], 3, 2);
+    /// assert_eq!(a.par_mul(&b), &a * &b);
+    /// ```
+    pub fn par_mul(&self, other: &IntMat) -> IntMat {
+        assert_eq!(self.ncols(), other.nrows());
+        let (r, c) = (self.nrows_si(), other.ncols_si());
+        if self.is_empty() || other.is_empty() {
+            return IntMat::zero(r, c);
+        }
+
+        // every entry of the product is a sum of k products of entries
+        let bound = Integer::from(self.ncols()) 
+            * self.max_abs_entry() 
+            * other.max_abs_entry();
+        let primes = ModularPrimes::default().covering(&bound);
+
+        let (a, b) = (Shared(self), Shared(other));
+        let prods: Vec<Vec<u64>> = primes
+            .par_iter()
+            .map(|&p| {
+                let ctx = NmodRing::new(p);
+                let (a, b) = unsafe { (a.get(), b.get()) };
+                residues(&(NmodMat::from_intmat(a, &ctx) * NmodMat::from_intmat(b, &ctx)))
+            })
+            .collect();
+
+        let mut res = IntMat::zero(r, c);
+        let mut modulus = Integer::one();
+        for (i, (&p, vals)) in primes.iter().zip(prods.iter()).enumerate() {
+            check_interrupt();
+            let m = from_residues(vals, r, c, &NmodRing::new(p));
+            let sign = (i + 1 == primes.len()) as libc::c_int;
+            unsafe {
+                fmpz_mat::fmpz_mat_CRT_ui(
+                    res.as_mut_ptr(), 
+                    res.as_ptr(), 
+                    modulus.as_ptr(), 
+                    m.as_ptr(), 
+                    sign
+                );
+            }
+            modulus *= Integer::from(p);
+        }
+        res
+    }
+
+    /// Return the determinant of a square matrix, computed modulo enough 
+    /// word-size primes to exceed the Hadamard bound in parallel on the rayon
+    /// thread pool and recombined with the Chinese remainder theorem.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let m = IntMat::new([2, -1, 0, -1, 2, -1, 0, -1, 2], 3, 3);
+    /// assert_eq!(m.par_det(), m.det());
+    /// ```
+    pub fn par_det(&self) -> Integer {
+        assert!(self.is_square());
+        if self.is_empty() {
+            return Integer::one();
+        }
+        let primes = ModularPrimes::default().covering(&self.hadamard_bound());
+
+        let m = Shared(self);
+        let dets: Vec<u64> = primes
+            .par_iter()
+            .map(|&p| {
+                let ctx = NmodRing::new(p);
+                NmodMat::from_intmat(unsafe { m.get() }, &ctx).det().value()
+            })
+            .collect();
+
+        let mut res = Integer::zero();
+        let mut modulus = Integer::one();
+        for (i, (&p, &d)) in primes.iter().zip(dets.iter()).enumerate() {
+            let sign = (i + 1 == primes.len()) as libc::c_int;
+            unsafe {
+                fmpz::fmpz_CRT_ui(
+                    res.as_mut_ptr(), 
+                    res.as_ptr(), 
+                    modulus.as_ptr(), 
+                    d, 
+                    p, 
+                    sign
+                );
+            }
+            modulus *= Integer::from(p);
+        }
+        res
+    }
+}
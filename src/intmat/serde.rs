@@ -15,19 +15,21 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use crate::{Integer, IntMat};
+use crate::util::read_matrix_entries;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
 use serde::ser::{Serialize, SerializeSeq, Serializer};
+use std::fmt;
 
 impl Serialize for IntMat {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let entries = self.entries();
+        let entries = self.get_entries();
         let mut seq = serializer.serialize_seq(Some(entries.len() + 2))?;
-
-        seq.serialize_element(&self.nrows())?;
-        seq.serialize_element(&self.ncols())?;
+        seq.serialize_element(&self.nrows_si())?;
+        seq.serialize_element(&self.ncols_si())?;
         for e in entries.iter() {
             seq.serialize_element(e)?;
         }
@@ -54,17 +56,21 @@ impl<'de> Visitor<'de> for IntMatVisitor {
     where
         A: SeqAccess<'de>,
     {
-        let mut entries: Vec<Integer> = Vec::with_capacity(
-            access.size_hint().unwrap_or(0));
-        let nrows: i64 = access.next_element()?.unwrap();
-        let ncols: i64 = access.next_element()?.unwrap();
+        let nrows: i64 = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let ncols: i64 = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let entries: Vec<Integer> = 
+            read_matrix_entries(&mut access, nrows, ncols, 2, &self)?;
 
-        while let Some(x) = access.next_element()? {
-            entries.push(x);
+        let mut res = IntMat::zero(nrows, ncols);
+        let c = res.ncols();
+        for (k, x) in entries.into_iter().enumerate() {
+            res.set_entry(k / c, k % c, x);
         }
-
-        let zm = IntMatSpace::init(nrows, ncols);
-        Ok(zm.new(&entries[..]))
+        Ok(res)
     }
 }
 
@@ -78,13 +84,28 @@ impl<'de> Deserialize<'de> for IntMat {
 }
 
 #[cfg(test)]
-mod test {
+mod tests {
+    use crate::*;
+
     #[test]
     fn serde() {
-        let mz = IntMatSpace::init(2, 2);
-        let x = mz.new([1, 0, 0, 2]);
+        let x = IntMat::new([1, 0, -7, 2, 5, 3], 2, 3);
         let ser = bincode::serialize(&x).unwrap();
         let y: IntMat = bincode::deserialize(&ser).unwrap();
         assert_eq!(x, y);
+        
+        let ser = serde_json::to_string(&x).unwrap();
+        let y: IntMat = serde_json::from_str(&ser).unwrap();
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn serde_bad_dimensions() {
+        assert!(serde_json::from_str::<IntMat>("[4294967296, 4294967296]").is_err());
+        assert!(serde_json::from_str::<IntMat>("[9223372036854775807, 2, 1]").is_err());
+        assert!(serde_json::from_str::<IntMat>("[2, 2, 1, 2, 3]").is_err());
+        assert!(serde_json::from_str::<IntMat>("[1, 2, 1, 2, 3]").is_err());
+        assert!(serde_json::from_str::<IntMat>("[9223372036854775807, 0]").is_err());
+        assert_eq!(serde_json::from_str::<IntMat>("[3, 0]").unwrap(), IntMat::zero(3, 0));
     }
 }
@@ -0,0 +1,317 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpz_mat;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A read-only view of a rectangular block of an [IntMat], backed by a FLINT
+/// window matrix. Creating a window does not copy any entries.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let m = IntMat::new([1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+/// let w = m.window(1, 1, 3, 3);
+/// assert_eq!(w.to_intmat(), IntMat::new([5, 6, 8, 9], 2, 2));
+/// assert_eq!(w.det(), -3);
+///
+/// let v = w.window(0, 1, 2, 2);
+/// assert_eq!(v.to_intmat(), IntMat::new([6, 9], 2, 1));
+/// assert_eq!(&w * &v, IntMat::new([84, 129], 2, 1));
+/// ```
+pub struct IntMatWindow<'a> {
+    inner: fmpz_mat::fmpz_mat_struct,
+    phantom: PhantomData<&'a IntMat>,
+}
+
+impl fmt::Debug for IntMatWindow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntMatWindow")
+            .field("nrows", &self.nrows())
+            .field("ncols", &self.ncols())
+            .finish()
+    }
+}
+
+impl fmt::Display for IntMatWindow<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_intmat().fmt(f)
+    }
+}
+
+impl Drop for IntMatWindow<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { fmpz_mat::fmpz_mat_window_clear(&mut self.inner) }
+    }
+}
+
+impl PartialEq for IntMatWindow<'_> {
+    fn eq(&self, rhs: &IntMatWindow<'_>) -> bool {
+        self.nrows() == rhs.nrows()
+            && self.ncols() == rhs.ncols()
+            && unsafe { fmpz_mat::fmpz_mat_equal(self.as_ptr(), rhs.as_ptr()) != 0 }
+    }
+}
+
+impl PartialEq<IntMat> for IntMatWindow<'_> {
+    fn eq(&self, rhs: &IntMat) -> bool {
+        self.nrows() == rhs.nrows()
+            && self.ncols() == rhs.ncols()
+            && unsafe { fmpz_mat::fmpz_mat_equal(self.as_ptr(), rhs.as_ptr()) != 0 }
+    }
+}
+
+impl<'a> IntMatWindow<'a> {
+    // `ptr` must point to an initialized matrix or window outliving 'a, and
+    // the indices must satisfy r1 <= r2 <= nrows, c1 <= c2 <= ncols.
+    unsafe fn init(
+        ptr: *const fmpz_mat::fmpz_mat_struct,
+        r1: i64,
+        c1: i64,
+        r2: i64,
+        c2: i64
+    ) -> Self {
+        let mut win = MaybeUninit::uninit();
+        fmpz_mat::fmpz_mat_window_init(win.as_mut_ptr(), ptr, r1, c1, r2, c2);
+        IntMatWindow { inner: win.assume_init(), phantom: PhantomData }
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const fmpz_mat::fmpz_mat_struct {
+        &self.inner
+    }
+
+    /// Return the number of rows.
+    #[inline]
+    pub fn nrows_si(&self) -> i64 {
+        unsafe { fmpz_mat::fmpz_mat_nrows(self.as_ptr()) }
+    }
+
+    /// Return the number of columns.
+    #[inline]
+    pub fn ncols_si(&self) -> i64 {
+        unsafe { fmpz_mat::fmpz_mat_ncols(self.as_ptr()) }
+    }
+
+    /// Return the number of rows.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows_si().try_into().expect("Cannot convert signed long to usize.")
+    }
+
+    /// Return the number of columns.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols_si().try_into().expect("Cannot convert signed long to usize.")
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nrows_si() == 0 || self.ncols_si() == 0
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.nrows_si() == self.ncols_si()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { fmpz_mat::fmpz_mat_is_zero(self.as_ptr()) != 0 }
+    }
+
+    /// Borrow the `(i, j)`-th entry of the window.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> &'a Integer {
+        assert!(i < self.nrows() && j < self.ncols());
+        unsafe {
+            let x = fmpz_mat::fmpz_mat_entry(
+                self.as_ptr(),
+                i.try_into().expect("Cannot convert index to a signed long."),
+                j.try_into().expect("Cannot convert index to a signed long.")
+            );
+            &*(x as *const Integer)
+        }
+    }
+
+    /// Get the `(i, j)`-th entry of the window.
+    #[inline]
+    pub fn get_entry(&self, i: usize, j: usize) -> Integer {
+        self.entry(i, j).clone()
+    }
+
+    /// Return a window into the `r2 - r1` by `c2 - c1` block of this window
+    /// whose `(0, 0)` entry is the `(r1, c1)` entry of `self`. The new window
+    /// borrows from the same matrix.
+    pub fn window(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> IntMatWindow<'a> {
+        assert!(r1 <= r2 && r2 <= self.nrows());
+        assert!(c1 <= c2 && c2 <= self.ncols());
+        let conv = |x: usize| -> i64 {
+            x.try_into().expect("Cannot convert index to a signed long.")
+        };
+        unsafe { IntMatWindow::init(self.as_ptr(), conv(r1), conv(c1), conv(r2), conv(c2)) }
+    }
+
+    /// Copy the entries of the window into a new matrix.
+    pub fn to_intmat(&self) -> IntMat {
+        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe { fmpz_mat::fmpz_mat_set(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Return the transpose of the window as a new matrix.
+    pub fn transpose(&self) -> IntMat {
+        let mut res = IntMat::zero(self.ncols_si(), self.nrows_si());
+        unsafe { fmpz_mat::fmpz_mat_transpose(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Compute the trace of a square window.
+    pub fn trace(&self) -> Integer {
+        assert!(self.is_square());
+        let mut res = Integer::zero();
+        unsafe { fmpz_mat::fmpz_mat_trace(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Compute the determinant of a square window.
+    pub fn det(&self) -> Integer {
+        assert!(self.is_square());
+        let mut res = Integer::zero();
+        unsafe { fmpz_mat::fmpz_mat_det(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+}
+
+impl IntMat {
+    /// Return a read-only window into the `r2 - r1` by `c2 - c1` block of the
+    /// matrix whose `(0, 0)` entry is the `(r1, c1)` entry of `self`, without
+    /// copying.
+    pub fn window(&self, r1: usize, c1: usize, r2: usize, c2: usize) -> IntMatWindow<'_> {
+        assert!(r1 <= r2 && r2 <= self.nrows());
+        assert!(c1 <= c2 && c2 <= self.ncols());
+        let conv = |x: usize| -> i64 {
+            x.try_into().expect("Cannot convert index to a signed long.")
+        };
+        unsafe { IntMatWindow::init(self.as_ptr(), conv(r1), conv(c1), conv(r2), conv(c2)) }
+    }
+
+    /// Return a window covering the whole matrix, for mixing matrices and
+    /// windows in arithmetic.
+    #[inline]
+    pub fn as_window(&self) -> IntMatWindow<'_> {
+        self.window(0, 0, self.nrows(), self.ncols())
+    }
+}
+
+impl From<&IntMatWindow<'_>> for IntMat {
+    #[inline]
+    fn from(x: &IntMatWindow<'_>) -> IntMat {
+        x.to_intmat()
+    }
+}
+
+impl From<IntMatWindow<'_>> for IntMat {
+    #[inline]
+    fn from(x: IntMatWindow<'_>) -> IntMat {
+        x.to_intmat()
+    }
+}
+
+// Read-only arithmetic. The results are new matrices.
+
+impl Neg for &IntMatWindow<'_> {
+    type Output = IntMat;
+    fn neg(self) -> IntMat {
+        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe { fmpz_mat::fmpz_mat_neg(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+}
+
+impl Add<&IntMatWindow<'_>> for &IntMatWindow<'_> {
+    type Output = IntMat;
+    fn add(self, rhs: &IntMatWindow<'_>) -> IntMat {
+        assert!(self.nrows() == rhs.nrows() && self.ncols() == rhs.ncols());
+        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe {
+            fmpz_mat::fmpz_mat_add(res.as_mut_ptr(), self.as_ptr(), rhs.as_ptr());
+        }
+        res
+    }
+}
+
+impl Sub<&IntMatWindow<'_>> for &IntMatWindow<'_> {
+    type Output = IntMat;
+    fn sub(self, rhs: &IntMatWindow<'_>) -> IntMat {
+        assert!(self.nrows() == rhs.nrows() && self.ncols() == rhs.ncols());
+        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe {
+            fmpz_mat::fmpz_mat_sub(res.as_mut_ptr(), self.as_ptr(), rhs.as_ptr());
+        }
+        res
+    }
+}
+
+impl Mul<&IntMatWindow<'_>> for &IntMatWindow<'_> {
+    type Output = IntMat;
+    fn mul(self, rhs: &IntMatWindow<'_>) -> IntMat {
+        assert_eq!(self.ncols(), rhs.nrows());
+        let mut res = IntMat::zero(self.nrows_si(), rhs.ncols_si());
+        unsafe {
+            fmpz_mat::fmpz_mat_mul(res.as_mut_ptr(), self.as_ptr(), rhs.as_ptr());
+        }
+        res
+    }
+}
+
+impl Mul<&Integer> for &IntMatWindow<'_> {
+    type Output = IntMat;
+    fn mul(self, rhs: &Integer) -> IntMat {
+        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe {
+            fmpz_mat::fmpz_mat_scalar_mul_fmpz(
+                res.as_mut_ptr(),
+                self.as_ptr(),
+                rhs.as_ptr()
+            );
+        }
+        res
+    }
+}
+
+impl Mul<&IntMat> for &IntMatWindow<'_> {
+    type Output = IntMat;
+    #[inline]
+    fn mul(self, rhs: &IntMat) -> IntMat {
+        self * &rhs.as_window()
+    }
+}
+
+impl Mul<&IntMatWindow<'_>> for &IntMat {
+    type Output = IntMat;
+    #[inline]
+    fn mul(self, rhs: &IntMatWindow<'_>) -> IntMat {
+        &self.as_window() * rhs
+    }
+}
@@ -17,11 +17,15 @@
 
 mod ops;
 mod conv;
+mod dlog;
+mod pow;
 
 #[cfg(feature = "serde")]
 mod serde;
 
-use crate::{NewCtx, Integer};
+pub use pow::FixedBasePow;
+
+use crate::{NewCtx, Integer, FlintRand};
 use flint_sys::{fmpz, fmpz_mod};
 use std::fmt;
 use std::hash::{Hash, Hasher};
@@ -66,7 +70,7 @@ impl FmpzModCtx {
 
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct IntModCtx {
     inner: Rc<FmpzModCtx>
 }
@@ -79,6 +83,14 @@ impl PartialEq for IntModCtx {
     }
 }
 
+impl fmt::Debug for IntModCtx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntModCtx")
+            .field("modulus", &format_args!("{}", self.modulus()))
+            .finish()
+    }
+}
+
 impl fmt::Display for IntModCtx {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Context for ring of integers modulo {}", self.modulus())
@@ -87,7 +99,7 @@ impl fmt::Display for IntModCtx {
 
 impl Hash for IntModCtx {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.modulus().hash(state)
+        unsafe { (*(self.modulus_as_ptr() as *const Integer)).hash(state) }
     }
 }
 
@@ -115,10 +127,62 @@ impl IntModCtx {
         unsafe { fmpz::fmpz_set(res.as_mut_ptr(), self.modulus_as_ptr()); }
         res
     }
-    
+
+    /// Return the element `x` modulo `n` of this context.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = IntModCtx::new(7);
+    /// let x = zn.element(10);
+    /// let f = IntModPoly::new([1, 1], &zn);
+    /// assert_eq!(x.context(), f.context());
+    /// assert_eq!(&f * &x, IntModPoly::new([3, 3], &zn));
+    /// ```
+    #[inline]
+    pub fn element<T: Into<Integer>>(&self, x: T) -> IntMod {
+        IntMod::new(x, self)
+    }
+
+    /// Return the zero element of this context.
+    #[inline]
+    pub fn zero(&self) -> IntMod {
+        IntMod::zero(self)
+    }
+
+    /// Return the one element of this context.
+    #[inline]
+    pub fn one(&self) -> IntMod {
+        IntMod::one(self)
+    }
+
+    /// Return the zero polynomial over this context.
+    #[inline]
+    pub fn zero_poly(&self) -> IntModPoly {
+        IntModPoly::zero(self)
+    }
+
+    /// Return the `nrows` by `ncols` zero matrix over this context.
+    #[inline]
+    pub fn zero_mat(&self, nrows: i64, ncols: i64) -> IntModMat {
+        IntModMat::zero(nrows, ncols, self)
+    }
+}
+
+impl AsRef<IntModCtx> for IntModCtx {
+    #[inline]
+    fn as_ref(&self) -> &IntModCtx {
+        self
+    }
+}
+
+impl AsRef<IntModCtx> for IntMod {
+    #[inline]
+    fn as_ref(&self) -> &IntModCtx {
+        self.context()
+    }
 }
 
-#[derive(Debug)]
 pub struct IntMod {
     pub(crate) inner: fmpz::fmpz,
     pub(crate) ctx: IntModCtx,
@@ -139,9 +203,24 @@ impl Clone for IntMod {
     }
 }
 
+impl fmt::Debug for IntMod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntMod")
+            .field("value", &format_args!("{}", self))
+            .field("modulus", &format_args!("{}", self.modulus()))
+            .finish()
+    }
+}
+
+/// The alternate form `{:#}` also prints the modulus, as in `4 (mod 7)`.
 impl fmt::Display for IntMod {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", Integer::from(self).to_string())
+        // format the internal value in place, it is already reduced
+        unsafe { fmt::Display::fmt(&*(self.as_ptr() as *const Integer), f)?; }
+        if f.alternate() {
+            write!(f, " (mod {})", self.modulus())?;
+        }
+        Ok(())
     }
 }
 
@@ -152,11 +231,10 @@ impl Drop for IntMod {
 }
 
 
-// TODO: avoid Integer allocation?
 impl Hash for IntMod {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.context().hash(state);
-        Integer::from(self).hash(state);
+        unsafe { (*(self.as_ptr() as *const Integer)).hash(state) }
     }
 }
 
@@ -269,4 +347,14 @@ impl IntMod {
     pub fn is_one(&self) -> bool {
         unsafe { fmpz::fmpz_is_one(self.as_ptr()) == 1 }
     }
+
+    /// Return a uniformly random element of `Z/nZ`.
+    #[inline]
+    pub fn randm(ctx: &IntModCtx, rng: &mut FlintRand) -> IntMod {
+        let mut res = IntMod::zero(ctx);
+        unsafe { 
+            fmpz::fmpz_randm(res.as_mut_ptr(), rng.as_mut_ptr(), ctx.modulus_as_ptr()); 
+        }
+        res
+    }
 }
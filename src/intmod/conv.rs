@@ -14,3 +14,40 @@
  *  You should have received a copy of the GNU General Public License
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
+
+use crate::*;
+use crate::Error::ParseIntModError;
+use std::str::FromStr;
+
+impl FromStr for IntMod {
+    type Err = Error;
+    /// Parse an integer together with its modulus, written `a mod n` or in
+    /// the alternate `Display` format `a (mod n)`. Each parsed element has its
+    /// own context, see [IntModCtx::new].
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a: IntMod = "-3 mod 7".parse().unwrap();
+    /// assert_eq!(a, 4);
+    /// assert_eq!(a.modulus(), 7);
+    /// assert_eq!(format!("{:#}", a), "4 (mod 7)");
+    /// assert_eq!(format!("{:#}", a).parse::<IntMod>().unwrap(), a);
+    /// assert!(matches!("3 mod 0".parse::<IntMod>(), Err(Error::ParseIntModError(_))));
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (a, n) = match s.strip_suffix(')').and_then(|t| t.split_once("(mod")) {
+            Some(pair) => pair,
+            None => s.split_once("mod").ok_or_else(|| {
+                ParseIntModError("Expected \"a mod n\" or \"a (mod n)\".".to_string())
+            })?,
+        };
+        let a = parse::integer(a).map_err(ParseIntModError)?;
+        let n = parse::integer(n).map_err(ParseIntModError)?;
+        if n.sign() <= 0 {
+            return Err(ParseIntModError("The modulus must be positive.".to_string()));
+        }
+        Ok(IntMod::new(a, &IntModCtx::new(n)))
+    }
+}
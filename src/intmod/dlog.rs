@@ -0,0 +1,127 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpz_mod;
+use std::mem::MaybeUninit;
+
+impl IntModCtx {
+    /// Return the smallest positive primitive root modulo `n`, a generator of
+    /// the group of units, or `None` if the group of units is not cyclic. 
+    /// Primitive roots exist exactly for `n = 1, 2, 4, p^k, 2p^k` with `p` an
+    /// odd prime.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(IntModCtx::new(50).primitive_root().unwrap(), 3);
+    /// assert_eq!(IntModCtx::new(101).primitive_root().unwrap(), 2);
+    /// assert!(IntModCtx::new(15).primitive_root().is_none());
+    /// ```
+    pub fn primitive_root(&self) -> Option<IntMod> {
+        let n = self.modulus();
+        if n <= 2 {
+            return Some(self.element(n - 1));
+        }
+
+        let fac = n.factor();
+        let e2 = if fac[0].0 == 2 { fac[0].1 } else { 0 };
+        let odd = if e2 > 0 { &fac[1..] } else { &fac[..] };
+        let cyclic = (odd.is_empty() && e2 <= 2) || (odd.len() == 1 && e2 <= 1);
+        if !cyclic {
+            return None;
+        }
+
+        let mut phi = Integer::one();
+        for (p, e) in fac.iter() {
+            phi *= p.pow(e - 1) * (p - 1);
+        }
+        let exps: Vec<Integer> = phi.factor().iter().map(|(q, _)| phi.fdiv_q(q)).collect();
+        let mut g = Integer::from(2);
+        loop {
+            if g.gcd(&n).is_one() && exps.iter().all(|e| !g.powm(e, &n).is_one()) {
+                return Some(self.element(g));
+            }
+            g += 1;
+        }
+    }
+}
+
+impl IntMod {
+    /// Return the smallest `x >= 0` with `base^x = self`, or `None` if there
+    /// is none. The modulus must be a prime `p`. The logarithm is computed 
+    /// with FLINT's Pohlig-Hellman implementation, which is fast when `p - 1`
+    /// has only small prime factors.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zp = IntModCtx::new(101);
+    /// let y = zp.element(14);
+    /// assert_eq!(y.dlog(zp.element(2)).unwrap(), 10);
+    ///
+    /// // 4 generates the subgroup of squares, which 2 is not in
+    /// assert_eq!(y.dlog(zp.element(4)).unwrap(), 5);
+    /// assert!(zp.element(2).dlog(zp.element(4)).is_none());
+    /// ```
+    pub fn dlog<T: AsRef<IntMod>>(&self, base: T) -> Option<Integer> {
+        let base = base.as_ref();
+        assert_eq!(self.context(), base.context());
+        let p = self.modulus();
+        assert!(p.is_prime(), "Discrete logarithms are only supported modulo a prime.");
+        if self.is_one() {
+            return Some(Integer::zero());
+        }
+        if base.is_zero() || self.is_zero() {
+            return if base.is_zero() && self.is_zero() { Some(Integer::one()) } else { None };
+        }
+
+        // logarithms of both to the primitive root chosen by FLINT
+        let mut lb = Integer::zero();
+        let mut ly = Integer::zero();
+        unsafe {
+            let mut l = MaybeUninit::uninit();
+            fmpz_mod::fmpz_mod_discrete_log_pohlig_hellman_init(l.as_mut_ptr());
+            let mut l = l.assume_init();
+            fmpz_mod::fmpz_mod_discrete_log_pohlig_hellman_precompute_prime(&mut l, p.as_ptr());
+            fmpz_mod::fmpz_mod_discrete_log_pohlig_hellman_run(
+                lb.as_mut_ptr(), 
+                &l, 
+                base.as_ptr()
+            );
+            fmpz_mod::fmpz_mod_discrete_log_pohlig_hellman_run(
+                ly.as_mut_ptr(), 
+                &l, 
+                self.as_ptr()
+            );
+            fmpz_mod::fmpz_mod_discrete_log_pohlig_hellman_clear(&mut l);
+        }
+
+        // solve lb*x = ly mod p - 1
+        let n = p - 1;
+        let d = lb.gcd(&n);
+        if !ly.divisible(&d) {
+            return None;
+        }
+        let m = n.fdiv_q(&d);
+        if m.is_one() {
+            return Some(Integer::zero());
+        }
+        let x = ly.fdiv_q(&d) * lb.fdiv_q(&d).invmod(&m).unwrap();
+        Some(x.fdiv_r(&m))
+    }
+}
@@ -20,6 +20,7 @@ use crate::{IntMod, Integer, Rational};
 use flint_sys::{fmpq, fmpz, fmpz_mod};
 use inertia_algebra::ops::*;
 use libc::{c_int, c_long, c_ulong};
+use std::mem::MaybeUninit;
 
 impl_assign_unsafe! {
     ctx
@@ -241,7 +242,7 @@ impl_binop_unsafe! {
     Pow {pow}
     PowAssign {pow_assign}
     AssignPow {assign_pow}
-    fmpz_mod::fmpz_mod_pow_fmpz;
+    fmpz_mod_pow_fmpz;
 }
 
 impl_binop_unsafe! {
@@ -583,11 +584,21 @@ unsafe fn fmpz_mod_pow_si(
     g: c_long,
     ctx: *const fmpz_mod::fmpz_mod_ctx,
 ) {
-    if g < 0 {
-        fmpz_mod::fmpz_mod_inv(res, f, ctx);
-    } else {
-        fmpz::fmpz_set(res, f);
-    }
+    let mut z = MaybeUninit::uninit();
+    fmpz::fmpz_init_set_si(z.as_mut_ptr(), g);
+    fmpz_mod_pow_fmpz(res, f, z.as_ptr(), ctx);
+    fmpz::fmpz_clear(z.as_mut_ptr());
+}
 
-    fmpz_mod::fmpz_mod_pow_ui(res, res, g.abs() as u64, ctx);
+// FLINT inverts the base for negative exponents and reports failure instead
+// of aborting, so check the result.
+#[inline]
+unsafe fn fmpz_mod_pow_fmpz(
+    res: *mut fmpz::fmpz,
+    f: *const fmpz::fmpz,
+    g: *const fmpz::fmpz,
+    ctx: *const fmpz_mod::fmpz_mod_ctx,
+) {
+    let success = fmpz_mod::fmpz_mod_pow_fmpz(res, f, g, ctx);
+    assert!(success != 0, "The base is not invertible.");
 }
@@ -0,0 +1,278 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use crate::multiexp;
+use flint_sys::{fmpz, fmpz_mod};
+
+// Return b^e for e >= 0.
+fn pow_nonneg(b: &IntMod, e: &Integer) -> IntMod {
+    let mut res = IntMod::zero(b.context());
+    unsafe {
+        fmpz_mod::fmpz_mod_pow_fmpz(res.as_mut_ptr(), b.as_ptr(), e.as_ptr(), b.ctx_as_ptr());
+    }
+    res
+}
+
+#[inline]
+fn is_unit(x: &IntMod) -> bool {
+    Integer::from(x).gcd(x.modulus()).is_one()
+}
+
+impl IntModCtx {
+    /// Return a [FixedBasePow] for repeatedly raising `base` to exponents of
+    /// up to the bit length of the modulus. Panics on negative exponents if
+    /// `base` is not invertible.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // toy Diffie-Hellman
+    /// let zp = IntModCtx::new(2147483647);
+    /// let g = zp.precompute_pow(7);
+    /// let (a, b) = (Integer::from(123456789), Integer::from(987654321));
+    /// let (ga, gb) = (g.pow(&a), g.pow(&b));
+    /// let shared = ga.clone().pow(&b);
+    /// assert_eq!(shared, gb.pow(&a));
+    /// assert_eq!(shared, g.pow(&a * &b));
+    ///
+    /// // negative exponents invert the base
+    /// assert_eq!(ga * zp.element(7).pow(Integer::from(-123456789)), 1);
+    /// ```
+    pub fn precompute_pow<T: Into<Integer>>(&self, base: T) -> FixedBasePow {
+        FixedBasePow::new(&self.element(base), self.modulus().bits())
+    }
+
+    /// Return `b^e` for each pair `(b, e)`. The bases with negative exponents
+    /// are inverted together with Montgomery's trick, at the cost of a single
+    /// modular inversion and three multiplications per base. Returns `None`
+    /// if one of them is not invertible.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = IntModCtx::new(101);
+    /// let res = zn.pow_many(&[
+    ///     (zn.element(2), Integer::from(10)),
+    ///     (zn.element(3), Integer::from(-1)),
+    ///     (zn.element(5), Integer::from(-2)),
+    /// ]).unwrap();
+    /// assert_eq!(res, vec![zn.element(14), zn.element(34), zn.element(97)]);
+    ///
+    /// let zn = IntModCtx::new(10);
+    /// assert!(zn.pow_many(&[(zn.element(2), Integer::from(-1))]).is_none());
+    /// ```
+    pub fn pow_many(&self, pairs: &[(IntMod, Integer)]) -> Option<Vec<IntMod>> {
+        assert!(pairs.iter().all(|(b, _)| b.context() == self));
+
+        // prefix products of the bases to invert
+        let neg: Vec<usize> = (0..pairs.len()).filter(|&i| pairs[i].1 < 0).collect();
+        let mut prefix = Vec::with_capacity(neg.len());
+        let mut acc = self.one();
+        for &i in neg.iter() {
+            acc *= &pairs[i].0;
+            prefix.push(acc.clone());
+        }
+        if !is_unit(&acc) {
+            return None;
+        }
+
+        // walk back from the inverse of the full product
+        let mut bases: Vec<IntMod> = pairs.iter().map(|(b, _)| b.clone()).collect();
+        let mut inv = acc.inv();
+        for k in (0..neg.len()).rev() {
+            let i = neg[k];
+            let bi = if k > 0 { &inv * &prefix[k - 1] } else { inv.clone() };
+            inv *= &pairs[i].0;
+            bases[i] = bi;
+        }
+
+        Some(bases.iter().zip(pairs).map(|(b, (_, e))| pow_nonneg(b, &e.abs())).collect())
+    }
+
+    /// Return `prod b_i^e_i`, see [IntMod::multi_exp]. The empty product is
+    /// one.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = IntModCtx::new(1000003);
+    /// let bases = [zn.element(2), zn.element(3)];
+    /// let exps = [Integer::from(20), Integer::from(12)];
+    /// assert_eq!(zn.multi_exp(&bases, &exps), zn.element(6).pow(12u64) * 256);
+    /// assert_eq!(zn.multi_exp(&[], &[]), 1);
+    /// ```
+    pub fn multi_exp(&self, bases: &[IntMod], exps: &[Integer]) -> IntMod {
+        assert_eq!(bases.len(), exps.len(), "The number of bases and exponents differ.");
+        let pairs: Vec<(IntMod, Integer)> = bases
+            .iter()
+            .zip(exps)
+            .map(|(b, e)| (b.clone(), if e < &0 { Integer::from(-1) } else { Integer::one() }))
+            .collect();
+        let bases = self.pow_many(&pairs).expect("A base with negative exponent is not invertible.");
+        let exps: Vec<Integer> = exps.iter().map(|e| e.abs()).collect();
+        multiexp::multi_exp(self.one(), &bases, &exps)
+    }
+}
+
+/// Exponentiation of a fixed base with precomputed windows. For a window of
+/// `w` bits the table holds `b^(d 2^(wi))` for all digits `0 <= d < 2^w`, so 
+/// raising the base to an exponent of `n` bits takes about `n/w` 
+/// multiplications and no squarings. This pays off when one base is raised to
+/// many exponents, as in signature verification.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let zn = IntModCtx::new(1000003);
+/// let g = zn.element(2);
+/// let fixed = FixedBasePow::new(&g, 64);
+/// for e in [0u64, 1, 12345, 1 << 40] {
+///     assert_eq!(fixed.pow(Integer::from(e)), g.clone().pow(e));
+/// }
+/// assert_eq!(fixed.pow(Integer::from(-7)) * g.clone().pow(7u64), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedBasePow {
+    base: IntMod,
+    window: u64,
+    // table[i][d] = base^(d 2^(window i))
+    table: Vec<Vec<IntMod>>,
+}
+
+impl FixedBasePow {
+    /// Precompute the powers of `base` for exponents of up to `max_bits` 
+    /// bits, using the window size of [tuning::fixed_base_window].
+    #[inline]
+    pub fn new(base: &IntMod, max_bits: u64) -> Self {
+        FixedBasePow::with_window(base, max_bits, tuning::fixed_base_window())
+    }
+
+    /// Precompute the powers of `base` for exponents of up to `max_bits` 
+    /// bits with a window of `window` bits. The table has 
+    /// `ceil(max_bits/window) 2^window` entries.
+    pub fn with_window(base: &IntMod, max_bits: u64, window: u64) -> Self {
+        assert!(window > 0 && window < 16, "The window must have between 1 and 15 bits.");
+        let rows = (max_bits + window - 1) / window;
+        let mut table = Vec::with_capacity(rows as usize);
+        let mut b = base.clone();
+        for _ in 0..rows {
+            let mut row = Vec::with_capacity(1 << window);
+            let mut x = base.context().one();
+            for _ in 0..1u64 << window {
+                row.push(x.clone());
+                x *= &b;
+            }
+            // x = b^(2^window) is the base of the next row
+            b = x;
+            table.push(row);
+        }
+        FixedBasePow { base: base.clone(), window, table }
+    }
+
+    /// The fixed base.
+    #[inline]
+    pub fn base(&self) -> &IntMod {
+        &self.base
+    }
+
+    /// The largest number of bits of an exponent covered by the table.
+    #[inline]
+    pub fn max_bits(&self) -> u64 {
+        self.table.len() as u64 * self.window
+    }
+
+    /// Return the base raised to `e`. Exponents with more than 
+    /// [max_bits](FixedBasePow::max_bits) bits fall back to ordinary 
+    /// powering. Panics if `e` is negative and the base is not invertible.
+    pub fn pow<T: AsRef<Integer>>(&self, e: T) -> IntMod {
+        let e = e.as_ref();
+        let abs = e.abs();
+        let res = if abs.bits() > self.max_bits() {
+            pow_nonneg(&self.base, &abs)
+        } else {
+            let mut res = self.base.context().one();
+            for (i, row) in self.table.iter().enumerate() {
+                let start = i as u64 * self.window;
+                let d = (0..self.window)
+                    .filter(|&k| abs.testbit(start + k))
+                    .fold(0usize, |d, k| d | 1 << k);
+                if d != 0 {
+                    res *= &row[d];
+                }
+            }
+            res
+        };
+        if e < &0 {
+            assert!(is_unit(&res), "The base is not invertible.");
+            res.inv()
+        } else {
+            res
+        }
+    }
+}
+
+impl IntMod {
+    /// Return `prod b_i^e_i` computed by simultaneous exponentiation, which
+    /// shares the squarings between all the bases. Straus' interleaved 
+    /// windows are used for few bases and Pippenger's bucket method for 
+    /// many, see [tuning::multi_exp_pippenger_cutoff]. Bases with negative
+    /// exponents are inverted first and must be invertible.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = IntModCtx::new(1000003);
+    /// let bases = [zn.element(2), zn.element(3), zn.element(5)];
+    /// let exps = [Integer::from(100), Integer::from(-20), Integer::from(7)];
+    /// let expected = zn.pow_many(&[
+    ///     (bases[0].clone(), exps[0].clone()),
+    ///     (bases[1].clone(), exps[1].clone()),
+    ///     (bases[2].clone(), exps[2].clone()),
+    /// ]).unwrap().into_iter().fold(zn.one(), |acc, x| acc * x);
+    /// assert_eq!(IntMod::multi_exp(&bases, &exps), expected);
+    /// ```
+    pub fn multi_exp(bases: &[IntMod], exps: &[Integer]) -> IntMod {
+        assert!(!bases.is_empty(), "At least one base is required for the context.");
+        bases[0].context().multi_exp(bases, exps)
+    }
+
+    /// Return the dot product `sum x_i y_i`. The products are accumulated as
+    /// integers and reduced once at the end, instead of reducing and
+    /// allocating a new element for every term.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = IntModCtx::new(17);
+    /// let x = [zn.element(3), zn.element(5), zn.element(16)];
+    /// let y = [zn.element(4), zn.element(10), zn.element(16)];
+    /// assert_eq!(IntMod::dot(&x, &y), zn.element(12 + 50 + 256));
+    /// ```
+    pub fn dot(x: &[IntMod], y: &[IntMod]) -> IntMod {
+        assert_eq!(x.len(), y.len(), "The vectors have different lengths.");
+        assert!(!x.is_empty(), "At least one entry is required for the context.");
+        let ctx = x[0].context();
+        assert!(x.iter().chain(y).all(|a| a.context() == ctx));
+
+        let mut acc = Integer::zero();
+        for (a, b) in x.iter().zip(y) {
+            unsafe { fmpz::fmpz_addmul(acc.as_mut_ptr(), a.as_ptr(), b.as_ptr()); }
+        }
+        ctx.element(acc)
+    }
+}
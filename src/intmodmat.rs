@@ -18,17 +18,17 @@
 mod ops;
 mod conv;
 
-//#[cfg(feature = "serde")]
-//mod serde;
+#[cfg(feature = "serde")]
+mod serde;
 
 use crate::*;
 use flint_sys::fmpz_mod_mat::*;
+use crate::util::{fmt_rows, DisplayRows};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::MaybeUninit;
 
 
-#[derive(Debug)]
 pub struct IntModMat {
     inner: fmpz_mod_mat_struct,
     ctx: IntModCtx
@@ -50,11 +50,36 @@ impl Clone for IntModMat {
     }
 }
 
+impl fmt::Debug for IntModMat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntModMat")
+            .field("nrows", &self.nrows())
+            .field("ncols", &self.ncols())
+            .field("entries", &DisplayRows(self.rows().collect()))
+            .field("modulus", &format_args!("{}", self.modulus()))
+            .finish()
+    }
+}
+
+/// Entries are printed in place and honor the width, fill and alignment
+/// options. The alternate form `{:#}` also prints the modulus.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let zn = IntModCtx::new(10);
+/// let m = IntModMatSpace::init(2, 2, &zn).new([1, 12, 3, 4]);
+/// assert_eq!(format!("{}", m), "[ 1  2 ]\n[ 3  4 ]");
+/// assert_eq!(format!("{:>2}", m), "[  1   2 ]\n[  3   4 ]");
+/// assert_eq!(format!("{:#}", m), "[ 1  2 ]\n[ 3  4 ] (mod 10)");
+/// ```
 impl fmt::Display for IntModMat {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", IntMat::from(self))
-        //write!(f, "{}", IntMat::from(self) % x.modulus())
+        fmt_rows(f, self.rows())?;
+        if f.alternate() {
+            write!(f, " (mod {})", self.modulus())?;
+        }
+        Ok(())
     }
 }
 
@@ -65,12 +90,21 @@ impl Drop for IntModMat {
     }
 }
 
-// TODO: avoid IntMat allocation
-impl Hash for IntModMat {
+impl AsRef<IntModCtx> for IntModMat {
     #[inline]
+    fn as_ref(&self) -> &IntModCtx {
+        self.context()
+    }
+}
+
+impl Hash for IntModMat {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.context().hash(state);
-        IntMat::from(self).hash(state);
+        self.nrows().hash(state);
+        self.ncols().hash(state);
+        for x in self.iter() {
+            x.hash(state);
+        }
     }
 }
 
@@ -175,7 +209,6 @@ where
 */
 
 impl IntModMat {
-    /*
     // private helper methods to convert usize indices to i64, emit consistent
     // messages on panic, and bounds check
     fn check_indices(&self, i: usize, j: usize) -> (i64, i64) {
@@ -193,7 +226,6 @@ impl IntModMat {
         assert!(j < self.ncols_si());
         j
     }
-    */
     
     /*
     #[inline]
@@ -214,15 +246,51 @@ impl IntModMat {
         }
     }
    
-    /*
     #[inline]
-    pub fn one(dim: i64) -> IntMat {
-        let mut res = IntMat::zero(dim, dim);
-        unsafe {
-            fmpz_mat::fmpz_mat_one(res.as_mut_ptr());
-        }
+    pub fn one(dim: i64, ctx: &IntModCtx) -> IntModMat {
+        let mut res = IntModMat::zero(dim, dim, ctx);
+        unsafe { fmpz_mod_mat_one(res.as_mut_ptr()); }
         res
-    }*/
+    }
+
+    /// Return a random `nrows` by `ncols` matrix.
+    pub fn randtest(nrows: i64, ncols: i64, ctx: &IntModCtx, rng: &mut FlintRand) -> IntModMat {
+        let mut res = IntModMat::zero(nrows, ncols, ctx);
+        unsafe { fmpz_mod_mat_randtest(res.as_mut_ptr(), rng.as_mut_ptr()); }
+        res
+    }
+
+    /// Return the matrix raised to the power `exp`, using binary powering. The
+    /// matrix must be square.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let f = IntModMatSpace::init(2, 2, &ctx).new([1, 1, 1, 0]);
+    /// // F_10 = 55 = 6 mod 7
+    /// assert_eq!(f.pow(10).get_entry(0, 1), 6);
+    /// assert_eq!(f.pow(0), IntModMat::one(2, &ctx));
+    /// ```
+    pub fn pow(&self, exp: u64) -> IntModMat {
+        assert_eq!(self.nrows_si(), self.ncols_si());
+        let mut res = IntModMat::one(self.nrows_si(), self.context());
+        if exp == 0 {
+            return res;
+        }
+        let mut base = self.clone();
+        let mut e = exp;
+        loop {
+            if e & 1 == 1 {
+                res = &res * &base;
+            }
+            e >>= 1;
+            if e == 0 {
+                break res;
+            }
+            base = &base * &base;
+        }
+    }
 
     #[inline]
     pub const fn as_ptr(&self) -> *const fmpz_mod_mat_struct {
@@ -272,6 +340,98 @@ impl IntModMat {
     pub fn ncols_si(&self) -> i64 {
         unsafe { fmpz_mod_mat_ncols(self.as_ptr())}
     }
+
+    /// Get the `(i, j)`-th entry of the matrix.
+    #[inline]
+    pub fn get_entry(&self, i: usize, j: usize) -> IntMod {
+        let (i, j) = self.check_indices(i, j);
+        let mut res = IntMod::zero(self.context());
+        unsafe {
+            fmpz_mod_mat_get_entry(res.as_mut_ptr(), self.as_ptr(), i, j);
+        }
+        res
+    }
+
+    /// Set the `(i, j)`-th entry of the matrix.
+    #[inline]
+    pub fn set_entry<T: AsRef<IntMod>>(&mut self, i: usize, j: usize, e: T) {
+        let e = e.as_ref();
+        assert_eq!(self.context(), e.context());
+        let (i, j) = self.check_indices(i, j);
+        unsafe {
+            fmpz_mod_mat_set_entry(self.as_mut_ptr(), i, j, e.as_ptr());
+        }
+    }
+
+    /// Get a vector with all of the entries of the matrix.
+    pub fn get_entries(&self) -> Vec<IntMod> {
+        let r = self.nrows();
+        let c = self.ncols();
+        let mut out = Vec::with_capacity(r * c);
+
+        for i in 0..r {
+            for j in 0..c {
+                out.push(self.get_entry(i, j));
+            }
+        }
+        out
+    }
+
+    /// Borrow the reduced representative of the `(i, j)`-th entry of the 
+    /// matrix without copying.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> &Integer {
+        let (i, j) = self.check_indices(i, j);
+        unsafe { &*(fmpz_mod_mat_entry(self.as_ptr(), i, j) as *const Integer) }
+    }
+
+    /// Borrow the reduced representatives of row `i` of the matrix as a slice 
+    /// without copying.
+    #[inline]
+    pub fn row_slice(&self, i: usize) -> &[Integer] {
+        let i = self.check_row_index(i);
+        let c = self.ncols();
+        if c == 0 {
+            return &[];
+        }
+        unsafe {
+            let ptr = fmpz_mod_mat_entry(self.as_ptr(), i, 0) as *const Integer;
+            std::slice::from_raw_parts(ptr, c)
+        }
+    }
+
+    /// Iterate over the rows of the matrix as borrowed slices. There is no 
+    /// mutable version since entries must stay reduced.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[Integer]> + '_ {
+        (0..self.nrows()).map(move |i| self.row_slice(i))
+    }
+    
+    /// Iterate over the columns of the matrix, each given as an iterator over 
+    /// borrowed entries.
+    #[inline]
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &Integer> + '_> + '_ {
+        let r = self.nrows();
+        (0..self.ncols()).map(move |j| (0..r).map(move |i| self.entry(i, j)))
+    }
+    
+    /// Iterate over the entries of the matrix in row-major order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Integer> + '_ {
+        self.rows().flatten()
+    }
+
+    /// Return the integer matrix of the reduced representatives in
+    /// `[0, n)` of the entries.
+    pub fn lift(&self) -> IntMat {
+        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
+        for (i, row) in self.rows().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                res.set_entry(i, j, x);
+            }
+        }
+        res
+    }
     /*
 
     /// Set `self` to the zero matrix.
@@ -21,21 +21,20 @@ use inertia_algebra::ops::*;
 //use libc::{c_long, c_ulong};
 //use std::mem::MaybeUninit;
 
-/*
 impl_cmp! {
     eq
     IntModMat
     {
         fn eq(&self, rhs: &IntModMat) -> bool {
             unsafe {
-                self.parent() == rhs.parent() && fmpz_mod_mat::fmpz_mod_mat_equal(
+                self.context() == rhs.context() && fmpz_mod_mat::fmpz_mod_mat_equal(
                     self.as_ptr(),
                     rhs.as_ptr()
                 ) != 0
             }
         }
     }
-}*/
+}
 
 impl_unop_unsafe! {
     matrix_ctx_new_only
@@ -67,3 +66,40 @@ impl_binop_unsafe! {
     AssignMul {assign_mul}
     fmpz_mod_mat::fmpz_mod_mat_mul;
 }
+
+impl Mul<&IntMod> for &IntModMat {
+    type Output = IntModMat;
+    fn mul(self, rhs: &IntMod) -> IntModMat {
+        assert_eq!(self.context(), rhs.context());
+        let mut res = IntModMat::zero(self.nrows_si(), self.ncols_si(), self.context());
+        unsafe {
+            fmpz_mod_mat::fmpz_mod_mat_scalar_mul_fmpz(
+                res.as_mut_ptr(),
+                self.as_ptr(),
+                rhs.as_ptr()
+            );
+        }
+        res
+    }
+}
+
+impl Mul<&IntModMat> for &IntMod {
+    type Output = IntModMat;
+    #[inline]
+    fn mul(self, rhs: &IntModMat) -> IntModMat {
+        rhs * self
+    }
+}
+
+impl MulAssign<&IntMod> for IntModMat {
+    fn mul_assign(&mut self, rhs: &IntMod) {
+        assert_eq!(self.context(), rhs.context());
+        unsafe {
+            fmpz_mod_mat::fmpz_mod_mat_scalar_mul_fmpz(
+                self.as_mut_ptr(),
+                self.as_ptr(),
+                rhs.as_ptr()
+            );
+        }
+    }
+}
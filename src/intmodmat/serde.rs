@@ -0,0 +1,115 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{Integer, IntMod, IntModCtx, IntModMat, NewCtx};
+use crate::util::read_matrix_entries;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+use std::fmt;
+
+impl Serialize for IntModMat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let entries = self.get_entries();
+        let mut seq = serializer.serialize_seq(Some(entries.len() + 3))?;
+        seq.serialize_element(&self.modulus())?;
+        seq.serialize_element(&self.nrows_si())?;
+        seq.serialize_element(&self.ncols_si())?;
+        for e in entries.iter() {
+            seq.serialize_element(&Integer::from(e))?;
+        }
+        seq.end()
+    }
+}
+
+struct IntModMatVisitor {}
+
+impl IntModMatVisitor {
+    fn new() -> Self {
+        IntModMatVisitor {}
+    }
+}
+
+impl<'de> Visitor<'de> for IntModMatVisitor {
+    type Value = IntModMat;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an IntModMat")
+    }
+
+    fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let modulus: Integer = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let nrows: i64 = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let ncols: i64 = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        if modulus.sign() <= 0 {
+            return Err(de::Error::custom("modulus must be positive"));
+        }
+        let entries: Vec<Integer> = 
+            read_matrix_entries(&mut access, nrows, ncols, 3, &self)?;
+
+        let ctx = IntModCtx::new(modulus);
+        let mut res = IntModMat::zero(nrows, ncols, &ctx);
+        let c = res.ncols();
+        for (k, x) in entries.into_iter().enumerate() {
+            res.set_entry(k / c, k % c, IntMod::new(x, &ctx));
+        }
+        Ok(res)
+    }
+}
+
+impl<'de> Deserialize<'de> for IntModMat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(IntModMatVisitor::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn serde() {
+        let ctx = IntModCtx::new(72);
+        let mut x = IntModMat::zero(2, 3, &ctx);
+        for (k, v) in [1, 0, -19, 2, 5, 100].into_iter().enumerate() {
+            x.set_entry(k / 3, k % 3, IntMod::new(v, &ctx));
+        }
+        let ser = bincode::serialize(&x).unwrap();
+        let y: IntModMat = bincode::deserialize(&ser).unwrap();
+        assert_eq!(x.modulus(), y.modulus());
+        assert_eq!(x.get_entries(), y.get_entries());
+        
+        let ser = serde_json::to_string(&x).unwrap();
+        let y: IntModMat = serde_json::from_str(&ser).unwrap();
+        assert_eq!(x.modulus(), y.modulus());
+        assert_eq!(x.get_entries(), y.get_entries());
+    }
+}
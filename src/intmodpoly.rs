@@ -25,7 +25,6 @@ use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
-#[derive(Debug)]
 pub struct IntModPoly {
     inner: fmpz_mod_poly::fmpz_mod_poly_struct,
     ctx: IntModCtx,
@@ -53,6 +52,15 @@ impl Clone for IntModPoly {
     }
 }
 
+impl fmt::Debug for IntModPoly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntModPoly")
+            .field("value", &format_args!("{}", self))
+            .field("modulus", &format_args!("{}", self.modulus()))
+            .finish()
+    }
+}
+
 impl fmt::Display for IntModPoly {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -69,6 +77,13 @@ impl Drop for IntModPoly {
     }
 }
 
+impl AsRef<IntModCtx> for IntModPoly {
+    #[inline]
+    fn as_ref(&self) -> &IntModCtx {
+        self.context()
+    }
+}
+
 // TODO: avoid IntPoly allocation
 impl Hash for IntModPoly {
     #[inline]
@@ -121,6 +136,20 @@ impl IntModPoly {
         unsafe{ fmpz_mod_poly::fmpz_mod_poly_one(res.as_mut_ptr(), ctx.as_ptr()); }
         res
     }
+
+    /// Return a random polynomial of length at most `len`.
+    pub fn randtest(len: usize, ctx: &IntModCtx, rng: &mut FlintRand) -> IntModPoly {
+        let mut res = IntModPoly::with_capacity(len, ctx);
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_randtest(
+                res.as_mut_ptr(), 
+                rng.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long."),
+                ctx.as_ptr()
+            );
+        }
+        res
+    }
     
     #[inline]
     pub const fn as_ptr(&self) -> *const fmpz_mod_poly::fmpz_mod_poly_struct {
@@ -227,6 +256,14 @@ impl IntModPoly {
             fmpz_mod_poly::fmpz_mod_poly_degree(self.as_ptr(), self.ctx_as_ptr()) 
         }
     }
+
+    /// Whether the polynomial is irreducible. The modulus must be prime.
+    #[inline]
+    pub fn is_irreducible(&self) -> bool {
+        unsafe { 
+            fmpz_mod_poly::fmpz_mod_poly_is_irreducible(self.as_ptr(), self.ctx_as_ptr()) != 0
+        }
+    }
     
     pub fn get_coeff(&self, i: usize) -> IntMod {
         let ctx = self.context();
@@ -262,5 +299,405 @@ impl IntModPoly {
         }
         res
     }
+
+    /// Return the resultant of `self` and `other`. The modulus must be prime.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = IntModCtx::new(5);
+    /// let f = IntModPoly::new([-2, 0, 1], &ctx);
+    /// let g = IntModPoly::new([-3, 1], &ctx);
+    /// assert_eq!(f.resultant(&g), IntMod::new(2, &ctx));
+    /// ```
+    #[inline]
+    pub fn resultant<T: AsRef<IntModPoly>>(&self, other: T) -> IntMod {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        let mut res = IntMod::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_resultant(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(),
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return the discriminant of the polynomial. The modulus must be prime. 
+    /// The discriminant of a constant polynomial is zero.
+    #[inline]
+    pub fn discriminant(&self) -> IntMod {
+        let mut res = IntMod::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_discriminant(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return the subresultant polynomial remainder sequence of `self` and 
+    /// `other`, computed as for [IntPoly::subresultants]. The modulus must be 
+    /// prime. The sequence is the reduction of the sequence over the 
+    /// integers as long as no leading coefficient vanishes modulo the prime.
+    pub fn subresultants<T: AsRef<IntModPoly>>(&self, other: T) -> Vec<IntModPoly> {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        assert!(self.modulus().is_prime(), "The modulus must be prime.");
+        crate::prs::subresultants(self, other)
+    }
+
+    /// Return the monic greatest common divisor of `self` and `other`. The 
+    /// modulus must be prime.
+    #[inline]
+    pub fn gcd<T: AsRef<IntModPoly>>(&self, other: T) -> IntModPoly {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        let mut res = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_gcd(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return `(g, s, t)` where `g` is the monic gcd of `self` and `other` and 
+    /// `s*self + t*other = g`. The modulus must be prime.
+    pub fn xgcd<T: AsRef<IntModPoly>>(&self, other: T) -> (IntModPoly, IntModPoly, IntModPoly) {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        let ctx = self.context();
+        let mut d = IntModPoly::zero(ctx);
+        let mut s = IntModPoly::zero(ctx);
+        let mut t = IntModPoly::zero(ctx);
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_xgcd(
+                d.as_mut_ptr(), 
+                s.as_mut_ptr(), 
+                t.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(),
+                ctx.as_ptr()
+            );
+        }
+        (d, s, t)
+    }
+
+    /// Return the inverse of `self` modulo `modulus`, or `None` if they are 
+    /// not coprime. The modulus of the coefficient ring must be prime. Panics 
+    /// if `modulus` is constant.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let f = IntModPoly::new([0, 1], &ctx);
+    /// let m = IntModPoly::new([1, 0, 1], &ctx);
+    /// assert_eq!(f.invmod(&m), Some(IntModPoly::new([0, 6], &ctx)));
+    /// assert!(f.invmod(IntModPoly::new([0, 0, 1], &ctx)).is_none());
+    /// ```
+    pub fn invmod<T: AsRef<IntModPoly>>(&self, modulus: T) -> Option<IntModPoly> {
+        let modulus = modulus.as_ref();
+        assert_eq!(self.context(), modulus.context());
+        assert!(modulus.degree() > 0, "Modulus must be nonconstant.");
+        let mut res = IntModPoly::zero(self.context());
+        let ok = unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_invmod(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                modulus.as_ptr(), 
+                self.ctx_as_ptr()
+            )
+        };
+        if ok != 0 {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Return `(q, r)` with `self = q*other + r` and `deg(r) < deg(other)`. 
+    /// The leading coefficient of `other` must be invertible. Panics if 
+    /// `other` is zero.
+    pub fn divrem<T: AsRef<IntModPoly>>(&self, other: T) -> (IntModPoly, IntModPoly) {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        assert!(!other.is_zero(), "Division by zero.");
+        let ctx = self.context();
+        let mut q = IntModPoly::zero(ctx);
+        let mut r = IntModPoly::zero(ctx);
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_divrem(
+                q.as_mut_ptr(), 
+                r.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(),
+                ctx.as_ptr()
+            );
+        }
+        (q, r)
+    }
+
+    /// Return `self^e` reduced modulo `modulus`, by binary exponentiation.
+    /// The leading coefficient of `modulus` must be invertible. Panics if 
+    /// `modulus` is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = IntModCtx::new(7);
+    /// let x = IntModPoly::new([0, 1], &ctx);
+    /// let f = IntModPoly::new([1, 0, 1], &ctx);
+    /// // x^2 = -1, so x^4 = 1 modulo x^2 + 1
+    /// assert!(x.powmod(4, &f).is_one());
+    /// assert_eq!(x.powmod(Integer::from(7), &f), IntModPoly::new([0, 6], &ctx));
+    /// ```
+    pub fn powmod<E, T>(&self, e: E, modulus: T) -> IntModPoly
+    where
+        E: Into<Integer>,
+        T: AsRef<IntModPoly>
+    {
+        let modulus = modulus.as_ref();
+        let finv = modulus.preinv();
+        self.powmod_preinv(e, modulus, &finv)
+    }
+
+    /// Return the inverse of the reversal of `self` as a power series to
+    /// precision `len(self)`. This is the precomputed inverse used by
+    /// [IntModPoly::powmod_preinv] to reduce quickly modulo `self`, and only 
+    /// depends on the modulus, so it can be computed once and reused. The 
+    /// leading coefficient must be invertible. Panics if `self` is zero.
+    pub fn preinv(&self) -> IntModPoly {
+        assert!(!self.is_zero(), "Division by zero.");
+        let len = self.len().try_into().expect("Cannot convert length to a signed long.");
+        let mut rev = IntModPoly::zero(self.context());
+        let mut res = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_reverse(
+                rev.as_mut_ptr(), 
+                self.as_ptr(), 
+                len, 
+                self.ctx_as_ptr()
+            );
+            #[cfg(feature = "flint3")]
+            fmpz_mod_poly::fmpz_mod_poly_inv_series(
+                res.as_mut_ptr(), 
+                rev.as_ptr(), 
+                len, 
+                self.ctx_as_ptr()
+            );
+            #[cfg(not(feature = "flint3"))]
+            fmpz_mod_poly::fmpz_mod_poly_inv_series_newton(
+                res.as_mut_ptr(), 
+                rev.as_ptr(), 
+                len, 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return `self^e` reduced modulo `modulus`, given the precomputed 
+    /// inverse `finv = modulus.preinv()`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = IntModCtx::new(101);
+    /// let f = IntModPoly::new([3, 1, 0, 1], &ctx);
+    /// let finv = f.preinv();
+    /// let g = IntModPoly::new([5, 2], &ctx);
+    /// assert_eq!(g.powmod_preinv(1000, &f, &finv), g.powmod(1000, &f));
+    /// ```
+    pub fn powmod_preinv<E, T>(&self, e: E, modulus: T, finv: &IntModPoly) -> IntModPoly
+    where
+        E: Into<Integer>,
+        T: AsRef<IntModPoly>
+    {
+        let modulus = modulus.as_ref();
+        assert_eq!(self.context(), modulus.context());
+        assert_eq!(self.context(), finv.context());
+        assert!(!modulus.is_zero(), "Division by zero.");
+        let e = e.into();
+        assert!(e >= 0, "Exponent must be nonnegative.");
+
+        // the exponentiation requires a reduced base
+        let base = if self.len() >= modulus.len() {
+            self.divrem(modulus).1
+        } else {
+            self.clone()
+        };
+        let mut res = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_powmod_fmpz_binexp_preinv(
+                res.as_mut_ptr(), 
+                base.as_ptr(), 
+                e.as_ptr(), 
+                modulus.as_ptr(), 
+                finv.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return `x^e` reduced modulo `modulus`, given the precomputed inverse 
+    /// `finv = modulus.preinv()`. FLINT uses sliding window exponentiation 
+    /// specialized to the base `x`, whose multiplications by the base are 
+    /// shifts. Panics unless `modulus` is nonconstant.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // x^(p^2) = x modulo every irreducible quadratic over Z/pZ
+    /// let ctx = IntModCtx::new(13);
+    /// let f = IntModPoly::new([2, 0, 1], &ctx);
+    /// let r = IntModPoly::x_powmod_preinv(Integer::from(13).pow(2u64), &f, &f.preinv());
+    /// assert_eq!(r, IntModPoly::new([0, 1], &ctx));
+    /// ```
+    pub fn x_powmod_preinv<E, T>(e: E, modulus: T, finv: &IntModPoly) -> IntModPoly
+    where
+        E: Into<Integer>,
+        T: AsRef<IntModPoly>
+    {
+        let modulus = modulus.as_ref();
+        assert_eq!(modulus.context(), finv.context());
+        assert!(modulus.degree() > 0, "Modulus must be nonconstant.");
+        let e = e.into();
+        assert!(e >= 0, "Exponent must be nonnegative.");
+        let mut res = IntModPoly::zero(modulus.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_powmod_x_fmpz_preinv(
+                res.as_mut_ptr(), 
+                e.as_ptr(), 
+                modulus.as_ptr(), 
+                finv.as_ptr(), 
+                modulus.ctx_as_ptr()
+            );
+        }
+        res
+    }
+}
+
+
+/// Return the monic characteristic polynomial `p_0 + p_1*x + ... + x^r` of 
+/// the shortest linear recurrence `p_0*a_k + p_1*a_{k+1} + ... + a_{k+r} = 0` 
+/// generating the sequence `seq` over `Z/pZ`, computed with the 
+/// Berlekamp-Massey algorithm. The modulus must be prime, and the recurrence 
+/// is only determined uniquely when `seq` has at least `2*r` terms. Panics if 
+/// `seq` is empty.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let ctx = IntModCtx::new(101);
+/// let fib: Vec<IntMod> = (0..10u64)
+///     .map(|n| IntMod::new(Integer::fibonacci(n), &ctx))
+///     .collect();
+/// assert_eq!(minimal_recurrence(&fib), IntModPoly::new([-1, -1, 1], &ctx));
+/// ```
+pub fn minimal_recurrence(seq: &[IntMod]) -> IntModPoly {
+    assert!(!seq.is_empty());
+    let ctx = seq[0].context();
+    let p = ctx.modulus();
+    let s: Vec<Integer> = seq.iter().map(Integer::from).collect();
+    let (c, len) = crate::intpoly::berlekamp_massey(&s, &p);
+
+    // reversing the connection polynomial 1 + c_1 x + ... + c_r x^r gives
+    // the characteristic polynomial
+    let mut res = IntPoly::zero();
+    for (i, ci) in c.iter().enumerate() {
+        res.set_coeff(len - i, ci);
+    }
+    IntModPoly::new(res, ctx)
 }
 
+/// Solve the square system `Ax = b` over `Z/pZ` for a nonsingular black-box 
+/// matrix `A`, given only the map `matvec: v -> Av`, using Wiedemann's 
+/// algorithm. The modulus must be prime. 
+///
+/// Only `O(n)` vectors are kept in memory and roughly `3n` products are 
+/// computed per attempt, so this is suited to large sparse or structured 
+/// systems. The minimal polynomial of the Krylov sequence is found by 
+/// Berlekamp-Massey on random projections; an attempt can fail with small 
+/// probability, so a few are made and the solution is verified. Returns 
+/// `None` if no solution was found, in particular if `A` is singular.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// // the tridiagonal matrix with 2 on the diagonal and 1 beside it
+/// let ctx = IntModCtx::new(1000003);
+/// let n = 50;
+/// let matvec = |v: &[IntMod]| -> Vec<IntMod> {
+///     (0..n).map(|i| {
+///         let mut s = &v[i] * IntMod::new(2, &ctx);
+///         if i > 0 { s += &v[i - 1]; }
+///         if i + 1 < n { s += &v[i + 1]; }
+///         s
+///     }).collect()
+/// };
+/// let b: Vec<IntMod> = (0..n).map(|i| IntMod::new(i, &ctx)).collect();
+/// let x = wiedemann_solve(&matvec, &b).unwrap();
+/// assert_eq!(matvec(&x), b);
+/// ```
+pub fn wiedemann_solve<F>(matvec: F, b: &[IntMod]) -> Option<Vec<IntMod>>
+where
+    F: Fn(&[IntMod]) -> Vec<IntMod>
+{
+    assert!(!b.is_empty());
+    trace_span!("wiedemann_solve", n = b.len());
+    let ctx = b[0].context();
+    let n = b.len();
+    if b.iter().all(|bi| bi.is_zero()) {
+        return Some(vec![IntMod::zero(ctx); n]);
+    }
+
+    let mut rng = FlintRand::new();
+    for _ in 0..4 {
+        // the projected Krylov sequence u.b, u.Ab, ..., u.A^(2n-1)b
+        let u: Vec<IntMod> = (0..n).map(|_| IntMod::randm(ctx, &mut rng)).collect();
+        let mut v = b.to_vec();
+        let mut seq = Vec::with_capacity(2 * n);
+        for _ in 0..2 * n {
+            check_interrupt();
+            seq.push(IntMod::dot(&u, &v));
+            v = matvec(&v);
+            assert_eq!(v.len(), n);
+        }
+
+        // f(A)b = 0 with f(0) != 0 gives x = -(f(A) - f(0))/(f(0) A) b, 
+        // evaluated by Horner's rule
+        let f = minimal_recurrence(&seq);
+        let f0 = f.get_coeff(0);
+        let d = f.degree();
+        if f0.is_zero() || d <= 0 {
+            continue;
+        }
+        let mut y: Vec<IntMod> = b.iter().map(|bi| bi * f.get_coeff(d as usize)).collect();
+        for k in (1..d as usize).rev() {
+            check_interrupt();
+            let fk = f.get_coeff(k);
+            y = matvec(&y)
+                .iter()
+                .zip(b)
+                .map(|(yi, bi)| yi + &fk * bi)
+                .collect();
+        }
+        let c = -(IntMod::one(ctx) / f0);
+        let x: Vec<IntMod> = y.iter().map(|yi| yi * &c).collect();
+        if matvec(&x) == b {
+            return Some(x);
+        }
+    }
+    None
+}
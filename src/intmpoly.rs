@@ -0,0 +1,241 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::Integer;
+use flint_sys::fmpz_mpoly::*;
+use flint_sys::mpoly::ordering_t_ORD_LEX;
+use std::ffi::CStr;
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub(crate) struct MPolyCtx(fmpz_mpoly_ctx_struct);
+
+impl Drop for MPolyCtx {
+    fn drop(&mut self) {
+        unsafe { fmpz_mpoly_ctx_clear(&mut self.0); }
+    }
+}
+
+/// The ring of multivariate polynomials with integer coefficients in `nvars`
+/// variables `x1, ..., xn`, with terms in lexicographic order.
+#[derive(Clone, Debug)]
+pub struct IntMPolyCtx {
+    inner: Rc<MPolyCtx>,
+}
+
+impl Eq for IntMPolyCtx {}
+
+impl PartialEq for IntMPolyCtx {
+    fn eq(&self, rhs: &IntMPolyCtx) -> bool {
+        Rc::ptr_eq(&self.inner, &rhs.inner) || self.nvars() == rhs.nvars()
+    }
+}
+
+impl IntMPolyCtx {
+    /// Return the ring of polynomials in `nvars` variables. Panics if `nvars`
+    /// is zero.
+    pub fn new(nvars: usize) -> Self {
+        assert!(nvars > 0, "The number of variables must be positive.");
+        let n = nvars.try_into().expect("Cannot convert usize to a signed long.");
+        let mut ctx = MaybeUninit::uninit();
+        unsafe {
+            fmpz_mpoly_ctx_init(ctx.as_mut_ptr(), n, ordering_t_ORD_LEX);
+            IntMPolyCtx { inner: Rc::new(MPolyCtx(ctx.assume_init())) }
+        }
+    }
+
+    #[inline]
+    pub fn as_ptr(&self) -> *const fmpz_mpoly_ctx_struct {
+        &self.inner.0
+    }
+
+    /// Return the number of variables.
+    #[inline]
+    pub fn nvars(&self) -> usize {
+        unsafe { fmpz_mpoly_ctx_nvars(self.as_ptr()) as usize }
+    }
+}
+
+/// A multivariate polynomial with integer coefficients, see [IntMPolyCtx]. 
+/// Monomials are given by their exponent vectors.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let ctx = IntMPolyCtx::new(2);
+/// let mut f = IntMPoly::zero(&ctx);
+/// f.set_coeff(&[2, 0], Integer::from(3));
+/// f.set_coeff(&[0, 1], Integer::from(-1));
+/// assert_eq!(f.to_string(), "3*x1^2 - x2");
+/// assert_eq!(f.degree(0), 2);
+/// assert_eq!(f.evaluate(&[Integer::from(2), Integer::from(5)]), 7);
+/// ```
+pub struct IntMPoly {
+    inner: fmpz_mpoly_struct,
+    ctx: IntMPolyCtx,
+}
+
+impl Clone for IntMPoly {
+    fn clone(&self) -> Self {
+        let mut res = IntMPoly::zero(&self.ctx);
+        unsafe { fmpz_mpoly_set(res.as_mut_ptr(), self.as_ptr(), self.ctx_as_ptr()); }
+        res
+    }
+}
+
+impl Drop for IntMPoly {
+    fn drop(&mut self) {
+        unsafe { fmpz_mpoly_clear(self.as_mut_ptr(), self.ctx_as_ptr()); }
+    }
+}
+
+impl Eq for IntMPoly {}
+
+impl PartialEq for IntMPoly {
+    fn eq(&self, rhs: &IntMPoly) -> bool {
+        self.ctx == rhs.ctx 
+            && unsafe { fmpz_mpoly_equal(self.as_ptr(), rhs.as_ptr(), self.ctx_as_ptr()) != 0 }
+    }
+}
+
+impl fmt::Debug for IntMPoly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IntMPoly({})", self)
+    }
+}
+
+impl fmt::Display for IntMPoly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = unsafe {
+            let ptr = fmpz_mpoly_get_str_pretty(
+                self.as_ptr(), 
+                std::ptr::null_mut(), 
+                self.ctx_as_ptr()
+            );
+            let res = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+            flint_sys::flint::flint_free(ptr as *mut libc::c_void);
+            res
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl IntMPoly {
+    /// Return the zero polynomial of the ring `ctx`.
+    pub fn zero(ctx: &IntMPolyCtx) -> IntMPoly {
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            fmpz_mpoly_init(z.as_mut_ptr(), ctx.as_ptr());
+            IntMPoly { inner: z.assume_init(), ctx: ctx.clone() }
+        }
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const fmpz_mpoly_struct {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut fmpz_mpoly_struct {
+        &mut self.inner
+    }
+
+    #[inline]
+    pub fn ctx_as_ptr(&self) -> *const fmpz_mpoly_ctx_struct {
+        self.ctx.as_ptr()
+    }
+
+    /// Return the ring of the polynomial.
+    #[inline]
+    pub fn context(&self) -> &IntMPolyCtx {
+        &self.ctx
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { fmpz_mpoly_is_zero(self.as_ptr(), self.ctx_as_ptr()) != 0 }
+    }
+
+    /// Return the number of nonzero terms.
+    #[inline]
+    pub fn len(&self) -> usize {
+        unsafe { fmpz_mpoly_length(self.as_ptr(), self.ctx_as_ptr()) as usize }
+    }
+
+    /// Return the degree in the variable with index `var`, or `-1` for the 
+    /// zero polynomial.
+    pub fn degree(&self, var: usize) -> i64 {
+        assert!(var < self.ctx.nvars());
+        unsafe { fmpz_mpoly_degree_si(self.as_ptr(), var as i64, self.ctx_as_ptr()) }
+    }
+
+    /// Return the total degree, or `-1` for the zero polynomial.
+    #[inline]
+    pub fn total_degree(&self) -> i64 {
+        unsafe { fmpz_mpoly_total_degree_si(self.as_ptr(), self.ctx_as_ptr()) }
+    }
+
+    /// Return the coefficient of the monomial with exponent vector `exp`.
+    pub fn get_coeff(&self, exp: &[u64]) -> Integer {
+        assert_eq!(exp.len(), self.ctx.nvars());
+        let mut res = Integer::zero();
+        unsafe {
+            fmpz_mpoly_get_coeff_fmpz_ui(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                exp.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Set the coefficient of the monomial with exponent vector `exp`.
+    pub fn set_coeff<T: AsRef<Integer>>(&mut self, exp: &[u64], c: T) {
+        assert_eq!(exp.len(), self.ctx.nvars());
+        unsafe {
+            fmpz_mpoly_set_coeff_fmpz_ui(
+                self.as_mut_ptr(), 
+                c.as_ref().as_ptr(), 
+                exp.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+    }
+
+    /// Evaluate the polynomial at the point `vals`, one value per variable.
+    pub fn evaluate(&self, vals: &[Integer]) -> Integer {
+        assert_eq!(vals.len(), self.ctx.nvars());
+        let mut ptrs: Vec<*mut flint_sys::fmpz::fmpz> = vals
+            .iter()
+            .map(|x| x.as_ptr() as *mut flint_sys::fmpz::fmpz)
+            .collect();
+        let mut res = Integer::zero();
+        unsafe {
+            let ok = fmpz_mpoly_evaluate_all_fmpz(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                ptrs.as_mut_ptr(), 
+                self.ctx_as_ptr()
+            );
+            assert!(ok != 0, "The value is too large.");
+        }
+        res
+    }
+}
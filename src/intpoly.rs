@@ -17,18 +17,25 @@
 
 mod ops;
 mod conv;
+mod mul;
+mod realroots;
 
 #[cfg(feature = "serde")]
 mod serde;
 
-use crate::{New, Integer};
+pub use mul::{convolve, MulAlgorithm};
+
+use crate::{
+    check_interrupt, tuning, New, NewCtx, Integer, IntMat, IntModCtx, IntModPoly, 
+    FlintRand, Rational, RatPoly, NmodRing, NmodPoly
+};
 use flint_sys::fmpz_poly::*;
+use flint_sys::nmod_poly_factor;
 
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
-#[derive(Debug)]
 pub struct IntPoly {
     inner: fmpz_poly_struct,
 }
@@ -62,14 +69,37 @@ impl Default for IntPoly {
 }
 
 // Note: Flint `get_str_pretty` doesnt space between terms.
+impl fmt::Debug for IntPoly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntPoly").field(&format_args!("{}", self)).finish()
+    }
+}
+
 impl fmt::Display for IntPoly {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_str_radix(10))
+    }
+}
+
+impl IntPoly {
+    /// Convert the polynomial to a string with the coefficients in base 
+    /// `base`, in the same format as the `Display` implementation. Exponents
+    /// are always written in base 10.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([-10, 0, 255]);
+    /// assert_eq!(f.to_str_radix(16), "ff*x^2 - a");
+    /// assert_eq!(f.to_str_radix(10), f.to_string());
+    /// ```
+    pub fn to_str_radix(&self, base: u8) -> String {
         let deg = self.degree();
         if deg < 0 {
-            return write!(f, "0");
+            return "0".to_string();
         } else if deg == 0 {
-            return write!(f, "{}", self.get_coeff(0).to_string());
+            return self.get_coeff(0).to_str_radix(base);
         }
 
         let deg: usize = deg.try_into().unwrap();
@@ -90,7 +120,7 @@ impl fmt::Display for IntPoly {
 
             let abs = c.abs();
             if k == 0 {
-                out.push_str(&format!("{}{}", sign(s), abs));
+                out.push_str(&format!("{}{}", sign(s), abs.to_str_radix(base)));
             } else if k == deg {
                 if abs.is_one() && s > 0 {
                     if k == 1 {
@@ -106,26 +136,26 @@ impl fmt::Display for IntPoly {
                     }
                 } else {
                     if k == 1 {
-                        out.push_str(&format!("{}*x", c));
+                        out.push_str(&format!("{}*x", c.to_str_radix(base)));
                     } else {
-                        out.push_str(&format!("{}*x^{}", c, k));
+                        out.push_str(&format!("{}*x^{}", c.to_str_radix(base), k));
                     }
                 }
             } else if k == 1 {
                 if abs.is_one() {
                     out.push_str(&format!("{}x", sign(s)));
                 } else {
-                    out.push_str(&format!("{}{}*x", sign(s), abs));
+                    out.push_str(&format!("{}{}*x", sign(s), abs.to_str_radix(base)));
                 }
             } else {
                 if abs.is_one() {
                     out.push_str(&format!("{}x^{}", sign(s), k));
                 } else {
-                    out.push_str(&format!("{}{}*x^{}", sign(s), abs, k));
+                    out.push_str(&format!("{}{}*x^{}", sign(s), abs.to_str_radix(base), k));
                 }
             }
         }
-        write!(f, "{}", out)
+        out
     }
 }
 
@@ -245,6 +275,32 @@ impl IntPoly {
     pub fn is_gen(&self) -> bool {
         unsafe { fmpz_poly_is_gen(self.as_ptr()) == 1}
     }
+
+    /// Return true if the leading coefficient is one.
+    #[inline]
+    pub fn is_monic(&self) -> bool {
+        !self.is_zero() && self.get_coeff(self.len() - 1).is_one()
+    }
+    
+    /// Return true if the polynomial has degree at most zero.
+    #[inline]
+    pub fn is_constant(&self) -> bool {
+        self.degree() <= 0
+    }
+    
+    /// Return true if the polynomial is a cyclotomic polynomial `Phi_n(x)` 
+    /// for some `n`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert!(IntPoly::cyclotomic(12).is_cyclotomic());
+    /// assert!(!IntPoly::from([1, 1, 1, 1]).is_cyclotomic());
+    /// ```
+    #[inline]
+    pub fn is_cyclotomic(&self) -> bool {
+        unsafe { fmpz_poly_is_cyclotomic(self.as_ptr()) != 0 }
+    }
     
     #[inline]
     pub fn len(&self) -> usize {
@@ -336,6 +392,740 @@ impl IntPoly {
         res
     }
 
+    /// Reduce the coefficients of the polynomial modulo the modulus of `ctx`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = IntModCtx::new(7);
+    /// let f = IntPoly::from([-1, 8, 14, 3]);
+    /// let g = f.reduce_mod(&zn);
+    /// assert_eq!(g.degree(), 3);
+    /// assert_eq!(IntPoly::from(&g), IntPoly::from([6, 1, 0, 3]));
+    /// ```
+    pub fn reduce_mod(&self, ctx: &IntModCtx) -> IntModPoly {
+        let mut res = IntModPoly::zero(ctx);
+        unsafe {
+            flint_sys::fmpz_mod_poly::fmpz_mod_poly_set_fmpz_poly(
+                res.as_mut_ptr(),
+                self.as_ptr(),
+                ctx.as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return the composition `self(other(x))`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 0, 1]);
+    /// let g = IntPoly::from([0, 2]);
+    /// assert_eq!(f.compose(&g), IntPoly::from([1, 0, 4]));
+    /// ```
+    #[inline]
+    pub fn compose<T: AsRef<IntPoly>>(&self, other: T) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly_compose(res.as_mut_ptr(), self.as_ptr(), other.as_ref().as_ptr());
+        }
+        res
+    }
+
+    /// Set `self` to the composition `self(other(x))`.
+    #[inline]
+    pub fn compose_assign<T: AsRef<IntPoly>>(&mut self, other: T) {
+        unsafe {
+            fmpz_poly_compose(self.as_mut_ptr(), self.as_ptr(), other.as_ref().as_ptr());
+        }
+    }
+
+    /// Return `self(g(x))` reduced modulo `h`. The leading coefficient of `h` 
+    /// must be `1` or `-1` so that the remainder is defined over the integers. 
+    /// Intermediate results are reduced after each Horner step, so their 
+    /// degree stays below `2*deg(h)`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // (x^2)^2 + 1 mod x^2 + 1 = 2
+    /// let f = IntPoly::from([1, 0, 1]);
+    /// let g = IntPoly::from([0, 0, 1]);
+    /// let h = IntPoly::from([1, 0, 1]);
+    /// assert_eq!(f.compose_mod(&g, &h), IntPoly::from([2]));
+    /// ```
+    pub fn compose_mod<T, S>(&self, g: T, h: S) -> IntPoly 
+    where
+        T: AsRef<IntPoly>,
+        S: AsRef<IntPoly>
+    {
+        let mut res = self.clone();
+        res.compose_mod_assign(g, h);
+        res
+    }
+
+    /// Set `self` to `self(g(x))` reduced modulo `h`. See 
+    /// [compose_mod][IntPoly::compose_mod].
+    pub fn compose_mod_assign<T, S>(&mut self, g: T, h: S)
+    where
+        T: AsRef<IntPoly>,
+        S: AsRef<IntPoly>
+    {
+        let (g, h) = (g.as_ref(), h.as_ref());
+        let deg = h.degree();
+        assert!(deg >= 0, "Modulus must be nonzero.");
+        let lc = h.get_coeff(deg as usize);
+        assert!(lc == 1 || lc == -1, "Modulus must have unit leading coefficient.");
+
+        let mut gr = IntPoly::default();
+        let mut acc = IntPoly::default();
+        unsafe {
+            fmpz_poly_rem(gr.as_mut_ptr(), g.as_ptr(), h.as_ptr());
+            for i in (0..self.len()).rev() {
+                let c = self.get_coeff(i);
+                fmpz_poly_mul(acc.as_mut_ptr(), acc.as_ptr(), gr.as_ptr());
+                fmpz_poly_add_fmpz(acc.as_mut_ptr(), acc.as_ptr(), c.as_ptr());
+                fmpz_poly_rem(acc.as_mut_ptr(), acc.as_ptr(), h.as_ptr());
+            }
+            fmpz_poly_swap(self.as_mut_ptr(), acc.as_mut_ptr());
+        }
+    }
+
+    /// Return the Taylor shift `self(x + c)`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([0, 0, 1]);
+    /// assert_eq!(f.taylor_shift(Integer::from(1)), IntPoly::from([1, 2, 1]));
+    /// ```
+    #[inline]
+    pub fn taylor_shift<T: AsRef<Integer>>(&self, c: T) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly_taylor_shift(res.as_mut_ptr(), self.as_ptr(), c.as_ref().as_ptr());
+        }
+        res
+    }
+
+    /// Set `self` to the Taylor shift `self(x + c)`.
+    #[inline]
+    pub fn taylor_shift_assign<T: AsRef<Integer>>(&mut self, c: T) {
+        unsafe {
+            fmpz_poly_taylor_shift(self.as_mut_ptr(), self.as_ptr(), c.as_ref().as_ptr());
+        }
+    }
+
+    /// Return the product `self * other` truncated to its first `n` 
+    /// coefficients.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 1]);
+    /// assert_eq!(f.mullow(&f, 2), IntPoly::from([1, 2]));
+    /// ```
+    #[inline]
+    pub fn mullow<T: AsRef<IntPoly>>(&self, other: T, n: usize) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly_mullow(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ref().as_ptr(),
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Pack the polynomial into the single integer `self(2^bits)`. Each
+    /// coefficient `c` must satisfy `|c| < 2^(bits - 1)` so that the
+    /// polynomial can be recovered with [IntPoly::from_kronecker]; panics
+    /// otherwise.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([3, -1, 2]);
+    /// let x = f.to_kronecker(8);
+    /// assert_eq!(x, 3 - 256 + 2 * 65536);
+    /// assert_eq!(IntPoly::from_kronecker(&x, 8), f);
+    /// ```
+    pub fn to_kronecker(&self, bits: u64) -> Integer {
+        assert!(
+            self.max_abs_bits() < bits,
+            "The coefficients do not fit in the given number of bits."
+        );
+        let mut res = Integer::zero();
+        unsafe { fmpz_poly_bit_pack(res.as_mut_ptr(), self.as_ptr(), bits); }
+        res
+    }
+
+    /// Unpack a polynomial from an integer produced by 
+    /// [IntPoly::to_kronecker] with the same number of bits.
+    pub fn from_kronecker(x: &Integer, bits: u64) -> IntPoly {
+        assert!(bits > 0);
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly_bit_unpack(res.as_mut_ptr(), x.as_ptr(), bits); }
+        res
+    }
+
+    /// Multiply two polynomials by Kronecker substitution: both are packed 
+    /// into integers with enough bits per coefficient to hold the 
+    /// coefficients of the product, the integers are multiplied, and the 
+    /// product is unpacked. It is built on [IntPoly::to_kronecker] and 
+    /// [IntPoly::from_kronecker]; [IntPoly::mul_ks] is FLINT's own version.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, -2, 3]);
+    /// let g = IntPoly::from([-4, 0, 5, 6]);
+    /// assert_eq!(f.mul_kronecker(&g), &f * &g);
+    /// ```
+    pub fn mul_kronecker<T: AsRef<IntPoly>>(&self, other: T) -> IntPoly {
+        let other = other.as_ref();
+        if self.is_zero() || other.is_zero() {
+            return IntPoly::zero();
+        }
+        // |c| <= min(len) * 2^(a + b) for every coefficient c of the product,
+        // plus one bit for the sign
+        let len = self.len().min(other.len()) as u64;
+        let log_len = u64::BITS as u64 - (len - 1).leading_zeros() as u64;
+        let bits = self.max_abs_bits() + other.max_abs_bits() + log_len + 1;
+        let x = self.to_kronecker(bits) * other.to_kronecker(bits);
+        IntPoly::from_kronecker(&x, bits)
+    }
+
+    // The number of bits of the largest coefficient in absolute value.
+    fn max_abs_bits(&self) -> u64 {
+        unsafe { fmpz_poly_max_bits(self.as_ptr()).unsigned_abs() }
+    }
+
+    /// Return the power series inverse of `self` to precision `n`, that is, 
+    /// the polynomial `g` of length at most `n` with `self * g = 1 mod x^n`. 
+    /// Panics unless the constant term is `1` or `-1` and `n` is positive.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // 1/(1 - x) = 1 + x + x^2 + ...
+    /// let f = IntPoly::from([1, -1]);
+    /// assert_eq!(f.inv_series(4), IntPoly::from([1, 1, 1, 1]));
+    /// ```
+    pub fn inv_series(&self, n: usize) -> IntPoly {
+        assert!(n > 0);
+        let c = self.get_coeff(0);
+        assert!(c == 1 || c == -1, "Constant term must be a unit.");
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly_inv_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the power series quotient `self / other` to precision `n`. 
+    /// Panics unless the constant term of `other` is `1` or `-1` and `n` is 
+    /// positive.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // x/(1 - x - x^2) generates the Fibonacci numbers
+    /// let f = IntPoly::from([0, 1]);
+    /// let g = IntPoly::from([1, -1, -1]);
+    /// assert_eq!(f.div_series(&g, 7), IntPoly::from([0, 1, 1, 2, 3, 5, 8]));
+    /// ```
+    pub fn div_series<T: AsRef<IntPoly>>(&self, other: T, n: usize) -> IntPoly {
+        let other = other.as_ref();
+        assert!(n > 0);
+        let c = other.get_coeff(0);
+        assert!(c == 1 || c == -1, "Constant term must be a unit.");
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly_div_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(),
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the power series square root of `self` to precision `n` if it 
+    /// has integer coefficients, otherwise `None`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 2, 1, 0, 5]);
+    /// assert_eq!(f.sqrt_series(3), Some(IntPoly::from([1, 1])));
+    /// assert_eq!(IntPoly::from([1, 1]).sqrt_series(3), None);
+    /// ```
+    pub fn sqrt_series(&self, n: usize) -> Option<IntPoly> {
+        let mut res = IntPoly::default();
+        let exact = unsafe {
+            fmpz_poly_sqrt_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            )
+        };
+        if exact != 0 {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Truncate the polynomial to its first `n` coefficients.
+    #[inline]
+    pub fn truncate(&mut self, n: usize) {
+        unsafe {
+            fmpz_poly_truncate(
+                self.as_mut_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+    }
+
+    /// Return the resultant of `self` and `other`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([-2, 0, 1]);
+    /// let g = IntPoly::from([-3, 1]);
+    /// assert_eq!(f.resultant(&g), 7);
+    /// ```
+    #[inline]
+    pub fn resultant<T: AsRef<IntPoly>>(&self, other: T) -> Integer {
+        let mut res = Integer::zero();
+        unsafe {
+            fmpz_poly_resultant(res.as_mut_ptr(), self.as_ptr(), other.as_ref().as_ptr());
+        }
+        res
+    }
+
+    /// Return the discriminant of the polynomial. The discriminant of a 
+    /// constant polynomial is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 1, 1]);
+    /// assert_eq!(f.discriminant(), -3);
+    /// ```
+    #[inline]
+    pub fn discriminant(&self) -> Integer {
+        let mut res = Integer::zero();
+        unsafe { fmpz_poly_discriminant(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Return the subresultant polynomial remainder sequence of `self` and 
+    /// `other`, starting with the input of larger degree followed by the 
+    /// other one. The pseudo-remainders are divided by the factors of the 
+    /// subresultant algorithm, so all coefficients stay integral and grow 
+    /// only polynomially. The last polynomial is a gcd of the inputs up to a 
+    /// scalar factor, and is the resultant when the inputs are coprime.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([-5, 2, 8, -3, -3, 0, 1, 0, 1]);
+    /// let g = IntPoly::from([21, -9, -4, 0, 5, 0, 3]);
+    /// let prs = f.subresultants(&g);
+    /// assert_eq!(prs.len(), 6);
+    /// assert_eq!(prs[2], IntPoly::from([-9, 0, 3, 0, -15]));
+    /// assert_eq!(prs[5], IntPoly::from([260708]));
+    /// ```
+    pub fn subresultants<T: AsRef<IntPoly>>(&self, other: T) -> Vec<IntPoly> {
+        let other = other.as_ref();
+        trace_span!("IntPoly::subresultants", deg1 = self.degree(), deg2 = other.degree());
+        let (mut a, mut b) = if self.degree() >= other.degree() {
+            (self.clone(), other.clone())
+        } else {
+            (other.clone(), self.clone())
+        };
+        if b.is_zero() {
+            return if a.is_zero() { vec![] } else { vec![a] };
+        }
+
+        let mut res = vec![a.clone(), b.clone()];
+        let mut g = Integer::one();
+        let mut h = Integer::one();
+        loop {
+            check_interrupt();
+            let delta = (a.degree() - b.degree()) as u64;
+            let lc = b.get_coeff(b.degree() as usize);
+            let mut r = IntPoly::default();
+            let mut d: u64 = 0;
+            unsafe {
+                fmpz_poly_pseudo_rem(r.as_mut_ptr(), &mut d, a.as_ptr(), b.as_ptr());
+                // FLINT may use a smaller power of the leading coefficient
+                let c = (&lc).pow(delta + 1 - d);
+                fmpz_poly_scalar_mul_fmpz(r.as_mut_ptr(), r.as_ptr(), c.as_ptr());
+            }
+            if r.is_zero() {
+                break;
+            }
+
+            let c = &g * (&h).pow(delta);
+            unsafe {
+                fmpz_poly_scalar_divexact_fmpz(r.as_mut_ptr(), r.as_ptr(), c.as_ptr());
+            }
+            a = b;
+            b = r;
+            g = lc;
+            h = if delta == 0 {
+                h
+            } else {
+                (&g).pow(delta).divexact_unchecked((&h).pow(delta - 1))
+            };
+            res.push(b.clone());
+        }
+        res
+    }
+
+    /// Return the greatest common divisor of `self` and `other`, normalized 
+    /// to have positive leading coefficient. The gcd of two zero 
+    /// polynomials is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([-2, 0, 2]);
+    /// let g = IntPoly::from([2, 4, 2]);
+    /// assert_eq!(f.gcd(&g), IntPoly::from([2, 2]));
+    /// ```
+    #[inline]
+    pub fn gcd<T: AsRef<IntPoly>>(&self, other: T) -> IntPoly {
+        let mut res = IntPoly::default();
+        unsafe { fmpz_poly_gcd(res.as_mut_ptr(), self.as_ptr(), other.as_ref().as_ptr()); }
+        res
+    }
+
+    /// Factor the polynomial into irreducibles over the integers. Returns 
+    /// the signed content `c` and the primitive irreducible factors `f_i` 
+    /// with positive leading coefficient and their multiplicities `e_i`, so 
+    /// that the polynomial is `c * f_1^e_1 * ... * f_k^e_k`. The zero 
+    /// polynomial has content zero and no factors.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // -2*(x - 1)^2*(x^2 + 1)
+    /// let f = IntPoly::from([-2, 4, -4, 4, -2]);
+    /// let (c, fac) = f.factor();
+    /// assert_eq!(c, -2);
+    /// assert_eq!(fac.len(), 2);
+    /// assert!(fac.contains(&(IntPoly::from([-1, 1]), 2)));
+    /// assert!(fac.contains(&(IntPoly::from([1, 0, 1]), 1)));
+    /// ```
+    pub fn factor(&self) -> (Integer, Vec<(IntPoly, u64)>) {
+        trace_span!("IntPoly::factor", degree = self.degree());
+        check_interrupt();
+        let mut c = Integer::zero();
+        let mut res = Vec::new();
+        unsafe {
+            let mut fac = MaybeUninit::uninit();
+            flint_sys::fmpz_poly_factor::fmpz_poly_factor_init(fac.as_mut_ptr());
+            let mut fac = fac.assume_init();
+            flint_sys::fmpz_poly_factor::fmpz_poly_factor(&mut fac, self.as_ptr());
+            flint_sys::fmpz::fmpz_set(c.as_mut_ptr(), &fac.c);
+            for i in 0..fac.num.try_into().expect("Cannot convert signed long to usize.") {
+                let mut f = IntPoly::zero();
+                fmpz_poly_set(f.as_mut_ptr(), fac.p.add(i));
+                let e = (*fac.exp.add(i)).try_into().expect(
+                    "Cannot convert signed long to an unsigned long.");
+                res.push((f, e));
+            }
+            flint_sys::fmpz_poly_factor::fmpz_poly_factor_clear(&mut fac);
+        }
+        (c, res)
+    }
+
+    /// Lift a factorization of `self` modulo the prime `p` to a factorization
+    /// modulo `p^e`. The polynomial must be monic and the `factors` must be 
+    /// monic, pairwise coprime modulo `p`, and multiply to `self` modulo `p`. 
+    /// Returns the lifted monic factors with coefficients reduced modulo 
+    /// `p^e`, not necessarily in the order of `factors`. Panics if `e < 2` or
+    /// there are fewer than two factors.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // x^2 - 2 = (x - 3)(x + 3) mod 7
+    /// let f = IntPoly::from([-2, 0, 1]);
+    /// let fac = [IntPoly::from([-3, 1]), IntPoly::from([3, 1])];
+    /// let lifted = f.hensel_lift(&fac, 7, 4);
+    ///
+    /// // both square roots of 2 modulo 7^4
+    /// let m = Integer::from(7).pow(4u64);
+    /// for g in lifted {
+    ///     assert_eq!(g.degree(), 1);
+    ///     assert!(f.evaluate(-g.get_coeff(0)).divisible(&m));
+    /// }
+    /// ```
+    pub fn hensel_lift(&self, factors: &[IntPoly], p: u64, e: u64) -> Vec<IntPoly> {
+        assert!(self.is_monic(), "The polynomial must be monic.");
+        assert!(e >= 2, "The target exponent must be at least 2.");
+        assert!(factors.len() >= 2, "At least two factors are required.");
+        assert!(Integer::from(p).is_prime(), "The modulus must be prime.");
+        let ctx = NmodRing::new(p);
+        let n = e.try_into().expect("Cannot convert exponent to a signed long.");
+
+        let mut res = Vec::with_capacity(factors.len());
+        unsafe {
+            let mut local = MaybeUninit::uninit();
+            nmod_poly_factor::nmod_poly_factor_init(local.as_mut_ptr());
+            let mut local = local.assume_init();
+            for f in factors {
+                let g = NmodPoly::new(f.clone(), &ctx);
+                nmod_poly_factor::nmod_poly_factor_insert(&mut local, g.as_ptr(), 1);
+            }
+
+            let mut fac = MaybeUninit::uninit();
+            flint_sys::fmpz_poly_factor::fmpz_poly_factor_init(fac.as_mut_ptr());
+            let mut fac = fac.assume_init();
+            fmpz_poly_hensel_lift_once(&mut fac, self.as_ptr(), &local, n);
+            for i in 0..fac.num.try_into().expect("Cannot convert signed long to usize.") {
+                let mut f = IntPoly::zero();
+                fmpz_poly_set(f.as_mut_ptr(), fac.p.add(i));
+                res.push(f);
+            }
+            flint_sys::fmpz_poly_factor::fmpz_poly_factor_clear(&mut fac);
+            nmod_poly_factor::nmod_poly_factor_clear(&mut local);
+        }
+        res
+    }
+
+    /// Return `(g, s, t)` with `g = gcd(self, other)` and `s*self + t*other = g`,
+    /// where `s` and `t` are the cofactors over the rationals scaled by the 
+    /// leading coefficient of `g`, if these have integer coefficients, 
+    /// otherwise `None`. The cofactors are not guaranteed to be minimal, and 
+    /// `None` does not rule out other integral cofactors.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([0, 1]);
+    /// let g = IntPoly::from([1, 1]);
+    /// let (d, s, t) = f.xgcd(&g).unwrap();
+    /// assert_eq!(d, 1);
+    /// assert_eq!(s * &f + t * &g, d);
+    /// assert!(IntPoly::from([0, 2]).xgcd(&g).is_none());
+    /// ```
+    pub fn xgcd<T: AsRef<IntPoly>>(&self, other: T) -> Option<(IntPoly, IntPoly, IntPoly)> {
+        let other = other.as_ref();
+        let d = self.gcd(other);
+        if d.is_zero() {
+            return Some((d, IntPoly::zero(), IntPoly::zero()));
+        }
+
+        // the gcd over Q is monic, so scale the cofactors by lc(d)
+        let (_, s, t) = RatPoly::from(self).xgcd(RatPoly::from(other));
+        let lc = Rational::from(d.get_coeff(d.degree() as usize));
+        let (s, t) = (s * &lc, t * &lc);
+        if s.is_integral() && t.is_integral() {
+            Some((d, s.numerator(), t.numerator()))
+        } else {
+            None
+        }
+    }
+
+    /// Return the inverse of `self` modulo `modulus`, that is, a polynomial 
+    /// `s` of degree less than that of `modulus` with integer coefficients 
+    /// such that `s*self - 1` is divisible by `modulus` over the rationals, or 
+    /// `None` if no such `s` exists. Panics if `modulus` is constant.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 1]);
+    /// let m = IntPoly::from([1, 0, 1]);
+    /// assert!(f.invmod(&m).is_none());
+    /// let f = IntPoly::from([0, 1]);
+    /// assert_eq!(f.invmod(&m), Some(IntPoly::from([0, -1])));
+    /// ```
+    pub fn invmod<T: AsRef<IntPoly>>(&self, modulus: T) -> Option<IntPoly> {
+        let modulus = modulus.as_ref();
+        assert!(modulus.degree() > 0, "Modulus must be nonconstant.");
+        let s = RatPoly::from(self).invmod(RatPoly::from(modulus))?;
+        if s.is_integral() {
+            Some(s.numerator())
+        } else {
+            None
+        }
+    }
+
+    /// Return `(q, r)` with `self = q*other + r`, where each coefficient of 
+    /// `r` of degree at least `deg(other)` is reduced modulo the leading 
+    /// coefficient of `other`. If the leading coefficient of `other` is `1` or 
+    /// `-1`, or the division is exact, this agrees with division over the 
+    /// rationals. Panics if `other` is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 2, 1]);
+    /// let (q, r) = f.divrem(IntPoly::from([0, 1]));
+    /// assert_eq!(q, IntPoly::from([2, 1]));
+    /// assert_eq!(r, 1);
+    /// ```
+    pub fn divrem<T: AsRef<IntPoly>>(&self, other: T) -> (IntPoly, IntPoly) {
+        let other = other.as_ref();
+        assert!(!other.is_zero(), "Division by zero.");
+        let mut q = IntPoly::default();
+        let mut r = IntPoly::default();
+        unsafe {
+            fmpz_poly_divrem(q.as_mut_ptr(), r.as_mut_ptr(), self.as_ptr(), other.as_ptr());
+        }
+        (q, r)
+    }
+
+    /// Evaluate the polynomial at `x`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 0, 1]);
+    /// assert_eq!(f.evaluate(&Integer::from(3)), 10);
+    /// ```
+    #[inline]
+    pub fn evaluate<T: AsRef<Integer>>(&self, x: T) -> Integer {
+        let mut res = Integer::zero();
+        unsafe {
+            fmpz_poly_evaluate_fmpz(res.as_mut_ptr(), self.as_ptr(), x.as_ref().as_ptr());
+        }
+        res
+    }
+
+    /// Evaluate the polynomial at each of the points `xs`. For more than a 
+    /// handful of points the polynomial is reduced down a subproduct tree of 
+    /// the linear factors `x - a`, which is much faster than evaluating at 
+    /// each point separately when the degree is large. The crossover is 
+    /// [tuning::multipoint_eval_cutoff](crate::tuning::multipoint_eval_cutoff).
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 0, 1]);
+    /// let xs: Vec<Integer> = (-3..5).map(Integer::from).collect();
+    /// let ys = f.evaluate_vec(&xs);
+    /// assert_eq!(ys, xs.iter().map(|x| f.evaluate(x)).collect::<Vec<_>>());
+    /// ```
+    pub fn evaluate_vec(&self, xs: &[Integer]) -> Vec<Integer> {
+        trace_span!("IntPoly::evaluate_vec", degree = self.degree(), points = xs.len());
+        let cutoff = tuning::multipoint_eval_cutoff();
+        if (xs.len() as u64) < cutoff || self.degree() < cutoff as i64 {
+            return xs.iter().map(|x| self.evaluate(x)).collect();
+        }
+
+        let tree = subproduct_tree(xs);
+        let mut rems = vec![self % &tree[tree.len() - 1][0]];
+        for level in tree.iter().rev().skip(1) {
+            check_interrupt();
+            rems = level
+                .iter()
+                .enumerate()
+                .map(|(i, m)| &rems[i / 2] % m)
+                .collect();
+        }
+        rems.iter().map(|r| r.get_coeff(0)).collect()
+    }
+
+    /// Evaluate the polynomial at each entry of the matrix `m`, returning the
+    /// matrix of values. This is not the matrix polynomial `f(m)`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 0, 1]);
+    /// let m = IntMat::new([0, 1, 2, 3], 2, 2);
+    /// assert_eq!(f.evaluate_mat(&m), IntMat::new([1, 2, 5, 10], 2, 2));
+    /// ```
+    pub fn evaluate_mat(&self, m: &IntMat) -> IntMat {
+        let ys = self.evaluate_vec(&m.get_entries());
+        IntMat::new(ys.as_slice(), m.nrows_si(), m.ncols_si())
+    }
+
+    /// Return the unique polynomial of degree less than `xs.len()` taking the 
+    /// values `ys` at the points `xs`, or `None` if its coefficients are not 
+    /// all integers. Panics if the lengths differ or the points are not 
+    /// distinct.
+    ///
+    /// The Lagrange form `sum_i ys[i]/M'(xs[i]) * M/(x - xs[i])` with 
+    /// `M = prod_i (x - xs[i])` is assembled up the same subproduct tree used 
+    /// by [evaluate_vec](IntPoly::evaluate_vec).
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let xs: Vec<Integer> = (0..4).map(Integer::from).collect();
+    /// let ys: Vec<Integer> = [1, 2, 5, 10].into_iter().map(Integer::from).collect();
+    /// assert_eq!(IntPoly::interpolate(&xs, &ys), Some(IntPoly::from([1, 0, 1])));
+    ///
+    /// let ys: Vec<Integer> = [0, 1, 0, 0].into_iter().map(Integer::from).collect();
+    /// assert_eq!(IntPoly::interpolate(&xs, &ys), None);
+    /// ```
+    pub fn interpolate(xs: &[Integer], ys: &[Integer]) -> Option<IntPoly> {
+        assert_eq!(xs.len(), ys.len());
+        let mut sorted: Vec<&Integer> = xs.iter().collect();
+        sorted.sort();
+        assert!(sorted.windows(2).all(|w| w[0] != w[1]), "Points must be distinct.");
+
+        if xs.is_empty() {
+            return Some(IntPoly::zero());
+        }
+
+        let tree = subproduct_tree(xs);
+        let mut dm = IntPoly::default();
+        unsafe { 
+            fmpz_poly_derivative(dm.as_mut_ptr(), tree[tree.len() - 1][0].as_ptr()); 
+        }
+        let mut rs: Vec<RatPoly> = ys
+            .iter()
+            .zip(dm.evaluate_vec(xs).iter())
+            .map(|(y, d)| RatPoly::from(Rational::from([y, d])))
+            .collect();
+        for level in tree.iter().take(tree.len() - 1) {
+            check_interrupt();
+            rs = rs
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|pair| match pair {
+                    ([r0, r1], [m0, m1]) => r0 * m1 + r1 * m0,
+                    ([r0], [_]) => r0.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        if rs[0].is_integral() {
+            Some(rs[0].numerator())
+        } else {
+            None
+        }
+    }
+
+    /// Return the `n`-th cyclotomic polynomial `Phi_n(x)`, the minimal 
+    /// polynomial of a primitive `n`-th root of unity.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(IntPoly::cyclotomic(6), IntPoly::from([1, -1, 1]));
+    /// assert_eq!(IntPoly::cyclotomic(12).degree(), 4);
+    /// ```
     pub fn cyclotomic(n: u64) -> Self {
         let mut res = IntPoly::default();
         unsafe {
@@ -343,5 +1133,242 @@ impl IntPoly {
         }
         res
     }
+
+    /// Return the Chebyshev polynomial of the first kind `T_n(x)`, defined 
+    /// by `T_n(cos t) = cos(nt)`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(IntPoly::chebyshev_t(3), IntPoly::from([0, -3, 0, 4]));
+    /// ```
+    pub fn chebyshev_t(n: u64) -> Self {
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly_chebyshev_t(res.as_mut_ptr(), n);
+        }
+        res
+    }
+
+    /// Return the Chebyshev polynomial of the second kind `U_n(x)`, defined 
+    /// by `U_n(cos t) sin t = sin((n + 1)t)`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(IntPoly::chebyshev_u(2), IntPoly::from([-1, 0, 4]));
+    /// ```
+    pub fn chebyshev_u(n: u64) -> Self {
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly_chebyshev_u(res.as_mut_ptr(), n);
+        }
+        res
+    }
+
+    /// Return the Swinnerton-Dyer polynomial `S_n(x)`, the product of 
+    /// `x - (±sqrt(2) ± sqrt(3) ± ... ± sqrt(p_n))` over all sign choices, 
+    /// where `p_n` is the `n`-th prime. It has degree `2^n` and is 
+    /// irreducible but factors into polynomials of degree at most two modulo
+    /// every prime, which makes it a hard input for factoring algorithms.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(IntPoly::swinnerton_dyer(1), IntPoly::from([-2, 0, 1]));
+    /// assert_eq!(IntPoly::swinnerton_dyer(2), IntPoly::from([1, 0, -10, 0, 1]));
+    /// ```
+    pub fn swinnerton_dyer(n: u64) -> Self {
+        assert!(n < 32, "The degree 2^n is too large.");
+        let mut res = IntPoly::default();
+        unsafe {
+            fmpz_poly_swinnerton_dyer(res.as_mut_ptr(), n);
+        }
+        res
+    }
+    
+    /// Return a random polynomial of length at most `len` with coefficients of 
+    /// up to `bits` bits.
+    pub fn randtest<S>(len: usize, bits: S, rng: &mut FlintRand) -> Self 
+    where
+        S: Into<u64>
+    {
+        let mut res = IntPoly::with_capacity(len);
+        unsafe {
+            fmpz_poly_randtest(
+                res.as_mut_ptr(), 
+                rng.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long."),
+                bits.into()
+            );
+        }
+        res
+    }
+    
+    /// Return a random polynomial of length at most `len` whose leading 
+    /// coefficient is nonzero.
+    pub fn randtest_not_zero<S>(len: usize, bits: S, rng: &mut FlintRand) -> Self 
+    where
+        S: Into<u64>
+    {
+        let bits = bits.into();
+        assert!(len > 0 && bits > 0);
+        let mut res = IntPoly::with_capacity(len);
+        unsafe {
+            fmpz_poly_randtest_not_zero(
+                res.as_mut_ptr(), 
+                rng.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long."),
+                bits
+            );
+        }
+        res
+    }
+}
+
+/// Guess a linear recurrence with constant coefficients satisfied by the 
+/// sequence `seq` of order at most `max_order`. On success returns the 
+/// characteristic polynomial `p_0 + p_1*x + ... + p_r*x^r`, normalized to be 
+/// primitive with positive leading coefficient, such that 
+/// `p_0*a_k + p_1*a_{k+1} + ... + p_r*a_{k+r} = 0` for every window of `seq`.
+///
+/// The recurrence is found with the Berlekamp-Massey algorithm modulo a large 
+/// prime followed by rational reconstruction of the coefficients, and is then 
+/// verified over the integers. At least `2*r` terms are needed to determine a 
+/// recurrence of order `r`, otherwise `None` is returned.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let fib: Vec<Integer> = (0..20u64).map(|n| Integer::fibonacci(n)).collect();
+/// let p = guess_recurrence(&fib, 5).unwrap();
+/// assert_eq!(p, IntPoly::from([-1, -1, 1]));
+/// ```
+pub fn guess_recurrence(seq: &[Integer], max_order: usize) -> Option<IntPoly> {
+    trace_span!("guess_recurrence", len = seq.len(), max_order);
+    let n = seq.len();
+    if n == 0 {
+        return None;
+    }
+
+    // The recurrence coefficients are ratios of minors of a Hankel matrix 
+    // built from the sequence, so bound their size using Hadamard's bound.
+    let max_bits = seq.iter().map(|a| a.bits()).max().unwrap();
+    let order_bits = (usize::BITS - max_order.leading_zeros()) as u64;
+    let bits = 2 * (max_order as u64) * (max_bits + order_bits) + 64;
+    let mut p = Integer::one_2exp(bits) + 1u64;
+    while !p.is_prime() {
+        p += 2u64;
+    }
+
+    let s: Vec<Integer> = seq.iter().map(|a| a.fdiv_r(&p)).collect();
+    let (c, len) = berlekamp_massey(&s, &p);
+    trace_event!(prime_bits = p.bits(), order = len, "berlekamp-massey done");
+    if len > max_order || 2 * len > n {
+        return None;
+    }
+
+    // reconstruct the connection polynomial 1 + c_1 x + ... + c_r x^r over Q
+    let mut coeffs = Vec::with_capacity(len + 1);
+    let mut den = Integer::one();
+    for ci in c.iter().take(len + 1) {
+        let q = ci.reconstruct(&p)?;
+        den = den.lcm(q.denominator());
+        coeffs.push(q);
+    }
+   
+    // reversing gives the characteristic polynomial
+    let mut res = IntPoly::zero();
+    for (i, q) in coeffs.iter().enumerate() {
+        let ci = (q * &den).numerator();
+        res.set_coeff(len - i, ci);
+    }
+    // the leading coefficient is den > 0, so we only need to remove content
+    let content = res.get_coeffs().iter().fold(Integer::zero(), |g, c| g.gcd(c));
+    for i in 0..=len {
+        let ci = res.get_coeff(i).divexact(&content)?;
+        res.set_coeff(i, ci);
+    }
+
+    // verify over the integers
+    let pc = res.get_coeffs();
+    for k in 0..n - len {
+        let mut t = Integer::zero();
+        for (i, pi) in pc.iter().enumerate() {
+            t += pi * &seq[k + i];
+        }
+        if !t.is_zero() {
+            return None;
+        }
+    }
+    Some(res)
+}
+
+// Subproduct tree of the linear factors x - a for a in `xs`. The first level
+// holds the factors and each following level the products of adjacent pairs,
+// with an unpaired last node carried up unchanged, so node i of a level
+// divides node i/2 of the next. The last level is the product of all factors.
+fn subproduct_tree(xs: &[Integer]) -> Vec<Vec<IntPoly>> {
+    let leaves: Vec<IntPoly> = xs
+        .iter()
+        .map(|a| IntPoly::from([-a, Integer::one()]))
+        .collect();
+    let mut tree = vec![leaves];
+    while tree[tree.len() - 1].len() > 1 {
+        let next = tree[tree.len() - 1]
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => a * b,
+                [a] => a.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+        tree.push(next);
+    }
+    tree
 }
 
+// Berlekamp-Massey over Z/pZ for the reduced sequence `s`. Returns the 
+// connection polynomial coefficients and the linear complexity.
+pub(crate) fn berlekamp_massey(s: &[Integer], p: &Integer) -> (Vec<Integer>, usize) {
+    let mut c = vec![Integer::one()];
+    let mut b = vec![Integer::one()];
+    let mut bd = Integer::one();
+    let mut len = 0usize;
+    let mut m = 1usize;
+
+    for (k, sk) in s.iter().enumerate() {
+        check_interrupt();
+        let mut d = sk.clone();
+        for i in 1..=len {
+            d += &c[i] * &s[k - i];
+        }
+        d = d.fdiv_r(p);
+
+        if d.is_zero() {
+            m += 1;
+            continue;
+        }
+
+        // c <- c - (d/bd) x^m b
+        let coef = (d.clone() * bd.invmod(p).unwrap()).fdiv_r(p);
+        let prev = c.clone();
+        if c.len() < b.len() + m {
+            c.resize(b.len() + m, Integer::zero());
+        }
+        for (i, bi) in b.iter().enumerate() {
+            c[i + m] = (&c[i + m] - &coef * bi).fdiv_r(p);
+        }
+
+        if 2 * len <= k {
+            len = k + 1 - len;
+            b = prev;
+            bd = d;
+            m = 1;
+        } else {
+            m += 1;
+        }
+    }
+    c.resize(len + 1, Integer::zero());
+    (c, len)
+}
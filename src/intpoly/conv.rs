@@ -16,6 +16,8 @@
  */
 
 use crate::*;
+use crate::Error::ParsePolyError;
+use std::str::FromStr;
 use flint_sys::{
     fmpz_poly, 
     fmpz_mod_poly, 
@@ -23,6 +25,35 @@ use flint_sys::{
 };
 
 
+impl FromStr for IntPoly {
+    type Err = Error;
+    /// Parse a polynomial with integer coefficients, either as a sum of terms
+    /// in a single variable like `x^2 + 3*x - 1` or as a list of coefficients
+    /// `[-1, 3, 1]` starting with the constant term.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f: IntPoly = "x^2 + 3*x - 1".parse().unwrap();
+    /// assert_eq!(f, IntPoly::from([-1, 3, 1]));
+    /// assert_eq!("[-1, 3, 1]".parse::<IntPoly>().unwrap(), f);
+    /// assert_eq!("-2t^3 + t".parse::<IntPoly>().unwrap(), IntPoly::from([0, 1, 0, -2]));
+    /// assert_eq!(f.to_string().parse::<IntPoly>().unwrap(), f);
+    /// assert!(matches!("x/2".parse::<IntPoly>(), Err(Error::ParsePolyError(_))));
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let coeffs = parse::poly(s).map_err(ParsePolyError)?;
+        let mut res = IntPoly::with_capacity(coeffs.len());
+        for (i, c) in coeffs.iter().enumerate() {
+            if !c.denominator().is_one() {
+                return Err(ParsePolyError(format!("{} is not an integer.", c)));
+            }
+            res.set_coeff(i, c.numerator());
+        }
+        Ok(res)
+    }
+}
+
 impl_from_unsafe! {
     None
     IntPoly, u64 {usize u64 u32 u16 u8}
@@ -0,0 +1,124 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpz_poly::*;
+
+/// A polynomial multiplication algorithm, see [IntPoly::mul_with]. 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MulAlgorithm {
+    /// Let FLINT choose from the lengths and coefficient sizes.
+    #[default]
+    Auto,
+    /// Schoolbook multiplication, best for short polynomials.
+    Classical,
+    /// Karatsuba's divide and conquer, for moderate lengths with large 
+    /// coefficients.
+    Karatsuba,
+    /// Kronecker substitution, packing the coefficients into one large 
+    /// integer and multiplying with GMP. Best for long polynomials with small
+    /// coefficients.
+    KroneckerSubstitution,
+    /// The Schönhage–Strassen FFT over the integers modulo a Fermat number, 
+    /// for long polynomials with large coefficients.
+    SchonhageStrassen,
+}
+
+impl IntPoly {
+    /// Return the product `self * other` using the algorithm `alg`. All 
+    /// algorithms give the same result.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([1, 2, 3]);
+    /// let g = IntPoly::from([-1, 0, 5, 7]);
+    /// let h = &f * &g;
+    /// for alg in [
+    ///     MulAlgorithm::Classical, 
+    ///     MulAlgorithm::Karatsuba, 
+    ///     MulAlgorithm::KroneckerSubstitution, 
+    ///     MulAlgorithm::SchonhageStrassen
+    /// ] {
+    ///     assert_eq!(f.mul_with(&g, alg), h);
+    /// }
+    /// ```
+    pub fn mul_with<T: AsRef<IntPoly>>(&self, other: T, alg: MulAlgorithm) -> IntPoly {
+        let other = other.as_ref();
+        let mut res = IntPoly::zero();
+        let func = match alg {
+            MulAlgorithm::Auto => fmpz_poly_mul,
+            MulAlgorithm::Classical => fmpz_poly_mul_classical,
+            MulAlgorithm::Karatsuba => fmpz_poly_mul_karatsuba,
+            MulAlgorithm::KroneckerSubstitution => fmpz_poly_mul_KS,
+            MulAlgorithm::SchonhageStrassen => {
+                // FLINT's Schönhage–Strassen code requires nonzero inputs
+                if self.is_zero() || other.is_zero() {
+                    return res;
+                }
+                fmpz_poly_mul_SS
+            }
+        };
+        unsafe { func(res.as_mut_ptr(), self.as_ptr(), other.as_ptr()); }
+        res
+    }
+
+    /// Return `self * other` by schoolbook multiplication.
+    #[inline]
+    pub fn mul_classical<T: AsRef<IntPoly>>(&self, other: T) -> IntPoly {
+        self.mul_with(other, MulAlgorithm::Classical)
+    }
+
+    /// Return `self * other` by Karatsuba multiplication.
+    #[inline]
+    pub fn mul_karatsuba<T: AsRef<IntPoly>>(&self, other: T) -> IntPoly {
+        self.mul_with(other, MulAlgorithm::Karatsuba)
+    }
+
+    /// Return `self * other` by Kronecker substitution.
+    #[inline]
+    pub fn mul_ks<T: AsRef<IntPoly>>(&self, other: T) -> IntPoly {
+        self.mul_with(other, MulAlgorithm::KroneckerSubstitution)
+    }
+
+    /// Return `self * other` with the Schönhage–Strassen FFT.
+    #[inline]
+    pub fn mul_ss<T: AsRef<IntPoly>>(&self, other: T) -> IntPoly {
+        self.mul_with(other, MulAlgorithm::SchonhageStrassen)
+    }
+}
+
+/// Return the linear convolution `c_k = sum_{i + j = k} a_i b_j` of two 
+/// integer sequences, of length `a.len() + b.len() - 1`, or empty if either 
+/// input is empty. The sequences are multiplied as polynomials, so FLINT 
+/// picks the fastest algorithm for their shape.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let a = [Integer::from(1), Integer::from(2)];
+/// let b = [Integer::from(3), Integer::from(0), Integer::from(0)];
+/// let c = convolve(&a, &b);
+/// assert_eq!(c, vec![Integer::from(3), Integer::from(6), Integer::zero(), Integer::zero()]);
+/// ```
+pub fn convolve(a: &[Integer], b: &[Integer]) -> Vec<Integer> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let prod = IntPoly::from(a) * IntPoly::from(b);
+    (0..a.len() + b.len() - 1).map(|k| prod.get_coeff(k)).collect()
+}
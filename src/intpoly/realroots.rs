@@ -0,0 +1,202 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpz_poly::*;
+
+impl IntPoly {
+    /// Return the Sturm sequence `p_0 = f, p_1 = f', ..., p_k` of the 
+    /// polynomial, where `p_{i+1}` is a positive multiple of the negated 
+    /// remainder of `p_{i-1}` by `p_i`. The terms are kept primitive, which
+    /// does not change their signs. Panics if the polynomial is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([-1, 0, 1]);
+    /// let seq = f.sturm_sequence();
+    /// assert_eq!(seq, vec![f.clone(), IntPoly::from([0, 1]), IntPoly::from([1])]);
+    /// ```
+    pub fn sturm_sequence(&self) -> Vec<IntPoly> {
+        assert!(!self.is_zero(), "The polynomial must be nonzero.");
+        let mut df = IntPoly::zero();
+        unsafe { fmpz_poly_derivative(df.as_mut_ptr(), self.as_ptr()); }
+        let mut res = vec![self.clone()];
+        if df.is_zero() {
+            return res;
+        }
+        primitive_assign(&mut df);
+        res.push(df);
+
+        loop {
+            check_interrupt();
+            let a = &res[res.len() - 2];
+            let b = &res[res.len() - 1];
+            if b.degree() == 0 {
+                break;
+            }
+            // lc(b)^d * a = q*b + r
+            let mut r = IntPoly::zero();
+            let mut d: u64 = 0;
+            unsafe {
+                fmpz_poly_pseudo_rem(r.as_mut_ptr(), &mut d, a.as_ptr(), b.as_ptr());
+            }
+            if r.is_zero() {
+                break;
+            }
+            let lc = b.get_coeff(b.degree() as usize);
+            if lc.sign() > 0 || d % 2 == 0 {
+                r = -r;
+            }
+            primitive_assign(&mut r);
+            res.push(r);
+        }
+        res
+    }
+
+    /// Return the number of distinct real roots of the polynomial. Panics if
+    /// the polynomial is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // (x^2 - 2)^2 (x^2 + 1)
+    /// let f = IntPoly::from([-2, 0, 1]).pow(2u64) * IntPoly::from([1, 0, 1]);
+    /// assert_eq!(f.num_real_roots(), 2);
+    /// ```
+    pub fn num_real_roots(&self) -> usize {
+        let seq = self.squarefree_part().sturm_sequence();
+        let at_neg = variations(seq.iter().map(|p| {
+            let s = p.get_coeff(p.degree() as usize).sign();
+            if p.degree() % 2 == 0 { s } else { -s }
+        }));
+        let at_pos = variations(seq.iter().map(|p| p.get_coeff(p.degree() as usize).sign()));
+        at_neg - at_pos
+    }
+
+    /// Return the number of distinct roots of the polynomial in the closed 
+    /// interval `[a, b]`. Panics if the polynomial is zero or `a > b`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([0, -1, 0, 1]);
+    /// assert_eq!(f.num_roots_in_interval(&Rational::from(-1), &Rational::from(1)), 3);
+    /// assert_eq!(f.num_roots_in_interval(&Rational::from([1, 2]), &Rational::from(2)), 1);
+    /// ```
+    pub fn num_roots_in_interval(&self, a: &Rational, b: &Rational) -> usize {
+        assert!(a <= b);
+        let seq = self.squarefree_part().sturm_sequence();
+        let n = sturm_count(&seq, a, b);
+        if seq[0].sign_at(a) == 0 {
+            n + 1
+        } else {
+            n
+        }
+    }
+
+    /// Return disjoint intervals `(a_i, b_i]` with rational endpoints, in 
+    /// increasing order, each containing exactly one real root of the 
+    /// polynomial, with one interval for every distinct real root. Panics if
+    /// the polynomial is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = IntPoly::from([-2, 0, 1]);
+    /// let roots = f.isolate_real_roots();
+    /// assert_eq!(roots.len(), 2);
+    /// for (a, b) in &roots {
+    ///     assert!(f.sign_at(a) * f.sign_at(b) <= 0);
+    /// }
+    /// assert!(roots[0].1 <= roots[1].0);
+    /// ```
+    pub fn isolate_real_roots(&self) -> Vec<(Rational, Rational)> {
+        let seq = self.squarefree_part().sturm_sequence();
+        // all roots lie in (-B, B) with B = 1 + max |a_i|
+        let bound = self.get_coeffs().iter().map(|c| c.abs()).max().unwrap() + 1;
+        let bound = Rational::from(bound);
+        let half = Rational::from([1, 2]);
+
+        let mut res = Vec::new();
+        let mut stack = vec![(-&bound, bound)];
+        while let Some((lo, hi)) = stack.pop() {
+            check_interrupt();
+            match sturm_count(&seq, &lo, &hi) {
+                0 => {}
+                1 => res.push((lo, hi)),
+                _ => {
+                    let mid = (&lo + &hi) * &half;
+                    // the left half is popped first
+                    stack.push((mid.clone(), hi));
+                    stack.push((lo, mid));
+                }
+            }
+        }
+        res
+    }
+
+    /// Return the sign of the polynomial evaluated at `x`.
+    #[inline]
+    pub fn sign_at(&self, x: &Rational) -> i32 {
+        let mut res = Rational::zero();
+        unsafe { fmpz_poly_evaluate_fmpq(res.as_mut_ptr(), self.as_ptr(), x.as_ptr()); }
+        res.sign()
+    }
+
+    // The polynomial divided by its gcd with its derivative.
+    fn squarefree_part(&self) -> IntPoly {
+        assert!(!self.is_zero(), "The polynomial must be nonzero.");
+        let mut df = IntPoly::zero();
+        unsafe { fmpz_poly_derivative(df.as_mut_ptr(), self.as_ptr()); }
+        if df.is_zero() {
+            return self.clone();
+        }
+        let g = self.gcd(&df);
+        let mut res = IntPoly::zero();
+        unsafe { fmpz_poly_div(res.as_mut_ptr(), self.as_ptr(), g.as_ptr()); }
+        res
+    }
+}
+
+// Divide by the (positive) content.
+fn primitive_assign(f: &mut IntPoly) {
+    let mut c = Integer::zero();
+    unsafe {
+        fmpz_poly_content(c.as_mut_ptr(), f.as_ptr());
+        fmpz_poly_scalar_divexact_fmpz(f.as_mut_ptr(), f.as_ptr(), c.as_ptr());
+    }
+}
+
+// The number of sign changes in a sequence, ignoring zeros.
+fn variations<I: Iterator<Item = i32>>(signs: I) -> usize {
+    let mut res = 0;
+    let mut last = 0;
+    for s in signs.filter(|&s| s != 0) {
+        if last != 0 && s != last {
+            res += 1;
+        }
+        last = s;
+    }
+    res
+}
+
+// The number of roots in (a, b] of a squarefree polynomial with Sturm 
+// sequence `seq`.
+fn sturm_count(seq: &[IntPoly], a: &Rational, b: &Rational) -> usize {
+    variations(seq.iter().map(|p| p.sign_at(a))) - variations(seq.iter().map(|p| p.sign_at(b)))
+}
@@ -0,0 +1,391 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod ops;
+mod popov;
+
+use crate::*;
+use flint_sys::{fmpz_poly, fmpz_poly_mat};
+use crate::util::{fmt_rows, DisplayRows};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+
+
+/// A matrix with entries in `Z[x]`, wrapping FLINT's `fmpz_poly_mat`.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let x = IntPoly::from([0, 1]);
+/// let a = IntPolyMat::new([x.clone(), IntPoly::from(1), IntPoly::from(2), x.clone()], 2, 2);
+/// // det = x^2 - 2
+/// assert_eq!(a.det(), IntPoly::from([-2, 0, 1]));
+/// assert_eq!(a.evaluate(&Integer::from(3)), IntMat::new([3, 1, 2, 3], 2, 2));
+/// ```
+pub struct IntPolyMat {
+    inner: fmpz_poly_mat::fmpz_poly_mat_struct,
+}
+
+impl AsRef<IntPolyMat> for IntPolyMat {
+    fn as_ref(&self) -> &IntPolyMat {
+        self
+    }
+}
+
+impl Clone for IntPolyMat {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_init_set(z.as_mut_ptr(), self.as_ptr());
+            IntPolyMat::from_raw(z.assume_init())
+        }
+    }
+}
+
+impl fmt::Debug for IntPolyMat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self.get_rows();
+        f.debug_struct("IntPolyMat")
+            .field("nrows", &self.nrows())
+            .field("ncols", &self.ncols())
+            .field("entries", &DisplayRows(rows.iter().map(|r| r.as_slice()).collect()))
+            .finish()
+    }
+}
+
+impl fmt::Display for IntPolyMat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_rows(f, self.get_rows().iter().map(|row| row.as_slice()))
+    }
+}
+
+impl Drop for IntPolyMat {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_clear(self.as_mut_ptr()) }
+    }
+}
+
+impl Hash for IntPolyMat {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get_entries().hash(state);
+    }
+}
+
+impl<T, const CAP: usize> NewMatrix<[T; CAP]> for IntPolyMat 
+where
+    T: Into<IntPoly>
+{
+    fn new(src: [T; CAP], nrows: i64, ncols: i64) -> Self {
+        let ncols_ui: usize = ncols.try_into().expect(
+            "Cannot convert signed long to usize.");
+        let nrows_ui: usize = nrows.try_into().expect(
+            "Cannot convert signed long to usize.");
+        
+        assert_eq!(src.len(), nrows_ui * ncols_ui);
+        let mut res = IntPolyMat::zero(nrows, ncols);
+        for (k, x) in src.into_iter().enumerate() {
+            res.set_entry(k / ncols_ui, k % ncols_ui, x.into());
+        }
+        res
+    }
+}
+
+impl NewMatrix<&[IntPoly]> for IntPolyMat {
+    fn new(src: &[IntPoly], nrows: i64, ncols: i64) -> Self {
+        let ncols_ui: usize = ncols.try_into().expect(
+            "Cannot convert signed long to usize.");
+        let nrows_ui: usize = nrows.try_into().expect(
+            "Cannot convert signed long to usize.");
+        
+        assert_eq!(src.len(), nrows_ui * ncols_ui);
+        let mut res = IntPolyMat::zero(nrows, ncols);
+        for (k, x) in src.iter().enumerate() {
+            res.set_entry(k / ncols_ui, k % ncols_ui, x);
+        }
+        res
+    }
+}
+
+impl IntPolyMat {
+    // private helper methods to convert usize indices to i64, emit consistent
+    // messages on panic, and bounds check
+    fn check_indices(&self, i: usize, j: usize) -> (i64, i64) {
+        let i = i.try_into().expect("Cannot convert index to a signed long.");
+        let j = j.try_into().expect("Cannot convert index to a signed long.");
+        assert!(i < self.nrows_si() && j < self.ncols_si());
+        (i, j)
+    }
+
+    #[inline]
+    pub fn new<S>(src: S, nrows: i64, ncols: i64) -> IntPolyMat 
+    where
+        Self: NewMatrix<S>
+    {
+        <IntPolyMat as NewMatrix<S>>::new(src, nrows, ncols)
+    }
+
+    #[inline]
+    pub fn zero(nrows: i64, ncols: i64) -> IntPolyMat {
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_init(z.as_mut_ptr(), nrows, ncols);
+            IntPolyMat::from_raw(z.assume_init())
+        }
+    }
+    
+    #[inline]
+    pub fn one(dim: i64) -> IntPolyMat {
+        let mut res = IntPolyMat::zero(dim, dim);
+        unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_one(res.as_mut_ptr());
+        }
+        res
+    }
+
+    /// Return the matrix with constant entries given by `m`.
+    pub fn from_intmat(m: &IntMat) -> IntPolyMat {
+        let mut res = IntPolyMat::zero(m.nrows_si(), m.ncols_si());
+        for i in 0..m.nrows() {
+            for j in 0..m.ncols() {
+                res.set_entry(i, j, IntPoly::from(m.entry(i, j)));
+            }
+        }
+        res
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const fmpz_poly_mat::fmpz_poly_mat_struct {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut fmpz_poly_mat::fmpz_poly_mat_struct {
+        &mut self.inner
+    }
+
+    #[inline]
+    pub fn from_raw(raw: fmpz_poly_mat::fmpz_poly_mat_struct) -> IntPolyMat {
+        IntPolyMat { inner: raw }
+    }
+
+    /// Return the number of rows.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows_si().try_into().expect("Cannot convert signed long to usize.")
+    }
+    
+    /// Return the number of rows.
+    #[inline]
+    pub fn nrows_si(&self) -> i64 {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_nrows(self.as_ptr()) }
+    }
+
+    /// Return the number of columns.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols_si().try_into().expect("Cannot convert signed long to usize.")
+    }
+    
+    /// Return the number of columns.
+    #[inline]
+    pub fn ncols_si(&self) -> i64 {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_ncols(self.as_ptr()) }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_is_empty(self.as_ptr()) != 0 }
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_is_square(self.as_ptr()) != 0 }
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_is_zero(self.as_ptr()) != 0 }
+    }
+
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_is_one(self.as_ptr()) != 0 }
+    }
+
+    /// Get the `(i, j)`-th entry of the matrix.
+    pub fn get_entry(&self, i: usize, j: usize) -> IntPoly {
+        let (i, j) = self.check_indices(i, j);
+        let mut res = IntPoly::zero();
+        unsafe {
+            let x = fmpz_poly_mat::fmpz_poly_mat_entry(self.as_ptr(), i, j);
+            fmpz_poly::fmpz_poly_set(res.as_mut_ptr(), x);
+        }
+        res
+    }
+
+    /// Set the `(i, j)`-th entry of the matrix.
+    pub fn set_entry<T: AsRef<IntPoly>>(&mut self, i: usize, j: usize, e: T) {
+        let (i, j) = self.check_indices(i, j);
+        unsafe {
+            let x = fmpz_poly_mat::fmpz_poly_mat_entry(self.as_ptr(), i, j);
+            fmpz_poly::fmpz_poly_set(x, e.as_ref().as_ptr());
+        }
+    }
+
+    /// Get a vector with all of the entries of the matrix in row-major order.
+    pub fn get_entries(&self) -> Vec<IntPoly> {
+        self.get_rows().into_iter().flatten().collect()
+    }
+
+    /// Get the rows of the matrix.
+    pub fn get_rows(&self) -> Vec<Vec<IntPoly>> {
+        (0..self.nrows())
+            .map(|i| (0..self.ncols()).map(|j| self.get_entry(i, j)).collect())
+            .collect()
+    }
+
+    /// Return the largest degree of an entry, or `-1` for the zero matrix.
+    #[inline]
+    pub fn degree(&self) -> i64 {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_degree(self.as_ptr()) }
+    }
+
+    /// Return the transpose of the matrix.
+    pub fn transpose(&self) -> IntPolyMat {
+        let mut res = IntPolyMat::zero(self.ncols_si(), self.nrows_si());
+        unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_transpose(res.as_mut_ptr(), self.as_ptr());
+        }
+        res
+    }
+
+    /// Evaluate every entry of the matrix at `x`.
+    pub fn evaluate<T: AsRef<Integer>>(&self, x: T) -> IntMat {
+        let mut res = IntMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_evaluate_fmpz(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                x.as_ref().as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return the matrix raised to the power `exp`. The matrix must be 
+    /// square.
+    pub fn pow(&self, exp: u64) -> IntPolyMat {
+        assert!(self.is_square());
+        let mut res = IntPolyMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_pow(res.as_mut_ptr(), self.as_ptr(), exp);
+        }
+        res
+    }
+
+    /// Return the trace of a square matrix.
+    pub fn trace(&self) -> IntPoly {
+        assert!(self.is_square());
+        let mut res = IntPoly::zero();
+        unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_trace(res.as_mut_ptr(), self.as_ptr());
+        }
+        res
+    }
+
+    /// Return the determinant of a square matrix.
+    pub fn det(&self) -> IntPoly {
+        assert!(self.is_square());
+        let mut res = IntPoly::zero();
+        unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_det(res.as_mut_ptr(), self.as_ptr());
+        }
+        res
+    }
+
+    /// Return the rank of the matrix over `Q(x)`.
+    #[inline]
+    pub fn rank(&self) -> i64 {
+        unsafe { fmpz_poly_mat::fmpz_poly_mat_rank(self.as_ptr()) }
+    }
+
+    /// Return the rank `r`, a matrix `R` and a denominator `d` such that 
+    /// `R/d` is the reduced row echelon form of the matrix over `Q(x)`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = IntPoly::from([0, 1]);
+    /// let a = IntPolyMat::new([x.clone(), IntPoly::from(1), IntPoly::from([0, 0, 1]), x], 2, 2);
+    /// let (rank, _, _) = a.rref();
+    /// assert_eq!(rank, 1);
+    /// ```
+    pub fn rref(&self) -> (i64, IntPolyMat, IntPoly) {
+        let mut res = IntPolyMat::zero(self.nrows_si(), self.ncols_si());
+        let mut den = IntPoly::zero();
+        let rank = unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_rref(
+                res.as_mut_ptr(), 
+                den.as_mut_ptr(), 
+                self.as_ptr()
+            )
+        };
+        (rank, res, den)
+    }
+
+    /// Return a matrix `B` and a denominator `d` with `B/d` the inverse of 
+    /// the matrix over `Q(x)`, or `None` if it is singular. The matrix must 
+    /// be square.
+    pub fn inv(&self) -> Option<(IntPolyMat, IntPoly)> {
+        assert!(self.is_square());
+        let mut res = IntPolyMat::zero(self.nrows_si(), self.ncols_si());
+        let mut den = IntPoly::zero();
+        unsafe {
+            let x = fmpz_poly_mat::fmpz_poly_mat_inv(
+                res.as_mut_ptr(), 
+                den.as_mut_ptr(), 
+                self.as_ptr()
+            );
+            if x != 0 {
+                Some((res, den))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Return a matrix whose columns form a basis of the right nullspace of 
+    /// the matrix over `Q(x)`, with entries in `Z[x]`.
+    pub fn nullspace(&self) -> IntPolyMat {
+        let n = self.ncols_si();
+        let mut basis = IntPolyMat::zero(n, n);
+        let nullity = unsafe {
+            fmpz_poly_mat::fmpz_poly_mat_nullspace(basis.as_mut_ptr(), self.as_ptr())
+        };
+        let mut res = IntPolyMat::zero(n, nullity);
+        for i in 0..res.nrows() {
+            for j in 0..res.ncols() {
+                res.set_entry(i, j, basis.get_entry(i, j));
+            }
+        }
+        res
+    }
+}
@@ -0,0 +1,85 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpz_poly_mat;
+use inertia_algebra::ops::*;
+
+impl_assign_unsafe! {
+    matrix
+    IntPolyMat, IntPolyMat
+    fmpz_poly_mat::fmpz_poly_mat_set
+}
+
+impl_cmp_unsafe! {
+    eq
+    IntPolyMat
+    fmpz_poly_mat::fmpz_poly_mat_equal
+}
+
+impl_unop_unsafe! {
+    matrix
+    IntPolyMat
+    Neg {neg}
+    NegAssign {neg_assign}
+    fmpz_poly_mat::fmpz_poly_mat_neg
+}
+
+impl_binop_unsafe! {
+    matrix
+    IntPolyMat, IntPolyMat, IntPolyMat
+
+    Add {add}
+    AddAssign {add_assign}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    fmpz_poly_mat::fmpz_poly_mat_add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    fmpz_poly_mat::fmpz_poly_mat_sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    fmpz_poly_mat::fmpz_poly_mat_mul;
+}
+
+impl_binop_unsafe! {
+    scalar_rhs
+    op_assign
+    IntPolyMat, Integer, IntPolyMat
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    AssignMul {assign_mul}
+    fmpz_poly_mat::fmpz_poly_mat_scalar_mul_fmpz;
+}
+
+impl_binop_unsafe! {
+    scalar_rhs
+    op_assign
+    IntPolyMat, IntPoly, IntPolyMat
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    AssignMul {assign_mul}
+    fmpz_poly_mat::fmpz_poly_mat_scalar_mul_fmpz_poly;
+}
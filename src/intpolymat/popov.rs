@@ -0,0 +1,256 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// Popov forms are defined over K[x] for a field K. Here K = Q, and the rows are 
+// kept in Z[x] by clearing denominators: each row operation multiplies the row
+// by a nonzero integer and the row is then divided by its content. This does 
+// not change the Q[x]-module spanned by the rows.
+
+use crate::*;
+use flint_sys::{fmpz, fmpz_poly};
+
+// The pivot of a row: its rightmost entry of maximal degree, as a pair
+// (column, degree). Zero rows have no pivot.
+fn pivot(row: &[IntPoly]) -> Option<(usize, i64)> {
+    let mut res: Option<(usize, i64)> = None;
+    for (j, e) in row.iter().enumerate() {
+        let d = e.degree();
+        if d >= 0 && res.map_or(true, |(_, rd)| d >= rd) {
+            res = Some((j, d));
+        }
+    }
+    res
+}
+
+#[inline]
+fn lead(p: &IntPoly) -> Integer {
+    p.get_coeff(p.degree().try_into().expect("Cannot convert signed long to usize."))
+}
+
+// Divide the row by the gcd of the coefficients of its entries and make the 
+// leading coefficient of the pivot positive.
+fn normalize(row: &mut [IntPoly]) {
+    let mut g = Integer::zero();
+    let mut c = Integer::zero();
+    for e in row.iter() {
+        unsafe {
+            fmpz_poly::fmpz_poly_content(c.as_mut_ptr(), e.as_ptr());
+            fmpz::fmpz_gcd(g.as_mut_ptr(), g.as_ptr(), c.as_ptr());
+        }
+    }
+    if g.is_zero() {
+        return;
+    }
+    if let Some((p, _)) = pivot(row) {
+        if lead(&row[p]) < 0 {
+            unsafe { fmpz::fmpz_neg(g.as_mut_ptr(), g.as_ptr()); }
+        }
+    }
+    if !g.is_one() {
+        for e in row.iter_mut() {
+            unsafe {
+                fmpz_poly::fmpz_poly_scalar_divexact_fmpz(e.as_mut_ptr(), e.as_ptr(), g.as_ptr());
+            }
+        }
+    }
+}
+
+// Cancel the leading term of `row[col]` against the pivot of `other`, which 
+// must be at column `col` with degree `deg`: row = a*row - b*x^k*other where 
+// `a` and `b` are the leading coefficients and `k` the difference in degree.
+fn reduce(row: &mut [IntPoly], other: &[IntPoly], col: usize, deg: i64) {
+    let a = lead(&other[col]);
+    let b = lead(&row[col]);
+    let k = row[col].degree() - deg;
+    debug_assert!(k >= 0);
+    let mut t = IntPoly::zero();
+    for (e, o) in row.iter_mut().zip(other) {
+        unsafe { fmpz_poly::fmpz_poly_shift_left(t.as_mut_ptr(), o.as_ptr(), k); }
+        *e = &(&*e * &a) - &(&t * &b);
+    }
+    normalize(row);
+}
+
+fn from_rows(rows: &[Vec<IntPoly>], nrows: i64, ncols: i64) -> IntPolyMat {
+    let mut res = IntPolyMat::zero(nrows, ncols);
+    for (i, row) in rows.iter().enumerate() {
+        for (j, e) in row.iter().enumerate() {
+            res.set_entry(i, j, e);
+        }
+    }
+    res
+}
+
+// Mulders-Storjohann: while two rows share a pivot column, cancel the pivot 
+// of the one of larger degree.
+fn weak_popov_rows(rows: &mut [Vec<IntPoly>]) {
+    loop {
+        let pivots: Vec<Option<(usize, i64)>> = rows.iter().map(|r| pivot(r)).collect();
+        let mut pair = None;
+        'search: for (i, pi) in pivots.iter().enumerate() {
+            for (j, pj) in pivots.iter().enumerate() {
+                if let (Some((ci, di)), Some((cj, dj))) = (pi, pj) {
+                    if i != j && ci == cj && di >= dj {
+                        pair = Some((i, j, *cj, *dj));
+                        break 'search;
+                    }
+                }
+            }
+        }
+        match pair {
+            Some((i, j, col, deg)) => {
+                check_interrupt();
+                let other = rows[j].clone();
+                reduce(&mut rows[i], &other, col, deg);
+            }
+            None => break,
+        }
+    }
+}
+
+impl IntPolyMat {
+    /// Return true if the pivots of the nonzero rows, their rightmost 
+    /// entries of maximal degree, are in distinct columns.
+    pub fn is_weak_popov(&self) -> bool {
+        let mut cols: Vec<usize> = self.get_rows().iter().filter_map(|r| pivot(r)).map(|p| p.0).collect();
+        let n = cols.len();
+        cols.sort_unstable();
+        cols.dedup();
+        cols.len() == n
+    }
+
+    /// Return a weak Popov form of the matrix over `Q[x]`: a matrix whose 
+    /// rows span the same `Q[x]`-module and whose nonzero rows have their 
+    /// pivots, the rightmost entries of maximal degree, in distinct 
+    /// columns. The form is computed with the Mulders–Storjohann algorithm. 
+    /// Since `Z[x]` is not a principal ideal domain, the rows are scaled to 
+    /// be primitive in `Z[x]` with positive leading coefficient at the 
+    /// pivot. Dependent rows become zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = IntPolyMat::new([
+    ///     IntPoly::from([1, 0, 1]), IntPoly::from([0, 1]),
+    ///     IntPoly::from([0, 0, 0, 1]), IntPoly::from([1, 0, 1]),
+    /// ], 2, 2);
+    /// assert!(!a.is_weak_popov());
+    /// let w = a.weak_popov_form();
+    /// assert!(w.is_weak_popov());
+    /// assert_eq!(w.det().degree(), a.det().degree());
+    /// ```
+    pub fn weak_popov_form(&self) -> IntPolyMat {
+        let mut rows = self.get_rows();
+        for row in rows.iter_mut() {
+            normalize(row);
+        }
+        weak_popov_rows(&mut rows);
+        from_rows(&rows, self.nrows_si(), self.ncols_si())
+    }
+
+    /// Return the Popov form of the matrix over `Q[x]`: a weak Popov form 
+    /// whose nonzero rows are sorted by the column of their pivot and in 
+    /// which every entry in the pivot column of another row has smaller 
+    /// degree than that pivot. Zero rows come last. Over `Q[x]` the form is 
+    /// unique once the pivots are made monic; here each row is instead 
+    /// scaled to be primitive in `Z[x]` with positive leading coefficient at 
+    /// the pivot, which is again unique.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = IntPolyMat::new([
+    ///     IntPoly::from([1, 0, 1]), IntPoly::from([0, 1]),
+    ///     IntPoly::from([0, 0, 0, 1]), IntPoly::from([1, 0, 1]),
+    /// ], 2, 2);
+    /// let p = a.popov_form();
+    /// assert!(p.is_popov());
+    /// // the form only depends on the row space
+    /// let u = IntPolyMat::new([
+    ///     IntPoly::from(1), IntPoly::from([0, 2]),
+    ///     IntPoly::from(0), IntPoly::from(-1),
+    /// ], 2, 2);
+    /// assert_eq!((&u * &a).popov_form(), p);
+    /// ```
+    pub fn popov_form(&self) -> IntPolyMat {
+        let mut rows = self.get_rows();
+        for row in rows.iter_mut() {
+            normalize(row);
+        }
+        weak_popov_rows(&mut rows);
+
+        // fully reduce each row modulo the others: the pivots form a Gröbner 
+        // basis for the degree-then-column order, and each step replaces the 
+        // largest reducible term by smaller ones
+        let pivots: Vec<Option<(usize, i64)>> = rows.iter().map(|r| pivot(r)).collect();
+        for i in 0..rows.len() {
+            if pivots[i].is_none() {
+                continue;
+            }
+            loop {
+                let mut best: Option<(i64, usize, usize)> = None;
+                for (j, pj) in pivots.iter().enumerate() {
+                    if let Some((c, d)) = *pj {
+                        let e = rows[i][c].degree();
+                        if j != i && e >= d && best.map_or(true, |b| (e, c) > (b.0, b.1)) {
+                            best = Some((e, c, j));
+                        }
+                    }
+                }
+                match best {
+                    Some((_, c, j)) => {
+                        check_interrupt();
+                        let other = rows[j].clone();
+                        reduce(&mut rows[i], &other, c, pivots[j].unwrap().1);
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        order.sort_by_key(|&i| pivots[i].map_or(usize::MAX, |p| p.0));
+        let sorted: Vec<Vec<IntPoly>> = order.into_iter().map(|i| rows[i].clone()).collect();
+        from_rows(&sorted, self.nrows_si(), self.ncols_si())
+    }
+
+    /// Return true if the matrix is in [Popov form](IntPolyMat::popov_form), 
+    /// up to scaling the rows by nonzero constants.
+    pub fn is_popov(&self) -> bool {
+        if !self.is_weak_popov() {
+            return false;
+        }
+        let rows = self.get_rows();
+        let pivots: Vec<Option<(usize, i64)>> = rows.iter().map(|r| pivot(r)).collect();
+
+        // nonzero rows sorted by pivot column, then zero rows
+        let keys: Vec<usize> = pivots.iter().map(|p| p.map_or(usize::MAX, |p| p.0)).collect();
+        if keys.windows(2).any(|w| w[0] > w[1]) {
+            return false;
+        }
+        for (i, row) in rows.iter().enumerate() {
+            for (j, pj) in pivots.iter().enumerate() {
+                if let Some((c, d)) = *pj {
+                    if i != j && row[c].degree() >= d {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+}
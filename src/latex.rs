@@ -0,0 +1,213 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+
+/// LaTeX output for notebooks and papers.
+///
+/// Polynomials are written in the variable passed to
+/// [to_latex_var](ToLatex::to_latex_var), or in `x` by
+/// [to_latex](ToLatex::to_latex). Number field elements are written as
+/// polynomials in the generator, and matrices use the `pmatrix` environment.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let f = RatPoly::from([[1, 2], [0, 1], [-3, 1]]);
+/// assert_eq!(f.to_latex(), "-3x^{2} + \\frac{1}{2}");
+/// assert_eq!(f.to_latex_var("t"), "-3t^{2} + \\frac{1}{2}");
+///
+/// let m = IntMat::new([1, -2, 0, 4], 2, 2);
+/// assert_eq!(m.to_latex(), "\\begin{pmatrix} 1 & -2 \\\\ 0 & 4 \\end{pmatrix}");
+/// ```
+pub trait ToLatex {
+    /// Return the LaTeX representation, using `var` as the name of the
+    /// polynomial variable.
+    fn to_latex_var(&self, var: &str) -> String;
+
+    /// Return the LaTeX representation, with polynomials in `x`.
+    #[inline]
+    fn to_latex(&self) -> String {
+        self.to_latex_var("x")
+    }
+}
+
+// Join the nonzero terms `(k, sign, |c|)` of a polynomial, given by
+// decreasing exponent `k` with the LaTeX representation of the absolute value
+// of the coefficient.
+fn latex_poly<I>(terms: I, var: &str) -> String
+where
+    I: IntoIterator<Item = (usize, i32, String)>
+{
+    let mut out = String::new();
+    for (k, s, abs) in terms {
+        if out.is_empty() {
+            if s < 0 {
+                out.push('-');
+            }
+        } else if s < 0 {
+            out.push_str(" - ");
+        } else {
+            out.push_str(" + ");
+        }
+
+        if k == 0 || abs != "1" {
+            out.push_str(&abs);
+        }
+        if k == 1 {
+            out.push_str(var);
+        } else if k > 1 {
+            out.push_str(&format!("{}^{{{}}}", var, k));
+        }
+    }
+    if out.is_empty() {
+        out.push('0');
+    }
+    out
+}
+
+// Wrap the rows of entries in a `pmatrix` environment.
+fn latex_matrix<I, R>(rows: I) -> String
+where
+    I: IntoIterator<Item = R>,
+    R: IntoIterator<Item = String>
+{
+    let rows: Vec<String> = rows
+        .into_iter()
+        .map(|row| row.into_iter().collect::<Vec<_>>().join(" & "))
+        .collect();
+    format!("\\begin{{pmatrix}} {} \\end{{pmatrix}}", rows.join(" \\\\ "))
+}
+
+impl ToLatex for Integer {
+    #[inline]
+    fn to_latex_var(&self, _var: &str) -> String {
+        self.to_string()
+    }
+}
+
+impl ToLatex for Rational {
+    fn to_latex_var(&self, _var: &str) -> String {
+        let d = self.denominator();
+        if d.is_one() {
+            return self.numerator().to_string();
+        }
+        let n = self.numerator();
+        let sign = if n.sign() < 0 { "-" } else { "" };
+        format!("{}\\frac{{{}}}{{{}}}", sign, n.abs(), d)
+    }
+}
+
+impl ToLatex for IntMod {
+    #[inline]
+    fn to_latex_var(&self, _var: &str) -> String {
+        self.to_string()
+    }
+}
+
+impl ToLatex for Nmod {
+    #[inline]
+    fn to_latex_var(&self, _var: &str) -> String {
+        self.value().to_string()
+    }
+}
+
+impl ToLatex for IntPoly {
+    fn to_latex_var(&self, var: &str) -> String {
+        let coeffs = self.get_coeffs();
+        let terms = coeffs.iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(k, c)| (k, c.sign(), c.abs().to_string()));
+        latex_poly(terms, var)
+    }
+}
+
+impl ToLatex for RatPoly {
+    fn to_latex_var(&self, var: &str) -> String {
+        let coeffs = self.get_coeffs();
+        let terms = coeffs.iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(k, c)| (k, c.sign(), c.abs().to_latex()));
+        latex_poly(terms, var)
+    }
+}
+
+/// ```
+/// use inertia_core::*;
+///
+/// let f = RatFunc::from_poly_quotient(IntPoly::from([1, 1]), IntPoly::from([0, 0, 2]));
+/// assert_eq!(f.unwrap().to_latex_var("y"), "\\frac{y + 1}{2y^{2}}");
+/// ```
+impl ToLatex for RatFunc {
+    fn to_latex_var(&self, var: &str) -> String {
+        let num = self.numerator().to_latex_var(var);
+        let den = self.denominator();
+        if den.is_one() {
+            num
+        } else {
+            format!("\\frac{{{}}}{{{}}}", num, den.to_latex_var(var))
+        }
+    }
+}
+
+impl ToLatex for NumFldElem {
+    #[inline]
+    fn to_latex_var(&self, var: &str) -> String {
+        RatPoly::from(self).to_latex_var(var)
+    }
+}
+
+impl<T: ToLatex> ToLatex for Series<T> {
+    fn to_latex_var(&self, var: &str) -> String {
+        let poly = self.poly().to_latex_var(var);
+        format!("{} + O({}^{{{}}})", poly, var, self.prec())
+    }
+}
+
+impl ToLatex for IntMat {
+    fn to_latex_var(&self, _var: &str) -> String {
+        latex_matrix(self.rows().map(|row| row.iter().map(|x| x.to_string())))
+    }
+}
+
+impl ToLatex for RatMat {
+    fn to_latex_var(&self, var: &str) -> String {
+        latex_matrix(self.rows().map(|row| row.iter().map(|x| x.to_latex_var(var))))
+    }
+}
+
+impl ToLatex for IntModMat {
+    fn to_latex_var(&self, _var: &str) -> String {
+        latex_matrix(self.rows().map(|row| row.iter().map(|x| x.to_string())))
+    }
+}
+
+impl ToLatex for NmodMat {
+    fn to_latex_var(&self, _var: &str) -> String {
+        latex_matrix(self.rows().map(|row| row.iter().map(|x| x.to_string())))
+    }
+}
+
+impl ToLatex for IntPolyMat {
+    fn to_latex_var(&self, var: &str) -> String {
+        latex_matrix(self.get_rows().iter().map(|row| row.iter().map(|x| x.to_latex_var(var))))
+    }
+}
@@ -17,13 +17,24 @@
 
 #![allow(unused_macros)]
 
+#[cfg(all(target_os = "emscripten", feature = "rug"))]
+compile_error!("The `rug` feature is not supported on emscripten targets.");
+
 #[macro_use]
 mod macros;
 mod error;
+mod checked;
+mod rand;
+mod primes;
+mod interrupt;
+mod memory;
+mod multiexp;
+mod parse;
 
 mod integer;
 mod intpoly;
 mod intmat;
+mod intpolymat;
 
 mod rational;
 mod ratpoly;
@@ -33,31 +44,222 @@ mod intmod;
 mod intmodpoly;
 mod intmodmat;
 
+mod nmod;
+mod nmodpoly;
+mod nmodmat;
+
 mod finfld;
 mod finfldpoly;
 mod finfldmat;
 
-//mod intmpoly;
+mod sparse;
+mod vector;
+
+mod intmpoly;
 pub mod ratfunc;
+mod series;
+mod subproduct;
+mod prs;
+mod latex;
+mod parent;
+mod coerce;
+mod polymap;
 
 mod real;
 mod complex;
 
 pub mod binquad;
+pub mod quadratic;
 pub mod numfld;
 
+pub mod prelude;
+pub mod tuning;
+
+#[cfg(feature = "serde")]
+pub mod serde_readable;
+
+#[cfg(feature = "capi")]
+pub mod capi;
+
 mod util {
-    #[must_use]
-    #[inline]
-    pub fn is_digit(c: char) -> bool {
-        match c {
-            '0'..='9' => true,
-            _ => false,
+    use flint_sys::fmpq_mat;
+    use std::fmt;
+    use std::mem::MaybeUninit;
+
+    // An empty `n x 0` matrix still allocates `n` row pointers, so bound the
+    // number of rows of an empty matrix read from untrusted input.
+    #[cfg(feature = "serde")]
+    const MAX_EMPTY_ROWS: i64 = 1 << 20;
+
+    /// Read the entries of a serialized `nrows x ncols` matrix in row-major 
+    /// order. The dimensions come from untrusted input, so they are checked 
+    /// against the number of entries actually supplied before the caller 
+    /// allocates the matrix. `offset` is the number of elements of the 
+    /// sequence already read, for error messages.
+    #[cfg(feature = "serde")]
+    pub fn read_matrix_entries<'de, A, T>(
+        access: &mut A,
+        nrows: i64,
+        ncols: i64,
+        offset: usize,
+        exp: &dyn serde::de::Expected
+    ) -> Result<Vec<T>, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+        T: serde::de::Deserialize<'de>
+    {
+        use serde::de::Error;
+        if nrows < 0 || ncols < 0 {
+            return Err(A::Error::custom("negative matrix dimensions"));
+        }
+        let len = nrows
+            .checked_mul(ncols)
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| A::Error::custom("matrix dimensions overflow"))?;
+        if len == 0 && nrows > MAX_EMPTY_ROWS {
+            return Err(A::Error::custom("too many rows for an empty matrix"));
         }
+
+        let mut entries = Vec::new();
+        while let Some(x) = access.next_element()? {
+            if entries.len() == len {
+                return Err(A::Error::invalid_length(offset + len + 1, exp));
+            }
+            entries.push(x);
+        }
+        if entries.len() != len {
+            return Err(A::Error::invalid_length(offset + entries.len(), exp));
+        }
+        Ok(entries)
+    }
+
+    /// Fill a new `r x c` rational matrix with `op` and swap it into `res`,
+    /// so that `res` may alias an operand and need not have the right
+    /// dimensions.
+    pub unsafe fn fmpq_mat_swap_into<F>(
+        res: *mut fmpq_mat::fmpq_mat_struct,
+        r: i64,
+        c: i64,
+        op: F
+    )
+    where
+        F: FnOnce(*mut fmpq_mat::fmpq_mat_struct)
+    {
+        let mut z = MaybeUninit::uninit();
+        fmpq_mat::fmpq_mat_init(z.as_mut_ptr(), r, c);
+        op(z.as_mut_ptr());
+        fmpq_mat::fmpq_mat_swap(res, z.as_mut_ptr());
+        fmpq_mat::fmpq_mat_clear(z.as_mut_ptr());
+    }
+
+    /// Debug formatting of a list using the `Display` impl of the entries,
+    /// e.g. `[1, x + 1]`.
+    pub struct DisplayList<'a, T>(pub &'a [T]);
+
+    impl<T: fmt::Display> fmt::Debug for DisplayList<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut list = f.debug_list();
+            for x in self.0 {
+                list.entry(&format_args!("{}", x));
+            }
+            list.finish()
+        }
+    }
+
+    /// Display formatting of the rows of a matrix, one row per line, e.g.
+    /// `[ 1  -2 ]` and `[ 0   4 ]`. If `f` has a width, it is applied to
+    /// each entry together with the fill, alignment and sign options,
+    /// otherwise the entries are right-aligned to the width of their column.
+    pub fn fmt_rows<'a, T, I>(f: &mut fmt::Formatter<'_>, rows: I) -> fmt::Result
+    where
+        T: fmt::Display + 'a,
+        I: IntoIterator<Item = &'a [T]>
+    {
+        if f.width().is_some() {
+            for (i, row) in rows.into_iter().enumerate() {
+                if i > 0 {
+                    f.write_str("\n")?;
+                }
+                f.write_str("[")?;
+                for x in row {
+                    f.write_str(" ")?;
+                    fmt::Display::fmt(x, f)?;
+                    f.write_str(" ")?;
+                }
+                f.write_str("]")?;
+            }
+            return Ok(());
+        }
+
+        let cells: Vec<Vec<String>> = rows
+            .into_iter()
+            .map(|row| row.iter().map(|x| x.to_string()).collect())
+            .collect();
+        let mut widths: Vec<usize> = Vec::new();
+        for row in cells.iter() {
+            for (j, s) in row.iter().enumerate() {
+                let w = s.chars().count();
+                match widths.get_mut(j) {
+                    Some(m) => *m = (*m).max(w),
+                    None => widths.push(w),
+                }
+            }
+        }
+        for (i, row) in cells.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str("[")?;
+            for (s, w) in row.iter().zip(widths.iter()) {
+                write!(f, " {:>w$} ", s, w = w)?;
+            }
+            f.write_str("]")?;
+        }
+        Ok(())
+    }
+
+    /// Debug formatting of the rows of a matrix, e.g. `[[1, 2], [3, 4]]`.
+    pub struct DisplayRows<'a, T>(pub Vec<&'a [T]>);
+
+    impl<T: fmt::Display> fmt::Debug for DisplayRows<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_list().entries(self.0.iter().map(|row| DisplayList(*row))).finish()
+        }
+    }
+
+    /// Return true if the permutation `i -> perm[i]` is odd. Each cycle of 
+    /// length `l` contributes `l - 1` transpositions.
+    pub fn perm_is_odd(perm: &[usize]) -> bool {
+        let mut seen = vec![false; perm.len()];
+        let mut odd = false;
+        for i in 0..perm.len() {
+            if seen[i] {
+                continue;
+            }
+            let mut j = i;
+            while !seen[j] {
+                seen[j] = true;
+                j = perm[j];
+                odd = !odd;
+            }
+            odd = !odd;
+        }
+        odd
     }
 }
 
 pub use error::{Error, Result};
+pub use rand::FlintRand;
+pub use primes::ModularPrimes;
+pub use interrupt::{check_interrupt, interrupt_requested, with_interrupt_check};
+pub use latex::ToLatex;
+pub use memory::{
+    MemoryStats, 
+    enable_memory_tracking, 
+    flint_memory_stats, 
+    reset_peak_memory,
+    use_global_allocator
+};
 pub use inertia_algebra::ops::*;
 
 pub use integer::*;
@@ -65,6 +267,7 @@ pub use integer::macros::*;
 
 pub use intpoly::*;
 pub use intmat::*;
+pub use intpolymat::*;
 
 pub use rational::*;
 pub use ratpoly::*;
@@ -74,12 +277,24 @@ pub use intmod::*;
 pub use intmodpoly::*;
 pub use intmodmat::*;
 
+pub use nmod::*;
+pub use nmodpoly::*;
+pub use nmodmat::*;
+
 pub use finfld::*;
 pub use finfldpoly::*;
 pub use finfldmat::*;
 
-//pub use intmpoly::*;
+pub use sparse::*;
+pub use vector::*;
+
+pub use intmpoly::*;
 pub use ratfunc::*;
+pub use series::{Series, SeriesPoly, RatSeries};
+pub use subproduct::{SubproductTree, TreePoly};
+pub use parent::*;
+pub use coerce::Coerce;
+pub use polymap::{PolyMap, PolyCoeffs};
 
 pub use real::*;
 pub use complex::*;
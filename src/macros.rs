@@ -1567,3 +1567,208 @@ macro_rules! impl_assign_unsafe {
     )*);
 }
 
+
+// Enter a `tracing` span at debug level for the rest of the enclosing block
+// when the `trace` feature is enabled. Field expressions are only evaluated
+// when tracing is compiled in.
+macro_rules! trace_span {
+    // the dimensions and entry size of an integer matrix
+    ($name:literal, mat = $m:expr) => {
+        trace_span!($name, nrows = $m.nrows(), ncols = $m.ncols(), bits = $m.max_bits());
+    };
+    ($name:literal $(, $($field:tt)+)?) => {
+        #[cfg(feature = "trace")]
+        let _span = tracing::debug_span!($name $(, $($field)+)?).entered();
+    };
+}
+
+// Emit a `tracing` event at debug level when the `trace` feature is enabled.
+macro_rules! trace_event {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "trace")]
+        tracing::debug!($($arg)+);
+    };
+}
+
+// Allocate a `Real` or `Complex` result with working precision `prec` and 
+// run the unsafe `body` with the precision converted to a signed long.
+macro_rules! eval_prec {
+    ($t:ident, $prec:expr, |$res:ident, $p:ident| $body:expr) => {{
+        let mut $res = $t::default();
+        $res.prec = $prec;
+        let $p = $res.prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe { $body; }
+        $res
+    }};
+}
+
+// Arithmetic on Arb/Acb balls, which rounds to the larger of the working 
+// precisions of the operands. The functions take `(res, x, y, prec)`, or 
+// `(res, x)` for negation.
+macro_rules! impl_ball_ops {
+    (
+        $t:ident
+        $neg:ident;
+        $($op:ident {$meth:ident} $op_assign:ident {$meth_assign:ident} $func:ident;)+
+    ) => {
+        impl Neg for &$t {
+            type Output = $t;
+            #[inline]
+            fn neg(self) -> $t {
+                let mut res = $t::default();
+                res.prec = self.prec;
+                unsafe { $neg(res.as_mut_ptr(), self.as_ptr()); }
+                res
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+            #[inline]
+            fn neg(mut self) -> $t {
+                unsafe { $neg(self.as_mut_ptr(), self.as_ptr()); }
+                self
+            }
+        }
+        $(
+            impl $op<&$t> for &$t {
+                type Output = $t;
+                #[inline]
+                fn $meth(self, rhs: &$t) -> $t {
+                    let mut res = $t::default();
+                    res.prec = self.prec.max(rhs.prec);
+                    let prec = res.prec.try_into().expect(
+                        "Cannot convert precision to a signed long.");
+                    unsafe { $func(res.as_mut_ptr(), self.as_ptr(), rhs.as_ptr(), prec); }
+                    res
+                }
+            }
+
+            impl $op<$t> for &$t {
+                type Output = $t;
+                #[inline]
+                fn $meth(self, rhs: $t) -> $t {
+                    self.$meth(&rhs)
+                }
+            }
+
+            impl $op<&$t> for $t {
+                type Output = $t;
+                #[inline]
+                fn $meth(mut self, rhs: &$t) -> $t {
+                    self.$meth_assign(rhs);
+                    self
+                }
+            }
+
+            impl $op<$t> for $t {
+                type Output = $t;
+                #[inline]
+                fn $meth(mut self, rhs: $t) -> $t {
+                    self.$meth_assign(&rhs);
+                    self
+                }
+            }
+
+            impl $op_assign<&$t> for $t {
+                #[inline]
+                fn $meth_assign(&mut self, rhs: &$t) {
+                    self.prec = self.prec.max(rhs.prec);
+                    let prec = self.prec.try_into().expect(
+                        "Cannot convert precision to a signed long.");
+                    unsafe { $func(self.as_mut_ptr(), self.as_ptr(), rhs.as_ptr(), prec); }
+                }
+            }
+
+            impl $op_assign<$t> for $t {
+                #[inline]
+                fn $meth_assign(&mut self, rhs: $t) {
+                    self.$meth_assign(&rhs);
+                }
+            }
+        )+
+    };
+}
+
+// Arithmetic on the nmod types, which carry their modulus inline. The 
+// functions take `(res, x, y)`, or `(res, x)` for negation, and `$init` 
+// allocates the result from the operands `$a` and `$b`, checking that they
+// are compatible.
+macro_rules! impl_nmod_ops {
+    (
+        $t:ident
+        $neg:path;
+        $($op:ident {$meth:ident} $op_assign:ident {$meth_assign:ident} 
+          $func:path, |$a:ident, $b:ident| $init:expr;)+
+    ) => {
+        impl Neg for &$t {
+            type Output = $t;
+            #[inline]
+            fn neg(self) -> $t {
+                let mut res = self.clone();
+                unsafe { $neg(res.as_mut_ptr(), self.as_ptr()); }
+                res
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+            #[inline]
+            fn neg(mut self) -> $t {
+                unsafe { $neg(self.as_mut_ptr(), self.as_ptr()); }
+                self
+            }
+        }
+        $(
+            impl $op<&$t> for &$t {
+                type Output = $t;
+                #[inline]
+                fn $meth(self, rhs: &$t) -> $t {
+                    assert_eq!(self.context(), rhs.context());
+                    let ($a, $b) = (self, rhs);
+                    let mut res = $init;
+                    unsafe { $func(res.as_mut_ptr(), self.as_ptr(), rhs.as_ptr()); }
+                    res
+                }
+            }
+
+            impl $op<$t> for &$t {
+                type Output = $t;
+                #[inline]
+                fn $meth(self, rhs: $t) -> $t {
+                    self.$meth(&rhs)
+                }
+            }
+
+            impl $op<&$t> for $t {
+                type Output = $t;
+                #[inline]
+                fn $meth(self, rhs: &$t) -> $t {
+                    (&self).$meth(rhs)
+                }
+            }
+
+            impl $op<$t> for $t {
+                type Output = $t;
+                #[inline]
+                fn $meth(self, rhs: $t) -> $t {
+                    (&self).$meth(&rhs)
+                }
+            }
+
+            impl $op_assign<&$t> for $t {
+                #[inline]
+                fn $meth_assign(&mut self, rhs: &$t) {
+                    *self = (&*self).$meth(rhs);
+                }
+            }
+
+            impl $op_assign<$t> for $t {
+                #[inline]
+                fn $meth_assign(&mut self, rhs: $t) {
+                    *self = (&*self).$meth(&rhs);
+                }
+            }
+        )+
+    };
+}
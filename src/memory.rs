@@ -0,0 +1,213 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use flint_sys::flint;
+use libc::c_void;
+use std::alloc::{self, Layout};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+// Every tracked block is prefixed by a header holding its size. 16 bytes keeps
+// the returned pointer aligned like malloc's.
+const HEADER: usize = 16;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// A snapshot of the memory allocated through FLINT's memory manager, see
+/// [enable_memory_tracking].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Bytes currently allocated.
+    pub current_bytes: usize,
+    /// Largest value of `current_bytes` since tracking was enabled or the peak
+    /// was last reset.
+    pub peak_bytes: usize,
+    /// Number of allocations (including reallocations) since tracking was
+    /// enabled.
+    pub allocations: usize,
+}
+
+/// Route FLINT's memory manager through counting wrappers around the system
+/// allocator so that [flint_memory_stats] reports live usage. Calling this
+/// more than once has no further effect.
+///
+/// Only allocations made through FLINT's `flint_malloc` family are counted.
+/// In particular the limbs of large integers are allocated by GMP and are not
+/// included; see [Integer::allocated_limbs](crate::Integer::allocated_limbs)
+/// and [IntMat::memory_footprint](crate::IntMat::memory_footprint) for those.
+///
+/// # Safety
+///
+/// Blocks allocated by FLINT before the hooks are installed would be released
+/// through the new hooks, so this must be called before any FLINT object
+/// (including thread local caches) is created.
+pub unsafe fn enable_memory_tracking() {
+    if ENABLED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    flint::__flint_set_memory_functions(
+        Some(tracked_malloc),
+        Some(tracked_calloc),
+        Some(tracked_realloc),
+        Some(tracked_free)
+    );
+}
+
+/// Route FLINT's memory manager through Rust's global allocator instead of 
+/// the C allocator, counting usage as [enable_memory_tracking] does. On 
+/// `wasm32-unknown-emscripten` this keeps FLINT from growing a second heap in
+/// linear memory when the application installs its own `#[global_allocator]`.
+/// Only the first call to this or [enable_memory_tracking] has an effect. In
+/// both cases the limbs of large integers are still allocated by GMP.
+///
+/// # Safety
+///
+/// As for [enable_memory_tracking], this must be called before any FLINT 
+/// object is created.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// unsafe { use_global_allocator(); }
+/// let m = IntMat::one(3);
+/// assert_eq!(m.det(), 1);
+/// assert!(flint_memory_stats().allocations > 0);
+/// ```
+pub unsafe fn use_global_allocator() {
+    if ENABLED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    flint::__flint_set_memory_functions(
+        Some(global_malloc),
+        Some(global_calloc),
+        Some(global_realloc),
+        Some(global_free)
+    );
+}
+
+/// Return the current memory statistics. All counts are zero unless
+/// [enable_memory_tracking] or [use_global_allocator] has been called.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let stats = flint_memory_stats();
+/// assert!(stats.current_bytes <= stats.peak_bytes);
+/// ```
+pub fn flint_memory_stats() -> MemoryStats {
+    MemoryStats {
+        current_bytes: CURRENT.load(Ordering::Relaxed),
+        peak_bytes: PEAK.load(Ordering::Relaxed),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+/// Reset the peak to the current usage, to measure the peak of a single
+/// computation.
+pub fn reset_peak_memory() {
+    PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+// Write the header and return the user pointer, or null if `base` is null.
+unsafe fn finish(base: *mut c_void, size: usize) -> *mut c_void {
+    if base.is_null() {
+        return base;
+    }
+    *(base as *mut usize) = size;
+    record_alloc(size);
+    (base as *mut u8).add(HEADER) as *mut c_void
+}
+
+unsafe extern "C" fn tracked_malloc(size: usize) -> *mut c_void {
+    finish(libc::malloc(size + HEADER), size)
+}
+
+unsafe extern "C" fn tracked_calloc(num: usize, size: usize) -> *mut c_void {
+    let total = num.checked_mul(size).expect("Allocation size overflow.");
+    finish(libc::calloc(1, total + HEADER), total)
+}
+
+unsafe extern "C" fn tracked_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    if ptr.is_null() {
+        return tracked_malloc(size);
+    }
+    let base = (ptr as *mut u8).sub(HEADER) as *mut c_void;
+    let old = *(base as *const usize);
+    let new = libc::realloc(base, size + HEADER);
+    if new.is_null() {
+        return new;
+    }
+    CURRENT.fetch_sub(old, Ordering::Relaxed);
+    finish(new, size)
+}
+
+unsafe extern "C" fn tracked_free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    let base = (ptr as *mut u8).sub(HEADER) as *mut c_void;
+    CURRENT.fetch_sub(*(base as *const usize), Ordering::Relaxed);
+    libc::free(base);
+}
+
+// The layout of a block of `size` bytes after the header.
+fn layout(size: usize) -> Layout {
+    size.checked_add(HEADER)
+        .and_then(|n| Layout::from_size_align(n, HEADER).ok())
+        .expect("Allocation size overflow.")
+}
+
+unsafe extern "C" fn global_malloc(size: usize) -> *mut c_void {
+    finish(alloc::alloc(layout(size)) as *mut c_void, size)
+}
+
+unsafe extern "C" fn global_calloc(num: usize, size: usize) -> *mut c_void {
+    let total = num.checked_mul(size).expect("Allocation size overflow.");
+    finish(alloc::alloc_zeroed(layout(total)) as *mut c_void, total)
+}
+
+unsafe extern "C" fn global_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    if ptr.is_null() {
+        return global_malloc(size);
+    }
+    let base = (ptr as *mut u8).sub(HEADER);
+    let old = *(base as *const usize);
+    let new = alloc::realloc(base, layout(old), layout(size).size()) as *mut c_void;
+    if new.is_null() {
+        return new;
+    }
+    CURRENT.fetch_sub(old, Ordering::Relaxed);
+    finish(new, size)
+}
+
+unsafe extern "C" fn global_free(ptr: *mut c_void) {
+    if ptr.is_null() {
+        return;
+    }
+    let base = (ptr as *mut u8).sub(HEADER);
+    let old = *(base as *const usize);
+    CURRENT.fetch_sub(old, Ordering::Relaxed);
+    alloc::dealloc(base, layout(old));
+}
@@ -0,0 +1,126 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// Simultaneous exponentiation `prod b_i^e_i` for nonnegative exponents, 
+// shared by the types with a multiplicative group. Few bases use Straus' 
+// interleaved windows, many bases use Pippenger's bucket method.
+
+use crate::{tuning, Integer};
+use std::ops::MulAssign;
+
+const STRAUS_WINDOW: u64 = 4;
+
+// The `w` bit digit of `e` starting at bit `start`.
+fn digit(e: &Integer, start: u64, w: u64) -> usize {
+    (0..w).filter(|&k| e.testbit(start + k)).fold(0, |d, k| d | 1 << k)
+}
+
+fn square<T>(x: &mut T)
+where
+    T: Clone,
+    for<'a> T: MulAssign<&'a T>,
+{
+    let y = x.clone();
+    *x *= &y;
+}
+
+pub(crate) fn multi_exp<T>(one: T, bases: &[T], exps: &[Integer]) -> T
+where
+    T: Clone,
+    for<'a> T: MulAssign<&'a T>,
+{
+    assert_eq!(bases.len(), exps.len(), "The number of bases and exponents differ.");
+    debug_assert!(exps.iter().all(|e| e >= &0));
+    let bits = exps.iter().map(Integer::bits).max().unwrap_or(0);
+    if bits == 0 {
+        return one;
+    }
+    if (bases.len() as u64) < tuning::multi_exp_pippenger_cutoff() {
+        straus(one, bases, exps, bits)
+    } else {
+        pippenger(one, bases, exps, bits)
+    }
+}
+
+fn straus<T>(one: T, bases: &[T], exps: &[Integer], bits: u64) -> T
+where
+    T: Clone,
+    for<'a> T: MulAssign<&'a T>,
+{
+    let w = STRAUS_WINDOW;
+    // tables[i][d] = b_i^d
+    let tables: Vec<Vec<T>> = bases
+        .iter()
+        .map(|b| {
+            let mut row = Vec::with_capacity(1 << w);
+            let mut x = one.clone();
+            for _ in 0..1u64 << w {
+                row.push(x.clone());
+                x *= b;
+            }
+            row
+        })
+        .collect();
+
+    let mut res = one;
+    for j in (0..(bits + w - 1) / w).rev() {
+        for _ in 0..w {
+            square(&mut res);
+        }
+        for (e, row) in exps.iter().zip(tables.iter()) {
+            let d = digit(e, j * w, w);
+            if d != 0 {
+                res *= &row[d];
+            }
+        }
+    }
+    res
+}
+
+fn pippenger<T>(one: T, bases: &[T], exps: &[Integer], bits: u64) -> T
+where
+    T: Clone,
+    for<'a> T: MulAssign<&'a T>,
+{
+    // about log2(n) - 2 bits per window balances the bucket sums against the
+    // number of windows
+    let n = bases.len() as u64;
+    let c = (63 - n.leading_zeros() as u64).saturating_sub(2).max(1);
+
+    let mut res = one.clone();
+    for j in (0..(bits + c - 1) / c).rev() {
+        for _ in 0..c {
+            square(&mut res);
+        }
+        let mut buckets = vec![one.clone(); 1 << c];
+        for (b, e) in bases.iter().zip(exps) {
+            let d = digit(e, j * c, c);
+            if d != 0 {
+                buckets[d] *= b;
+            }
+        }
+        // prod_d buckets[d]^d as a product of running products
+        let mut acc = one.clone();
+        let mut sum = one.clone();
+        for bucket in buckets[1..].iter().rev() {
+            acc *= bucket;
+            sum *= &acc;
+        }
+        res *= &sum;
+    }
+    res
+}
@@ -0,0 +1,304 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod ops;
+
+use crate::*;
+use flint_sys::flint::nmod_t;
+use flint_sys::{fmpz, nmod_vec, ulong_extras};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+
+/// The ring of integers modulo a machine word `n`, the counterpart of 
+/// [IntModCtx] backed by FLINT's `nmod` functions. Elements, polynomials and
+/// matrices store the modulus and its precomputed inverse inline, so there is
+/// no reference counting and arithmetic avoids multiprecision integers 
+/// entirely.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let zn = NmodRing::new(17);
+/// let x = zn.element(5);
+/// assert_eq!((x * x).value(), 8);
+/// assert_eq!(x.inv().unwrap() * x, zn.one());
+/// assert_eq!(zn.element(-1).value(), 16);
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct NmodRing {
+    inner: nmod_t,
+}
+
+impl Eq for NmodRing {}
+
+impl PartialEq for NmodRing {
+    #[inline]
+    fn eq(&self, rhs: &NmodRing) -> bool {
+        self.modulus() == rhs.modulus()
+    }
+}
+
+impl fmt::Debug for NmodRing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NmodRing")
+            .field("modulus", &self.modulus())
+            .finish()
+    }
+}
+
+impl fmt::Display for NmodRing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ring of integers modulo {}", self.modulus())
+    }
+}
+
+impl Hash for NmodRing {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.modulus().hash(state)
+    }
+}
+
+impl AsRef<NmodRing> for NmodRing {
+    #[inline]
+    fn as_ref(&self) -> &NmodRing {
+        self
+    }
+}
+
+impl NmodRing {
+    /// Return the ring of integers modulo `n`. Panics if `n` is zero.
+    pub fn new(n: u64) -> Self {
+        assert!(n > 0, "The modulus must be positive.");
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            nmod_vec::nmod_init(z.as_mut_ptr(), n);
+            NmodRing { inner: z.assume_init() }
+        }
+    }
+
+    // Borrow a FLINT modulus as a ring. This relies on the transparent 
+    // representation.
+    #[inline]
+    pub(crate) fn from_nmod_ref(x: &nmod_t) -> &NmodRing {
+        unsafe { &*(x as *const nmod_t as *const NmodRing) }
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const nmod_t {
+        &self.inner
+    }
+
+    /// Return the underlying FLINT modulus.
+    #[inline]
+    pub const fn as_raw(&self) -> nmod_t {
+        self.inner
+    }
+
+    #[inline]
+    pub const fn modulus(&self) -> u64 {
+        self.inner.n
+    }
+
+    /// Return the element `x` modulo `n` of this ring.
+    #[inline]
+    pub fn element<T: Into<Integer>>(&self, x: T) -> Nmod {
+        Nmod::new(x, self)
+    }
+
+    /// Return the zero element of this ring.
+    #[inline]
+    pub fn zero(&self) -> Nmod {
+        Nmod::zero(self)
+    }
+
+    /// Return the one element of this ring.
+    #[inline]
+    pub fn one(&self) -> Nmod {
+        Nmod::one(self)
+    }
+
+    /// Return the zero polynomial over this ring.
+    #[inline]
+    pub fn zero_poly(&self) -> NmodPoly {
+        NmodPoly::zero(self)
+    }
+
+    /// Return the `nrows` by `ncols` zero matrix over this ring.
+    #[inline]
+    pub fn zero_mat(&self, nrows: i64, ncols: i64) -> NmodMat {
+        NmodMat::zero(nrows, ncols, self)
+    }
+}
+
+/// An integer modulo a machine word, see [NmodRing].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Nmod {
+    val: u64,
+    ctx: NmodRing,
+}
+
+impl AsRef<Nmod> for Nmod {
+    #[inline]
+    fn as_ref(&self) -> &Nmod {
+        self
+    }
+}
+
+impl AsRef<NmodRing> for Nmod {
+    #[inline]
+    fn as_ref(&self) -> &NmodRing {
+        self.context()
+    }
+}
+
+impl fmt::Debug for Nmod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Nmod")
+            .field("value", &self.val)
+            .field("modulus", &self.modulus())
+            .finish()
+    }
+}
+
+impl fmt::Display for Nmod {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.val, f)
+    }
+}
+
+impl<T: Into<Integer>> NewCtx<T, NmodRing> for Nmod {
+    fn new(src: T, ctx: &NmodRing) -> Self {
+        let val = unsafe { fmpz::fmpz_fdiv_ui(src.into().as_ptr(), ctx.modulus()) };
+        Nmod { val, ctx: *ctx }
+    }
+}
+
+impl Nmod {
+    /// Return the residue `val` modulo `n` without reducing it. 
+    ///
+    /// # Safety
+    ///
+    /// The arithmetic assumes `val` is less than the modulus.
+    #[inline]
+    pub const unsafe fn from_raw(val: u64, ctx: NmodRing) -> Nmod {
+        Nmod { val, ctx }
+    }
+
+    #[inline]
+    pub fn zero(ctx: &NmodRing) -> Nmod {
+        Nmod { val: 0, ctx: *ctx }
+    }
+
+    #[inline]
+    pub fn one(ctx: &NmodRing) -> Nmod {
+        Nmod { val: u64::from(ctx.modulus() != 1), ctx: *ctx }
+    }
+
+    /// Return the canonical representative in `[0, n)`.
+    #[inline]
+    pub const fn value(&self) -> u64 {
+        self.val
+    }
+
+    #[inline]
+    pub const fn context(&self) -> &NmodRing {
+        &self.ctx
+    }
+
+    #[inline]
+    pub const fn modulus(&self) -> u64 {
+        self.ctx.modulus()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.val == 0
+    }
+
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.val == Nmod::one(self.context()).val
+    }
+
+    /// Return the inverse of the element, or `None` if it is not a unit.
+    pub fn inv(&self) -> Option<Nmod> {
+        let n = self.modulus();
+        if n == 1 {
+            return Some(*self);
+        }
+        let mut s = 0;
+        let g = unsafe { ulong_extras::n_gcdinv(&mut s, self.val, n) };
+        if g == 1 {
+            Some(Nmod { val: s, ctx: self.ctx })
+        } else {
+            None
+        }
+    }
+
+    /// Return the element raised to the power `exp`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = NmodRing::new(1000000007);
+    /// assert!(zn.element(3).pow(1000000006).is_one());
+    /// ```
+    #[inline]
+    pub fn pow(&self, exp: u64) -> Nmod {
+        let val = unsafe { nmod_vec::nmod_pow_ui(self.val, exp, self.ctx.inner) };
+        Nmod { val, ctx: self.ctx }
+    }
+
+    /// Return a uniformly random element of `Z/nZ`.
+    #[inline]
+    pub fn randm(ctx: &NmodRing, rng: &mut FlintRand) -> Nmod {
+        let val = unsafe { ulong_extras::n_randint(rng.as_mut_ptr(), ctx.modulus()) };
+        Nmod { val, ctx: *ctx }
+    }
+}
+
+impl From<&Nmod> for Integer {
+    #[inline]
+    fn from(x: &Nmod) -> Integer {
+        Integer::from(x.val)
+    }
+}
+
+impl From<Nmod> for Integer {
+    #[inline]
+    fn from(x: Nmod) -> Integer {
+        Integer::from(x.val)
+    }
+}
+
+impl From<&Nmod> for IntMod {
+    #[inline]
+    fn from(x: &Nmod) -> IntMod {
+        IntMod::new(x.val, &IntModCtx::new(x.modulus()))
+    }
+}
+
+impl From<Nmod> for IntMod {
+    #[inline]
+    fn from(x: Nmod) -> IntMod {
+        IntMod::from(&x)
+    }
+}
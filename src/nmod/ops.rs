@@ -0,0 +1,119 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::nmod_vec;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+macro_rules! impl_nmod_cmp {
+    ($($t:ident)*) => ($(
+        impl PartialEq<$t> for Nmod {
+            #[inline]
+            fn eq(&self, rhs: &$t) -> bool {
+                *self == self.context().element(*rhs)
+            }
+        }
+    )*)
+}
+
+impl_nmod_cmp! {usize u64 u32 u16 u8 isize i64 i32 i16 i8}
+
+impl Neg for Nmod {
+    type Output = Nmod;
+    #[inline]
+    fn neg(self) -> Nmod {
+        let ctx = self.context().as_raw();
+        unsafe { Nmod::from_raw(nmod_vec::nmod_neg(self.value(), ctx), *self.context()) }
+    }
+}
+
+impl Neg for &Nmod {
+    type Output = Nmod;
+    #[inline]
+    fn neg(self) -> Nmod {
+        -*self
+    }
+}
+
+macro_rules! impl_nmod_scalar_ops {
+    ($($op:ident {$meth:ident} $op_assign:ident {$meth_assign:ident} $func:path;)+) => ($(
+        impl $op for Nmod {
+            type Output = Nmod;
+            #[inline]
+            fn $meth(self, rhs: Nmod) -> Nmod {
+                assert_eq!(self.context(), rhs.context());
+                let ctx = self.context().as_raw();
+                unsafe { 
+                    Nmod::from_raw($func(self.value(), rhs.value(), ctx), *self.context()) 
+                }
+            }
+        }
+
+        impl $op<&Nmod> for Nmod {
+            type Output = Nmod;
+            #[inline]
+            fn $meth(self, rhs: &Nmod) -> Nmod {
+                self.$meth(*rhs)
+            }
+        }
+
+        impl $op<Nmod> for &Nmod {
+            type Output = Nmod;
+            #[inline]
+            fn $meth(self, rhs: Nmod) -> Nmod {
+                (*self).$meth(rhs)
+            }
+        }
+
+        impl $op<&Nmod> for &Nmod {
+            type Output = Nmod;
+            #[inline]
+            fn $meth(self, rhs: &Nmod) -> Nmod {
+                (*self).$meth(*rhs)
+            }
+        }
+
+        impl $op_assign<Nmod> for Nmod {
+            #[inline]
+            fn $meth_assign(&mut self, rhs: Nmod) {
+                *self = (*self).$meth(rhs);
+            }
+        }
+
+        impl $op_assign<&Nmod> for Nmod {
+            #[inline]
+            fn $meth_assign(&mut self, rhs: &Nmod) {
+                *self = (*self).$meth(*rhs);
+            }
+        }
+    )+)
+}
+
+impl_nmod_scalar_ops! {
+    Add {add} AddAssign {add_assign} nmod_vec::nmod_add;
+    Sub {sub} SubAssign {sub_assign} nmod_vec::nmod_sub;
+    Mul {mul} MulAssign {mul_assign} nmod_vec::nmod_mul;
+    Div {div} DivAssign {div_assign} nmod_div;
+}
+
+// Panics unless `b` is invertible, unlike `nmod_div` which aborts.
+#[inline]
+unsafe fn nmod_div(a: u64, b: u64, ctx: flint_sys::flint::nmod_t) -> u64 {
+    let b = Nmod::from_raw(b, *NmodRing::from_nmod_ref(&ctx));
+    let inv = b.inv().expect("Divisor is not invertible.");
+    nmod_vec::nmod_mul(a, inv.value(), ctx)
+}
@@ -0,0 +1,390 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod ops;
+
+use crate::*;
+use crate::util::{fmt_rows, DisplayRows};
+use flint_sys::{fmpz_mat, nmod_mat};
+use flint_sys::nmod_mat::nmod_mat_struct;
+use flint_sys::ulong_extras::n_is_prime;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::{ManuallyDrop, MaybeUninit};
+
+/// A matrix over the integers modulo a machine word, see [NmodRing]. The 
+/// linear algebra (rank, determinant, inverse, reduced row echelon form, 
+/// solving and nullspaces) requires a prime modulus.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let zn = NmodRing::new(7);
+/// let m = NmodMat::new([1, 2, 3, 4], 2, 2, &zn);
+/// assert_eq!(m.det(), zn.element(-2));
+/// assert_eq!(&m * &m.inv().unwrap(), NmodMat::one(2, &zn));
+/// ```
+pub struct NmodMat {
+    inner: nmod_mat_struct,
+}
+
+impl AsRef<NmodMat> for NmodMat {
+    #[inline]
+    fn as_ref(&self) -> &NmodMat {
+        self
+    }
+}
+
+impl AsRef<NmodRing> for NmodMat {
+    #[inline]
+    fn as_ref(&self) -> &NmodRing {
+        self.context()
+    }
+}
+
+impl Clone for NmodMat {
+    fn clone(&self) -> Self {
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            nmod_mat::nmod_mat_init_set(z.as_mut_ptr(), self.as_ptr());
+            NmodMat::from_raw(z.assume_init())
+        }
+    }
+}
+
+impl fmt::Debug for NmodMat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NmodMat")
+            .field("nrows", &self.nrows())
+            .field("ncols", &self.ncols())
+            .field("entries", &DisplayRows(self.rows().collect()))
+            .field("modulus", &self.modulus())
+            .finish()
+    }
+}
+
+/// Entries honor the width, fill and alignment options. The alternate form
+/// `{:#}` also prints the modulus.
+impl fmt::Display for NmodMat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_rows(f, self.rows())?;
+        if f.alternate() {
+            write!(f, " (mod {})", self.modulus())?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for NmodMat {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { nmod_mat::nmod_mat_clear(self.as_mut_ptr()) }
+    }
+}
+
+impl Hash for NmodMat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.context().hash(state);
+        self.nrows().hash(state);
+        self.ncols().hash(state);
+        for row in self.rows() {
+            row.hash(state);
+        }
+    }
+}
+
+impl NmodMat {
+    // private helper methods to convert usize indices to i64, emit consistent
+    // messages on panic, and bounds check
+    fn check_indices(&self, i: usize, j: usize) -> (i64, i64) {
+        let i = i.try_into().expect("Cannot convert index to a signed long.");
+        let j = j.try_into().expect("Cannot convert index to a signed long.");
+        assert!(i < self.nrows_si() && j < self.ncols_si());
+        (i, j)
+    }
+
+    /// Return the matrix with the given entries, in row-major order, reduced
+    /// modulo `n`.
+    #[inline]
+    pub fn new<S>(src: S, nrows: i64, ncols: i64, ctx: &NmodRing) -> NmodMat 
+    where
+        IntMat: NewMatrix<S>
+    {
+        NmodMat::from_intmat(&<IntMat as NewMatrix<S>>::new(src, nrows, ncols), ctx)
+    }
+
+    /// Return the reduction of the integer matrix `m` modulo `n`.
+    pub fn from_intmat(m: &IntMat, ctx: &NmodRing) -> NmodMat {
+        let mut res = NmodMat::zero(m.nrows_si(), m.ncols_si(), ctx);
+        unsafe { fmpz_mat::fmpz_mat_get_nmod_mat(res.as_mut_ptr(), m.as_ptr()); }
+        res
+    }
+
+    #[inline]
+    pub fn zero(nrows: i64, ncols: i64, ctx: &NmodRing) -> NmodMat {
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            nmod_mat::nmod_mat_init(z.as_mut_ptr(), nrows, ncols, ctx.modulus());
+            NmodMat::from_raw(z.assume_init())
+        }
+    }
+
+    #[inline]
+    pub fn one(dim: i64, ctx: &NmodRing) -> NmodMat {
+        let mut res = NmodMat::zero(dim, dim, ctx);
+        unsafe { nmod_mat::nmod_mat_one(res.as_mut_ptr()); }
+        res
+    }
+
+    /// Return a random `nrows` by `ncols` matrix.
+    pub fn randtest(nrows: i64, ncols: i64, ctx: &NmodRing, rng: &mut FlintRand) -> NmodMat {
+        let mut res = NmodMat::zero(nrows, ncols, ctx);
+        unsafe { nmod_mat::nmod_mat_randtest(res.as_mut_ptr(), rng.as_mut_ptr()); }
+        res
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const nmod_mat_struct {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut nmod_mat_struct {
+        &mut self.inner
+    }
+
+    #[inline]
+    pub const unsafe fn from_raw(inner: nmod_mat_struct) -> Self {
+        NmodMat { inner }
+    }
+    
+    #[inline]
+    pub const fn into_raw(self) -> nmod_mat_struct {
+        let inner = self.inner;
+        let _ = ManuallyDrop::new(self);
+        inner
+    }
+
+    #[inline]
+    pub fn context(&self) -> &NmodRing {
+        NmodRing::from_nmod_ref(&self.inner.mod_)
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.context().modulus()
+    }
+
+    #[inline]
+    pub fn nrows_si(&self) -> i64 {
+        self.inner.r
+    }
+
+    #[inline]
+    pub fn ncols_si(&self) -> i64 {
+        self.inner.c
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows_si().try_into().expect("Cannot convert signed long to usize.")
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols_si().try_into().expect("Cannot convert signed long to usize.")
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nrows_si() == 0 || self.ncols_si() == 0
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.nrows_si() == self.ncols_si()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { nmod_mat::nmod_mat_is_zero(self.as_ptr()) != 0 }
+    }
+
+    /// Borrow the residues of row `i` of the matrix as a slice without 
+    /// copying.
+    #[inline]
+    pub fn row_slice(&self, i: usize) -> &[u64] {
+        assert!(i < self.nrows());
+        let c = self.ncols();
+        if c == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(*self.inner.rows.add(i), c) }
+    }
+
+    /// Iterate over the rows of the matrix as borrowed slices of residues.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[u64]> + '_ {
+        (0..self.nrows()).map(move |i| self.row_slice(i))
+    }
+
+    pub fn get_entry(&self, i: usize, j: usize) -> Nmod {
+        self.check_indices(i, j);
+        unsafe { Nmod::from_raw(self.row_slice(i)[j], *self.context()) }
+    }
+
+    pub fn set_entry<T: AsRef<Nmod>>(&mut self, i: usize, j: usize, e: T) {
+        let e = e.as_ref();
+        assert_eq!(self.context(), e.context());
+        self.check_indices(i, j);
+        unsafe { *(*self.inner.rows.add(i)).add(j) = e.value(); }
+    }
+
+    pub fn get_entries(&self) -> Vec<Nmod> {
+        let ctx = *self.context();
+        self.rows()
+            .flatten()
+            .map(|&x| unsafe { Nmod::from_raw(x, ctx) })
+            .collect()
+    }
+
+    pub fn transpose(&self) -> NmodMat {
+        let mut res = NmodMat::zero(self.ncols_si(), self.nrows_si(), self.context());
+        unsafe { nmod_mat::nmod_mat_transpose(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Compute the trace of a square matrix.
+    pub fn trace(&self) -> Nmod {
+        assert!(self.is_square());
+        let t = unsafe { nmod_mat::nmod_mat_trace(self.as_ptr()) };
+        unsafe { Nmod::from_raw(t, *self.context()) }
+    }
+
+    /// Return the matrix raised to the power `exp`. The matrix must be square.
+    pub fn pow(&self, exp: u64) -> NmodMat {
+        assert!(self.is_square());
+        let mut res = NmodMat::zero(self.nrows_si(), self.ncols_si(), self.context());
+        unsafe { nmod_mat::nmod_mat_pow(res.as_mut_ptr(), self.as_ptr(), exp); }
+        res
+    }
+
+    // FLINT's elimination assumes that every nonzero pivot is invertible.
+    #[inline]
+    fn assert_prime_modulus(&self) {
+        let n = self.context().modulus();
+        assert!(unsafe { n_is_prime(n) } != 0, "The modulus must be prime.");
+    }
+
+    /// Return the rank of the matrix. The modulus must be prime.
+    #[inline]
+    pub fn rank(&self) -> i64 {
+        self.assert_prime_modulus();
+        unsafe { nmod_mat::nmod_mat_rank(self.as_ptr()) }
+    }
+
+    /// Return the rank and the reduced row echelon form of the matrix. The 
+    /// modulus must be prime.
+    pub fn rref(&self) -> (i64, NmodMat) {
+        self.assert_prime_modulus();
+        let mut res = self.clone();
+        let rank = unsafe { nmod_mat::nmod_mat_rref(res.as_mut_ptr()) };
+        (rank, res)
+    }
+
+    /// Compute the determinant of a square matrix. The modulus must be prime.
+    pub fn det(&self) -> Nmod {
+        assert!(self.is_square());
+        self.assert_prime_modulus();
+        let d = unsafe { nmod_mat::nmod_mat_det(self.as_ptr()) };
+        unsafe { Nmod::from_raw(d, *self.context()) }
+    }
+
+    /// Return the inverse of the matrix, or `None` if it is singular. The 
+    /// matrix must be square and the modulus prime.
+    pub fn inv(&self) -> Option<NmodMat> {
+        assert!(self.is_square());
+        self.assert_prime_modulus();
+        let mut res = NmodMat::zero(self.nrows_si(), self.ncols_si(), self.context());
+        let x = unsafe { nmod_mat::nmod_mat_inv(res.as_mut_ptr(), self.as_ptr()) };
+        if x != 0 {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solve `AX = B` for a square, invertible matrix `A`, returning `None` if
+    /// `A` is singular. The modulus must be prime.
+    pub fn solve(&self, rhs: &NmodMat) -> Option<NmodMat> {
+        assert!(self.is_square());
+        assert_eq!(self.context(), rhs.context());
+        assert_eq!(self.nrows(), rhs.nrows());
+        self.assert_prime_modulus();
+        let mut res = NmodMat::zero(self.ncols_si(), rhs.ncols_si(), self.context());
+        let x = unsafe { 
+            nmod_mat::nmod_mat_solve(res.as_mut_ptr(), self.as_ptr(), rhs.as_ptr()) 
+        };
+        if x != 0 {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Return a matrix whose columns form a basis of the right nullspace of 
+    /// the matrix. The modulus must be prime.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = NmodRing::new(5);
+    /// let m = NmodMat::new([1, 2, 3, 2, 4, 2], 2, 3, &zn);
+    /// let x = m.nullspace();
+    /// assert_eq!(x.ncols(), 1);
+    /// assert!((&m * &x).is_zero());
+    /// ```
+    pub fn nullspace(&self) -> NmodMat {
+        self.assert_prime_modulus();
+        let n = self.ncols_si();
+        let mut basis = NmodMat::zero(n, n, self.context());
+        let nullity = unsafe { nmod_mat::nmod_mat_nullspace(basis.as_mut_ptr(), self.as_ptr()) };
+        let mut res = NmodMat::zero(n, nullity, self.context());
+        for i in 0..res.nrows() {
+            for j in 0..res.ncols() {
+                res.set_entry(i, j, basis.get_entry(i, j));
+            }
+        }
+        res
+    }
+}
+
+impl From<&NmodMat> for IntMat {
+    #[inline]
+    fn from(x: &NmodMat) -> IntMat {
+        let mut res = IntMat::zero(x.nrows_si(), x.ncols_si());
+        unsafe { fmpz_mat::fmpz_mat_set_nmod_mat_unsigned(res.as_mut_ptr(), x.as_ptr()); }
+        res
+    }
+}
+
+impl From<NmodMat> for IntMat {
+    #[inline]
+    fn from(x: NmodMat) -> IntMat {
+        IntMat::from(&x)
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::nmod_mat;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl_cmp! {
+    eq
+    NmodMat
+    {
+        fn eq(&self, rhs: &NmodMat) -> bool {
+            self.context() == rhs.context() 
+                && self.nrows() == rhs.nrows()
+                && self.ncols() == rhs.ncols()
+                && unsafe { nmod_mat::nmod_mat_equal(self.as_ptr(), rhs.as_ptr()) != 0 }
+        }
+    }
+}
+
+impl_nmod_ops! {
+    NmodMat
+    nmod_mat::nmod_mat_neg;
+
+    Add {add} AddAssign {add_assign} 
+    nmod_mat::nmod_mat_add, |a, b| {
+        assert!(a.nrows() == b.nrows() && a.ncols() == b.ncols());
+        NmodMat::zero(a.nrows_si(), a.ncols_si(), a.context())
+    };
+
+    Sub {sub} SubAssign {sub_assign} 
+    nmod_mat::nmod_mat_sub, |a, b| {
+        assert!(a.nrows() == b.nrows() && a.ncols() == b.ncols());
+        NmodMat::zero(a.nrows_si(), a.ncols_si(), a.context())
+    };
+
+    Mul {mul} MulAssign {mul_assign} 
+    nmod_mat::nmod_mat_mul, |a, b| {
+        assert_eq!(a.ncols(), b.nrows());
+        NmodMat::zero(a.nrows_si(), b.ncols_si(), a.context())
+    };
+}
+
+impl Mul<&Nmod> for &NmodMat {
+    type Output = NmodMat;
+    fn mul(self, rhs: &Nmod) -> NmodMat {
+        assert_eq!(self.context(), rhs.context());
+        let mut res = NmodMat::zero(self.nrows_si(), self.ncols_si(), self.context());
+        unsafe { nmod_mat::nmod_mat_scalar_mul(res.as_mut_ptr(), self.as_ptr(), rhs.value()); }
+        res
+    }
+}
+
+impl Mul<Nmod> for &NmodMat {
+    type Output = NmodMat;
+    #[inline]
+    fn mul(self, rhs: Nmod) -> NmodMat {
+        self * &rhs
+    }
+}
+
+impl Mul<&Nmod> for NmodMat {
+    type Output = NmodMat;
+    #[inline]
+    fn mul(self, rhs: &Nmod) -> NmodMat {
+        &self * rhs
+    }
+}
+
+impl Mul<Nmod> for NmodMat {
+    type Output = NmodMat;
+    #[inline]
+    fn mul(self, rhs: Nmod) -> NmodMat {
+        &self * &rhs
+    }
+}
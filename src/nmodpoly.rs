@@ -0,0 +1,395 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod ops;
+
+use crate::*;
+use flint_sys::{fmpz_poly, nmod_poly};
+use flint_sys::nmod_poly::nmod_poly_struct;
+use flint_sys::ulong_extras::n_is_prime;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::{ManuallyDrop, MaybeUninit};
+
+/// A polynomial over the integers modulo a machine word, see [NmodRing].
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let zn = NmodRing::new(7);
+/// let f = NmodPoly::new([1, 1], &zn);
+/// let g = NmodPoly::new([-1, 1], &zn);
+/// assert_eq!(&f * &g, NmodPoly::new([6, 0, 1], &zn));
+/// assert_eq!((&f * &g).evaluate(&zn.element(3)), 1);
+/// ```
+pub struct NmodPoly {
+    inner: nmod_poly_struct,
+}
+
+impl AsRef<NmodPoly> for NmodPoly {
+    #[inline]
+    fn as_ref(&self) -> &NmodPoly {
+        self
+    }
+}
+
+impl AsRef<NmodRing> for NmodPoly {
+    #[inline]
+    fn as_ref(&self) -> &NmodRing {
+        self.context()
+    }
+}
+
+impl Clone for NmodPoly {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut res = NmodPoly::zero(self.context());
+        unsafe { nmod_poly::nmod_poly_set(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+}
+
+impl fmt::Debug for NmodPoly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NmodPoly")
+            .field("value", &format_args!("{}", self))
+            .field("modulus", &self.modulus())
+            .finish()
+    }
+}
+
+impl fmt::Display for NmodPoly {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", IntPoly::from(self))
+    }
+}
+
+impl Drop for NmodPoly {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { nmod_poly::nmod_poly_clear(self.as_mut_ptr()) }
+    }
+}
+
+impl Hash for NmodPoly {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.context().hash(state);
+        self.get_coeffs_ui().hash(state);
+    }
+}
+
+impl<T: Into<IntPoly>> NewCtx<T, NmodRing> for NmodPoly {
+    fn new(src: T, ctx: &NmodRing) -> Self {
+        let mut res = NmodPoly::zero(ctx);
+        unsafe { fmpz_poly::fmpz_poly_get_nmod_poly(res.as_mut_ptr(), src.into().as_ptr()); }
+        res
+    }
+}
+
+impl NmodPoly {
+    pub fn with_capacity(capacity: usize, ctx: &NmodRing) -> Self {
+        let mut z = MaybeUninit::uninit();
+        let m = ctx.as_raw();
+        unsafe {
+            nmod_poly::nmod_poly_init2_preinv(
+                z.as_mut_ptr(), 
+                m.n, 
+                m.ninv, 
+                capacity.try_into().expect("Cannot convert input to a signed long.")
+            );
+            NmodPoly::from_raw(z.assume_init())
+        }
+    }
+
+    #[inline]
+    pub fn zero(ctx: &NmodRing) -> NmodPoly {
+        let mut z = MaybeUninit::uninit();
+        let m = ctx.as_raw();
+        unsafe {
+            nmod_poly::nmod_poly_init_preinv(z.as_mut_ptr(), m.n, m.ninv);
+            NmodPoly::from_raw(z.assume_init())
+        }
+    }
+
+    #[inline]
+    pub fn one(ctx: &NmodRing) -> NmodPoly {
+        let mut res = NmodPoly::zero(ctx);
+        unsafe { nmod_poly::nmod_poly_one(res.as_mut_ptr()); }
+        res
+    }
+
+    /// Return a random polynomial of length at most `len`.
+    pub fn randtest(len: usize, ctx: &NmodRing, rng: &mut FlintRand) -> NmodPoly {
+        let mut res = NmodPoly::with_capacity(len, ctx);
+        unsafe {
+            nmod_poly::nmod_poly_randtest(
+                res.as_mut_ptr(), 
+                rng.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const nmod_poly_struct {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut nmod_poly_struct {
+        &mut self.inner
+    }
+
+    #[inline]
+    pub const unsafe fn from_raw(inner: nmod_poly_struct) -> Self {
+        NmodPoly { inner }
+    }
+    
+    #[inline]
+    pub const fn into_raw(self) -> nmod_poly_struct {
+        let inner = self.inner;
+        let _ = ManuallyDrop::new(self);
+        inner
+    }
+
+    #[inline]
+    pub fn context(&self) -> &NmodRing {
+        NmodRing::from_nmod_ref(&self.inner.mod_)
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.context().modulus()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { nmod_poly::nmod_poly_is_zero(self.as_ptr()) != 0 }
+    }
+
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        unsafe { nmod_poly::nmod_poly_is_one(self.as_ptr()) != 0 }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        unsafe { 
+            nmod_poly::nmod_poly_length(self.as_ptr())
+                .try_into()
+                .expect("Cannot convert signed long to usize.")
+        }
+    }
+
+    #[inline]
+    pub fn degree(&self) -> i64 {
+        unsafe { nmod_poly::nmod_poly_degree(self.as_ptr()) }
+    }
+
+    pub fn get_coeff(&self, i: usize) -> Nmod {
+        let c = unsafe {
+            nmod_poly::nmod_poly_get_coeff_ui(
+                self.as_ptr(), 
+                i.try_into().expect("Cannot convert index to a signed long.")
+            )
+        };
+        unsafe { Nmod::from_raw(c, *self.context()) }
+    }
+
+    pub fn set_coeff<T: AsRef<Nmod>>(&mut self, i: usize, coeff: T) {
+        let coeff = coeff.as_ref();
+        assert_eq!(self.context(), coeff.context());
+        unsafe {
+            nmod_poly::nmod_poly_set_coeff_ui(
+                self.as_mut_ptr(), 
+                i.try_into().expect("Cannot convert index to a signed long."), 
+                coeff.value()
+            );
+        }
+    }
+
+    #[inline]
+    pub fn get_coeffs(&self) -> Vec<Nmod> {
+        (0..self.len()).map(|i| self.get_coeff(i)).collect()
+    }
+
+    /// Return the coefficients as residues in `[0, n)`.
+    #[inline]
+    pub fn get_coeffs_ui(&self) -> Vec<u64> {
+        (0..self.len()).map(|i| self.get_coeff(i).value()).collect()
+    }
+
+    /// Evaluate the polynomial at `x`.
+    #[inline]
+    pub fn evaluate<T: AsRef<Nmod>>(&self, x: T) -> Nmod {
+        let x = x.as_ref();
+        assert_eq!(self.context(), x.context());
+        let c = unsafe { nmod_poly::nmod_poly_evaluate_nmod(self.as_ptr(), x.value()) };
+        unsafe { Nmod::from_raw(c, *self.context()) }
+    }
+
+    /// Return the formal derivative.
+    #[inline]
+    pub fn derivative(&self) -> NmodPoly {
+        let mut res = NmodPoly::zero(self.context());
+        unsafe { nmod_poly::nmod_poly_derivative(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Return the product `self * other` truncated to its first `n` 
+    /// coefficients.
+    pub fn mullow<T: AsRef<NmodPoly>>(&self, other: T, n: usize) -> NmodPoly {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        let mut res = NmodPoly::zero(self.context());
+        unsafe {
+            nmod_poly::nmod_poly_mullow(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(),
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the cyclic convolution of length `n` of the coefficient 
+    /// sequences, that is, `self * other mod x^n - 1`. This is the full 
+    /// product with its coefficients folded modulo `x^n - 1`, not a 
+    /// transform of length `n`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = NmodRing::new(17);
+    /// let f = NmodPoly::new([1, 2, 3], &zn);
+    /// let g = NmodPoly::new([0, 0, 1], &zn);
+    /// // multiplying by x^2 rotates the coefficients
+    /// assert_eq!(f.mul_cyclic(&g, 3), NmodPoly::new([2, 3, 1], &zn));
+    /// ```
+    pub fn mul_cyclic<T: AsRef<NmodPoly>>(&self, other: T, n: usize) -> NmodPoly {
+        assert!(n > 0, "The length must be positive.");
+        let prod = self * other.as_ref();
+        let ctx = *self.context();
+        let mut res = NmodPoly::with_capacity(n, &ctx);
+        let mut coeffs = vec![ctx.zero(); n];
+        for (i, c) in prod.get_coeffs().into_iter().enumerate() {
+            coeffs[i % n] += c;
+        }
+        for (i, c) in coeffs.iter().enumerate() {
+            res.set_coeff(i, c);
+        }
+        res
+    }
+
+    /// Return the polynomial raised to the power `exp`.
+    #[inline]
+    pub fn pow(&self, exp: u64) -> NmodPoly {
+        let mut res = NmodPoly::zero(self.context());
+        unsafe { nmod_poly::nmod_poly_pow(res.as_mut_ptr(), self.as_ptr(), exp); }
+        res
+    }
+
+    /// Return `(q, r)` with `self = q*other + r` and `deg(r) < deg(other)`. 
+    /// The leading coefficient of `other` must be invertible, which is 
+    /// automatic when the modulus is prime.
+    pub fn divrem<T: AsRef<NmodPoly>>(&self, other: T) -> (NmodPoly, NmodPoly) {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        assert!(!other.is_zero(), "Division by zero.");
+        let lead = other.get_coeff(other.len() - 1);
+        assert!(lead.inv().is_some(), "Leading coefficient is not invertible.");
+        let mut q = NmodPoly::zero(self.context());
+        let mut r = NmodPoly::zero(self.context());
+        unsafe {
+            nmod_poly::nmod_poly_divrem(
+                q.as_mut_ptr(), 
+                r.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr()
+            );
+        }
+        (q, r)
+    }
+
+    // FLINT's Euclidean algorithm assumes that every nonzero leading 
+    // coefficient is invertible.
+    #[inline]
+    fn assert_prime_modulus(&self) {
+        let n = self.context().modulus();
+        assert!(unsafe { n_is_prime(n) } != 0, "The modulus must be prime.");
+    }
+
+    /// Return the monic greatest common divisor. The modulus must be prime.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let zn = NmodRing::new(5);
+    /// let f = NmodPoly::new([-1, 0, 1], &zn);
+    /// let g = NmodPoly::new([1, 2, 1], &zn);
+    /// assert_eq!(f.gcd(&g), NmodPoly::new([1, 1], &zn));
+    /// ```
+    pub fn gcd<T: AsRef<NmodPoly>>(&self, other: T) -> NmodPoly {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        self.assert_prime_modulus();
+        let mut res = NmodPoly::zero(self.context());
+        unsafe { nmod_poly::nmod_poly_gcd(res.as_mut_ptr(), self.as_ptr(), other.as_ptr()); }
+        res
+    }
+
+    /// Whether the polynomial is irreducible. The modulus must be prime.
+    #[inline]
+    pub fn is_irreducible(&self) -> bool {
+        self.assert_prime_modulus();
+        unsafe { nmod_poly::nmod_poly_is_irreducible(self.as_ptr()) != 0 }
+    }
+}
+
+impl From<&NmodPoly> for IntPoly {
+    #[inline]
+    fn from(x: &NmodPoly) -> IntPoly {
+        let mut res = IntPoly::zero();
+        unsafe { fmpz_poly::fmpz_poly_set_nmod_poly_unsigned(res.as_mut_ptr(), x.as_ptr()); }
+        res
+    }
+}
+
+impl From<NmodPoly> for IntPoly {
+    #[inline]
+    fn from(x: NmodPoly) -> IntPoly {
+        IntPoly::from(&x)
+    }
+}
+
+impl From<&NmodPoly> for IntModPoly {
+    #[inline]
+    fn from(x: &NmodPoly) -> IntModPoly {
+        IntModPoly::new(IntPoly::from(x), &IntModCtx::new(x.modulus()))
+    }
+}
+
+impl From<NmodPoly> for IntModPoly {
+    #[inline]
+    fn from(x: NmodPoly) -> IntModPoly {
+        IntModPoly::from(&x)
+    }
+}
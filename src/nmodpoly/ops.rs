@@ -0,0 +1,89 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::nmod_poly;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+impl_cmp! {
+    eq
+    NmodPoly
+    {
+        fn eq(&self, rhs: &NmodPoly) -> bool {
+            self.context() == rhs.context() && 
+                unsafe { nmod_poly::nmod_poly_equal(self.as_ptr(), rhs.as_ptr()) != 0 }
+        }
+    }
+}
+
+impl_nmod_ops! {
+    NmodPoly
+    nmod_poly::nmod_poly_neg;
+
+    Add {add} AddAssign {add_assign} 
+    nmod_poly::nmod_poly_add, |a, _b| NmodPoly::zero(a.context());
+
+    Sub {sub} SubAssign {sub_assign} 
+    nmod_poly::nmod_poly_sub, |a, _b| NmodPoly::zero(a.context());
+
+    Mul {mul} MulAssign {mul_assign} 
+    nmod_poly::nmod_poly_mul, |a, _b| NmodPoly::zero(a.context());
+
+    Rem {rem} RemAssign {rem_assign} 
+    nmod_poly::nmod_poly_rem, |a, b| {
+        assert!(!b.is_zero(), "Division by zero.");
+        let lead = b.get_coeff(b.len() - 1);
+        assert!(lead.inv().is_some(), "Leading coefficient is not invertible.");
+        NmodPoly::zero(a.context())
+    };
+}
+
+impl Mul<&Nmod> for &NmodPoly {
+    type Output = NmodPoly;
+    fn mul(self, rhs: &Nmod) -> NmodPoly {
+        assert_eq!(self.context(), rhs.context());
+        let mut res = NmodPoly::zero(self.context());
+        unsafe {
+            nmod_poly::nmod_poly_scalar_mul_nmod(res.as_mut_ptr(), self.as_ptr(), rhs.value());
+        }
+        res
+    }
+}
+
+impl Mul<Nmod> for &NmodPoly {
+    type Output = NmodPoly;
+    #[inline]
+    fn mul(self, rhs: Nmod) -> NmodPoly {
+        self * &rhs
+    }
+}
+
+impl Mul<&Nmod> for NmodPoly {
+    type Output = NmodPoly;
+    #[inline]
+    fn mul(self, rhs: &Nmod) -> NmodPoly {
+        &self * rhs
+    }
+}
+
+impl Mul<Nmod> for NmodPoly {
+    type Output = NmodPoly;
+    #[inline]
+    fn mul(self, rhs: Nmod) -> NmodPoly {
+        &self * &rhs
+    }
+}
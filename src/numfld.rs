@@ -15,11 +15,17 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-//mod ops;
+mod ops;
 mod conv;
+mod ideal;
+mod cyclotomic;
+mod order;
 
-use crate::{NewCtx, RatPoly};
-use flint_sys::fmpq_poly::{fmpq_poly_struct, fmpq_poly_set};
+pub use ideal::NfIdeal;
+
+use crate::{NewCtx, Rational, RatPoly, RatMat};
+use flint_sys::{fmpq_mat, fmpz_poly};
+use flint_sys::fmpq_poly::{self, fmpq_poly_struct, fmpq_poly_set};
 use antic_sys::{
     nf::*,
     nf_elem::*
@@ -54,17 +60,35 @@ impl NfCtx {
 
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct NumFldCtx {
     inner: Rc<NfCtx>
 }
 
+impl AsRef<NumFldCtx> for NumFldCtx {
+    #[inline]
+    fn as_ref(&self) -> &NumFldCtx {
+        self
+    }
+}
+
 impl Eq for NumFldCtx {}
 
+// Contexts are equal exactly when their defining polynomials are, and `Hash` 
+// hashes the same polynomial.
 impl PartialEq for NumFldCtx {
     fn eq(&self, rhs: &NumFldCtx) -> bool {
-        Rc::ptr_eq(&self.inner, &rhs.inner) 
-            || (self.defining_polynomial() == rhs.defining_polynomial())
+        Rc::ptr_eq(&self.inner, &rhs.inner) || unsafe {
+            fmpq_poly::fmpq_poly_equal(self.poly_as_ptr(), rhs.poly_as_ptr()) != 0
+        }
+    }
+}
+
+impl fmt::Debug for NumFldCtx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumFldCtx")
+            .field("defining_polynomial", &format_args!("{}", self.defining_polynomial()))
+            .finish()
     }
 }
 
@@ -104,10 +128,46 @@ impl NumFldCtx {
         unsafe { fmpq_poly_set(res.as_mut_ptr(), self.poly_as_ptr()); }
         res
     }
-    
+   
+    /// Return the degree of the number field over the rationals.
+    #[inline]
+    pub fn degree(&self) -> i64 {
+        self.defining_polynomial().degree()
+    }
+
+    /// Return the discriminant of the defining polynomial. This agrees with 
+    /// the discriminant of the number field up to the square of the index of 
+    /// the equation order in the maximal order.
+    #[inline]
+    pub fn discriminant(&self) -> Rational {
+        let mut res = Rational::default();
+        unsafe { 
+            fmpq_poly::fmpq_poly_discriminant(res.as_mut_ptr(), self.poly_as_ptr()); 
+        }
+        res
+    }
+
+    /// Return the signature `(r1, r2)` of the number field, where `r1` is the 
+    /// number of real embeddings and `r2` the number of pairs of complex 
+    /// embeddings.
+    pub fn signature(&self) -> (i64, i64) {
+        // the defining polynomial is irreducible, hence squarefree
+        let pol = self.defining_polynomial().numerator();
+        let r1 = unsafe { fmpz_poly::fmpz_poly_num_real_roots(pol.as_ptr()) };
+        (r1, (pol.degree() - r1) / 2)
+    }
+
+    /// Return the generator of the number field, that is, the image of `x` in 
+    /// `Q[x]/(f)` where `f` is the defining polynomial.
+    #[inline]
+    pub fn gen(&self) -> NumFldElem {
+        let mut res = NumFldElem::zero(self);
+        unsafe { nf_elem_gen(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
 }
 
-// Debug? nf_elem_struct is a union
+// nf_elem_struct is a union so can't derive Debug
 pub struct NumFldElem {
     pub(crate) inner: nf_elem_struct,
     pub(crate) ctx: NumFldCtx
@@ -119,14 +179,21 @@ impl AsRef<NumFldElem> for NumFldElem {
     }
 }
 
+impl AsRef<NumFldCtx> for NumFldElem {
+    #[inline]
+    fn as_ref(&self) -> &NumFldCtx {
+        self.context()
+    }
+}
+
 impl Clone for NumFldElem {
     #[inline]
     fn clone(&self) -> Self {
         let mut res = NumFldElem::zero(self.context());
         unsafe {
-            nf_elem_set_fmpq_poly(
+            nf_elem_set(
                 res.as_mut_ptr(), 
-                self.poly_as_ptr(), 
+                self.as_ptr(), 
                 self.ctx_as_ptr()
             );
         }
@@ -134,6 +201,15 @@ impl Clone for NumFldElem {
     }
 }
 
+impl fmt::Debug for NumFldElem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumFldElem")
+            .field("value", &format_args!("{}", self))
+            .field("ctx", &self.ctx)
+            .finish()
+    }
+}
+
 impl fmt::Display for NumFldElem {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -158,14 +234,13 @@ impl Drop for NumFldElem {
     }
 }
 
-/*
-// TODO
 impl Hash for NumFldElem {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
+        self.context().hash(state);
+        RatPoly::from(self).hash(state);
     }
 }
-*/
 
 impl<T: Into<RatPoly>> NewCtx<T, NumFldCtx> for NumFldElem {
     fn new(src: T, ctx: &NumFldCtx) -> Self {
@@ -204,6 +279,23 @@ impl NumFldElem {
             NumFldElem::from_raw(z.assume_init(), ctx.clone())
         }
     }
+    
+    #[inline]
+    pub fn one(ctx: &NumFldCtx) -> Self {
+        let mut res = NumFldElem::zero(ctx);
+        unsafe { nf_elem_one(res.as_mut_ptr(), ctx.as_ptr()); }
+        res
+    }
+    
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        unsafe { nf_elem_is_zero(self.as_ptr(), self.ctx_as_ptr()) == 1 }
+    }
+    
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        unsafe { nf_elem_is_one(self.as_ptr(), self.ctx_as_ptr()) == 1 }
+    }
 
     #[inline]
     pub const fn as_ptr(&self) -> *const nf_elem_struct {
@@ -246,4 +338,61 @@ impl NumFldElem {
     pub fn defining_polynomial(&self) -> RatPoly {
         self.context().defining_polynomial()
     }
+    /// Return the norm of the element, that is, the determinant of its 
+    /// representation matrix.
+    #[inline]
+    pub fn norm(&self) -> Rational {
+        let mut res = Rational::default();
+        unsafe { nf_elem_norm(res.as_mut_ptr(), self.as_ptr(), self.ctx_as_ptr()); }
+        res
+    }
+    
+    /// Return the trace of the element, that is, the trace of its 
+    /// representation matrix.
+    #[inline]
+    pub fn trace(&self) -> Rational {
+        let mut res = Rational::default();
+        unsafe { nf_elem_trace(res.as_mut_ptr(), self.as_ptr(), self.ctx_as_ptr()); }
+        res
+    }
+
+    /// Return the matrix of multiplication by the element with respect to the 
+    /// power basis `1, x, ..., x^(n-1)`. Row `i` holds the coordinates of 
+    /// `x^i` times the element.
+    #[inline]
+    pub fn representation_matrix(&self) -> RatMat {
+        let d = self.context().degree();
+        let mut res = RatMat::zero(d, d);
+        unsafe { 
+            nf_elem_rep_mat(res.as_mut_ptr(), self.as_ptr(), self.ctx_as_ptr()); 
+        }
+        res
+    }
+    
+    /// Return the characteristic polynomial of the element, which has degree 
+    /// equal to the degree of the number field.
+    #[inline]
+    pub fn charpoly(&self) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe { 
+            fmpq_mat::fmpq_mat_charpoly(
+                res.as_mut_ptr(), 
+                self.representation_matrix().as_ptr()
+            ); 
+        }
+        res
+    }
+    
+    /// Return the minimal polynomial of the element over the rationals.
+    #[inline]
+    pub fn minpoly(&self) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe { 
+            fmpq_mat::fmpq_mat_minpoly(
+                res.as_mut_ptr(), 
+                self.representation_matrix().as_ptr()
+            ); 
+        }
+        res
+    }
 }
@@ -0,0 +1,151 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpz_poly;
+
+impl NumFldCtx {
+    /// Return the `n`-th cyclotomic field `Q(z)`, defined by the cyclotomic
+    /// polynomial `Phi_n`, whose generator `z` is a primitive `n`-th root of 
+    /// unity. Panics if `n` is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let k = NumFldCtx::cyclotomic(12);
+    /// assert_eq!(k.degree(), 4);
+    /// assert_eq!(k.cyclotomic_order(), Some(12));
+    ///
+    /// // z^6 = -1
+    /// let z = k.gen();
+    /// let z3 = &z * &z * &z;
+    /// assert!((&z3 * &z3 + NumFldElem::one(&k)).is_zero());
+    /// ```
+    pub fn cyclotomic(n: u64) -> NumFldCtx {
+        assert!(n > 0, "The order of a root of unity must be positive.");
+        NumFldCtx::new(RatPoly::from(IntPoly::cyclotomic(n)))
+    }
+
+    /// Return `n` if the defining polynomial is the cyclotomic polynomial
+    /// `Phi_n`, otherwise `None`.
+    pub fn cyclotomic_order(&self) -> Option<u64> {
+        let pol = self.defining_polynomial();
+        if !pol.denominator().is_one() {
+            return None;
+        }
+        let n = unsafe { fmpz_poly::fmpz_poly_is_cyclotomic(pol.numerator().as_ptr()) };
+        if n == 0 {
+            None
+        } else {
+            Some(n)
+        }
+    }
+
+    /// Return the conductor of the field if it is defined by a cyclotomic 
+    /// polynomial, that is, the least `m` such that the field is `Q(z_m)`. 
+    /// Since `Q(z_n) = Q(z_{n/2})` for `n = 2 mod 4`, this is `n/2` in that 
+    /// case and `n` otherwise.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(NumFldCtx::cyclotomic(10).conductor(), Some(5));
+    /// assert_eq!(NumFldCtx::cyclotomic(8).conductor(), Some(8));
+    /// assert_eq!(NumFldCtx::new(RatPoly::from([-2, 0, 1])).conductor(), None);
+    /// ```
+    pub fn conductor(&self) -> Option<u64> {
+        self.cyclotomic_order().map(|n| if n % 4 == 2 { n / 2 } else { n })
+    }
+}
+
+impl NumFldElem {
+    /// Return the image of the element under the automorphism `z -> z^k` of
+    /// the cyclotomic field `Q(z)`, where `z` is a primitive `n`-th root of 
+    /// unity. As `k` runs over the units modulo `n` this gives the Galois 
+    /// group, and `k = -1` is complex conjugation. Panics unless the field is
+    /// defined by a cyclotomic polynomial `Phi_n` and `k` is coprime to `n`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let k = NumFldCtx::cyclotomic(5);
+    /// let z = k.gen();
+    /// let a = &z + NumFldElem::new(RatPoly::from([0, 0, 3]), &k);
+    ///
+    /// // the norm is the product of all conjugates
+    /// let mut prod = NumFldElem::one(&k);
+    /// for j in 1..5 {
+    ///     prod = prod * a.cyclotomic_automorphism(j);
+    /// }
+    /// assert_eq!(prod, NumFldElem::new(RatPoly::from(a.norm()), &k));
+    ///
+    /// // z * conj(z) = 1
+    /// assert!((&z * z.cyclotomic_automorphism(-1)).is_one());
+    /// ```
+    pub fn cyclotomic_automorphism(&self, k: i64) -> NumFldElem {
+        let n = self.context()
+            .cyclotomic_order()
+            .expect("The number field must be defined by a cyclotomic polynomial.");
+        let n = i64::try_from(n).expect("Cannot convert order to a signed long.");
+        let k = k.rem_euclid(n);
+        assert!(
+            Integer::from(k).gcd(Integer::from(n)).is_one(),
+            "The exponent must be coprime to the order of the root of unity."
+        );
+        // z^i -> z^(ik mod n), which are distinct for i < deg(Phi_n) < n
+        let pol = RatPoly::from(self);
+        let mut res = RatPoly::zero();
+        for i in 0..pol.len() {
+            let j = (i as i64 * k).rem_euclid(n) as usize;
+            res.set_coeff(j, pol.get_coeff(i));
+        }
+        NumFldElem::new(&res, self.context())
+    }
+
+    /// Return the nonzero coordinates `(i, c_i)` of the element with respect
+    /// to the power basis `1, x, ..., x^(d-1)`, in increasing order of `i`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let k = NumFldCtx::cyclotomic(7);
+    /// let a = NumFldElem::new(RatPoly::from([1, 0, 0, 0, 0, 2]), &k);
+    /// let sparse = a.to_sparse();
+    /// assert_eq!(sparse, vec![(0, Rational::from(1)), (5, Rational::from(2))]);
+    /// assert_eq!(NumFldElem::from_sparse(&sparse, &k), a);
+    /// ```
+    pub fn to_sparse(&self) -> Vec<(usize, Rational)> {
+        let pol = RatPoly::from(self);
+        (0..pol.len())
+            .map(|i| (i, pol.get_coeff(i)))
+            .filter(|(_, c)| !c.is_zero())
+            .collect()
+    }
+
+    /// Return the element with the given coordinates `(i, c_i)` with respect
+    /// to the power basis, see [NumFldElem::to_sparse]. Repeated indices are
+    /// summed, and indices beyond the degree are reduced by the defining 
+    /// polynomial.
+    pub fn from_sparse(coords: &[(usize, Rational)], ctx: &NumFldCtx) -> NumFldElem {
+        let mut pol = RatPoly::zero();
+        for (i, c) in coords {
+            let cur = pol.get_coeff(*i);
+            pol.set_coeff(*i, cur + c);
+        }
+        NumFldElem::new(&pol, ctx)
+    }
+}
@@ -0,0 +1,341 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::{fmpz_mod_poly, fmpz_mod_poly_factor};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+use std::ops::{Add, Mul};
+
+/// A nonzero ideal of the equation order `Z[x]/(f)` of a number field with 
+/// monic integral defining polynomial `f`. The ideal is stored as the `n` by 
+/// `n` Hermite normal form of a `Z`-basis, where row `i` holds the coordinates 
+/// of the `i`-th basis element with respect to the power basis 
+/// `1, x, ..., x^(n-1)`.
+///
+/// Note that the equation order need not be the maximal order, so the ideals 
+/// above a prime `p` dividing the index `[O_K : Z[x]]` may not be invertible.
+#[derive(Clone, Debug)]
+pub struct NfIdeal {
+    basis: IntMat,
+    ctx: NumFldCtx,
+}
+
+impl Eq for NfIdeal {}
+
+impl PartialEq for NfIdeal {
+    fn eq(&self, rhs: &NfIdeal) -> bool {
+        self.context() == rhs.context() && self.basis == rhs.basis
+    }
+}
+
+impl fmt::Display for NfIdeal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ideal of norm {} with basis matrix\n{}", self.norm(), self.basis)
+    }
+}
+
+impl Hash for NfIdeal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.context().hash(state);
+        self.basis.hash(state);
+    }
+}
+
+impl NfIdeal {
+    /// Construct the ideal generated by `gens`. The generators must be 
+    /// integral, that is, have integer coordinates in the power basis. Panics 
+    /// if the generated ideal is zero.
+    pub fn from_generators<T: AsRef<NumFldElem>>(
+        gens: &[T], 
+        ctx: &NumFldCtx
+    ) -> NfIdeal {
+        let n = equation_order_degree(ctx);
+        let a = ctx.gen();
+
+        // multiply each generator by the power basis
+        let mut rows = Vec::with_capacity(gens.len() * n);
+        for g in gens {
+            let g = g.as_ref();
+            assert_eq!(g.context(), ctx);
+            let mut t = g.clone();
+            for _ in 0..n {
+                rows.push(coordinates(&t, n));
+                t = &t * &a;
+            }
+        }
+        NfIdeal::from_rows(rows, ctx)
+    }
+
+    /// Construct the principal ideal generated by `x`.
+    #[inline]
+    pub fn principal<T: AsRef<NumFldElem>>(x: T) -> NfIdeal {
+        let x = x.as_ref();
+        NfIdeal::from_generators(&[x], x.context())
+    }
+    
+    /// Return the prime ideals of the equation order lying above the rational 
+    /// prime `p`, together with their ramification indices, using the 
+    /// Kummer-Dedekind theorem: if the defining polynomial factors modulo `p` 
+    /// as the product of `g_i^e_i` then the primes above `p` are 
+    /// `(p, g_i(x))`. When `p` does not divide the index of the equation 
+    /// order this is the decomposition of `p` in the ring of integers.
+    pub fn primes_above<T: AsRef<Integer>>(
+        p: T, 
+        ctx: &NumFldCtx
+    ) -> Vec<(NfIdeal, i64)> {
+        let p = p.as_ref();
+        assert!(p.is_prime());
+        equation_order_degree(ctx);
+
+        let modctx = IntModCtx::new(p.clone());
+        let f = IntModPoly::new(ctx.defining_polynomial().numerator(), &modctx);
+        let mut res = vec![];
+        unsafe {
+            let mut fac = MaybeUninit::uninit();
+            fmpz_mod_poly_factor::fmpz_mod_poly_factor_init(
+                fac.as_mut_ptr(), 
+                modctx.as_ptr()
+            );
+            let mut fac = fac.assume_init();
+            fmpz_mod_poly_factor::fmpz_mod_poly_factor(
+                &mut fac, 
+                f.as_ptr(), 
+                modctx.as_ptr()
+            );
+
+            let num = fac.num.try_into().expect("Cannot convert signed long to usize.");
+            for i in 0..num {
+                let mut g = IntPoly::zero();
+                fmpz_mod_poly::fmpz_mod_poly_get_fmpz_poly(
+                    g.as_mut_ptr(), 
+                    fac.poly.add(i), 
+                    modctx.as_ptr()
+                );
+                let gens = [
+                    NumFldElem::new(p.clone(), ctx), 
+                    NumFldElem::new(g, ctx)
+                ];
+                res.push((NfIdeal::from_generators(&gens, ctx), *fac.exp.add(i)));
+            }
+            fmpz_mod_poly_factor::fmpz_mod_poly_factor_clear(&mut fac, modctx.as_ptr());
+        }
+        res
+    }
+
+    // Build the ideal spanned by the given coordinate vectors.
+    fn from_rows(rows: Vec<Vec<Integer>>, ctx: &NumFldCtx) -> NfIdeal {
+        let n = equation_order_degree(ctx);
+        let nrows = rows.len().try_into().expect("Cannot convert usize to a signed long.");
+        let ncols = n.try_into().expect("Cannot convert usize to a signed long.");
+
+        let mut m = IntMat::zero(nrows, ncols);
+        for (i, row) in rows.iter().enumerate() {
+            for (j, c) in row.iter().enumerate() {
+                m.set_entry(i, j, c);
+            }
+        }
+        
+        // the nonzero rows of the HNF come first
+        let h = m.hnf();
+        let mut basis = IntMat::zero(ncols, ncols);
+        for i in 0..n.min(rows.len()) {
+            for j in 0..n {
+                basis.set_entry(i, j, h.get_entry(i, j));
+            }
+        }
+        assert!(!basis.det().is_zero(), "The zero ideal is not supported.");
+        NfIdeal { basis, ctx: ctx.clone() }
+    }
+
+    #[inline]
+    pub fn context(&self) -> &NumFldCtx {
+        &self.ctx
+    }
+
+    /// Return the basis matrix of the ideal in Hermite normal form.
+    #[inline]
+    pub fn basis_matrix(&self) -> &IntMat {
+        &self.basis
+    }
+    
+    /// Return the elements of the Hermite normal form basis of the ideal.
+    pub fn basis(&self) -> Vec<NumFldElem> {
+        let n = self.basis.nrows();
+        (0..n).map(|i| self.basis_element(i)).collect()
+    }
+
+    fn basis_element(&self, i: usize) -> NumFldElem {
+        let mut pol = RatPoly::zero();
+        for j in 0..self.basis.ncols() {
+            pol.set_coeff_int(j, self.basis.get_entry(i, j));
+        }
+        NumFldElem::new(pol, self.context())
+    }
+
+    /// Return the norm of the ideal, that is, its index in the equation order.
+    #[inline]
+    pub fn norm(&self) -> Integer {
+        self.basis.det().abs()
+    }
+    
+    /// Return true if the ideal is the whole equation order.
+    #[inline]
+    pub fn is_one(&self) -> bool {
+        self.basis.is_one()
+    }
+
+    /// Return true if `x` is an element of the ideal.
+    pub fn contains<T: AsRef<NumFldElem>>(&self, x: T) -> bool {
+        let x = x.as_ref();
+        assert_eq!(x.context(), self.context());
+        let pol = RatPoly::from(x);
+        if !pol.denominator().is_one() {
+            return false;
+        }
+       
+        // reduce by the rows of the (upper triangular) basis
+        let n = self.basis.nrows();
+        let mut v: Vec<Integer> = (0..n).map(|j| pol.get_coeff_int(j)).collect();
+        for i in 0..n {
+            let d = self.basis.get_entry(i, i);
+            match v[i].divexact(&d) {
+                Some(q) => {
+                    for (j, vj) in v.iter_mut().enumerate().skip(i) {
+                        *vj -= &q * self.basis.get_entry(i, j);
+                    }
+                },
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Return the intersection of two ideals.
+    pub fn intersection<T: AsRef<NfIdeal>>(&self, other: T) -> NfIdeal {
+        let other = other.as_ref();
+        assert_eq!(self.context(), other.context());
+        let n = self.basis.nrows();
+
+        // The HNF of [[A, A], [B, 0]] has the form [[*, *], [0, C]] where the 
+        // rows of C span the intersection of the row spaces of A and B.
+        let nn = (2 * n).try_into().expect("Cannot convert usize to a signed long.");
+        let mut m = IntMat::zero(nn, nn);
+        for i in 0..n {
+            for j in 0..n {
+                let a = self.basis.get_entry(i, j);
+                m.set_entry(i, j, &a);
+                m.set_entry(i, j + n, &a);
+                m.set_entry(i + n, j, other.basis.get_entry(i, j));
+            }
+        }
+        let h = m.hnf();
+        let rows = (n..2 * n)
+            .map(|i| (n..2 * n).map(|j| h.get_entry(i, j)).collect())
+            .collect();
+        NfIdeal::from_rows(rows, self.context())
+    }
+
+    /// Return true if the ideal is a prime ideal.
+    pub fn is_prime(&self) -> bool {
+        let mut p = self.norm();
+        if p.is_one() {
+            return false;
+        }
+        while !p.is_prime() {
+            match p.is_perfect_power() {
+                Some((r, _)) => p = r,
+                None => return false,
+            }
+        }
+        NfIdeal::primes_above(&p, self.context())
+            .iter()
+            .any(|(q, _)| q == self)
+    }
+}
+
+impl AsRef<NfIdeal> for NfIdeal {
+    fn as_ref(&self) -> &NfIdeal {
+        self
+    }
+}
+
+impl Add<&NfIdeal> for &NfIdeal {
+    type Output = NfIdeal;
+    fn add(self, rhs: &NfIdeal) -> NfIdeal {
+        assert_eq!(self.context(), rhs.context());
+        let n = self.basis.nrows();
+        let rows = (0..n)
+            .map(|i| (0..n).map(|j| self.basis.get_entry(i, j)).collect())
+            .chain((0..n).map(|i| (0..n).map(|j| rhs.basis.get_entry(i, j)).collect()))
+            .collect();
+        NfIdeal::from_rows(rows, self.context())
+    }
+}
+
+impl Add for NfIdeal {
+    type Output = NfIdeal;
+    #[inline]
+    fn add(self, rhs: NfIdeal) -> NfIdeal {
+        &self + &rhs
+    }
+}
+
+impl Mul<&NfIdeal> for &NfIdeal {
+    type Output = NfIdeal;
+    fn mul(self, rhs: &NfIdeal) -> NfIdeal {
+        assert_eq!(self.context(), rhs.context());
+        let n = self.basis.nrows();
+        let lhs_basis = self.basis();
+        let rhs_basis = rhs.basis();
+
+        let mut rows = Vec::with_capacity(n * n);
+        for a in lhs_basis.iter() {
+            for b in rhs_basis.iter() {
+                rows.push(coordinates(&(a * b), n));
+            }
+        }
+        NfIdeal::from_rows(rows, self.context())
+    }
+}
+
+impl Mul for NfIdeal {
+    type Output = NfIdeal;
+    #[inline]
+    fn mul(self, rhs: NfIdeal) -> NfIdeal {
+        &self * &rhs
+    }
+}
+
+// Return the degree of the number field, panicking if the defining polynomial 
+// is not monic with integer coefficients.
+fn equation_order_degree(ctx: &NumFldCtx) -> usize {
+    let f = ctx.defining_polynomial();
+    assert!(
+        f.denominator().is_one() && f.get_coeff(f.len() - 1).is_one(),
+        "Ideals require a monic integral defining polynomial."
+    );
+    f.degree().try_into().expect("Cannot convert signed long to usize.")
+}
+
+// Return the coordinates of an integral element in the power basis.
+fn coordinates(x: &NumFldElem, n: usize) -> Vec<Integer> {
+    let pol = RatPoly::from(x);
+    assert!(pol.denominator().is_one(), "Element is not integral.");
+    (0..n).map(|i| pol.get_coeff_int(i)).collect()
+}
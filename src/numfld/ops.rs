@@ -0,0 +1,302 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+
+use antic_sys::nf_elem::*;
+use antic_sys::nf::nf_struct;
+use flint_sys::{fmpz, fmpq};
+use inertia_algebra::ops::*;
+use std::mem::MaybeUninit;
+
+impl_cmp! {
+    eq
+    NumFldElem
+    {
+        fn eq(&self, rhs: &NumFldElem) -> bool {
+            unsafe {
+                self.context() == rhs.context() && nf_elem_equal(
+                    self.as_ptr(),
+                    rhs.as_ptr(),
+                    self.ctx_as_ptr()
+                ) != 0
+            }
+        }
+    }
+}
+
+impl_unop_unsafe! {
+    ctx
+    NumFldElem
+    Neg {neg}
+    NegAssign {neg_assign}
+    nf_elem_neg
+}
+
+impl_unop_unsafe! {
+    ctx
+    NumFldElem
+    Inv {inv}
+    InvAssign {inv_assign}
+    nf_elem_inv_checked
+}
+
+impl_binop_unsafe! {
+    ctx
+    NumFldElem, NumFldElem, NumFldElem
+
+    Add {add}
+    AddAssign {add_assign}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    nf_elem_add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    nf_elem_sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    nf_elem_mul;
+
+    Div {div}
+    DivAssign {div_assign}
+    DivFrom {div_from}
+    AssignDiv {assign_div}
+    nf_elem_div_checked;
+}
+
+impl_binop_unsafe! {
+    ctx_lhs
+    op_assign
+    NumFldElem, Integer, NumFldElem
+
+    Add {add}
+    AddAssign {add_assign}
+    AssignAdd {assign_add}
+    nf_elem_add_fmpz;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    AssignSub {assign_sub}
+    nf_elem_sub_fmpz;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    AssignMul {assign_mul}
+    nf_elem_scalar_mul_fmpz;
+
+    Div {div}
+    DivAssign {div_assign}
+    AssignDiv {assign_div}
+    nf_elem_scalar_div_fmpz_checked;
+}
+
+impl_binop_unsafe! {
+    ctx_lhs
+    op_assign
+    NumFldElem, Rational, NumFldElem
+
+    Add {add}
+    AddAssign {add_assign}
+    AssignAdd {assign_add}
+    nf_elem_add_fmpq;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    AssignSub {assign_sub}
+    nf_elem_sub_fmpq;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    AssignMul {assign_mul}
+    nf_elem_scalar_mul_fmpq;
+
+    Div {div}
+    DivAssign {div_assign}
+    AssignDiv {assign_div}
+    nf_elem_scalar_div_fmpq_checked;
+}
+
+impl_binop_unsafe! {
+    ctx_rhs
+    op_from
+    Integer, NumFldElem, NumFldElem
+
+    Add {add}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    nf_elem_fmpz_add;
+
+    Sub {sub}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    nf_elem_fmpz_sub;
+
+    Mul {mul}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    nf_elem_fmpz_scalar_mul;
+
+    Div {div}
+    DivFrom {div_from}
+    AssignDiv {assign_div}
+    nf_elem_fmpz_div;
+}
+
+impl_binop_unsafe! {
+    ctx_rhs
+    op_from
+    Rational, NumFldElem, NumFldElem
+
+    Add {add}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    nf_elem_fmpq_add;
+
+    Sub {sub}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    nf_elem_fmpq_sub;
+
+    Mul {mul}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    nf_elem_fmpq_scalar_mul;
+
+    Div {div}
+    DivFrom {div_from}
+    AssignDiv {assign_div}
+    nf_elem_fmpq_div;
+}
+
+#[inline]
+unsafe fn nf_elem_inv_checked(
+    res: *mut nf_elem_struct,
+    f: *const nf_elem_struct,
+    ctx: *const nf_struct,
+) {
+    assert!(nf_elem_is_zero(f, ctx) == 0, "Division by zero.");
+    nf_elem_inv(res, f, ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_div_checked(
+    res: *mut nf_elem_struct,
+    f: *const nf_elem_struct,
+    g: *const nf_elem_struct,
+    ctx: *const nf_struct,
+) {
+    assert!(nf_elem_is_zero(g, ctx) == 0, "Division by zero.");
+    nf_elem_div(res, f, g, ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_scalar_div_fmpz_checked(
+    res: *mut nf_elem_struct,
+    f: *const nf_elem_struct,
+    g: *const fmpz::fmpz,
+    ctx: *const nf_struct,
+) {
+    assert!(fmpz::fmpz_is_zero(g) == 0, "Division by zero.");
+    nf_elem_scalar_div_fmpz(res, f, g, ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_scalar_div_fmpq_checked(
+    res: *mut nf_elem_struct,
+    f: *const nf_elem_struct,
+    g: *const fmpq::fmpq,
+    ctx: *const nf_struct,
+) {
+    assert!(fmpq::fmpq_is_zero(g) == 0, "Division by zero.");
+    nf_elem_scalar_div_fmpq(res, f, g, ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_fmpz_add(
+    res: *mut nf_elem_struct,
+    f: *const fmpz::fmpz,
+    g: *const nf_elem_struct,
+    ctx: *const nf_struct,
+) {
+    nf_elem_add_fmpz(res, g, f, ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_fmpz_scalar_mul(
+    res: *mut nf_elem_struct,
+    f: *const fmpz::fmpz,
+    g: *const nf_elem_struct,
+    ctx: *const nf_struct,
+) {
+    nf_elem_scalar_mul_fmpz(res, g, f, ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_fmpz_div(
+    res: *mut nf_elem_struct,
+    f: *const fmpz::fmpz,
+    g: *const nf_elem_struct,
+    ctx: *const nf_struct,
+) {
+    // `res` may alias `g`
+    let mut z = MaybeUninit::uninit();
+    nf_elem_init(z.as_mut_ptr(), ctx);
+    nf_elem_inv_checked(z.as_mut_ptr(), g, ctx);
+    nf_elem_scalar_mul_fmpz(res, z.as_ptr(), f, ctx);
+    nf_elem_clear(z.as_mut_ptr(), ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_fmpq_add(
+    res: *mut nf_elem_struct,
+    f: *const fmpq::fmpq,
+    g: *const nf_elem_struct,
+    ctx: *const nf_struct,
+) {
+    nf_elem_add_fmpq(res, g, f, ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_fmpq_scalar_mul(
+    res: *mut nf_elem_struct,
+    f: *const fmpq::fmpq,
+    g: *const nf_elem_struct,
+    ctx: *const nf_struct,
+) {
+    nf_elem_scalar_mul_fmpq(res, g, f, ctx);
+}
+
+#[inline]
+unsafe fn nf_elem_fmpq_div(
+    res: *mut nf_elem_struct,
+    f: *const fmpq::fmpq,
+    g: *const nf_elem_struct,
+    ctx: *const nf_struct,
+) {
+    // `res` may alias `g`
+    let mut z = MaybeUninit::uninit();
+    nf_elem_init(z.as_mut_ptr(), ctx);
+    nf_elem_inv_checked(z.as_mut_ptr(), g, ctx);
+    nf_elem_scalar_mul_fmpq(res, z.as_ptr(), f, ctx);
+    nf_elem_clear(z.as_mut_ptr(), ctx);
+}
@@ -0,0 +1,298 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+
+impl NumFldCtx {
+    /// Return the matrix of the trace form `(a, b) -> Tr(ab)` with respect to
+    /// the power basis, whose `(i, j)`-th entry is `Tr(x^(i+j))`. When the 
+    /// defining polynomial is monic its determinant is the discriminant.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let k = NumFldCtx::new(RatPoly::from([-2, 0, 1]));
+    /// let t = k.trace_matrix();
+    /// assert_eq!(t, RatMat::new([2, 0, 0, 4], 2, 2));
+    /// ```
+    pub fn trace_matrix(&self) -> RatMat {
+        let n = self.degree();
+        let d = n as usize;
+        let x = self.gen();
+        // the traces of x^0, ..., x^(2n - 2)
+        let mut traces = Vec::with_capacity(2 * d - 1);
+        let mut p = NumFldElem::one(self);
+        for _ in 0..(2 * d - 1) {
+            traces.push(p.trace());
+            p = &p * &x;
+        }
+        let mut res = RatMat::zero(n, n);
+        for i in 0..d {
+            for j in 0..d {
+                res.set_entry(i, j, &traces[i + j]);
+            }
+        }
+        res
+    }
+
+    /// Return a basis of the maximal order (the ring of integers) of the 
+    /// number field as a pair `(M, d)`, where the rows of `M/d` are the 
+    /// coordinates of the basis elements with respect to the power basis and
+    /// `M` is in Hermite normal form. The defining polynomial must be monic 
+    /// with integer coefficients.
+    ///
+    /// The order is computed with the Round 2 algorithm: starting from the 
+    /// equation order `Z[x]`, for every prime `p` whose square divides the 
+    /// discriminant the order is replaced by the ring of multipliers of its
+    /// `p`-radical until it stops growing, at which point it is `p`-maximal.
+    /// The linear algebra is done in `O/pO`, so primes of any size are 
+    /// supported, but the discriminant of the defining polynomial has to be 
+    /// factored.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // x^2 - 5: the ring of integers is Z[(1 + sqrt(5))/2]
+    /// let k = NumFldCtx::new(RatPoly::from([-5, 0, 1]));
+    /// let (m, d) = k.maximal_order();
+    /// assert_eq!(d, 2);
+    /// assert_eq!(m, IntMat::new([1, 1, 0, 2], 2, 2));
+    /// assert_eq!(k.field_discriminant(), 5);
+    ///
+    /// // x^2 - 5p^2 with the prime p = 2^64 + 13 defines the same field
+    /// let p = Integer::from(2).pow(64u32) + Integer::from(13);
+    /// let c = -(Integer::from(5) * &p * &p);
+    /// let k = NumFldCtx::new(RatPoly::from([c, Integer::zero(), Integer::one()]));
+    /// assert_eq!(k.field_discriminant(), 5);
+    /// ```
+    pub fn maximal_order(&self) -> (IntMat, Integer) {
+        let pol = self.defining_polynomial();
+        assert!(
+            pol.denominator().is_one() && pol.get_coeff(pol.degree() as usize).is_one(),
+            "The defining polynomial must be monic with integer coefficients."
+        );
+        let n = self.degree();
+        let mut basis = RatMat::one(n);
+
+        let disc = Integer::try_from(self.discriminant())
+            .expect("The discriminant of a monic integer polynomial is an integer.");
+        for (p, e) in disc.factor() {
+            if e < 2 {
+                continue;
+            }
+            loop {
+                check_interrupt();
+                match enlarge_order(self, &basis, &p) {
+                    Some(b) => basis = b,
+                    None => break,
+                }
+            }
+        }
+
+        let d = basis.get_entries()
+            .iter()
+            .fold(Integer::one(), |d, c| d.lcm(c.denominator()));
+        let mut m = IntMat::zero(n, n);
+        for i in 0..n as usize {
+            for j in 0..n as usize {
+                let c = basis.get_entry(i, j) * Rational::from(&d);
+                m.set_entry(i, j, c.numerator());
+            }
+        }
+        (m.hnf(), d)
+    }
+
+    /// Return an integral basis of the number field, that is, a basis of its
+    /// maximal order over the integers. See [NumFldCtx::maximal_order].
+    pub fn integral_basis(&self) -> Vec<NumFldElem> {
+        let (m, d) = self.maximal_order();
+        let d = Rational::from(d);
+        m.rows()
+            .map(|row| {
+                let mut pol = RatPoly::zero();
+                for (j, c) in row.iter().enumerate() {
+                    pol.set_coeff(j, Rational::from(c) / &d);
+                }
+                NumFldElem::new(&pol, self)
+            })
+            .collect()
+    }
+
+    /// Return the discriminant of the number field, that is, of its maximal
+    /// order. See [NumFldCtx::maximal_order] for the conditions on the 
+    /// defining polynomial.
+    pub fn field_discriminant(&self) -> Integer {
+        let (m, d) = self.maximal_order();
+        // disc(O) = det(M/d)^2 disc(Z[x])
+        let index = Rational::from(m.det()) / Rational::from(d.pow(self.degree() as u64));
+        let res = &index * &index * self.discriminant();
+        Integer::try_from(res).expect("The discriminant of an order is an integer.")
+    }
+}
+
+// The element with power basis coordinates given by row `i` of `m`.
+fn row_elem(m: &RatMat, i: usize, ctx: &NumFldCtx) -> NumFldElem {
+    let mut pol = RatPoly::zero();
+    for j in 0..m.ncols() {
+        pol.set_coeff(j, m.get_entry(i, j));
+    }
+    NumFldElem::new(&pol, ctx)
+}
+
+// The integer coordinates of `a` with respect to the lattice whose basis 
+// matrix has inverse `inv`, reduced modulo `p`.
+fn coords_mod(a: &NumFldElem, inv: &RatMat, p: &Integer) -> Vec<Integer> {
+    let n = inv.nrows();
+    let pol = RatPoly::from(a);
+    let mut v = RatMat::zero(1, n as i64);
+    for j in 0..n {
+        v.set_entry(0, j, pol.get_coeff(j));
+    }
+    let c = &v * inv;
+    (0..n)
+        .map(|j| {
+            let x = Integer::try_from(c.get_entry(0, j))
+                .expect("The element does not lie in the lattice.");
+            x.fdiv_r(p)
+        })
+        .collect()
+}
+
+// The element with integer coordinates `c` with respect to the lattice with
+// basis matrix `basis`.
+fn from_coords(c: &[Integer], basis: &RatMat, ctx: &NumFldCtx) -> NumFldElem {
+    let mut v = RatMat::zero(1, c.len() as i64);
+    for (j, x) in c.iter().enumerate() {
+        v.set_entry(0, j, Rational::from(x));
+    }
+    row_elem(&(&v * basis), 0, ctx)
+}
+
+// A basis of the left kernel `{v : vM = 0}` of `m` over F_p, as the rows of 
+// the result, computed by Gauss-Jordan elimination of the transpose.
+fn left_kernel_mod(m: &IntMat, p: &Integer) -> IntMat {
+    let (rows, cols) = (m.ncols(), m.nrows());
+    let mut a: Vec<Vec<Integer>> = (0..rows)
+        .map(|i| (0..cols).map(|j| m.get_entry(j, i).fdiv_r(p)).collect())
+        .collect();
+    let mut pivots = Vec::new();
+    for c in 0..cols {
+        let r = pivots.len();
+        if r == rows {
+            break;
+        }
+        let i = match (r..rows).find(|&i| !a[i][c].is_zero()) {
+            Some(i) => i,
+            None => continue,
+        };
+        a.swap(r, i);
+        let inv = a[r][c].invmod(p).expect("The modulus must be prime.");
+        let pivot: Vec<Integer> = a[r].iter().map(|x| (x * &inv).fdiv_r(p)).collect();
+        for (i, row) in a.iter_mut().enumerate() {
+            if i != r && !row[c].is_zero() {
+                let f = row[c].clone();
+                for (x, y) in row.iter_mut().zip(&pivot) {
+                    *x = (&*x - &(&f * y)).fdiv_r(p);
+                }
+            }
+        }
+        a[r] = pivot;
+        pivots.push(c);
+    }
+
+    // one basis vector for each free column
+    let free: Vec<usize> = (0..cols).filter(|c| !pivots.contains(c)).collect();
+    let mut res = IntMat::zero(free.len() as i64, cols as i64);
+    for (t, &f) in free.iter().enumerate() {
+        res.set_entry(t, f, Integer::one());
+        for (i, &c) in pivots.iter().enumerate() {
+            res.set_entry(t, c, (-&a[i][f]).fdiv_r(p));
+        }
+    }
+    res
+}
+
+// The rows of an F_p matrix lifted to the integers, together with p times the
+// identity, put in Hermite normal form. This is the lattice pO + span(rows) in
+// the coordinates of O.
+fn lattice_mod_p(rows: &IntMat, n: usize, p: &Integer) -> IntMat {
+    let k = rows.nrows();
+    let mut gens = IntMat::zero((n + k) as i64, n as i64);
+    for i in 0..n {
+        gens.set_entry(i, i, p);
+    }
+    for i in 0..k {
+        for j in 0..n {
+            gens.set_entry(n + i, j, rows.get_entry(i, j));
+        }
+    }
+    gens.hnf().submatrix(0, 0, n, n)
+}
+
+// One step of Round 2 at the prime p: return the ring of multipliers of the 
+// p-radical of the order with basis matrix `basis` if it is strictly larger,
+// or None if the order is p-maximal.
+fn enlarge_order(ctx: &NumFldCtx, basis: &RatMat, p: &Integer) -> Option<RatMat> {
+    let n = basis.nrows();
+    let inv = basis.inv().unwrap();
+    let elems: Vec<NumFldElem> = (0..n).map(|i| row_elem(basis, i, ctx)).collect();
+    // reduce an element of O to a small representative of its class in O/pO
+    let reduce = |a: &NumFldElem| from_coords(&coords_mod(a, &inv, p), basis, ctx);
+
+    // the p-radical is the kernel of the Frobenius a -> a^q on O/pO, q >= n
+    let mut q = p.clone();
+    while q < Integer::from(n as u64) {
+        q = &q * p;
+    }
+    let mut frob = IntMat::zero(n as i64, n as i64);
+    for (i, b) in elems.iter().enumerate() {
+        let mut pow = NumFldElem::one(ctx);
+        for k in (0..q.bits()).rev() {
+            pow = reduce(&(&pow * &pow));
+            if q.testbit(k) {
+                pow = reduce(&(&pow * b));
+            }
+        }
+        for (j, c) in coords_mod(&pow, &inv, p).into_iter().enumerate() {
+            frob.set_entry(i, j, c);
+        }
+    }
+    let ker = left_kernel_mod(&frob, p);
+    let radical = lattice_mod_p(&ker, n, p);
+
+    // U/pO is the kernel of O/pO -> End(I/pI), u -> (a -> ua)
+    let ibasis = &RatMat::from(&radical) * basis;
+    let iinv = ibasis.inv().unwrap();
+    let ielems: Vec<NumFldElem> = (0..n).map(|i| row_elem(&ibasis, i, ctx)).collect();
+    let mut mult = IntMat::zero(n as i64, (n * n) as i64);
+    for (i, u) in elems.iter().enumerate() {
+        for (j, a) in ielems.iter().enumerate() {
+            for (k, c) in coords_mod(&(u * a), &iinv, p).into_iter().enumerate() {
+                mult.set_entry(i, j * n + k, c);
+            }
+        }
+    }
+    let ker = left_kernel_mod(&mult, p);
+    if ker.nrows() == 0 {
+        return None;
+    }
+    let u = lattice_mod_p(&ker, n, p);
+
+    // O' = U/p
+    let scale = Rational::from(1) / Rational::from(p);
+    Some(&(&RatMat::from(&u) * basis) * &scale)
+}
@@ -0,0 +1,525 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Parent objects for the integers, the rationals, the polynomial rings and
+//! matrix spaces. A parent fixes everything an element needs besides its
+//! entries (the base ring context and, for matrices, the dimensions), so
+//! elements can be created with `zero`, `one`, `new` and `random` without
+//! repeating it. Parents are also the targets of [Coerce].
+//!
+//! ```
+//! use inertia_core::*;
+//!
+//! let zn = IntModCtx::new(5);
+//! let ms = IntModMatSpace::init(2, 2, &zn);
+//! let a = ms.new([1, 2, 3, 4]);
+//! assert_eq!(&a * &ms.identity(), a);
+//!
+//! let r = IntModPolyRing::init(&zn);
+//! assert_eq!(r.gen() * r.gen(), r.new([0, 0, 1]));
+//! ```
+
+use crate::*;
+
+/// The ring of integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntegerRing;
+
+impl IntegerRing {
+    #[inline]
+    pub fn init() -> Self {
+        IntegerRing
+    }
+
+    #[inline]
+    pub fn new<T: Into<Integer>>(&self, src: T) -> Integer {
+        src.into()
+    }
+
+    #[inline]
+    pub fn zero(&self) -> Integer {
+        Integer::zero()
+    }
+
+    #[inline]
+    pub fn one(&self) -> Integer {
+        Integer::one()
+    }
+}
+
+/// The field of rational numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RationalField;
+
+impl RationalField {
+    #[inline]
+    pub fn init() -> Self {
+        RationalField
+    }
+
+    #[inline]
+    pub fn new<T: Into<Rational>>(&self, src: T) -> Rational {
+        src.into()
+    }
+
+    #[inline]
+    pub fn zero(&self) -> Rational {
+        Rational::zero()
+    }
+
+    #[inline]
+    pub fn one(&self) -> Rational {
+        Rational::one()
+    }
+}
+
+/// The ring of polynomials with integer coefficients.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntPolyRing;
+
+impl IntPolyRing {
+    #[inline]
+    pub fn init() -> Self {
+        IntPolyRing
+    }
+
+    /// Return the polynomial with the given coefficients.
+    #[inline]
+    pub fn new<T: Into<IntPoly>>(&self, src: T) -> IntPoly {
+        src.into()
+    }
+
+    #[inline]
+    pub fn zero(&self) -> IntPoly {
+        IntPoly::zero()
+    }
+
+    #[inline]
+    pub fn one(&self) -> IntPoly {
+        IntPoly::one()
+    }
+
+    /// Return the generator `x` of the ring.
+    #[inline]
+    pub fn gen(&self) -> IntPoly {
+        IntPoly::from([0, 1])
+    }
+
+    /// See [IntPoly::randtest].
+    #[inline]
+    pub fn random(&self, len: usize, bits: u64, rng: &mut FlintRand) -> IntPoly {
+        IntPoly::randtest(len, bits, rng)
+    }
+}
+
+/// The ring of polynomials with rational coefficients.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RatPolyRing;
+
+impl RatPolyRing {
+    #[inline]
+    pub fn init() -> Self {
+        RatPolyRing
+    }
+
+    /// Return the polynomial with the given coefficients.
+    #[inline]
+    pub fn new<T: Into<RatPoly>>(&self, src: T) -> RatPoly {
+        src.into()
+    }
+
+    #[inline]
+    pub fn zero(&self) -> RatPoly {
+        RatPoly::zero()
+    }
+
+    #[inline]
+    pub fn one(&self) -> RatPoly {
+        RatPoly::one()
+    }
+
+    /// Return the generator `x` of the ring.
+    #[inline]
+    pub fn gen(&self) -> RatPoly {
+        RatPoly::from([0, 1])
+    }
+
+    /// See [RatPoly::randtest].
+    #[inline]
+    pub fn random(&self, len: usize, bits: u64, rng: &mut FlintRand) -> RatPoly {
+        RatPoly::randtest(len, bits, rng)
+    }
+}
+
+/// The ring of polynomials over the integers modulo `n`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntModPolyRing {
+    ctx: IntModCtx,
+}
+
+impl IntModPolyRing {
+    #[inline]
+    pub fn init(ctx: &IntModCtx) -> Self {
+        IntModPolyRing { ctx: ctx.clone() }
+    }
+
+    #[inline]
+    pub fn context(&self) -> &IntModCtx {
+        &self.ctx
+    }
+
+    /// Return the polynomial with the given coefficients reduced modulo `n`.
+    #[inline]
+    pub fn new<T: Into<IntPoly>>(&self, src: T) -> IntModPoly {
+        IntModPoly::new(src, &self.ctx)
+    }
+
+    #[inline]
+    pub fn zero(&self) -> IntModPoly {
+        IntModPoly::zero(&self.ctx)
+    }
+
+    #[inline]
+    pub fn one(&self) -> IntModPoly {
+        IntModPoly::one(&self.ctx)
+    }
+
+    /// Return the generator `x` of the ring.
+    #[inline]
+    pub fn gen(&self) -> IntModPoly {
+        self.new([0, 1])
+    }
+
+    /// See [IntModPoly::randtest].
+    #[inline]
+    pub fn random(&self, len: usize, rng: &mut FlintRand) -> IntModPoly {
+        IntModPoly::randtest(len, &self.ctx, rng)
+    }
+}
+
+/// The ring of polynomials over a finite field.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FinFldPolyRing {
+    ctx: FinFldCtx,
+}
+
+impl FinFldPolyRing {
+    #[inline]
+    pub fn init(ctx: &FinFldCtx) -> Self {
+        FinFldPolyRing { ctx: ctx.clone() }
+    }
+
+    #[inline]
+    pub fn context(&self) -> &FinFldCtx {
+        &self.ctx
+    }
+
+    /// Return the polynomial with the given coefficients.
+    pub fn new<T: AsRef<FinFldElem>>(&self, coeffs: &[T]) -> FinFldPoly {
+        let mut res = FinFldPoly::with_capacity(coeffs.len(), &self.ctx);
+        for (i, c) in coeffs.iter().enumerate() {
+            res.set_coeff(i, c);
+        }
+        res
+    }
+
+    #[inline]
+    pub fn zero(&self) -> FinFldPoly {
+        FinFldPoly::zero(&self.ctx)
+    }
+
+    #[inline]
+    pub fn one(&self) -> FinFldPoly {
+        FinFldPoly::one(&self.ctx)
+    }
+
+    /// Return the generator `x` of the ring.
+    #[inline]
+    pub fn gen(&self) -> FinFldPoly {
+        let mut res = FinFldPoly::zero(&self.ctx);
+        res.set_coeff(1, FinFldElem::one(&self.ctx));
+        res
+    }
+
+    /// See [FinFldPoly::randtest].
+    #[inline]
+    pub fn random(&self, len: usize, rng: &mut FlintRand) -> FinFldPoly {
+        FinFldPoly::randtest(len, &self.ctx, rng)
+    }
+}
+
+/// The space of `nrows` by `ncols` matrices over the integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IntMatSpace {
+    nrows: i64,
+    ncols: i64,
+}
+
+impl IntMatSpace {
+    #[inline]
+    pub fn init(nrows: usize, ncols: usize) -> Self {
+        IntMatSpace {
+            nrows: nrows.try_into().expect("Cannot convert length to a signed long."),
+            ncols: ncols.try_into().expect("Cannot convert length to a signed long."),
+        }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows as usize
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols as usize
+    }
+
+    /// Return the matrix with the given entries in row-major order.
+    #[inline]
+    pub fn new<S>(&self, src: S) -> IntMat
+    where
+        IntMat: NewMatrix<S>
+    {
+        IntMat::new(src, self.nrows, self.ncols)
+    }
+
+    #[inline]
+    pub fn zero(&self) -> IntMat {
+        IntMat::zero(self.nrows, self.ncols)
+    }
+
+    /// Return the identity matrix. Panics unless the space is square.
+    #[inline]
+    pub fn one(&self) -> IntMat {
+        assert_eq!(self.nrows, self.ncols);
+        IntMat::one(self.nrows)
+    }
+
+    /// Return the identity matrix. Panics unless the space is square.
+    #[inline]
+    pub fn identity(&self) -> IntMat {
+        self.one()
+    }
+
+    /// See [IntMat::randtest].
+    #[inline]
+    pub fn random(&self, bits: u64, rng: &mut FlintRand) -> IntMat {
+        IntMat::randtest(self.nrows, self.ncols, bits, rng)
+    }
+}
+
+/// The space of `nrows` by `ncols` matrices over the rationals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RatMatSpace {
+    nrows: i64,
+    ncols: i64,
+}
+
+impl RatMatSpace {
+    #[inline]
+    pub fn init(nrows: usize, ncols: usize) -> Self {
+        RatMatSpace {
+            nrows: nrows.try_into().expect("Cannot convert length to a signed long."),
+            ncols: ncols.try_into().expect("Cannot convert length to a signed long."),
+        }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows as usize
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols as usize
+    }
+
+    /// Return the matrix with the given entries in row-major order.
+    #[inline]
+    pub fn new<S>(&self, src: S) -> RatMat
+    where
+        RatMat: NewMatrix<S>
+    {
+        RatMat::new(src, self.nrows, self.ncols)
+    }
+
+    #[inline]
+    pub fn zero(&self) -> RatMat {
+        RatMat::zero(self.nrows, self.ncols)
+    }
+
+    /// Return the identity matrix. Panics unless the space is square.
+    #[inline]
+    pub fn one(&self) -> RatMat {
+        assert_eq!(self.nrows, self.ncols);
+        RatMat::one(self.nrows)
+    }
+
+    /// Return the identity matrix. Panics unless the space is square.
+    #[inline]
+    pub fn identity(&self) -> RatMat {
+        self.one()
+    }
+
+    /// See [RatMat::randtest].
+    #[inline]
+    pub fn random(&self, bits: u64, rng: &mut FlintRand) -> RatMat {
+        RatMat::randtest(self.nrows, self.ncols, bits, rng)
+    }
+}
+
+/// The space of `nrows` by `ncols` matrices over the integers modulo `n`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntModMatSpace {
+    nrows: i64,
+    ncols: i64,
+    ctx: IntModCtx,
+}
+
+impl IntModMatSpace {
+    #[inline]
+    pub fn init(nrows: usize, ncols: usize, ctx: &IntModCtx) -> Self {
+        IntModMatSpace {
+            nrows: nrows.try_into().expect("Cannot convert length to a signed long."),
+            ncols: ncols.try_into().expect("Cannot convert length to a signed long."),
+            ctx: ctx.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows as usize
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols as usize
+    }
+
+    #[inline]
+    pub fn context(&self) -> &IntModCtx {
+        &self.ctx
+    }
+
+    /// Return the matrix with the given integer entries in row-major order,
+    /// reduced modulo `n`.
+    pub fn new<S>(&self, src: S) -> IntModMat
+    where
+        IntMat: NewMatrix<S>
+    {
+        let src = IntMat::new(src, self.nrows, self.ncols);
+        let mut res = self.zero();
+        for i in 0..self.nrows() {
+            for j in 0..self.ncols() {
+                res.set_entry(i, j, IntMod::new(src.get_entry(i, j), &self.ctx));
+            }
+        }
+        res
+    }
+
+    #[inline]
+    pub fn zero(&self) -> IntModMat {
+        IntModMat::zero(self.nrows, self.ncols, &self.ctx)
+    }
+
+    /// Return the identity matrix. Panics unless the space is square.
+    #[inline]
+    pub fn one(&self) -> IntModMat {
+        assert_eq!(self.nrows, self.ncols);
+        IntModMat::one(self.nrows, &self.ctx)
+    }
+
+    /// Return the identity matrix. Panics unless the space is square.
+    #[inline]
+    pub fn identity(&self) -> IntModMat {
+        self.one()
+    }
+
+    /// See [IntModMat::randtest].
+    #[inline]
+    pub fn random(&self, rng: &mut FlintRand) -> IntModMat {
+        IntModMat::randtest(self.nrows, self.ncols, &self.ctx, rng)
+    }
+}
+
+/// The space of `nrows` by `ncols` matrices over a finite field.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FinFldMatSpace {
+    nrows: i64,
+    ncols: i64,
+    ctx: FinFldCtx,
+}
+
+impl FinFldMatSpace {
+    #[inline]
+    pub fn init(nrows: usize, ncols: usize, ctx: &FinFldCtx) -> Self {
+        FinFldMatSpace {
+            nrows: nrows.try_into().expect("Cannot convert length to a signed long."),
+            ncols: ncols.try_into().expect("Cannot convert length to a signed long."),
+            ctx: ctx.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows as usize
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols as usize
+    }
+
+    #[inline]
+    pub fn context(&self) -> &FinFldCtx {
+        &self.ctx
+    }
+
+    /// Return the matrix with the given entries in row-major order.
+    pub fn new<T: AsRef<FinFldElem>>(&self, src: &[T]) -> FinFldMat {
+        assert_eq!(src.len(), self.nrows() * self.ncols());
+        let mut res = self.zero();
+        for (k, x) in src.iter().enumerate() {
+            res.set_entry(k / self.ncols(), k % self.ncols(), x);
+        }
+        res
+    }
+
+    #[inline]
+    pub fn zero(&self) -> FinFldMat {
+        FinFldMat::zero(self.nrows, self.ncols, &self.ctx)
+    }
+
+    /// Return the identity matrix. Panics unless the space is square.
+    #[inline]
+    pub fn one(&self) -> FinFldMat {
+        assert_eq!(self.nrows, self.ncols);
+        FinFldMat::one(self.nrows, &self.ctx)
+    }
+
+    /// Return the identity matrix. Panics unless the space is square.
+    #[inline]
+    pub fn identity(&self) -> FinFldMat {
+        self.one()
+    }
+
+    /// See [FinFldMat::randtest].
+    #[inline]
+    pub fn random(&self, rng: &mut FlintRand) -> FinFldMat {
+        FinFldMat::randtest(self.nrows, self.ncols, &self.ctx, rng)
+    }
+}
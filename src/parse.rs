@@ -0,0 +1,206 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// A small shared parser for the `FromStr` impls. The functions here only deal
+// with the syntax and return the reason for a failure, which the callers wrap
+// in the error variant of their type.
+
+use crate::{Integer, Rational};
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+type ParseResult<T> = std::result::Result<T, String>;
+
+// Parse a sequence of decimal digits, without a sign.
+pub fn digits(s: &str) -> ParseResult<Integer> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("{:?} is not a sequence of digits.", s));
+    }
+    Integer::from_str(s).map_err(|e| e.to_string())
+}
+
+// Parse an optionally signed integer `[+-]d+`.
+pub fn integer(s: &str) -> ParseResult<Integer> {
+    let s = s.trim();
+    match s.strip_prefix('-') {
+        Some(d) => Ok(-digits(d)?),
+        None => digits(s.strip_prefix('+').unwrap_or(s)),
+    }
+}
+
+// Parse an optionally signed fraction `[+-]d+` or `[+-]d+/d+`.
+pub fn rational(s: &str) -> ParseResult<Rational> {
+    let s = s.trim();
+    match s.split_once('/') {
+        None => Ok(Rational::from(integer(s)?)),
+        Some((n, d)) => {
+            let n = integer(n)?;
+            let d = digits(d.trim())?;
+            if d.is_zero() {
+                return Err("The denominator is zero.".to_string());
+            }
+            Ok(Rational::from([n, d]))
+        }
+    }
+}
+
+// Parse a polynomial into its dense list of coefficients, constant term
+// first. Two forms are accepted: a coefficient list `[c0, c1, ...]`, and a
+// sum of terms like `x^2 + 3*x - 1/2` in a single variable, where the `*` is
+// optional and repeated powers are added up.
+pub fn poly(s: &str) -> ParseResult<Vec<Rational>> {
+    let s = s.trim();
+    if let Some(inner) = s.strip_prefix('[') {
+        let inner = inner.strip_suffix(']').ok_or("Missing closing bracket.")?;
+        return entries(inner).into_iter().map(rational).collect();
+    }
+    PolyParser { chars: s.chars().peekable(), var: None }.parse()
+}
+
+struct PolyParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    var: Option<String>,
+}
+
+impl PolyParser<'_> {
+    fn skip_ws(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    fn take_while<F: Fn(char) -> bool>(&mut self, pred: F) -> String {
+        let mut out = String::new();
+        while let Some(c) = self.chars.next_if(|&c| pred(c)) {
+            out.push(c);
+        }
+        out
+    }
+
+    fn parse(mut self) -> ParseResult<Vec<Rational>> {
+        let mut coeffs: Vec<Rational> = Vec::new();
+        self.skip_ws();
+        if self.chars.peek().is_none() {
+            return Err("Empty input.".to_string());
+        }
+
+        let mut first = true;
+        loop {
+            self.skip_ws();
+            let neg = match self.chars.peek() {
+                None => break,
+                Some('+') => { self.chars.next(); false },
+                Some('-') => { self.chars.next(); true },
+                Some(_) if first => false,
+                Some(&c) => return Err(format!("Expected '+' or '-', found {:?}.", c)),
+            };
+            first = false;
+
+            let (mut c, k) = self.term()?;
+            if neg {
+                c = -c;
+            }
+            if coeffs.len() <= k {
+                coeffs.resize(k + 1, Rational::zero());
+            }
+            coeffs[k] += c;
+        }
+        Ok(coeffs)
+    }
+
+    // Parse a single unsigned term `c`, `c*x^k`, `c x^k` or `x^k`.
+    fn term(&mut self) -> ParseResult<(Rational, usize)> {
+        self.skip_ws();
+        let num = self.take_while(|c| c.is_ascii_digit());
+        let c = if num.is_empty() {
+            Rational::one()
+        } else {
+            self.skip_ws();
+            let c = if self.chars.next_if_eq(&'/').is_some() {
+                self.skip_ws();
+                let den = self.take_while(|c| c.is_ascii_digit());
+                rational(&format!("{}/{}", num, den))?
+            } else {
+                Rational::from(digits(&num)?)
+            };
+            self.skip_ws();
+            if self.chars.next_if_eq(&'*').is_some() {
+                self.skip_ws();
+            } else if !matches!(self.chars.peek(), Some(c) if c.is_alphabetic()) {
+                return Ok((c, 0));
+            }
+            c
+        };
+
+        let var = self.take_while(|c| c.is_alphanumeric() || c == '_');
+        if var.is_empty() || !var.starts_with(char::is_alphabetic) {
+            return match self.chars.peek() {
+                Some(c) => Err(format!("Unexpected {:?}.", c)),
+                None => Err("Unexpected end of input.".to_string()),
+            };
+        }
+        match &self.var {
+            Some(v) if *v != var => {
+                return Err(format!("Found variables {} and {}.", v, var));
+            }
+            _ => self.var = Some(var),
+        }
+
+        self.skip_ws();
+        if self.chars.next_if_eq(&'^').is_none() {
+            return Ok((c, 1));
+        }
+        self.skip_ws();
+        let exp = self.take_while(|c| c.is_ascii_digit());
+        let k = exp.parse::<usize>().map_err(|_| format!("Invalid exponent {:?}.", exp))?;
+        Ok((c, k))
+    }
+}
+
+// Split a row or list on commas and whitespace.
+pub fn entries(s: &str) -> Vec<&str> {
+    s.split(|c: char| c == ',' || c.is_whitespace()).filter(|e| !e.is_empty()).collect()
+}
+
+// Parse a matrix into its dimensions and row-major entries. Three forms are
+// accepted: nested lists `[[1, 2], [3, 4]]`, Pari/GP syntax `[1, 2; 3, 4]`, 
+// and the `Display` form with one bracketed row per line. The empty string is
+// the zero by zero matrix.
+pub fn matrix(s: &str) -> ParseResult<(usize, usize, Vec<&str>)> {
+    let mut s = s.trim();
+    let mut rows: Vec<Vec<&str>> = Vec::new();
+    if let Some(inner) = s.strip_prefix('[') {
+        if inner.trim_start().starts_with('[') {
+            s = inner.strip_suffix(']').ok_or("Missing closing bracket.")?.trim();
+        } else if inner.contains(';') {
+            let inner = inner.strip_suffix(']').ok_or("Missing closing bracket.")?;
+            rows = inner.split(';').map(entries).collect();
+            s = "";
+        }
+    }
+
+    while !s.is_empty() {
+        let rest = s.strip_prefix('[').ok_or("Each row must start with '['.")?;
+        let end = rest.find(']').ok_or("Missing closing bracket.")?;
+        rows.push(entries(&rest[..end]));
+        s = rest[end + 1..].trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+    }
+
+    let ncols = rows.first().map_or(0, |r| r.len());
+    if rows.iter().any(|r| r.len() != ncols) {
+        return Err("Rows have different lengths.".to_string());
+    }
+    Ok((rows.len(), ncols, rows.concat()))
+}
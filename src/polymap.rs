@@ -0,0 +1,126 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use std::ops::{Add, Mul};
+
+/// The ring homomorphism from a univariate polynomial ring into the ring with
+/// parent `R` sending the variable to `image`. The coefficients are mapped by
+/// [Coerce], so the same map applies to [IntPoly], [RatPoly] and [IntModPoly]
+/// whenever their coefficients coerce into `R`.
+///
+/// Mapping into a square matrix space evaluates the matrix polynomial `f(A)`:
+///
+/// ```
+/// use inertia_core::*;
+///
+/// // A satisfies its characteristic polynomial
+/// let a = IntMat::new([1, 2, 3, 4], 2, 2);
+/// let phi = PolyMap::new(&IntMatSpace::init(2, 2), a.clone());
+/// assert!(phi.apply(&a.charpoly()).is_zero());
+///
+/// let f = IntPoly::from([1, 0, 1]);
+/// assert_eq!(phi.apply(&f), &a * &a + IntMat::one(2));
+/// ```
+///
+/// Evaluating at an element of `Z/nZ` or of a number field:
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let zn = IntModCtx::new(7);
+/// let phi = PolyMap::new(&zn, IntMod::new(3, &zn));
+/// assert_eq!(phi.apply(&IntPoly::from([1, 0, 1])), 3);
+///
+/// let k = NumFldCtx::new(RatPoly::from([-2, 0, 1]));
+/// let a = NumFldElem::new(RatPoly::from([0, 1]), &k);
+/// let phi = PolyMap::new(&k, a);
+/// assert!(phi.apply(&RatPoly::from([-2, 0, 1])).is_zero());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolyMap<R, T> {
+    ring: R,
+    image: T,
+}
+
+/// A polynomial whose coefficients can be mapped by a [PolyMap].
+pub trait PolyCoeffs {
+    type Coeff;
+
+    /// Return the coefficients in order of increasing degree.
+    fn coeffs(&self) -> Vec<Self::Coeff>;
+}
+
+macro_rules! impl_poly_coeffs {
+    ($($poly:ident, $coeff:ident);*) => {$(
+        impl PolyCoeffs for $poly {
+            type Coeff = $coeff;
+            #[inline]
+            fn coeffs(&self) -> Vec<$coeff> {
+                self.get_coeffs()
+            }
+        }
+    )*};
+}
+
+impl_poly_coeffs!(IntPoly, Integer; RatPoly, Rational; IntModPoly, IntMod);
+
+impl<R: Clone, T> PolyMap<R, T> {
+    /// Return the map sending the variable to `image`, an element of the ring
+    /// with parent `ring`.
+    #[inline]
+    pub fn new(ring: &R, image: T) -> Self {
+        PolyMap { ring: ring.clone(), image }
+    }
+
+    /// Return the parent of the target ring.
+    #[inline]
+    pub fn ring(&self) -> &R {
+        &self.ring
+    }
+
+    /// Return the image of the variable.
+    #[inline]
+    pub fn image(&self) -> &T {
+        &self.image
+    }
+}
+
+impl<R, T> PolyMap<R, T>
+where
+    T: Clone,
+    for<'a> &'a T: Add<&'a T, Output = T> + Mul<&'a T, Output = T>,
+{
+    /// Return the image of `f`, computed by Horner's rule.
+    pub fn apply<P>(&self, f: &P) -> T
+    where
+        P: PolyCoeffs,
+        P::Coeff: Coerce<R, Output = T>,
+        Integer: Coerce<R, Output = T>,
+    {
+        let coeffs = f.coeffs();
+        let mut iter = coeffs.iter().rev();
+        let mut res = match iter.next() {
+            Some(c) => c.coerce_into(&self.ring),
+            None => return Integer::zero().coerce_into(&self.ring),
+        };
+        for c in iter {
+            res = &(&res * &self.image) + &c.coerce_into(&self.ring);
+        }
+        res
+    }
+}
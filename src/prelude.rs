@@ -0,0 +1,116 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! A single import for interactive and notebook use: the construction and
+//! operation traits, the main types with their contexts, the construction
+//! macros, and short names for the common rings.
+//!
+//! ```
+//! use inertia_core::prelude::*;
+//!
+//! let x = ZZ::from(6);
+//! let y = QQ::from([1, 3]);
+//! assert_eq!(x * y, 2);
+//!
+//! let zn = Zmod(7);
+//! assert_eq!(IntMod::new(10, &zn), 3);
+//!
+//! let k = GF(25);
+//! assert_eq!(k.prime(), 5);
+//! assert_eq!(k.degree(), 2);
+//! ```
+
+pub use inertia_algebra::ops::*;
+
+pub use crate::{
+    Error,
+    Result,
+    ToLatex,
+    Coerce,
+    PolyMap,
+    FlintRand,
+    Integer,
+    IntPoly,
+    IntMat,
+    Rational,
+    RatPoly,
+    RatMat,
+    RatFunc,
+    IntModCtx,
+    IntMod,
+    IntModPoly,
+    IntModMat,
+    NmodRing,
+    Nmod,
+    NmodPoly,
+    NmodMat,
+    FinFldCtx,
+    FinFldElem,
+    FinFldPoly,
+    FinFldMat,
+    IntVec,
+    RatVec,
+    ModVec,
+    Real,
+    RealField,
+    Complex,
+    ComplexField,
+    NumFldCtx,
+    NumFldElem,
+    Series,
+    RatSeries,
+    IntegerRing,
+    RationalField,
+    IntPolyRing,
+    RatPolyRing,
+    IntModPolyRing,
+    FinFldPolyRing,
+    IntMatSpace,
+    RatMatSpace,
+    IntModMatSpace,
+    FinFldMatSpace,
+};
+
+pub use crate::{integer, rational, intpoly, intmat};
+
+/// The integers, as an alias of [Integer].
+pub type ZZ = Integer;
+
+/// The rationals, as an alias of [Rational].
+pub type QQ = Rational;
+
+/// Return the context of the integers modulo `n`.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Zmod<T: Into<Integer>>(n: T) -> IntModCtx {
+    IntModCtx::new(n)
+}
+
+/// Return the context of the finite field with `q` elements. Panics unless
+/// `q` is a prime power.
+#[allow(non_snake_case)]
+pub fn GF<T: Into<Integer>>(q: T) -> FinFldCtx {
+    let q = q.into();
+    assert!(q > 1, "The order of a finite field must be a prime power.");
+    let (mut p, mut k) = (q, 1i64);
+    while let Some((r, e)) = p.is_perfect_power() {
+        p = r;
+        k *= i64::from(e);
+    }
+    assert!(p.is_prime(), "The order of a finite field must be a prime power.");
+    FinFldCtx::new(p, k)
+}
@@ -0,0 +1,120 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{check_interrupt, Integer};
+use flint_sys::{fmpz, ulong_extras};
+
+/// An iterator over increasing word-size primes suitable for multi-modular
+/// algorithms. Primes dividing any of the integers registered with
+/// [avoiding][ModularPrimes::avoiding] (for example a leading coefficient or
+/// a determinant divisor) are skipped, so every prime yielded is of good
+/// reduction.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let primes: Vec<u64> = ModularPrimes::starting_at(10)
+///     .avoiding(Integer::from(13 * 17))
+///     .take(3)
+///     .collect();
+/// assert_eq!(primes, vec![11, 19, 23]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ModularPrimes {
+    current: u64,
+    avoid: Vec<Integer>,
+}
+
+impl Default for ModularPrimes {
+    /// Primes starting just above `2^63`.
+    #[inline]
+    fn default() -> Self {
+        ModularPrimes::with_bits(64)
+    }
+}
+
+impl ModularPrimes {
+    /// Primes of at least `bits` bits, in increasing order. Panics unless
+    /// `2 <= bits <= 64`.
+    #[inline]
+    pub fn with_bits(bits: u32) -> Self {
+        assert!((2..=64).contains(&bits));
+        ModularPrimes::starting_at(1u64 << (bits - 1))
+    }
+
+    /// Primes strictly greater than `n`, in increasing order.
+    #[inline]
+    pub fn starting_at(n: u64) -> Self {
+        ModularPrimes { current: n, avoid: vec![] }
+    }
+
+    /// Skip primes dividing `x`. Panics if `x` is zero.
+    #[inline]
+    pub fn avoiding<T: AsRef<Integer>>(mut self, x: T) -> Self {
+        let x = x.as_ref();
+        assert!(!x.is_zero());
+        self.avoid.push(x.abs());
+        self
+    }
+
+    /// Return the shortest list of primes from the iterator whose product
+    /// exceeds `2*bound`, enough to recover any integer of absolute value at
+    /// most `bound` from its residues with a symmetric Chinese remainder.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let m = IntMat::new([3, 1, -1, 2], 2, 2);
+    /// let primes = ModularPrimes::with_bits(4).covering(&m.hadamard_bound());
+    /// assert_eq!(primes, vec![11, 13]);
+    /// ```
+    pub fn covering<T: AsRef<Integer>>(&mut self, bound: T) -> Vec<u64> {
+        let target = bound.as_ref().abs() * 2u32;
+        let mut prod = Integer::one();
+        let mut res = vec![];
+        while prod <= target {
+            let p = self.next().expect("Ran out of word-size primes.");
+            prod *= Integer::from(p);
+            res.push(p);
+        }
+        res
+    }
+
+    fn is_bad(&self, p: u64) -> bool {
+        self.avoid
+            .iter()
+            .any(|x| unsafe { fmpz::fmpz_fdiv_ui(x.as_ptr(), p) == 0 })
+    }
+}
+
+impl Iterator for ModularPrimes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            check_interrupt();
+            // the largest prime below 2^64
+            if self.current >= 18446744073709551557 {
+                return None;
+            }
+            self.current = unsafe { ulong_extras::n_nextprime(self.current, 1) };
+            if !self.is_bad(self.current) {
+                return Some(self.current);
+            }
+        }
+    }
+}
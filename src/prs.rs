@@ -0,0 +1,237 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// The subresultant polynomial remainder sequence over a field, shared by the 
+// rational, modular and finite field polynomial types. It follows the 
+// integer version [IntPoly::subresultants], with the pseudo-remainders 
+// replaced by remainders scaled back to the same sequence.
+
+use crate::*;
+use flint_sys::{fmpq_poly, fmpz_mod_poly, fq_default_poly};
+
+// The field polynomial types with a subresultant sequence. The methods are 
+// prefixed so that they do not shadow the inherent methods of the 
+// polynomial types.
+pub(crate) trait PrsPoly: Clone {
+    type Coeff: Clone;
+    fn prs_degree(&self) -> i64;
+    fn prs_is_zero(&self) -> bool;
+    fn prs_lc(&self) -> Self::Coeff;
+    fn prs_one(&self) -> Self::Coeff;
+    fn prs_rem(&self, other: &Self) -> Self;
+    fn prs_scale(&mut self, c: &Self::Coeff);
+    fn prs_pow(c: &Self::Coeff, e: u64) -> Self::Coeff;
+    fn prs_mul(a: &Self::Coeff, b: &Self::Coeff) -> Self::Coeff;
+    fn prs_div(a: &Self::Coeff, b: &Self::Coeff) -> Self::Coeff;
+}
+
+pub(crate) fn subresultants<P: PrsPoly>(x: &P, y: &P) -> Vec<P> {
+    let (mut a, mut b) = if x.prs_degree() >= y.prs_degree() {
+        (x.clone(), y.clone())
+    } else {
+        (y.clone(), x.clone())
+    };
+    if b.prs_is_zero() {
+        return if a.prs_is_zero() { vec![] } else { vec![a] };
+    }
+
+    let mut res = vec![a.clone(), b.clone()];
+    let mut g = a.prs_one();
+    let mut h = a.prs_one();
+    loop {
+        check_interrupt();
+        let delta = (a.prs_degree() - b.prs_degree()) as u64;
+        let lc = b.prs_lc();
+        let mut r = a.prs_rem(&b);
+        if r.prs_is_zero() {
+            break;
+        }
+
+        // the pseudo-remainder lc^(delta + 1) * r divided by g * h^delta
+        let c = P::prs_div(
+            &P::prs_pow(&lc, delta + 1), 
+            &P::prs_mul(&g, &P::prs_pow(&h, delta))
+        );
+        r.prs_scale(&c);
+        a = b;
+        b = r;
+        g = lc;
+        h = if delta == 0 {
+            h
+        } else {
+            P::prs_div(&P::prs_pow(&g, delta), &P::prs_pow(&h, delta - 1))
+        };
+        res.push(b.clone());
+    }
+    res
+}
+
+impl PrsPoly for RatPoly {
+    type Coeff = Rational;
+    #[inline]
+    fn prs_degree(&self) -> i64 {
+        self.degree()
+    }
+    #[inline]
+    fn prs_is_zero(&self) -> bool {
+        self.is_zero()
+    }
+    #[inline]
+    fn prs_lc(&self) -> Rational {
+        self.get_coeff(self.degree() as usize)
+    }
+    #[inline]
+    fn prs_one(&self) -> Rational {
+        Rational::one()
+    }
+    #[inline]
+    fn prs_rem(&self, other: &Self) -> Self {
+        let mut res = RatPoly::default();
+        unsafe { fmpq_poly::fmpq_poly_rem(res.as_mut_ptr(), self.as_ptr(), other.as_ptr()); }
+        res
+    }
+    #[inline]
+    fn prs_scale(&mut self, c: &Rational) {
+        unsafe {
+            fmpq_poly::fmpq_poly_scalar_mul_fmpq(self.as_mut_ptr(), self.as_ptr(), c.as_ptr());
+        }
+    }
+    #[inline]
+    fn prs_pow(c: &Rational, e: u64) -> Rational {
+        c.pow(e)
+    }
+    #[inline]
+    fn prs_mul(a: &Rational, b: &Rational) -> Rational {
+        a * b
+    }
+    #[inline]
+    fn prs_div(a: &Rational, b: &Rational) -> Rational {
+        a / b
+    }
+}
+
+impl PrsPoly for IntModPoly {
+    type Coeff = IntMod;
+    #[inline]
+    fn prs_degree(&self) -> i64 {
+        self.degree()
+    }
+    #[inline]
+    fn prs_is_zero(&self) -> bool {
+        self.is_zero()
+    }
+    #[inline]
+    fn prs_lc(&self) -> IntMod {
+        self.get_coeff(self.degree() as usize)
+    }
+    #[inline]
+    fn prs_one(&self) -> IntMod {
+        IntMod::one(self.context())
+    }
+    #[inline]
+    fn prs_rem(&self, other: &Self) -> Self {
+        let mut res = IntModPoly::zero(self.context());
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_rem(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+    #[inline]
+    fn prs_scale(&mut self, c: &IntMod) {
+        unsafe {
+            fmpz_mod_poly::fmpz_mod_poly_scalar_mul_fmpz(
+                self.as_mut_ptr(), 
+                self.as_ptr(), 
+                c.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+    }
+    #[inline]
+    fn prs_pow(c: &IntMod, e: u64) -> IntMod {
+        c.pow(e)
+    }
+    #[inline]
+    fn prs_mul(a: &IntMod, b: &IntMod) -> IntMod {
+        a * b
+    }
+    #[inline]
+    fn prs_div(a: &IntMod, b: &IntMod) -> IntMod {
+        a / b
+    }
+}
+
+impl PrsPoly for FinFldPoly {
+    type Coeff = FinFldElem;
+    #[inline]
+    fn prs_degree(&self) -> i64 {
+        self.degree()
+    }
+    #[inline]
+    fn prs_is_zero(&self) -> bool {
+        self.is_zero()
+    }
+    #[inline]
+    fn prs_lc(&self) -> FinFldElem {
+        self.get_coeff(self.degree() as usize)
+    }
+    #[inline]
+    fn prs_one(&self) -> FinFldElem {
+        FinFldElem::one(self.context())
+    }
+    #[inline]
+    fn prs_rem(&self, other: &Self) -> Self {
+        let mut res = FinFldPoly::zero(self.context());
+        unsafe {
+            fq_default_poly::fq_default_poly_rem(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+        res
+    }
+    #[inline]
+    fn prs_scale(&mut self, c: &FinFldElem) {
+        unsafe {
+            fq_default_poly::fq_default_poly_scalar_mul_fq(
+                self.as_mut_ptr(), 
+                self.as_ptr(), 
+                c.as_ptr(), 
+                self.ctx_as_ptr()
+            );
+        }
+    }
+    #[inline]
+    fn prs_pow(c: &FinFldElem, e: u64) -> FinFldElem {
+        c.clone().pow(e)
+    }
+    #[inline]
+    fn prs_mul(a: &FinFldElem, b: &FinFldElem) -> FinFldElem {
+        a.clone() * b.clone()
+    }
+    #[inline]
+    fn prs_div(a: &FinFldElem, b: &FinFldElem) -> FinFldElem {
+        a.clone() / b.clone()
+    }
+}
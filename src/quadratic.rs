@@ -0,0 +1,488 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+//! Arithmetic in the Euclidean imaginary quadratic orders `Z[i]` and `Z[w]`,
+//! the Gaussian and Eisenstein integers. Both are unique factorization 
+//! domains and primes are found from the splitting of rational primes: `p`
+//! splits in `Z[i]` if `p = 1 mod 4` and in `Z[w]` if `p = 1 mod 3`, is inert
+//! if `p = 3 mod 4`, respectively `p = 2 mod 3`, and `2`, respectively `3`,
+//! ramifies.
+//!
+//! ```
+//! use inertia_core::quadratic::*;
+//!
+//! let z = GaussInt::new(3, 4);
+//! assert_eq!(z.norm(), 25);
+//! let (u, fac) = z.factor();
+//! assert_eq!(fac, [(GaussInt::new(2, 1), 2)]);
+//! assert_eq!(&u * &(&fac[0].0 * &fac[0].0), z);
+//!
+//! let w = EisensteinInt::omega();
+//! assert_eq!(&(&w * &w) * &w, EisensteinInt::one());
+//! ```
+
+use crate::*;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Round x/n to the nearest integer, n > 0.
+fn round_div(x: &Integer, n: &Integer) -> Integer {
+    let two = Integer::from(2);
+    (&two * x + n).fdiv_q(&two * n)
+}
+
+// Format `a + b*sym`.
+fn fmt_quadratic(f: &mut fmt::Formatter, a: &Integer, b: &Integer, sym: &str) -> fmt::Result {
+    let imag = if b.abs().is_one() {
+        sym.to_string()
+    } else {
+        format!("{}*{}", b.abs(), sym)
+    };
+    if b.is_zero() {
+        write!(f, "{}", a)
+    } else if a.is_zero() {
+        write!(f, "{}{}", if b.sign() < 0 { "-" } else { "" }, imag)
+    } else {
+        write!(f, "{} {} {}", a, if b.sign() < 0 { "-" } else { "+" }, imag)
+    }
+}
+
+// Operations common to both orders, given the multiplication, conjugation,
+// norm, units and the prime above each rational prime.
+macro_rules! impl_quadratic {
+    ($t:ident, $a:ident, $b:ident) => {
+        impl $t {
+            /// Return the element `a + b*x` where `x` is the generator.
+            #[inline]
+            pub fn new<S, T>(a: S, b: T) -> Self
+            where
+                S: Into<Integer>,
+                T: Into<Integer>
+            {
+                $t { $a: a.into(), $b: b.into() }
+            }
+
+            #[inline]
+            pub fn zero() -> Self {
+                $t::new(0, 0)
+            }
+
+            #[inline]
+            pub fn one() -> Self {
+                $t::new(1, 0)
+            }
+
+            #[inline]
+            pub fn is_zero(&self) -> bool {
+                self.$a.is_zero() && self.$b.is_zero()
+            }
+
+            #[inline]
+            pub fn is_one(&self) -> bool {
+                self.$a.is_one() && self.$b.is_zero()
+            }
+
+            /// Return true if the element is a unit, i.e. has norm one.
+            #[inline]
+            pub fn is_unit(&self) -> bool {
+                self.norm().is_one()
+            }
+
+            /// Return the associate of a nonzero element in the fundamental 
+            /// sector, used as the canonical representative of the ideal it 
+            /// generates. Zero is returned unchanged.
+            pub fn normalize(&self) -> Self {
+                if self.is_zero() {
+                    return self.clone();
+                }
+                $t::units()
+                    .iter()
+                    .map(|u| u * self)
+                    .find(|z| z.is_normalized())
+                    .expect("Every nonzero element has a normalized associate.")
+            }
+
+            /// Return the quotient and remainder `(q, r)` of Euclidean 
+            /// division, with `self = q*other + r` and `N(r) < N(other)`. The
+            /// quotient is the exact quotient rounded to the nearest lattice
+            /// point. Panics if `other` is zero.
+            pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+                assert!(!other.is_zero(), "Division by zero.");
+                let n = other.norm();
+                let z = self * &other.conj();
+                let q = $t { $a: round_div(&z.$a, &n), $b: round_div(&z.$b, &n) };
+                let r = self - &(&q * other);
+                (q, r)
+            }
+
+            /// Return the exact quotient `self/other` if `other` divides 
+            /// `self`, otherwise `None`. Panics if `other` is zero.
+            pub fn div_exact(&self, other: &Self) -> Option<Self> {
+                let (q, r) = self.div_rem(other);
+                if r.is_zero() { Some(q) } else { None }
+            }
+
+            /// Return the normalized greatest common divisor of `self` and 
+            /// `other`, computed with the Euclidean algorithm.
+            pub fn gcd(&self, other: &Self) -> Self {
+                let (mut x, mut y) = (self.clone(), other.clone());
+                while !y.is_zero() {
+                    let r = x.div_rem(&y).1;
+                    x = y;
+                    y = r;
+                }
+                x.normalize()
+            }
+
+            /// Return the normalized primes dividing the rational prime `p`:
+            /// one prime if `p` is inert or ramified and two conjugate primes
+            /// if `p` splits. Panics if `p` is not prime.
+            pub fn primes_above<T: AsRef<Integer>>(p: T) -> Vec<Self> {
+                let p = p.as_ref();
+                assert!(p.is_prime(), "The input must be a prime.");
+                match $t::prime_above(p) {
+                    Some(pi) => {
+                        let conj = pi.conj().normalize();
+                        if conj == pi { vec![pi] } else { vec![pi, conj] }
+                    }
+                    None => vec![$t::new(p.clone(), 0)],
+                }
+            }
+
+            /// Return true if the element is a prime of the order.
+            pub fn is_prime(&self) -> bool {
+                let n = self.norm();
+                if n.is_prime() {
+                    return true;
+                }
+                // an inert rational prime p has norm p^2
+                let p = n.sqrt();
+                &p * &p == n 
+                    && p.is_prime()
+                    && $t::prime_above(&p).is_none()
+                    && self.normalize() == $t::new(p, 0)
+            }
+
+            /// Factor a nonzero element as `u * p_1^e_1 * ... * p_k^e_k` with 
+            /// `u` a unit and the `p_i` distinct normalized primes, sorted by
+            /// norm. Returns `(u, [(p_1, e_1), ..., (p_k, e_k)])`.
+            pub fn factor(&self) -> (Self, Vec<(Self, u64)>) {
+                assert!(!self.is_zero(), "Cannot factor zero.");
+                let mut z = self.clone();
+                let mut res = Vec::new();
+                for (p, _) in self.norm().factor() {
+                    for pi in $t::primes_above(&p) {
+                        let mut e = 0;
+                        while let Some(q) = z.div_exact(&pi) {
+                            z = q;
+                            e += 1;
+                        }
+                        if e > 0 {
+                            res.push((pi, e));
+                        }
+                    }
+                }
+                debug_assert!(z.is_unit());
+                res.sort_by_key(|(pi, _)| pi.norm());
+                (z, res)
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt_quadratic(f, &self.$a, &self.$b, $t::GEN)
+            }
+        }
+
+        impl Neg for &$t {
+            type Output = $t;
+            #[inline]
+            fn neg(self) -> $t {
+                $t { $a: -&self.$a, $b: -&self.$b }
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+            #[inline]
+            fn neg(self) -> $t {
+                -&self
+            }
+        }
+
+        impl Add for &$t {
+            type Output = $t;
+            #[inline]
+            fn add(self, rhs: &$t) -> $t {
+                $t { $a: &self.$a + &rhs.$a, $b: &self.$b + &rhs.$b }
+            }
+        }
+
+        impl Sub for &$t {
+            type Output = $t;
+            #[inline]
+            fn sub(self, rhs: &$t) -> $t {
+                $t { $a: &self.$a - &rhs.$a, $b: &self.$b - &rhs.$b }
+            }
+        }
+
+        impl Mul for &$t {
+            type Output = $t;
+            #[inline]
+            fn mul(self, rhs: &$t) -> $t {
+                self.mul_impl(rhs)
+            }
+        }
+
+        impl Add for $t {
+            type Output = $t;
+            #[inline]
+            fn add(self, rhs: $t) -> $t {
+                &self + &rhs
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+            #[inline]
+            fn sub(self, rhs: $t) -> $t {
+                &self - &rhs
+            }
+        }
+
+        impl Mul for $t {
+            type Output = $t;
+            #[inline]
+            fn mul(self, rhs: $t) -> $t {
+                &self * &rhs
+            }
+        }
+
+        impl From<Integer> for $t {
+            #[inline]
+            fn from(x: Integer) -> $t {
+                $t::new(x, 0)
+            }
+        }
+
+        impl From<&Integer> for $t {
+            #[inline]
+            fn from(x: &Integer) -> $t {
+                $t::new(x.clone(), 0)
+            }
+        }
+    };
+}
+
+/// A Gaussian integer `a + b*i` in `Z[i]`.
+///
+/// ```
+/// use inertia_core::*;
+/// use inertia_core::quadratic::GaussInt;
+///
+/// // 5 = (2 + i)(2 - i) splits
+/// let z = GaussInt::new(5, 0);
+/// assert!(!z.is_prime());
+/// let fac = z.factor().1;
+/// assert_eq!(fac.len(), 2);
+/// assert!(fac.contains(&(GaussInt::new(2, 1), 1)));
+/// assert!(fac.contains(&(GaussInt::new(2, -1).normalize(), 1)));
+///
+/// // the inert prime 3 has norm 9 and comes after the primes above 5
+/// let fac = GaussInt::new(15, 0).factor().1;
+/// assert_eq!(fac.len(), 3);
+/// assert_eq!(fac[2], (GaussInt::new(3, 0), 1));
+///
+/// let (q, r) = GaussInt::new(7, 3).div_rem(&GaussInt::new(2, 1));
+/// assert!(r.norm() < 5);
+/// assert_eq!(&(&q * &GaussInt::new(2, 1)) + &r, GaussInt::new(7, 3));
+///
+/// assert_eq!(GaussInt::new(4, 2).gcd(&GaussInt::new(6, 0)), GaussInt::new(2, 0));
+/// assert!(GaussInt::new(7, 0).is_prime());
+/// assert_eq!(GaussInt::new(1, -2).to_string(), "1 - 2*i");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GaussInt {
+    re: Integer,
+    im: Integer,
+}
+
+impl GaussInt {
+    const GEN: &'static str = "i";
+
+    /// Return the imaginary unit `i`.
+    #[inline]
+    pub fn i() -> Self {
+        GaussInt::new(0, 1)
+    }
+
+    /// Borrow the real part.
+    #[inline]
+    pub fn re(&self) -> &Integer {
+        &self.re
+    }
+
+    /// Borrow the imaginary part.
+    #[inline]
+    pub fn im(&self) -> &Integer {
+        &self.im
+    }
+
+    /// Return the complex conjugate `a - b*i`.
+    #[inline]
+    pub fn conj(&self) -> Self {
+        GaussInt { re: self.re.clone(), im: -&self.im }
+    }
+
+    /// Return the norm `a^2 + b^2`.
+    #[inline]
+    pub fn norm(&self) -> Integer {
+        &self.re * &self.re + &self.im * &self.im
+    }
+
+    /// Return the four units `1, i, -1, -i`.
+    pub fn units() -> [Self; 4] {
+        [GaussInt::new(1, 0), GaussInt::new(0, 1), GaussInt::new(-1, 0), GaussInt::new(0, -1)]
+    }
+
+    // The fundamental sector is re > 0, im >= 0.
+    fn is_normalized(&self) -> bool {
+        self.re.sign() > 0 && self.im.sign() >= 0
+    }
+
+    fn mul_impl(&self, rhs: &Self) -> Self {
+        GaussInt {
+            re: &self.re * &rhs.re - &self.im * &rhs.im,
+            im: &self.re * &rhs.im + &self.im * &rhs.re,
+        }
+    }
+
+    // A normalized prime above the rational prime p, or None if p is inert.
+    fn prime_above(p: &Integer) -> Option<Self> {
+        if *p == 2 {
+            return Some(GaussInt::new(1, 1));
+        }
+        if p.fdiv_r(Integer::from(4)) == 3 {
+            return None;
+        }
+        // i corresponds to a square root x of -1 mod p, and (p, x - i) is prime
+        let x = (p - Integer::one()).sqrtmod(p).expect("-1 is a square modulo p = 1 mod 4.");
+        Some(GaussInt::new(p.clone(), 0).gcd(&GaussInt::new(x, -1)))
+    }
+}
+
+impl_quadratic!(GaussInt, re, im);
+
+/// An Eisenstein integer `a + b*w` in `Z[w]`, where `w = (-1 + sqrt(-3))/2`
+/// is a primitive cube root of unity, so `w^2 = -1 - w`.
+///
+/// ```
+/// use inertia_core::*;
+/// use inertia_core::quadratic::EisensteinInt;
+///
+/// // 7 = (3 + w)(2 - w)
+/// let (u, fac) = EisensteinInt::new(7, 0).factor();
+/// assert_eq!(fac.len(), 2);
+/// assert!(fac.iter().all(|(p, e)| p.norm() == 7 && *e == 1));
+/// assert_eq!(&u * &(&fac[0].0 * &fac[1].0), EisensteinInt::new(7, 0));
+///
+/// // 3 ramifies and 5 is inert
+/// assert_eq!(EisensteinInt::primes_above(Integer::from(3)).len(), 1);
+/// assert!(EisensteinInt::new(5, 0).is_prime());
+/// assert_eq!(EisensteinInt::new(6, 0).gcd(&EisensteinInt::new(4, 2)).norm(), 12);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EisensteinInt {
+    a: Integer,
+    b: Integer,
+}
+
+impl EisensteinInt {
+    const GEN: &'static str = "w";
+
+    /// Return the primitive cube root of unity `w`.
+    #[inline]
+    pub fn omega() -> Self {
+        EisensteinInt::new(0, 1)
+    }
+
+    /// Borrow the coefficient `a` of `a + b*w`.
+    #[inline]
+    pub fn a(&self) -> &Integer {
+        &self.a
+    }
+
+    /// Borrow the coefficient `b` of `a + b*w`.
+    #[inline]
+    pub fn b(&self) -> &Integer {
+        &self.b
+    }
+
+    /// Return the complex conjugate `a + b*w^2 = (a - b) - b*w`.
+    #[inline]
+    pub fn conj(&self) -> Self {
+        EisensteinInt { a: &self.a - &self.b, b: -&self.b }
+    }
+
+    /// Return the norm `a^2 - ab + b^2`.
+    #[inline]
+    pub fn norm(&self) -> Integer {
+        &self.a * &self.a - &self.a * &self.b + &self.b * &self.b
+    }
+
+    /// Return the six units `1, 1 + w, w, -1, -1 - w, -w`, the powers of 
+    /// `-w^2 = 1 + w`.
+    pub fn units() -> [Self; 6] {
+        [
+            EisensteinInt::new(1, 0),
+            EisensteinInt::new(1, 1),
+            EisensteinInt::new(0, 1),
+            EisensteinInt::new(-1, 0),
+            EisensteinInt::new(-1, -1),
+            EisensteinInt::new(0, -1),
+        ]
+    }
+
+    // The fundamental sector 0 <= arg < pi/3 is b >= 0, a > b.
+    fn is_normalized(&self) -> bool {
+        self.b.sign() >= 0 && self.a > self.b
+    }
+
+    fn mul_impl(&self, rhs: &Self) -> Self {
+        let bd = &self.b * &rhs.b;
+        EisensteinInt {
+            a: &self.a * &rhs.a - &bd,
+            b: &self.a * &rhs.b + &self.b * &rhs.a - bd,
+        }
+    }
+
+    // A normalized prime above the rational prime p, or None if p is inert.
+    fn prime_above(p: &Integer) -> Option<Self> {
+        if *p == 3 {
+            return Some(EisensteinInt::new(2, 1));
+        }
+        if p.fdiv_r(Integer::from(3)) == 2 {
+            return None;
+        }
+        // sqrt(-3) = 1 + 2w corresponds to a square root x of -3 mod p, and 
+        // (p, x - 1 - 2w) is prime
+        let x = (p - Integer::from(3)).sqrtmod(p).expect("-3 is a square modulo p = 1 mod 3.");
+        let pi = EisensteinInt::new(x - Integer::one(), -2);
+        Some(EisensteinInt::new(p.clone(), 0).gcd(&pi))
+    }
+}
+
+impl_quadratic!(EisensteinInt, a, b);
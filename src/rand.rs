@@ -0,0 +1,188 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use flint_sys::{flint, ulong_extras};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+/// Random state used by the `rand*` constructors, wrapping FLINT's 
+/// [flint_rand_s][flint::flint_rand_s]. Two states created with the same seed 
+/// produce the same sequence of elements on a given platform. FLINT's 
+/// generators work with machine words, so sequences are only reproducible 
+/// across 64-bit platforms.
+///
+/// With the `rand_compat` feature `FlintRand` implements `rand_core::RngCore` 
+/// and `rand_core::SeedableRng`, so it can drive code from the `rand` 
+/// ecosystem. The feature has no effect on emscripten targets.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let mut a = FlintRand::with_seed(42);
+/// let mut b = FlintRand::with_seed(42);
+/// assert_eq!(Integer::randtest(100u32, &mut a), Integer::randtest(100u32, &mut b));
+/// ```
+pub struct FlintRand {
+    inner: flint::flint_rand_s,
+}
+
+impl fmt::Debug for FlintRand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlintRand").finish()
+    }
+}
+
+impl Default for FlintRand {
+    #[inline]
+    fn default() -> Self {
+        let mut z = MaybeUninit::uninit();
+        unsafe {
+            flint::flint_randinit(z.as_mut_ptr());
+            FlintRand { inner: z.assume_init() }
+        }
+    }
+}
+
+impl Drop for FlintRand {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { flint::flint_randclear(self.as_mut_ptr()) }
+    }
+}
+
+impl FlintRand {
+    /// Initialize a random state with FLINT's default seed.
+    #[inline]
+    pub fn new() -> Self {
+        FlintRand::default()
+    }
+
+    /// Initialize a random state deterministically from `seed`.
+    #[inline]
+    pub fn with_seed(seed: u64) -> Self {
+        let mut res = FlintRand::default();
+        res.seed(seed, seed.rotate_left(32));
+        res
+    }
+
+    /// Reseed the state with the two words `seed1` and `seed2`. The state is 
+    /// reinitialized first, so the GMP generator used for large random values 
+    /// is reset as well and the resulting stream depends only on the seeds.
+    #[inline]
+    pub fn seed(&mut self, seed1: u64, seed2: u64) {
+        unsafe { 
+            flint::flint_randclear(self.as_mut_ptr());
+            flint::flint_randinit(self.as_mut_ptr());
+            flint::flint_randseed(self.as_mut_ptr(), seed1, seed2);
+        }
+    }
+
+    /// Return a uniformly random word.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let mut a = FlintRand::with_seed(7);
+    /// let x = a.randlimb();
+    /// a.seed(7, 7u64.rotate_left(32));
+    /// assert_eq!(a.randlimb(), x);
+    /// ```
+    #[inline]
+    pub fn randlimb(&mut self) -> u64 {
+        unsafe { ulong_extras::n_randlimb(self.as_mut_ptr()) }
+    }
+    
+    #[inline]
+    pub const fn as_ptr(&self) -> *const flint::flint_rand_s {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut flint::flint_rand_s {
+        &mut self.inner
+    }
+}
+
+#[cfg(all(feature = "rand_compat", not(target_os = "emscripten")))]
+impl rand_core::RngCore for FlintRand {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.randlimb() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.randlimb()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.randlimb().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(all(feature = "rand_compat", not(target_os = "emscripten")))]
+impl rand_core::SeedableRng for FlintRand {
+    type Seed = [u8; 16];
+
+    fn from_seed(seed: [u8; 16]) -> Self {
+        let mut lo = [0u8; 8];
+        let mut hi = [0u8; 8];
+        lo.copy_from_slice(&seed[..8]);
+        hi.copy_from_slice(&seed[8..]);
+        let mut res = FlintRand::default();
+        res.seed(u64::from_le_bytes(lo), u64::from_le_bytes(hi));
+        res
+    }
+
+    // Agree with `FlintRand::with_seed` rather than expanding the seed.
+    #[inline]
+    fn seed_from_u64(seed: u64) -> Self {
+        FlintRand::with_seed(seed)
+    }
+}
+
+#[cfg(all(test, feature = "rand_compat", not(target_os = "emscripten")))]
+mod tests {
+    use crate::*;
+    use rand_core::{RngCore, SeedableRng};
+
+    #[test]
+    fn rand_compat() {
+        let mut a = FlintRand::seed_from_u64(3);
+        let mut b = FlintRand::with_seed(3);
+        let mut buf = [0u8; 12];
+        a.fill_bytes(&mut buf);
+        assert_eq!(&buf[..8], &b.randlimb().to_le_bytes());
+        assert_eq!(&buf[8..], &b.randlimb().to_le_bytes()[..4]);
+
+        // interleaving with the crate constructors stays reproducible
+        let mut c = FlintRand::seed_from_u64(3);
+        let x = Integer::randtest(200u32, &mut a);
+        c.next_u64();
+        c.next_u64();
+        assert_eq!(Integer::randtest(200u32, &mut c), x);
+    }
+}
@@ -21,7 +21,11 @@ mod conv;
 //#[cfg(feature = "serde")]
 //mod serde;
 
-use crate::{New, IntPoly};
+use crate::{
+    check_interrupt, New, Error, Result, 
+    Integer, IntPoly, IntMat, IntMPoly, IntMPolyCtx, Rational, RatPoly, 
+    Real, Complex
+};
 use flint_sys::{
     fmpz_poly::fmpz_poly_set,
     fmpz_poly_q::*
@@ -30,7 +34,6 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
-#[derive(Debug)]
 pub struct RatFunc {
     inner: fmpz_poly_q_struct,
 }
@@ -64,6 +67,12 @@ impl Default for RatFunc {
 }
 
 // TODO: add parens to num or den if not constant, omit den if 1, etc
+impl fmt::Debug for RatFunc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RatFunc").field(&format_args!("{}", self)).finish()
+    }
+}
+
 impl fmt::Display for RatFunc {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -204,6 +213,243 @@ impl RatFunc {
     pub fn is_gen(&self) -> bool {
         self.denominator().is_one() && self.numerator().is_gen()
     }
+
+    /// Return the quotient `num/den` in lowest terms, or an error if `den` is
+    /// zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let r = RatFunc::from_poly_quotient(IntPoly::from([-1, 0, 1]), IntPoly::from([2, 2]));
+    /// assert_eq!(r.unwrap().numerator(), IntPoly::from([-1, 1]));
+    /// assert!(RatFunc::from_poly_quotient(IntPoly::one(), IntPoly::zero()).is_err());
+    /// ```
+    pub fn from_poly_quotient<S, T>(num: S, den: T) -> Result<RatFunc>
+    where
+        S: Into<IntPoly>,
+        T: Into<IntPoly>
+    {
+        let den = den.into();
+        if den.is_zero() {
+            return Err(Error::DivisionError("Denominator is zero.".to_string()));
+        }
+        Ok(RatFunc::from([num.into(), den]))
+    }
+
+    /// Whether the numerator and denominator are coprime and the denominator
+    /// has positive leading coefficient. Rational functions built through the
+    /// public constructors are always canonical.
+    #[inline]
+    pub fn is_canonical(&self) -> bool {
+        unsafe { fmpz_poly_q_is_canonical(self.as_ptr()) != 0 }
+    }
+
+    /// Reduce the rational function to lowest terms with a denominator of
+    /// positive leading coefficient. This is only needed after modifying the
+    /// underlying FLINT struct directly.
+    #[inline]
+    pub fn canonicalize(&mut self) {
+        unsafe { fmpz_poly_q_canonicalise(self.as_mut_ptr()) }
+    }
+
+    /// Return the derivative.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // d/dx 1/(x + 1) = -1/(x^2 + 2x + 1)
+    /// let r = RatFunc::from([IntPoly::one(), IntPoly::from([1, 1])]);
+    /// let dr = r.derivative();
+    /// assert_eq!(dr.numerator(), IntPoly::from([-1]));
+    /// assert_eq!(dr.denominator(), IntPoly::from([1, 2, 1]));
+    /// ```
+    pub fn derivative(&self) -> RatFunc {
+        let mut res = RatFunc::default();
+        unsafe { fmpz_poly_q_derivative(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Evaluate the rational function at a rational point, or return `None`
+    /// if the point is a pole.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let r = RatFunc::from([IntPoly::from([0, 1]), IntPoly::from([-1, 1])]);
+    /// assert_eq!(r.evaluate(Rational::from(3)), Some(Rational::from([3, 2])));
+    /// assert_eq!(r.evaluate(Rational::one()), None);
+    /// ```
+    pub fn evaluate<T: AsRef<Rational>>(&self, x: T) -> Option<Rational> {
+        let x = x.as_ref();
+        let den = RatPoly::from(self.denominator()).evaluate(x);
+        if den.is_zero() {
+            None
+        } else {
+            Some(RatPoly::from(self.numerator()).evaluate(x) / den)
+        }
+    }
+
+    /// Evaluate the rational function at a real ball, using the precision of
+    /// `x`. The result is indeterminate if the ball of denominator values 
+    /// contains zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let r = RatFunc::from([IntPoly::one(), IntPoly::from([1, 0, 1])]);
+    /// let y = r.evaluate_real(&Real::from(2));
+    /// assert!(y.contains(&Real::from(Rational::from([1, 5]))));
+    /// ```
+    pub fn evaluate_real(&self, x: &Real) -> Real {
+        let horner = |f: IntPoly| -> Real {
+            let mut res = Real::zero();
+            res.set_prec(x.prec());
+            for c in f.get_coeffs().iter().rev() {
+                let mut c = Real::from(c);
+                c.set_prec(x.prec());
+                res = res * x + c;
+            }
+            res
+        };
+        horner(self.numerator()) / horner(self.denominator())
+    }
+
+    /// Evaluate the rational function at a complex ball, using the precision 
+    /// of `x`. The result is indeterminate if the ball of denominator values 
+    /// contains zero.
+    pub fn evaluate_complex(&self, x: &Complex) -> Complex {
+        let horner = |f: IntPoly| -> Complex {
+            let mut res = Complex::zero();
+            res.set_prec(x.prec());
+            for c in f.get_coeffs().iter().rev() {
+                let mut c = Complex::from(c);
+                c.set_prec(x.prec());
+                res = res * x + c;
+            }
+            res
+        };
+        horner(self.numerator()) / horner(self.denominator())
+    }
+
+    /// Compute the partial fraction decomposition over the rationals. Returns
+    /// the polynomial part `q` and terms `(a, p, k)` such that the rational
+    /// function equals `q + sum a/p^k`, where the `p` are the distinct 
+    /// irreducible factors of the denominator (primitive, with positive 
+    /// leading coefficient) and `deg(a) < deg(p)`. Terms with zero numerator
+    /// are omitted; for each factor the powers are increasing.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // x^3/(x - 1)^2 = x + 2 + 3/(x - 1) + 1/(x - 1)^2
+    /// let r = RatFunc::from([IntPoly::from([0, 0, 0, 1]), IntPoly::from([1, -2, 1])]);
+    /// let (q, terms) = r.partial_fractions();
+    /// assert_eq!(q, RatPoly::from([2, 1]));
+    /// assert_eq!(terms, vec![
+    ///     (RatPoly::from([3]), IntPoly::from([-1, 1]), 1),
+    ///     (RatPoly::from([1]), IntPoly::from([-1, 1]), 2),
+    /// ]);
+    /// ```
+    pub fn partial_fractions(&self) -> (RatPoly, Vec<(RatPoly, IntPoly, u64)>) {
+        let den = self.denominator();
+        let rden = RatPoly::from(&den);
+        let (q, r) = RatPoly::from(self.numerator()).divrem(&rden);
+
+        let mut terms = Vec::new();
+        if r.is_zero() {
+            return (q, terms);
+        }
+        for (f, e) in den.factor().1 {
+            check_interrupt();
+            let p = RatPoly::from(&f);
+            let mut pe = p.clone();
+            for _ in 1..e {
+                pe = &pe * &p;
+            }
+
+            // the numerator over p^e is r * (den/p^e)^-1 mod p^e
+            let cofactor = rden.divrem(&pe).0;
+            let inv = cofactor.invmod(&pe).expect("Factors of the denominator are coprime.");
+            let mut a = (&r * &inv).divrem(&pe).1;
+
+            // write a = a_0 + a_1 p + ... + a_{e-1} p^{e-1}, so that a/p^e is
+            // the sum of the a_j/p^{e-j}
+            let mut digits = Vec::with_capacity(
+                e.try_into().expect("Cannot convert unsigned long to usize."));
+            for _ in 0..e {
+                let (quo, rem) = a.divrem(&p);
+                digits.push(rem);
+                a = quo;
+            }
+            for (k, aj) in (1..=e).zip(digits.into_iter().rev()) {
+                if !aj.is_zero() {
+                    terms.push((aj, f.clone(), k));
+                }
+            }
+        }
+        (q, terms)
+    }
+
+    /// Compute the `[m/n]` Padé approximant `P/Q` of a power series `f`, that 
+    /// is, the rational function with `deg(P) <= m`, `deg(Q) <= n` and 
+    /// `Q(0) = 1` such that `f*Q - P` vanishes to order `m + n + 1`. Only the 
+    /// first `m + n + 1` coefficients of `series` are used (missing ones are 
+    /// treated as zero).
+    ///
+    /// The denominator is found by solving an `n` by `n` Toeplitz system with 
+    /// the Levinson recursion. Returns `None` if the recursion breaks down, 
+    /// which in particular happens when no approximant with `Q(0) = 1` exists.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // 1 + x + x^2 + ... = 1/(1 - x)
+    /// let f = RatPoly::from([1, 1, 1, 1]);
+    /// let r = RatFunc::pade_from_series(&f, 0, 1).unwrap();
+    /// assert_eq!(r.numerator(), IntPoly::from([-1]));
+    /// assert_eq!(r.denominator(), IntPoly::from([-1, 1]));
+    /// ```
+    pub fn pade_from_series<T: AsRef<RatPoly>>(
+        series: T, 
+        m: usize, 
+        n: usize
+    ) -> Option<RatFunc> {
+        let series = series.as_ref();
+        let c = |k: usize| -> Rational { series.get_coeff(k) };
+
+        // Q = 1 + q_1 x + ... + q_n x^n where sum_{j=0}^n q_j c_{k-j} = 0 
+        // for k = m+1, ..., m+n.
+        let mut q = vec![Rational::one()];
+        if n > 0 {
+            let c_signed = |k: usize, j: usize| -> Rational {
+                if k >= j { c(k - j) } else { Rational::zero() }
+            };
+            let col: Vec<Rational> = (0..n).map(|i| c(m + i)).collect();
+            let row: Vec<Rational> = (0..n).map(|j| c_signed(m, j)).collect();
+            let rhs: Vec<Rational> = (0..n).map(|i| -c(m + 1 + i)).collect();
+            q.extend(crate::ratmat::levinson(&col, &row, &rhs)?);
+        }
+
+        // P = f*Q mod x^(m+1)
+        let mut p = RatPoly::zero();
+        for k in 0..=m {
+            let mut pk = Rational::zero();
+            for (j, qj) in q.iter().enumerate().take(k + 1) {
+                pk += qj * c(k - j);
+            }
+            p.set_coeff(k, pk);
+        }
+
+        let mut den = RatPoly::zero();
+        for (j, qj) in q.iter().enumerate() {
+            den.set_coeff(j, qj);
+        }
+
+        Some(RatFunc::from([
+            p.numerator() * den.denominator(), 
+            den.numerator() * p.denominator()
+        ]))
+    }
    
     /*
     #[inline]
@@ -298,3 +544,148 @@ impl RatFunc {
     */
 }
 
+/// Compute the implicit equation `F(x, y) = 0` of the plane curve with rational 
+/// parametrization `(x(t), y(t))`, defined as the resultant with respect to `t` 
+/// of `x*q1(t) - p1(t)` and `y*q2(t) - p2(t)` where `x(t) = p1/q1` and 
+/// `y(t) = p2/q2`, divided by its content.
+///
+/// The result is a polynomial in two variables, `x1 = x` and `x2 = y`, whose 
+/// leading coefficient in lexicographic order is positive. Its degree in `x` 
+/// is at most `d2` and its degree in `y` at most `d1`, where `d1` and `d2` 
+/// are the maximum of the numerator and denominator degrees of `x(t)` and 
+/// `y(t)`. If the parametrization is not proper the equation is a power of 
+/// the irreducible one. Panics if either coordinate is constant.
+///
+/// ```
+/// use inertia_core::*;
+/// use inertia_core::ratfunc::implicitize;
+///
+/// // (t, t^2) parametrizes x^2 - y = 0
+/// let x = RatFunc::from([IntPoly::from([0, 1]), IntPoly::from([1])]);
+/// let y = RatFunc::from([IntPoly::from([0, 0, 1]), IntPoly::from([1])]);
+/// let f = implicitize(&x, &y);
+/// assert_eq!(f.to_string(), "x1^2 - x2");
+/// ```
+pub fn implicitize<T: AsRef<RatFunc>>(x: T, y: T) -> IntMPoly {
+    let (x, y) = (x.as_ref(), y.as_ref());
+    let (p1, q1) = (x.numerator(), x.denominator());
+    let (p2, q2) = (y.numerator(), y.denominator());
+    let d1 = p1.degree().max(q1.degree());
+    let d2 = p2.degree().max(q2.degree());
+    assert!(d1 > 0 && d2 > 0, "Both coordinates must be nonconstant.");
+    let d1: usize = d1.try_into().unwrap();
+    let d2: usize = d2.try_into().unwrap();
+    trace_span!(
+        "implicitize",
+        deg_x = d1,
+        deg_y = d2,
+        algorithm = "sylvester-interpolation"
+    );
+
+    // Res_t(a*q1 - p1, b*q2 - p2), using the formal degrees so that the 
+    // result specializes correctly when a leading coefficient vanishes.
+    let res_at = |a: usize, b: usize| -> Integer {
+        check_interrupt();
+        let (a, b) = (Integer::from(a), Integer::from(b));
+        let f: Vec<Integer> = (0..=d1)
+            .map(|k| q1.get_coeff(k) * &a - p1.get_coeff(k))
+            .collect();
+        let g: Vec<Integer> = (0..=d2)
+            .map(|k| q2.get_coeff(k) * &b - p2.get_coeff(k))
+            .collect();
+        sylvester(&f, &g).det()
+    };
+
+    // The resultant has degree at most d2 in x and d1 in y, so interpolate 
+    // on the grid {0..d2} x {0..d1}, first in y and then in x.
+    let in_y: Vec<Vec<Integer>> = (0..=d2)
+        .map(|a| {
+            let vals: Vec<Integer> = (0..=d1).map(|b| res_at(a, b)).collect();
+            interpolate_range(&vals)
+        })
+        .collect();
+
+    // the coefficient of x^i y^j is in row i and column j
+    let mut coeffs = vec![vec![Integer::zero(); d1 + 1]; d2 + 1];
+    for j in 0..=d1 {
+        let vals: Vec<Integer> = in_y.iter().map(|row| row[j].clone()).collect();
+        for (i, c) in interpolate_range(&vals).into_iter().enumerate() {
+            coeffs[i][j] = c;
+        }
+    }
+
+    // divide by the content, with the sign of the leading coefficient
+    let content = coeffs.iter().flatten().fold(Integer::zero(), |g, c| g.gcd(c));
+    let lead = coeffs.iter()
+        .rev()
+        .flat_map(|row| row.iter().rev())
+        .find(|c| !c.is_zero());
+    let ctx = IntMPolyCtx::new(2);
+    let mut res = IntMPoly::zero(&ctx);
+    if let Some(lead) = lead {
+        let d = if lead.sign() < 0 { -content } else { content };
+        for (i, row) in coeffs.iter().enumerate() {
+            for (j, c) in row.iter().enumerate() {
+                if !c.is_zero() {
+                    res.set_coeff(&[i as u64, j as u64], c.divexact_unchecked(&d));
+                }
+            }
+        }
+    }
+    res
+}
+
+// The Sylvester matrix of polynomials with coefficients `f` and `g`, in order 
+// of increasing degree, treating `f.len() - 1` and `g.len() - 1` as their 
+// degrees.
+fn sylvester(f: &[Integer], g: &[Integer]) -> IntMat {
+    let (m, n) = (f.len() - 1, g.len() - 1);
+    let size: i64 = (m + n).try_into().expect("Cannot convert usize to a signed long.");
+    let mut res = IntMat::zero(size, size);
+    for i in 0..n {
+        for (k, c) in f.iter().enumerate() {
+            res.set_entry(i, i + m - k, c);
+        }
+    }
+    for i in 0..m {
+        for (k, c) in g.iter().enumerate() {
+            res.set_entry(n + i, i + n - k, c);
+        }
+    }
+    res
+}
+
+// Coefficients of the polynomial of degree less than `vals.len()` taking the 
+// values `vals` at `0, 1, 2, ...`, which are assumed to be integers.
+fn interpolate_range(vals: &[Integer]) -> Vec<Integer> {
+    let n = vals.len();
+    
+    // Newton forward differences, p(x) = sum_k D^k p(0) * binomial(x, k)
+    let mut diffs = vals.to_vec();
+    let mut newton = Vec::with_capacity(n);
+    for _ in 0..n {
+        newton.push(diffs[0].clone());
+        diffs = diffs.windows(2).map(|w| &w[1] - &w[0]).collect();
+    }
+
+    let mut res = vec![Rational::zero(); n];
+    let mut binom = vec![Rational::one()];
+    for (k, dk) in newton.iter().enumerate() {
+        for (i, c) in binom.iter().enumerate() {
+            res[i] += c * dk;
+        }
+        // binomial(x, k + 1) = binomial(x, k) * (x - k)/(k + 1)
+        let k = Integer::from(k);
+        let mut next = vec![Rational::zero(); binom.len() + 1];
+        for (i, c) in binom.iter().enumerate() {
+            next[i + 1] += c;
+            next[i] -= c * &k;
+        }
+        let k1 = k + 1u32;
+        binom = next.into_iter().map(|c| c / &k1).collect();
+    }
+
+    res.into_iter()
+        .map(|c| Integer::try_from(c).expect("Interpolant is not integral."))
+        .collect()
+}
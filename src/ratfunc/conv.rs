@@ -57,6 +57,24 @@ derive_from_intpoly! {
     Integer IntMod IntModPoly FinFldElem 
 }
 
+impl_from! {
+    RatFunc, RatPoly
+    {
+        fn from(x: &RatPoly) -> RatFunc {
+            RatFunc::from([x.numerator(), IntPoly::from(x.denominator())])
+        }
+    }
+}
+
+impl_from! {
+    RatFunc, Rational
+    {
+        fn from(x: &Rational) -> RatFunc {
+            RatFunc::from([x.numerator(), x.denominator()])
+        }
+    }
+}
+
 
 impl<T: Into<IntPoly>> From<[T; 2]> for RatFunc {
     fn from(src: [T; 2]) -> RatFunc {
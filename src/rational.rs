@@ -17,18 +17,27 @@
 
 mod ops;
 mod conv;
+mod cfrac;
+mod bernoulli;
 
 #[cfg(feature = "serde")]
 mod serde;
 
-use crate::{New, Integer};
+#[cfg(feature = "rug")]
+mod rug;
+
+use crate::{New, Integer, FlintRand};
 use flint_sys::{fmpz, fmpq};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
+pub use cfrac::Convergents;
+pub use bernoulli::BernoulliCache;
+
 
-#[derive(Debug)]
+// `repr(transparent)` lets matrix entries be borrowed as `&Rational`.
+#[repr(transparent)]
 pub struct Rational {
     inner: fmpq::fmpq,
 }
@@ -62,15 +71,27 @@ impl Default for Rational {
     }
 }
 
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Rational").field(&format_args!("{}", self)).finish()
+    }
+}
+
 impl fmt::Display for Rational {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let n = self.numerator();
         let d = self.denominator();
-        if d == 1 {
-            write!(f, "{}", n)
+        if f.width().is_none() {
+            if d == 1 {
+                write!(f, "{}", n)
+            } else {
+                write!(f, "{}/{}", n, d)
+            }
+        } else if d == 1 {
+            fmt::Display::fmt(&n, f)
         } else {
-            write!(f, "{}/{}", n, d)
+            f.pad(&format!("{}/{}", n, d))
         }
     }
 }
@@ -226,6 +247,25 @@ impl Rational {
         res
     }
 
+    /// Convert the rational number to a string `n/d`, or `n` if the 
+    /// denominator is one, with the numerator and denominator in base 
+    /// `base`. See [Integer::to_str_radix].
+    ///
+    /// ```
+    /// use inertia_core::Rational;
+    ///
+    /// assert_eq!(Rational::from([-255, 16]).to_str_radix(16), "-ff/10");
+    /// assert_eq!(Rational::from(5).to_str_radix(2), "101");
+    /// ```
+    pub fn to_str_radix(&self, base: u8) -> String {
+        let d = self.denominator();
+        if d.is_one() {
+            self.numerator().to_str_radix(base)
+        } else {
+            format!("{}/{}", self.numerator().to_str_radix(base), d.to_str_radix(base))
+        }
+    }
+
     #[inline]
     pub fn floor(&self) -> Integer {
         let mut res = self.numerator();
@@ -278,5 +318,66 @@ impl Rational {
             res
         }
     }
-}
 
+    /// Return an approximation of `log2(|self|)` in double precision, from the
+    /// leading bits of the numerator and denominator. Returns negative 
+    /// infinity for zero.
+    ///
+    /// ```
+    /// use inertia_core::Rational;
+    ///
+    /// assert_eq!(Rational::from([1, 8]).approx_log2(), -3.0);
+    /// assert!((Rational::from([-10, 3]).approx_log2() - 1.737).abs() < 1e-3);
+    /// assert_eq!(Rational::from(0).approx_log2(), f64::NEG_INFINITY);
+    /// ```
+    pub fn approx_log2(&self) -> f64 {
+        if self.is_zero() {
+            return f64::NEG_INFINITY;
+        }
+        // log2 of x = d 2^e with 0.5 <= |d| < 1
+        let log2 = |x: *const fmpz::fmpz| unsafe {
+            let mut e = 0;
+            let d = fmpz::fmpz_get_d_2exp(&mut e, x);
+            d.abs().log2() + e as f64
+        };
+        log2(&self.inner.num) - log2(&self.inner.den)
+    }
+
+    /// Return a random rational whose numerator and denominator have up to 
+    /// `bits` bits, biased towards values likely to trigger corner cases.
+    #[inline]
+    pub fn randtest<S>(bits: S, rng: &mut FlintRand) -> Rational 
+    where
+        S: Into<u64>
+    {
+        let mut res = Rational::default();
+        unsafe { fmpq::fmpq_randtest(res.as_mut_ptr(), rng.as_mut_ptr(), bits.into()); }
+        res
+    }
+    
+    /// Return a random nonzero rational whose numerator and denominator have 
+    /// up to `bits` bits.
+    #[inline]
+    pub fn randtest_not_zero<S>(bits: S, rng: &mut FlintRand) -> Rational 
+    where
+        S: Into<u64>
+    {
+        let bits = bits.into();
+        assert!(bits > 0);
+        let mut res = Rational::default();
+        unsafe { fmpq::fmpq_randtest_not_zero(res.as_mut_ptr(), rng.as_mut_ptr(), bits); }
+        res
+    }
+    
+    /// Return a random rational whose numerator and denominator both have 
+    /// exactly `bits` bits before canonicalisation.
+    #[inline]
+    pub fn randbits<S>(bits: S, rng: &mut FlintRand) -> Rational 
+    where
+        S: Into<u64>
+    {
+        let mut res = Rational::default();
+        unsafe { fmpq::fmpq_randbits(res.as_mut_ptr(), rng.as_mut_ptr(), bits.into()); }
+        res
+    }
+}
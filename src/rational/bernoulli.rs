@@ -0,0 +1,154 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::{Integer, Rational};
+use flint_sys::{arith, fmpq};
+
+impl Rational {
+    /// Return the Bernoulli number `B_n`, with the convention `B_1 = -1/2`.
+    /// To generate many consecutive Bernoulli numbers use a 
+    /// [BernoulliCache].
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(Rational::bernoulli(1), Rational::from([-1, 2]));
+    /// assert_eq!(Rational::bernoulli(12), Rational::from([-691, 2730]));
+    /// assert!(Rational::bernoulli(13).is_zero());
+    /// ```
+    pub fn bernoulli(n: u64) -> Rational {
+        let mut res = Rational::default();
+        unsafe { arith::arith_bernoulli_number(res.as_mut_ptr(), n); }
+        res
+    }
+
+    /// Return the harmonic number `H_n = 1 + 1/2 + ... + 1/n`. `H_0` is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(Rational::harmonic(4), Rational::from([25, 12]));
+    /// assert!(Rational::harmonic(0).is_zero());
+    /// ```
+    pub fn harmonic(n: u64) -> Rational {
+        let mut res = Rational::default();
+        let n = n.try_into().expect("Cannot convert u64 to a signed long.");
+        unsafe { arith::arith_harmonic_number(res.as_mut_ptr(), n); }
+        res
+    }
+}
+
+impl Integer {
+    /// Return the Euler number `E_n`, the coefficient of `x^n/n!` in 
+    /// `sech(x)`. The odd Euler numbers are zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let e: Vec<Integer> = (0..9).map(Integer::euler_number).collect();
+    /// assert_eq!(e, [1, 0, -1, 0, 5, 0, -61, 0, 1385].map(Integer::from));
+    /// ```
+    pub fn euler_number(n: u64) -> Integer {
+        let mut res = Integer::default();
+        unsafe { arith::arith_euler_number(res.as_mut_ptr(), n); }
+        res
+    }
+}
+
+/// A cache of the Bernoulli numbers `B_0, B_1, ..., B_{n-1}`. When an index
+/// past the end is requested the cache grows at least geometrically, 
+/// computing the new block with FLINT's multimodular 
+/// `arith_bernoulli_number_vec`, so generating the first `n` numbers costs 
+/// about the same as computing them in one call rather than `n` separate 
+/// evaluations of [Rational::bernoulli].
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let mut cache = BernoulliCache::new();
+/// assert_eq!(cache.get(10), &Rational::from([5, 66]));
+/// assert!(cache.len() >= 11);
+///
+/// // the sum of binomial(n + 1, k) B_k over k < n + 1 vanishes for n > 0
+/// let n = 20u64;
+/// let mut sum = Rational::zero();
+/// for k in 0..=n {
+///     sum += Rational::from(Integer::binomial(n + 1, k)) * cache.get(k as usize);
+/// }
+/// assert!(sum.is_zero());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BernoulliCache {
+    values: Vec<Rational>,
+}
+
+impl BernoulliCache {
+    /// Return an empty cache.
+    #[inline]
+    pub fn new() -> Self {
+        BernoulliCache { values: Vec::new() }
+    }
+
+    /// Return a cache holding `B_0, ..., B_{n-1}`.
+    pub fn with_len(n: usize) -> Self {
+        let mut res = BernoulliCache::new();
+        res.extend_to(n);
+        res
+    }
+
+    /// Return the number of cached Bernoulli numbers.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Borrow the cached values `B_0, ..., B_{len-1}`.
+    #[inline]
+    pub fn as_slice(&self) -> &[Rational] {
+        &self.values
+    }
+
+    /// Borrow the Bernoulli number `B_n`, extending the cache if needed.
+    pub fn get(&mut self, n: usize) -> &Rational {
+        if n >= self.values.len() {
+            // grow geometrically to amortize the recomputation
+            self.extend_to((n + 1).max(2 * self.values.len()));
+        }
+        &self.values[n]
+    }
+
+    // Recompute the cache to hold at least `n` values. FLINT has no way to
+    // resume a vector computation, so the whole block is recomputed.
+    fn extend_to(&mut self, n: usize) {
+        if n <= self.values.len() {
+            return;
+        }
+        let mut values = vec![Rational::default(); n];
+        unsafe {
+            // Rational is a transparent wrapper around fmpq
+            arith::arith_bernoulli_number_vec(
+                values.as_mut_ptr() as *mut fmpq::fmpq,
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        self.values = values;
+    }
+}
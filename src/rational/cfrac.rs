@@ -0,0 +1,167 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{Integer, Rational};
+use flint_sys::{fmpz, fmpq};
+
+impl Rational {
+    /// Return the terms `[a0; a1, ..., an]` of the regular continued fraction
+    /// expansion of the rational, where `a0` is the floor and the remaining
+    /// terms are positive.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let q = Rational::from([415, 93]);
+    /// let cf = q.continued_fraction();
+    /// assert_eq!(cf, [4, 2, 6, 7].map(Integer::from));
+    /// assert_eq!(Rational::from_continued_fraction(&cf), q);
+    ///
+    /// let q = Rational::from([-7, 3]);
+    /// assert_eq!(q.continued_fraction(), [-3, 1, 2].map(Integer::from));
+    /// ```
+    pub fn continued_fraction(&self) -> Vec<Integer> {
+        // the number of terms is at most log_phi(den) + 2
+        let n = 2 * self.denominator().bits() + 2;
+        let mut res = vec![Integer::zero(); n as usize];
+        let mut rem = Rational::default();
+        unsafe {
+            // Integer is a transparent wrapper around fmpz
+            let len = fmpq::fmpq_get_cfrac(
+                res.as_mut_ptr() as *mut fmpz::fmpz,
+                rem.as_mut_ptr(),
+                self.as_ptr(),
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+            res.truncate(len.try_into().expect("Cannot convert signed long to usize."));
+        }
+        debug_assert!(rem.is_zero());
+        res
+    }
+
+    /// Return the rational with the continued fraction expansion
+    /// `[a0; a1, ..., an]`. Panics if `terms` is empty or any term after the
+    /// first is not positive.
+    pub fn from_continued_fraction(terms: &[Integer]) -> Rational {
+        assert!(!terms.is_empty(), "A continued fraction needs at least one term.");
+        assert!(terms[1..].iter().all(|a| *a > 0), "Partial quotients must be positive.");
+        let mut res = Rational::default();
+        unsafe {
+            fmpq::fmpq_set_cfrac(
+                res.as_mut_ptr(),
+                terms.as_ptr() as *const fmpz::fmpz,
+                terms.len().try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return an iterator over the convergents `p_k/q_k` of the continued
+    /// fraction expansion of the rational, ending with the rational itself.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let q = Rational::from([415, 93]);
+    /// let conv: Vec<Rational> = q.convergents().collect();
+    /// assert_eq!(conv, [[4, 1], [9, 2], [58, 13], [415, 93]].map(Rational::from));
+    /// ```
+    pub fn convergents(&self) -> Convergents {
+        Convergents::new(self.continued_fraction())
+    }
+
+    /// Return the best rational approximation of `self` with denominator at
+    /// most `bound`, i.e. the closest such fraction, preferring the smaller
+    /// denominator on ties. Panics if `bound` is not positive.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let pi = Rational::from([314159265, 100000000]);
+    /// assert_eq!(pi.best_approximation(10), Rational::from([22, 7]));
+    /// assert_eq!(pi.best_approximation(200), Rational::from([355, 113]));
+    /// ```
+    pub fn best_approximation<T: Into<Integer>>(&self, bound: T) -> Rational {
+        let bound = bound.into();
+        assert!(bound > 0, "The denominator bound must be positive.");
+        if self.denominator() <= bound {
+            return self.clone();
+        }
+
+        let mut conv = self.convergents();
+        loop {
+            let (p, q) = (conv.p0.clone(), conv.q0.clone());
+            let (p1, q1) = (conv.p1.clone(), conv.q1.clone());
+            conv.next();
+            if conv.q0 > bound {
+                // the best approximation is either the last convergent within
+                // the bound or the largest semiconvergent after it
+                let t = (&bound - &q1).fdiv_q(&q);
+                let semi = Rational::from([&t * &p + &p1, &t * &q + &q1]);
+                let last = Rational::from([p, q]);
+                return if t > 0 && (self - &semi).abs() < (self - &last).abs() {
+                    semi
+                } else {
+                    last
+                };
+            }
+        }
+    }
+}
+
+/// An iterator over the convergents of a continued fraction, see
+/// [Rational::convergents].
+#[derive(Clone, Debug)]
+pub struct Convergents {
+    terms: std::vec::IntoIter<Integer>,
+    // p0/q0 is the last convergent returned and p1/q1 the one before it
+    p0: Integer,
+    q0: Integer,
+    p1: Integer,
+    q1: Integer,
+}
+
+impl Convergents {
+    /// Return an iterator over the convergents of the continued fraction
+    /// `[a0; a1, ..., an]`.
+    pub fn new(terms: Vec<Integer>) -> Self {
+        Convergents {
+            terms: terms.into_iter(),
+            p0: Integer::one(),
+            q0: Integer::zero(),
+            p1: Integer::zero(),
+            q1: Integer::one(),
+        }
+    }
+}
+
+impl Iterator for Convergents {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        let a = self.terms.next()?;
+        let p = &a * &self.p0 + &self.p1;
+        let q = &a * &self.q0 + &self.q1;
+        self.p1 = std::mem::replace(&mut self.p0, p);
+        self.q1 = std::mem::replace(&mut self.q0, q);
+        Some(Rational::from([&self.p0, &self.q0]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.terms.size_hint()
+    }
+}
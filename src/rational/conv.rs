@@ -19,25 +19,31 @@ use crate::{
     Integer, 
     Rational, 
     IntMod,
-    Error::{self, Msg},
-    Result
+    Error::{self, ParseRationalError},
+    Result,
+    RoundMode,
+    arf::Arf,
+    parse
 };
-use flint_sys::fmpq;
+use arb_sys::arf::{arf_get_d, arf_set_fmpz_2exp};
+use flint_sys::{fmpq, fmpz};
 use std::str::FromStr;
 
 impl FromStr for Rational {
     type Err = Error;
+    /// Parse a rational number of the form `x` or `x/y` where `x` and `y` are
+    /// integers and `y` is positive.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x: Rational = "-6/8".parse().unwrap();
+    /// assert_eq!(x, Rational::from([-3, 4]));
+    /// assert_eq!(x.to_string().parse::<Rational>().unwrap(), x);
+    /// assert!(matches!("1/0".parse::<Rational>(), Err(Error::ParseRationalError(_))));
+    /// ```
     fn from_str(s: &str) -> Result<Self> {
-        let r = s.split("/").collect::<Vec<_>>();
-        match r.len() {
-            1 => Ok(Rational::from(Integer::from_str(r[0])?)),
-            2 => Ok(Rational::from([
-                Integer::from_str(r[0])?,
-                Integer::from_str(r[1])?,
-            ])),
-            _ => Err(Msg("Input must be of the form \"x\" or \"x/y\" where x and y are 
-                     integers.".to_string())),
-        }
+        parse::rational(s).map_err(ParseRationalError)
     }
 }
 
@@ -107,3 +113,66 @@ impl From<[&Integer; 2]> for Rational {
         }
     }
 }
+
+impl Rational {
+    /// Convert the rational number to a double, rounding in the direction 
+    /// `rnd`. The result is correctly rounded, including in the subnormal 
+    /// range, and values too large for a double round to infinity or to 
+    /// `f64::MAX` according to the direction.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Rational::from([1, 3]);
+    /// assert_eq!(x.to_f64_rounded(RoundMode::Nearest), 1.0 / 3.0);
+    /// assert!(x.to_f64_rounded(RoundMode::Floor) < x.to_f64_rounded(RoundMode::Ceil));
+    /// assert_eq!((-x).to_f64_rounded(RoundMode::Down), -(1.0 / 3.0));
+    /// ```
+    pub fn to_f64_rounded(&self, rnd: RoundMode) -> f64 {
+        if self.is_zero() {
+            return 0.0;
+        }
+        let num = self.numerator();
+        let mut a = num.abs();
+        let mut b = self.denominator();
+        // scale so that the quotient has at least 55 bits
+        let k = 55 + b.bits() as i64 - a.bits() as i64;
+        let mut q = Integer::default();
+        let mut r = Integer::default();
+        let mut x = Arf::default();
+        unsafe {
+            if k >= 0 {
+                fmpz::fmpz_mul_2exp(a.as_mut_ptr(), a.as_ptr(), k as u64);
+            } else {
+                fmpz::fmpz_mul_2exp(b.as_mut_ptr(), b.as_ptr(), k.unsigned_abs());
+            }
+            fmpz::fmpz_fdiv_qr(q.as_mut_ptr(), r.as_mut_ptr(), a.as_ptr(), b.as_ptr());
+            // append a sticky bit recording whether the division was inexact,
+            // so that rounding the truncated quotient agrees with rounding 
+            // the exact one
+            fmpz::fmpz_mul_2exp(q.as_mut_ptr(), q.as_ptr(), 1);
+            if !r.is_zero() {
+                fmpz::fmpz_add_ui(q.as_mut_ptr(), q.as_ptr(), 1);
+            }
+            if num.sign() < 0 {
+                fmpz::fmpz_neg(q.as_mut_ptr(), q.as_ptr());
+            }
+            arf_set_fmpz_2exp(x.as_mut_ptr(), q.as_ptr(), Integer::from(-k - 1).as_ptr());
+            arf_get_d(x.as_ptr(), rnd.as_arf_rnd())
+        }
+    }
+
+    /// Convert the rational number to the nearest double, with ties to 
+    /// even. See [Rational::to_f64_rounded].
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(Rational::from([-5, 4]).to_f64(), -1.25);
+    /// assert_eq!(Rational::from([1, 10]).to_f64(), 0.1);
+    /// ```
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        self.to_f64_rounded(RoundMode::Nearest)
+    }
+}
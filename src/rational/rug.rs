@@ -0,0 +1,59 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use ::rug::Integer as RugInteger;
+use ::rug::Rational as RugRational;
+
+impl_from! {
+    RugRational, Rational
+    {
+        fn from(src: &Rational) -> RugRational {
+            let num = RugInteger::from(src.numerator());
+            let den = RugInteger::from(src.denominator());
+            // Rational is always in canonical form.
+            unsafe { RugRational::from_canonical(num, den) }
+        }
+    }
+}
+
+impl_from! {
+    Rational, RugRational
+    {
+        fn from(src: &RugRational) -> Rational {
+            let num = Integer::from(src.numer());
+            let den = Integer::from(src.denom());
+            Rational::from([num, den])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Integer, Rational};
+    use ::rug::Rational as RugRational;
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn rug_roundtrip(num: i64, den: u64, big: Vec<u64>) -> bool {
+        let mut b = Integer::default();
+        b.set_ui_vector(big);
+        let x = Rational::from([Integer::from(num) * b, Integer::from(den) + 1u32]);
+        let y = RugRational::from(&x);
+        x.to_string() == y.to_string() && Rational::from(&y) == x
+    }
+}
@@ -16,19 +16,39 @@
  */
 
 mod ops;
-//mod conv;
+mod conv;
+mod lp;
+mod decomp;
+mod eigen;
 
-//#[cfg(feature = "serde")]
-//mod serde;
+#[cfg(feature = "serde")]
+mod serde;
 
 use crate::*;
 use flint_sys::{fmpq, fmpq_mat};
+use crate::util::{fmt_rows, DisplayRows};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::MaybeUninit;
 
+pub use lp::Feasibility;
+pub use eigen::Eigenvalues;
+pub use decomp::RatMatLU;
 
-#[derive(Debug)]
+
+/// A matrix with rational entries. In mixed arithmetic, integer matrices and
+/// integer or rational scalars are coerced into rational matrices.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let a = IntMat::new([1, 2, 3, 4], 2, 2);
+/// let b = &a * &Rational::from([1, 2]);
+/// assert_eq!(b, RatMat::new([[1, 2], [1, 1], [3, 2], [2, 1]], 2, 2));
+/// assert_eq!(&b + &a, &b * &Rational::from(3));
+/// assert_eq!(&Rational::from(2) * &b, &a / &Integer::from(1));
+/// assert_eq!(&a * &b - &b * &a, RatMat::zero(2, 2));
+/// ```
 pub struct RatMat {
     inner: fmpq_mat::fmpq_mat_struct,
 }
@@ -50,29 +70,20 @@ impl Clone for RatMat {
     }
 }
 
+impl fmt::Debug for RatMat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RatMat")
+            .field("nrows", &self.nrows())
+            .field("ncols", &self.ncols())
+            .field("entries", &DisplayRows(self.rows().collect()))
+            .finish()
+    }
+}
+
 impl fmt::Display for RatMat {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let r = self.nrows().try_into().expect(
-            "Cannot convert signed long to usize.");
-        let c = self.ncols().try_into().expect(
-            "Cannot convert signed long to usize.");
-        let mut out = Vec::with_capacity(r);
-
-        for i in 0..r {
-            let mut row = Vec::with_capacity(c + 2);
-            row.push("[".to_string());
-            for j in 0..c {
-                row.push(format!(" {} ", self.get_entry(i, j)));
-            }
-            if i == r - 1 {
-                row.push("]".to_string());
-            } else {
-                row.push("]\n".to_string());
-            }
-            out.push(row.join(""));
-        }
-        write!(f, "{}", out.join(""))
+        fmt_rows(f, self.rows())
     }
 }
 
@@ -249,6 +260,19 @@ impl RatMat {
         res
     }
 
+    /// Return a random `nrows` by `ncols` matrix with numerators and
+    /// denominators of up to `bits` bits.
+    pub fn randtest<S>(nrows: i64, ncols: i64, bits: S, rng: &mut FlintRand) -> RatMat 
+    where
+        S: Into<u64>
+    {
+        let mut res = RatMat::zero(nrows, ncols);
+        unsafe { 
+            fmpq_mat::fmpq_mat_randtest(res.as_mut_ptr(), rng.as_mut_ptr(), bits.into()); 
+        }
+        res
+    }
+
     /// Set `self` to the zero matrix.
     #[inline]
     pub fn zero_assign(&mut self) {
@@ -356,6 +380,479 @@ impl RatMat {
         out
     }
 
+    /// Return the Toeplitz matrix with first column `col` and first row `row`, 
+    /// that is, the matrix whose `(i, j)`-th entry only depends on `i - j`. 
+    /// Panics if the first entries of `col` and `row` do not agree.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let col = [Rational::from(1), Rational::from(2), Rational::from(3)];
+    /// let row = [Rational::from(1), Rational::from([1, 2])];
+    /// let t = RatMat::toeplitz(&col, &row);
+    /// assert_eq!(t.get_entry(2, 1), 2);
+    /// assert_eq!(t.get_entry(1, 1), 1);
+    /// assert!(t.is_toeplitz());
+    /// ```
+    pub fn toeplitz<T: AsRef<Rational>>(col: &[T], row: &[T]) -> RatMat {
+        assert!(!col.is_empty() && !row.is_empty());
+        assert_eq!(col[0].as_ref(), row[0].as_ref());
+        
+        let nrows = col.len().try_into().expect(
+            "Cannot convert usize to a signed long.");
+        let ncols = row.len().try_into().expect(
+            "Cannot convert usize to a signed long.");
+        let mut res = RatMat::zero(nrows, ncols);
+        for i in 0..col.len() {
+            for j in 0..row.len() {
+                res.set_entry(i, j, toeplitz_entry(col, row, i, j));
+            }
+        }
+        res
+    }
+    
+    /// Return the Hankel matrix with first column `col` and last row `row`, 
+    /// that is, the matrix whose `(i, j)`-th entry only depends on `i + j`. 
+    /// Panics if the last entry of `col` and the first entry of `row` do not 
+    /// agree.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let col = [Rational::from(1), Rational::from(2)];
+    /// let row = [Rational::from(2), Rational::from(3), Rational::from(4)];
+    /// let h = RatMat::hankel(&col, &row);
+    /// assert_eq!(h, RatMat::new([1, 2, 3, 2, 3, 4], 2, 3));
+    /// assert!(h.is_hankel());
+    /// ```
+    pub fn hankel<T: AsRef<Rational>>(col: &[T], row: &[T]) -> RatMat {
+        assert!(!col.is_empty() && !row.is_empty());
+        assert_eq!(col[col.len() - 1].as_ref(), row[0].as_ref());
+        
+        let nrows = col.len().try_into().expect(
+            "Cannot convert usize to a signed long.");
+        let ncols = row.len().try_into().expect(
+            "Cannot convert usize to a signed long.");
+        let mut res = RatMat::zero(nrows, ncols);
+        for i in 0..col.len() {
+            for j in 0..row.len() {
+                res.set_entry(i, j, hankel_entry(col, row, i, j));
+            }
+        }
+        res
+    }
+
+    /// Return true if the matrix is a Toeplitz matrix, that is, each of its 
+    /// diagonals is constant.
+    pub fn is_toeplitz(&self) -> bool {
+        for i in 1..self.nrows() {
+            for j in 1..self.ncols() {
+                if self.get_entry(i, j) != self.get_entry(i - 1, j - 1) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+    
+    /// Return true if the matrix is a Hankel matrix, that is, each of its 
+    /// anti-diagonals is constant.
+    pub fn is_hankel(&self) -> bool {
+        for i in 1..self.nrows() {
+            for j in 0..self.ncols().saturating_sub(1) {
+                if self.get_entry(i, j) != self.get_entry(i - 1, j + 1) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Solve `AX = B` for a square Toeplitz matrix `A` using the Levinson 
+    /// recursion, which requires `O(n^2)` operations per column of `B` rather 
+    /// than the `O(n^3)` of a general solver. Returns `None` if a leading 
+    /// principal minor of `A` vanishes, in which case the recursion breaks 
+    /// down (even if `A` is nonsingular). Panics if `A` is not Toeplitz.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([2, 1, 0, 1, 2, 1, 0, 1, 2], 3, 3);
+    /// let b = RatMat::new([1, 2, 3], 3, 1);
+    /// let x = a.solve_toeplitz(&b).unwrap();
+    /// assert_eq!(&a * &x, b);
+    /// ```
+    pub fn solve_toeplitz<T: AsRef<RatMat>>(&self, rhs: T) -> Option<RatMat> {
+        let rhs = rhs.as_ref();
+        assert!(self.is_square());
+        assert!(self.is_toeplitz());
+        assert_eq!(self.nrows(), rhs.nrows());
+
+        let n = self.nrows();
+        let col: Vec<Rational> = (0..n).map(|i| self.get_entry(i, 0)).collect();
+        let row: Vec<Rational> = (0..n).map(|j| self.get_entry(0, j)).collect();
+        solve_toeplitz_columns(&col, &row, rhs)
+    }
+    
+    /// Solve `AX = B` for a square Hankel matrix `A`. Reversing the rows of a 
+    /// Hankel matrix gives a Toeplitz matrix, so this has the same complexity 
+    /// and failure conditions as [solve_toeplitz][RatMat::solve_toeplitz]. 
+    /// Panics if `A` is not Hankel.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([1, 2, 2, 5], 2, 2);
+    /// let b = RatMat::new([1, 1], 2, 1);
+    /// let x = a.solve_hankel(&b).unwrap();
+    /// assert_eq!(&a * &x, b);
+    /// ```
+    pub fn solve_hankel<T: AsRef<RatMat>>(&self, rhs: T) -> Option<RatMat> {
+        let rhs = rhs.as_ref();
+        assert!(self.is_square());
+        assert!(self.is_hankel());
+        assert_eq!(self.nrows(), rhs.nrows());
+
+        let n = self.nrows();
+        if n == 0 {
+            return Some(RatMat::zero(0, rhs.ncols_si()));
+        }
+        let col: Vec<Rational> = (0..n).map(|i| self.get_entry(n - 1 - i, 0)).collect();
+        let row: Vec<Rational> = (0..n).map(|j| self.get_entry(n - 1, j)).collect();
+        
+        let mut rev = rhs.clone();
+        for i in 0..n / 2 {
+            for j in 0..rhs.ncols() {
+                let tmp = rev.get_entry(i, j);
+                rev.set_entry(i, j, rev.get_entry(n - 1 - i, j));
+                rev.set_entry(n - 1 - i, j, tmp);
+            }
+        }
+        solve_toeplitz_columns(&col, &row, &rev)
+    }
+
+    /// Return the transpose of the matrix.
+    #[inline]
+    pub fn transpose(&self) -> RatMat {
+        let mut res = RatMat::zero(self.ncols_si(), self.nrows_si());
+        unsafe {
+            fmpq_mat::fmpq_mat_transpose(res.as_mut_ptr(), self.as_ptr());
+        }
+        res
+    }
+
+    /// Borrow the `(i, j)`-th entry of the matrix without copying.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> &Rational {
+        let (i, j) = self.check_indices(i, j);
+        unsafe { &*(fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, j) as *const Rational) }
+    }
+
+    /// Mutably borrow the `(i, j)`-th entry of the matrix.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let mut m = RatMat::zero(2, 2);
+    /// *m.entry_mut(1, 0) = Rational::from(3);
+    /// m.row_mut(0)[1] += Rational::from(2);
+    /// assert_eq!(m, RatMat::new([0, 2, 3, 0], 2, 2));
+    /// ```
+    #[inline]
+    pub fn entry_mut(&mut self, i: usize, j: usize) -> &mut Rational {
+        let (i, j) = self.check_indices(i, j);
+        unsafe { &mut *(fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, j) as *mut Rational) }
+    }
+
+    /// Borrow row `i` of the matrix as a slice without copying.
+    #[inline]
+    pub fn row_slice(&self, i: usize) -> &[Rational] {
+        let i = self.check_row_index(i);
+        let c = self.ncols();
+        if c == 0 {
+            return &[];
+        }
+        unsafe {
+            let ptr = fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, 0) as *const Rational;
+            std::slice::from_raw_parts(ptr, c)
+        }
+    }
+
+    /// Mutably borrow row `i` of the matrix as a slice.
+    #[inline]
+    pub fn row_mut(&mut self, i: usize) -> &mut [Rational] {
+        let i = self.check_row_index(i);
+        let c = self.ncols();
+        if c == 0 {
+            return &mut [];
+        }
+        unsafe {
+            let ptr = fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, 0) as *mut Rational;
+            std::slice::from_raw_parts_mut(ptr, c)
+        }
+    }
+
+    /// Iterate over the rows of the matrix as borrowed slices.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = &[Rational]> + '_ {
+        (0..self.nrows()).map(move |i| self.row_slice(i))
+    }
+    
+    /// Iterate over the columns of the matrix, each given as an iterator over 
+    /// borrowed entries.
+    #[inline]
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &Rational> + '_> + '_ {
+        let r = self.nrows();
+        (0..self.ncols()).map(move |j| (0..r).map(move |i| self.entry(i, j)))
+    }
+    
+    /// Iterate over the entries of the matrix in row-major order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Rational> + '_ {
+        self.rows().flatten()
+    }
+
+    /// Return a pair `(m, d)` with `m` an integer matrix and `d` a positive
+    /// integer such that `self = m/d`, with `d` the least common multiple of
+    /// the denominators of the entries.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([[1, 2], [1, 3], [0, 1], [5, 6]], 2, 2);
+    /// let (m, d) = a.clear_denominators();
+    /// assert_eq!(m, IntMat::new([3, 2, 0, 5], 2, 2));
+    /// assert_eq!(d, 6);
+    /// ```
+    pub fn clear_denominators(&self) -> (IntMat, Integer) {
+        let mut num = IntMat::zero(self.nrows_si(), self.ncols_si());
+        let mut den = Integer::default();
+        unsafe {
+            fmpq_mat::fmpq_mat_get_fmpz_mat_matwise(
+                num.as_mut_ptr(),
+                den.as_mut_ptr(),
+                self.as_ptr()
+            );
+        }
+        (num, den)
+    }
+
+    /// Return the largest absolute value of an entry of the matrix, or zero 
+    /// if the matrix is empty.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let m = RatMat::new([[1, 2], [-5, 1], [3, 1], [2, 1]], 2, 2);
+    /// assert_eq!(m.max_abs_entry(), 5);
+    /// assert_eq!(m.frobenius_norm_squared(), Rational::from([153, 4]));
+    /// assert_eq!(m.l1_norm(), 7);
+    /// assert_eq!(m.linf_norm(), Rational::from([11, 2]));
+    /// ```
+    pub fn max_abs_entry(&self) -> Rational {
+        let mut res = Rational::zero();
+        let mut t = Rational::zero();
+        unsafe {
+            for i in 0..self.nrows_si() {
+                for j in 0..self.ncols_si() {
+                    let x = fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, j);
+                    fmpq::fmpq_abs(t.as_mut_ptr(), x);
+                    if fmpq::fmpq_cmp(t.as_ptr(), res.as_ptr()) > 0 {
+                        fmpq::fmpq_set(res.as_mut_ptr(), t.as_ptr());
+                    }
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the sum of the squares of the entries of the matrix.
+    pub fn frobenius_norm_squared(&self) -> Rational {
+        let mut res = Rational::zero();
+        unsafe {
+            for i in 0..self.nrows_si() {
+                for j in 0..self.ncols_si() {
+                    let x = fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, j);
+                    fmpq::fmpq_addmul(res.as_mut_ptr(), x, x);
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the operator 1-norm of the matrix, that is, the largest sum of 
+    /// the absolute values of the entries in a column.
+    pub fn l1_norm(&self) -> Rational {
+        let mut res = Rational::zero();
+        let mut sum = Rational::zero();
+        let mut t = Rational::zero();
+        unsafe {
+            for j in 0..self.ncols_si() {
+                sum.zero_assign();
+                for i in 0..self.nrows_si() {
+                    let x = fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, j);
+                    fmpq::fmpq_abs(t.as_mut_ptr(), x);
+                    fmpq::fmpq_add(sum.as_mut_ptr(), sum.as_ptr(), t.as_ptr());
+                }
+                if sum > res {
+                    fmpq::fmpq_set(res.as_mut_ptr(), sum.as_ptr());
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the operator infinity-norm of the matrix, that is, the largest 
+    /// sum of the absolute values of the entries in a row.
+    pub fn linf_norm(&self) -> Rational {
+        let mut res = Rational::zero();
+        let mut sum = Rational::zero();
+        let mut t = Rational::zero();
+        unsafe {
+            for i in 0..self.nrows_si() {
+                sum.zero_assign();
+                for j in 0..self.ncols_si() {
+                    let x = fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, j);
+                    fmpq::fmpq_abs(t.as_mut_ptr(), x);
+                    fmpq::fmpq_add(sum.as_mut_ptr(), sum.as_ptr(), t.as_ptr());
+                }
+                if sum > res {
+                    fmpq::fmpq_set(res.as_mut_ptr(), sum.as_ptr());
+                }
+            }
+        }
+        res
+    }
+    
+    /// Return the product of the squared Euclidean norms of the rows of a 
+    /// square matrix. By the Hadamard inequality this bounds the square of the 
+    /// determinant.
+    pub fn hadamard_bound_squared(&self) -> Rational {
+        assert!(self.is_square());
+        let mut res = Rational::one();
+        let mut sum = Rational::zero();
+        unsafe {
+            for i in 0..self.nrows_si() {
+                sum.zero_assign();
+                for j in 0..self.ncols_si() {
+                    let x = fmpq_mat::fmpq_mat_entry(self.as_ptr(), i, j);
+                    fmpq::fmpq_addmul(sum.as_mut_ptr(), x, x);
+                }
+                fmpq::fmpq_mul(res.as_mut_ptr(), res.as_ptr(), sum.as_ptr());
+            }
+        }
+        res
+    }
+    
+    /// Return the inverse of the matrix, or `None` if it is singular. The 
+    /// matrix must be square.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([2, 1, 1, 1], 2, 2);
+    /// assert_eq!(a.inv().unwrap(), RatMat::new([1, -1, -1, 2], 2, 2));
+    /// assert!(RatMat::new([1, 2, 2, 4], 2, 2).inv().is_none());
+    /// ```
+    pub fn inv(&self) -> Option<RatMat> {
+        assert!(self.is_square());
+        let mut res = RatMat::zero(self.nrows_si(), self.ncols_si());
+        unsafe {
+            if fmpq_mat::fmpq_mat_inv(res.as_mut_ptr(), self.as_ptr()) != 0 {
+                Some(res)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Return the matrix raised to the power `exp`, using binary powering. 
+    /// Negative exponents are powers of the inverse and return `None` if the 
+    /// matrix is singular. The matrix must be square.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([[1, 2], [0, 1], [0, 1], [1, 3]], 2, 2);
+    /// assert_eq!(a.pow(3).unwrap(), RatMat::new([[1, 8], [0, 1], [0, 1], [1, 27]], 2, 2));
+    /// assert_eq!(a.pow(-2).unwrap(), RatMat::new([4, 0, 0, 9], 2, 2));
+    /// assert_eq!(&a.pow(5).unwrap() * &a.pow(-5).unwrap(), RatMat::one(2));
+    /// ```
+    pub fn pow(&self, exp: i64) -> Option<RatMat> {
+        assert!(self.is_square());
+        let mut base = if exp < 0 { self.inv()? } else { self.clone() };
+        let mut e = exp.unsigned_abs();
+        let mut res = RatMat::one(self.nrows_si());
+        while e > 0 {
+            if e & 1 == 1 {
+                res = &res * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        Some(res)
+    }
+
+    /// Return the rank and the reduced row echelon form of the matrix.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([1, 2, 3, 2, 4, 7], 2, 3);
+    /// let (rank, r) = a.rref();
+    /// assert_eq!(rank, 2);
+    /// assert_eq!(r, RatMat::new([1, 2, 0, 0, 0, 1], 2, 3));
+    /// ```
+    pub fn rref(&self) -> (i64, RatMat) {
+        let mut res = RatMat::zero(self.nrows_si(), self.ncols_si());
+        let rank = unsafe { fmpq_mat::fmpq_mat_rref(res.as_mut_ptr(), self.as_ptr()) };
+        (rank, res)
+    }
+
+    /// Return the rank of the matrix.
+    #[inline]
+    pub fn rank(&self) -> i64 {
+        self.rref().0
+    }
+
+    /// Return a matrix whose columns form a basis of the right nullspace of 
+    /// the matrix, that is, of the solutions of `Ax = 0`. The basis is the 
+    /// one read off the reduced row echelon form, with a one in each 
+    /// non-pivot position.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([1, 2, 3, 2, 4, 7], 2, 3);
+    /// let x = a.nullspace();
+    /// assert_eq!(x, RatMat::new([-2, 1, 0], 3, 1));
+    /// assert!((&a * &x).is_zero());
+    /// ```
+    pub fn nullspace(&self) -> RatMat {
+        let (rank, r) = self.rref();
+        let rank: usize = rank.try_into().expect("Cannot convert signed long to usize.");
+        let n = self.ncols();
+        
+        let mut pivots = Vec::with_capacity(rank);
+        for i in 0..rank {
+            let p = (0..n).find(|&j| !r.entry(i, j).is_zero()).unwrap();
+            pivots.push(p);
+        }
+        let free: Vec<usize> = (0..n).filter(|j| !pivots.contains(j)).collect();
+        
+        let nullity = free.len().try_into().expect("Cannot convert length to a signed long.");
+        let mut res = RatMat::zero(self.ncols_si(), nullity);
+        for (k, &f) in free.iter().enumerate() {
+            res.set_entry(f, k, Rational::one());
+            for (i, &p) in pivots.iter().enumerate() {
+                res.set_entry(p, k, -r.entry(i, f));
+            }
+        }
+        res
+    }
+
     /*
     /// Swap two integer matrices. The dimensions are allowed to be different.
     #[inline]
@@ -648,7 +1145,7 @@ impl RatMat {
         }
         res
     }
-    
+
     /// Applies a similarity transform to an `n` by `n` integer matrix. If `P` 
     /// is the identity matrix whose zero entries in row `r` have been replaced 
     /// by `d`, this transform is equivalent to `P^-1 * M * P`. 
@@ -707,3 +1204,113 @@ impl RatMat {
     }
     */
 }
+
+#[inline]
+fn toeplitz_entry<'a, T: AsRef<Rational>>(
+    col: &'a [T], 
+    row: &'a [T], 
+    i: usize, 
+    j: usize
+) -> &'a Rational {
+    if i >= j {
+        col[i - j].as_ref()
+    } else {
+        row[j - i].as_ref()
+    }
+}
+
+#[inline]
+fn hankel_entry<'a, T: AsRef<Rational>>(
+    col: &'a [T], 
+    row: &'a [T], 
+    i: usize, 
+    j: usize
+) -> &'a Rational {
+    let k = i + j;
+    if k < col.len() {
+        col[k].as_ref()
+    } else {
+        row[k + 1 - col.len()].as_ref()
+    }
+}
+
+// Solve the Toeplitz system with first column `col` and first row `row` for 
+// each column of `rhs`.
+pub(crate) fn solve_toeplitz_columns(
+    col: &[Rational], 
+    row: &[Rational], 
+    rhs: &RatMat
+) -> Option<RatMat> {
+    let n = col.len();
+    let mut res = RatMat::zero(rhs.nrows_si(), rhs.ncols_si());
+    for j in 0..rhs.ncols() {
+        let b: Vec<Rational> = (0..n).map(|i| rhs.get_entry(i, j)).collect();
+        let x = levinson(col, row, &b)?;
+        for (i, xi) in x.iter().enumerate() {
+            res.set_entry(i, j, xi);
+        }
+    }
+    Some(res)
+}
+
+/// Solve `Tx = b` where `T` is the square Toeplitz matrix with first column 
+/// `col` and first row `row` using the Levinson recursion. Returns `None` if a 
+/// leading principal minor of `T` vanishes.
+pub(crate) fn levinson(
+    col: &[Rational], 
+    row: &[Rational], 
+    b: &[Rational]
+) -> Option<Vec<Rational>> {
+    let n = b.len();
+    assert_eq!(col.len(), n);
+    assert_eq!(row.len(), n);
+    if n == 0 {
+        return Some(vec![]);
+    }
+    if col[0].is_zero() {
+        return None;
+    }
+
+    // Forward and backward vectors, satisfying `T_m f = e_1` and 
+    // `T_m b = e_m` for the leading `m` by `m` submatrix `T_m`.
+    let t0_inv = (&col[0]).inv();
+    let mut fw = vec![t0_inv.clone()];
+    let mut bw = vec![t0_inv.clone()];
+    let mut x = vec![&b[0] * &t0_inv];
+
+    for m in 1..n {
+        // errors introduced by extending f, b, x with a zero
+        let mut ef = Rational::zero();
+        let mut eb = Rational::zero();
+        let mut ex = Rational::zero();
+        for i in 0..m {
+            ef += toeplitz_entry(col, row, m, i) * &fw[i];
+            eb += toeplitz_entry(col, row, 0, i + 1) * &bw[i];
+            ex += toeplitz_entry(col, row, m, i) * &x[i];
+        }
+
+        let denom = Rational::one() - &ef * &eb;
+        if denom.is_zero() {
+            return None;
+        }
+        let denom_inv = (&denom).inv();
+
+        let mut fw_next = Vec::with_capacity(m + 1);
+        let mut bw_next = Vec::with_capacity(m + 1);
+        for i in 0..=m {
+            let f = if i < m { fw[i].clone() } else { Rational::zero() };
+            let b = if i > 0 { bw[i - 1].clone() } else { Rational::zero() };
+            fw_next.push((&f - &ef * &b) * &denom_inv);
+            bw_next.push((&b - &eb * &f) * &denom_inv);
+        }
+        fw = fw_next;
+        bw = bw_next;
+
+        let e = &b[m] - &ex;
+        x.push(Rational::zero());
+        for i in 0..=m {
+            x[i] += &e * &bw[i];
+        }
+    }
+    Some(x)
+}
@@ -17,13 +17,37 @@
 
 use flint_sys::fmpq_mat;
 use crate::*;
+use crate::Error::ParseMatrixError;
+use std::str::FromStr;
+
+impl FromStr for RatMat {
+    type Err = Error;
+    /// Parse a matrix given as nested lists `[[1/2, 2], [3, 4]]`, in Pari/GP 
+    /// syntax `[1/2, 2; 3, 4]`, or in the format of `Display`, with one 
+    /// bracketed row per line.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let m: RatMat = "[[1/2, -2], [3, 4/3]]".parse().unwrap();
+    /// assert_eq!(m.get_entry(1, 1), Rational::from([4, 3]));
+    /// assert_eq!(m.to_string().parse::<RatMat>().unwrap(), m);
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let (r, c, entries) = parse::matrix(s).map_err(ParseMatrixError)?;
+        let entries = entries.into_iter()
+            .map(parse::rational)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(ParseMatrixError)?;
+        Ok(RatMat::new(entries.as_slice(), r as i64, c as i64))
+    }
+}
 
 impl_from! {
     RatMat, IntMat
     {
         fn from(x: &IntMat) -> RatMat {
-            let rm = RatMatSpace::init(x.nrows(), x.ncols());
-            let mut res = rm.default();
+            let mut res = RatMat::zero(x.nrows_si(), x.ncols_si());
             unsafe {
                 fmpq_mat::fmpq_mat_set_fmpz_mat(res.as_mut_ptr(), x.as_ptr());
             }
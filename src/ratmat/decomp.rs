@@ -0,0 +1,322 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpq_mat;
+use crate::util::perm_is_odd;
+
+/// An LU decomposition with partial pivoting of a rational matrix, see 
+/// [RatMat::lu]. The factorization is computed once and reused by 
+/// [solve](RatMatLU::solve) for any number of right hand sides.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let a = RatMat::new([0, 2, 1, 1, 1, 0, 2, 4, 3], 3, 3);
+/// let lu = a.lu();
+/// assert_eq!(lu.rank(), 3);
+/// assert_eq!(lu.det(), -4);
+///
+/// let b = RatMat::new([3, 2, 9], 3, 1);
+/// assert_eq!(lu.solve(&b).unwrap(), RatMat::new([1, 1, 1], 3, 1));
+/// let b = RatMat::new([1, 0, 0], 3, 1);
+/// assert_eq!(lu.solve(&b), a.solve(&b));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RatMatLU {
+    perm: Vec<usize>,
+    l: RatMat,
+    u: RatMat,
+    rank: usize,
+}
+
+impl RatMatLU {
+    /// Return the row permutation: row `i` of `PA` is row `perm[i]` of `A`.
+    #[inline]
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Return the unit lower triangular factor `L`.
+    #[inline]
+    pub fn l(&self) -> &RatMat {
+        &self.l
+    }
+
+    /// Return the row echelon factor `U`.
+    #[inline]
+    pub fn u(&self) -> &RatMat {
+        &self.u
+    }
+
+    /// Return the rank of the factored matrix.
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Return the determinant of the factored square matrix.
+    pub fn det(&self) -> Rational {
+        assert!(self.u.is_square());
+        let mut res = Rational::one();
+        for i in 0..self.u.nrows() {
+            res = &res * self.u.entry(i, i);
+        }
+        if perm_is_odd(&self.perm) {
+            -res
+        } else {
+            res
+        }
+    }
+
+    /// Solve `AX = B` for a square, nonsingular matrix `A` by forward and 
+    /// back substitution, returning `None` if `A` is singular.
+    pub fn solve(&self, rhs: &RatMat) -> Option<RatMat> {
+        let n = self.u.nrows();
+        assert_eq!(n, rhs.nrows());
+        if !self.u.is_square() || self.rank < n {
+            return None;
+        }
+        let mut res = RatMat::zero(rhs.nrows_si(), rhs.ncols_si());
+        for c in 0..rhs.ncols() {
+            // Ly = Pb
+            let mut y: Vec<Rational> = Vec::with_capacity(n);
+            for i in 0..n {
+                let mut t = rhs.entry(self.perm[i], c).clone();
+                for (k, yk) in y.iter().enumerate() {
+                    t = &t - &(self.l.entry(i, k) * yk);
+                }
+                y.push(t);
+            }
+            // Ux = y
+            for i in (0..n).rev() {
+                let mut t = y[i].clone();
+                for k in i + 1..n {
+                    t = &t - &(self.u.entry(i, k) * res.entry(k, c));
+                }
+                res.set_entry(i, c, &t / self.u.entry(i, i));
+            }
+        }
+        Some(res)
+    }
+}
+
+impl RatMat {
+    // Swap rows `i` and `j` in place.
+    fn swap_rows_at(&mut self, i: usize, j: usize) {
+        if i == j {
+            return;
+        }
+        for c in 0..self.ncols() {
+            let a = self.entry(i, c).clone();
+            let b = self.entry(j, c).clone();
+            self.set_entry(i, c, b);
+            self.set_entry(j, c, a);
+        }
+    }
+
+    // Swap columns `i` and `j` in place.
+    fn swap_cols_at(&mut self, i: usize, j: usize) {
+        if i == j {
+            return;
+        }
+        for r in 0..self.nrows() {
+            let a = self.entry(r, i).clone();
+            let b = self.entry(r, j).clone();
+            self.set_entry(r, i, b);
+            self.set_entry(r, j, a);
+        }
+    }
+
+    /// Return an LU decomposition with partial pivoting `(p, L, U)` of the 
+    /// matrix. Row `i` of `PA` is row `p[i]` of `A`, `L` is square unit lower 
+    /// triangular and `U` is in row echelon form with the shape of `A`, so 
+    /// that `PA = LU`. Pivots are the first nonzero entries, which keeps the 
+    /// entries small for exact arithmetic.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([0, 2, 1, 1, 1, 0, 2, 4, 3], 3, 3);
+    /// let (p, l, u) = a.plu();
+    /// assert_eq!(p, vec![1, 0, 2]);
+    /// assert_eq!(l, RatMat::new([1, 0, 0, 0, 1, 0, 2, 1, 1], 3, 3));
+    /// assert_eq!(u, RatMat::new([1, 1, 0, 0, 2, 1, 0, 0, 2], 3, 3));
+    ///
+    /// let pa = RatMat::new([1, 1, 0, 0, 2, 1, 2, 4, 3], 3, 3);
+    /// assert_eq!(&l * &u, pa);
+    /// ```
+    pub fn plu(&self) -> (Vec<usize>, RatMat, RatMat) {
+        let (m, n) = (self.nrows(), self.ncols());
+        let mut perm: Vec<usize> = (0..m).collect();
+        let mut l = RatMat::one(self.nrows_si());
+        let mut u = self.clone();
+
+        let mut row = 0;
+        for col in 0..n {
+            if row == m {
+                break;
+            }
+            let piv = match (row..m).find(|&i| !u.entry(i, col).is_zero()) {
+                Some(i) => i,
+                None => continue,
+            };
+            if piv != row {
+                u.swap_rows_at(piv, row);
+                perm.swap(piv, row);
+                // only the multipliers computed so far move with the rows
+                for c in 0..row {
+                    let a = l.entry(piv, c).clone();
+                    let b = l.entry(row, c).clone();
+                    l.set_entry(piv, c, b);
+                    l.set_entry(row, c, a);
+                }
+            }
+            for i in row + 1..m {
+                if u.entry(i, col).is_zero() {
+                    continue;
+                }
+                let f = u.entry(i, col) / u.entry(row, col);
+                for c in col..n {
+                    let x = u.entry(i, c) - &(&f * u.entry(row, c));
+                    u.set_entry(i, c, x);
+                }
+                l.set_entry(i, row, f);
+            }
+            row += 1;
+        }
+        (perm, l, u)
+    }
+
+    /// Return the [plu](RatMat::plu) decomposition of the matrix as a 
+    /// reusable [RatMatLU].
+    pub fn lu(&self) -> RatMatLU {
+        let (perm, l, u) = self.plu();
+        let rank = (0..u.nrows())
+            .filter(|&i| (0..u.ncols()).any(|j| !u.entry(i, j).is_zero()))
+            .count();
+        RatMatLU { perm, l, u, rank }
+    }
+
+    /// Return an upper Hessenberg matrix similar to the square matrix, 
+    /// computed with elementary similarity transformations. All entries 
+    /// below the first subdiagonal of the result are zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([1, 2, 3, 4, 5, 6, 7, 8, 10], 3, 3);
+    /// let h = a.hessenberg();
+    /// assert!(h.entry(2, 0).is_zero());
+    /// assert_eq!(h.charpoly(), a.charpoly());
+    /// ```
+    pub fn hessenberg(&self) -> RatMat {
+        assert!(self.is_square());
+        let n = self.nrows();
+        let mut h = self.clone();
+
+        for j in 0..n.saturating_sub(2) {
+            let piv = match (j + 1..n).find(|&i| !h.entry(i, j).is_zero()) {
+                Some(i) => i,
+                None => continue,
+            };
+            h.swap_rows_at(piv, j + 1);
+            h.swap_cols_at(piv, j + 1);
+            for k in j + 2..n {
+                if h.entry(k, j).is_zero() {
+                    continue;
+                }
+                let f = h.entry(k, j) / h.entry(j + 1, j);
+                // row_k -= f * row_{j+1}, then col_{j+1} += f * col_k
+                for c in 0..n {
+                    let x = h.entry(k, c) - &(&f * h.entry(j + 1, c));
+                    h.set_entry(k, c, x);
+                }
+                for r in 0..n {
+                    let x = h.entry(r, j + 1) + &(&f * h.entry(r, k));
+                    h.set_entry(r, j + 1, x);
+                }
+            }
+        }
+        h
+    }
+
+    /// Return the characteristic polynomial of a square matrix. The matrix 
+    /// is reduced to [Hessenberg form](RatMat::hessenberg) and the 
+    /// polynomial is read off with the usual recurrence over its leading 
+    /// principal submatrices, using `O(n^3)` field operations.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([[1, 2], [1, 1], [0, 1], [3, 1]], 2, 2);
+    /// assert_eq!(a.charpoly(), RatPoly::from([[3, 2], [-7, 2], [1, 1]]));
+    /// ```
+    pub fn charpoly(&self) -> RatPoly {
+        let h = self.hessenberg();
+        let n = h.nrows();
+
+        // p[m] is the characteristic polynomial of the leading m x m block
+        let mut p = Vec::with_capacity(n + 1);
+        p.push(RatPoly::one());
+        for m in 1..=n {
+            let x = RatPoly::from([-h.entry(m - 1, m - 1), Rational::one()]);
+            let mut pm = &x * &p[m - 1];
+            let mut t = Rational::one();
+            for i in 1..m {
+                t = &t * h.entry(m - i, m - i - 1);
+                if t.is_zero() {
+                    break;
+                }
+                let c = &t * h.entry(m - i - 1, m - 1);
+                pm = &pm - &(&p[m - i - 1] * &c);
+            }
+            p.push(pm);
+        }
+        p.pop().unwrap()
+    }
+
+    /// Solve `AX = B` for a square, invertible matrix `A`, returning `None` if 
+    /// `A` is singular.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([2, 1, 1, 3], 2, 2);
+    /// let b = RatMat::new([1, 2], 2, 1);
+    /// let x = a.solve(&b).unwrap();
+    /// assert_eq!(x, RatMat::new([[1, 5], [3, 5]], 2, 1));
+    /// assert!(RatMat::new([1, 2, 2, 4], 2, 2).solve(&b).is_none());
+    /// ```
+    pub fn solve(&self, rhs: &RatMat) -> Option<RatMat> {
+        assert!(self.is_square());
+        assert_eq!(self.nrows(), rhs.nrows());
+        let mut res = RatMat::zero(self.ncols_si(), rhs.ncols_si());
+        unsafe {
+            let x = fmpq_mat::fmpq_mat_solve_fraction_free(
+                res.as_mut_ptr(),
+                self.as_ptr(),
+                rhs.as_ptr()
+            );
+            if x == 0 {
+                None
+            } else {
+                Some(res)
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntPoly, Rational, RatMat};
+
+/// The eigenvalues of a square rational matrix, see [RatMat::eigenvalues].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eigenvalues {
+    /// The rational eigenvalues in increasing order, with their algebraic 
+    /// multiplicities.
+    pub rational: Vec<(Rational, u64)>,
+    /// The irreducible factors of degree at least two of the characteristic 
+    /// polynomial, primitive with positive leading coefficient, with their 
+    /// multiplicities. Their roots are the remaining eigenvalues, which can 
+    /// be worked with in the number field each factor defines, see 
+    /// [NumFldCtx](crate::NumFldCtx).
+    pub irrational: Vec<(IntPoly, u64)>,
+}
+
+impl Eigenvalues {
+    /// Return true if all eigenvalues are rational.
+    #[inline]
+    pub fn all_rational(&self) -> bool {
+        self.irrational.is_empty()
+    }
+}
+
+impl RatMat {
+    /// Return the eigenvalues of a square matrix by factoring its 
+    /// characteristic polynomial over the integers.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // eigenvalues 2, 2 and +/- i
+    /// let a = RatMat::new([2, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, -1, 0, 0, 1, 0], 4, 4);
+    /// let ev = a.eigenvalues();
+    /// assert_eq!(ev.rational, vec![(Rational::from(2), 2)]);
+    /// assert_eq!(ev.irrational, vec![(IntPoly::from([1, 0, 1]), 1)]);
+    /// ```
+    pub fn eigenvalues(&self) -> Eigenvalues {
+        let (_, fac) = self.charpoly().primitive_part().factor();
+        let mut rational = Vec::new();
+        let mut irrational = Vec::new();
+        for (f, e) in fac {
+            if f.degree() == 1 {
+                rational.push((Rational::from([-f.get_coeff(0), f.get_coeff(1)]), e));
+            } else {
+                irrational.push((f, e));
+            }
+        }
+        rational.sort();
+        Eigenvalues { rational, irrational }
+    }
+
+    /// Return the rational eigenvalues of a square matrix in increasing 
+    /// order, each with a matrix whose columns form a basis of the 
+    /// corresponding eigenspace.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([2, 1, 1, 2], 2, 2);
+    /// let ev = a.eigenvectors();
+    /// assert_eq!(ev.len(), 2);
+    /// for (l, v) in ev {
+    ///     assert_eq!(v.ncols(), 1);
+    ///     let av = (&a * &v).get_entries();
+    ///     for (x, y) in av.iter().zip(v.get_entries()) {
+    ///         assert_eq!(x, &(y * &l));
+    ///     }
+    /// }
+    /// ```
+    pub fn eigenvectors(&self) -> Vec<(Rational, RatMat)> {
+        self.eigenvalues()
+            .rational
+            .into_iter()
+            .map(|(l, _)| {
+                let v = self.shift_diagonal(&l).nullspace();
+                (l, v)
+            })
+            .collect()
+    }
+
+    /// Return the sizes of the Jordan blocks of a square matrix as pairs 
+    /// `(eigenvalue, size)`, grouped by eigenvalue in increasing order and 
+    /// with the largest blocks first, or `None` if the matrix has 
+    /// eigenvalues that are not rational.
+    ///
+    /// The sizes are determined from the ranks of the powers of `A - lI` for 
+    /// each eigenvalue `l`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let a = RatMat::new([3, 1, 0, 0, 3, 0, 0, 0, 3], 3, 3);
+    /// let blocks = a.jordan_blocks().unwrap();
+    /// assert_eq!(blocks, vec![(Rational::from(3), 2), (Rational::from(3), 1)]);
+    ///
+    /// assert!(RatMat::new([0, -1, 1, 0], 2, 2).jordan_blocks().is_none());
+    /// ```
+    pub fn jordan_blocks(&self) -> Option<Vec<(Rational, u64)>> {
+        let ev = self.eigenvalues();
+        if !ev.all_rational() {
+            return None;
+        }
+        let n = self.nrows_si();
+        let mut res = Vec::new();
+        for (l, m) in ev.rational {
+            let m: i64 = m.try_into().expect("Cannot convert multiplicity to a signed long.");
+            let b = self.shift_diagonal(&l);
+            
+            // ranks[k] is the rank of (A - lI)^k, which decreases until the 
+            // nullity reaches the algebraic multiplicity
+            let mut ranks = vec![n];
+            let mut p = RatMat::one(n);
+            while n - ranks[ranks.len() - 1] < m {
+                p = &p * &b;
+                ranks.push(p.rank());
+            }
+            
+            // ranks[k - 1] - ranks[k] blocks have size at least k
+            let kmax = ranks.len() - 1;
+            for k in (1..=kmax).rev() {
+                let at_least = ranks[k - 1] - ranks[k];
+                let larger = if k < kmax { ranks[k] - ranks[k + 1] } else { 0 };
+                for _ in 0..at_least - larger {
+                    res.push((l.clone(), k as u64));
+                }
+            }
+        }
+        Some(res)
+    }
+
+    // Return `A - lI`.
+    fn shift_diagonal(&self, l: &Rational) -> RatMat {
+        let mut res = self.clone();
+        for i in 0..self.nrows() {
+            let x = res.entry(i, i) - l;
+            res.set_entry(i, i, x);
+        }
+        res
+    }
+}
@@ -0,0 +1,197 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{check_interrupt, Rational, RatMat};
+
+/// The outcome of [RatMat::feasibility] for the system `Ax = b, x >= 0`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Feasibility {
+    /// A column vector `x >= 0` with `Ax = b`.
+    Feasible(RatMat),
+    /// A Farkas certificate of infeasibility: a column vector `y` with 
+    /// `y^T A >= 0` and `y^T b < 0`.
+    Infeasible(RatMat),
+}
+
+impl RatMat {
+    /// Decide whether the system `Ax = b, x >= 0` has a solution, where `A` 
+    /// is `self` and `b` is a column vector, returning either a solution or a
+    /// certificate that none exists. 
+    ///
+    /// This runs the first phase of the simplex method in exact rational 
+    /// arithmetic with Bland's rule, so it always terminates, but the entries
+    /// can grow quickly and it is only intended for systems of modest size. 
+    /// Inequalities `Ax <= b` can be handled by appending slack columns, and 
+    /// free variables by splitting them as `x = x+ - x-`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // x + y = 1, x - y = 3 needs y = -1
+    /// let a = RatMat::new([1, 1, 1, -1], 2, 2);
+    /// let b = RatMat::new([1, 3], 2, 1);
+    /// let y = a.farkas_certificate(&b).unwrap();
+    /// assert!((&y.transpose() * &a).get_entries().iter().all(|e| e.sign() >= 0));
+    /// assert!((&y.transpose() * &b).get_entry(0, 0).sign() < 0);
+    ///
+    /// let b = RatMat::new([3, 1], 2, 1);
+    /// assert_eq!(a.feasibility(&b), Feasibility::Feasible(RatMat::new([2, 1], 2, 1)));
+    /// ```
+    pub fn feasibility(&self, b: &RatMat) -> Feasibility {
+        assert_eq!(b.ncols(), 1);
+        assert_eq!(self.nrows(), b.nrows());
+        trace_span!("RatMat::feasibility", nrows = self.nrows(), ncols = self.ncols());
+        let (m, n) = (self.nrows(), self.ncols());
+
+        // negate rows so that b >= 0, then add an artificial variable per row
+        let signs: Vec<bool> = (0..m).map(|i| b.get_entry(i, 0).sign() < 0).collect();
+        let mut tab = Tableau::new(m, n + m);
+        for i in 0..m {
+            for j in 0..n {
+                tab.rows[i][j] = self.get_entry(i, j);
+            }
+            tab.rows[i][n + i] = Rational::one();
+            tab.rows[i][n + m] = b.get_entry(i, 0);
+            if signs[i] {
+                for j in 0..n {
+                    tab.rows[i][j] = -&tab.rows[i][j];
+                }
+                tab.rows[i][n + m] = -&tab.rows[i][n + m];
+            }
+            tab.basis[i] = n + i;
+        }
+
+        // minimize the sum of the artificial variables; the objective row 
+        // holds the reduced costs and minus the objective value
+        for j in (0..n).chain(std::iter::once(n + m)) {
+            let mut s = Rational::zero();
+            for i in 0..m {
+                s -= &tab.rows[i][j];
+            }
+            tab.rows[m][j] = s;
+        }
+        tab.solve();
+
+        if tab.rows[m][n + m].is_zero() {
+            let mut x = RatMat::zero(n as i64, 1);
+            for i in 0..m {
+                if tab.basis[i] < n {
+                    x.set_entry(tab.basis[i], 0, &tab.rows[i][n + m]);
+                }
+            }
+            Feasibility::Feasible(x)
+        } else {
+            // the reduced cost of the i-th artificial column is 1 - u_i for 
+            // the optimal dual u, and y = -u certifies infeasibility
+            let mut y = RatMat::zero(m as i64, 1);
+            for i in 0..m {
+                let mut u = &tab.rows[m][n + i] - Rational::one();
+                if signs[i] {
+                    u = -u;
+                }
+                y.set_entry(i, 0, u);
+            }
+            Feasibility::Infeasible(y)
+        }
+    }
+
+    /// Return a column vector `x >= 0` with `Ax = b`, where `A` is `self`, or
+    /// `None` if there is none. See [RatMat::feasibility].
+    #[inline]
+    pub fn feasible_point(&self, b: &RatMat) -> Option<RatMat> {
+        match self.feasibility(b) {
+            Feasibility::Feasible(x) => Some(x),
+            Feasibility::Infeasible(_) => None,
+        }
+    }
+
+    /// Return a Farkas certificate `y` with `y^T A >= 0` and `y^T b < 0` 
+    /// proving that `Ax = b, x >= 0` has no solution, where `A` is `self`, or
+    /// `None` if the system is feasible. See [RatMat::feasibility].
+    #[inline]
+    pub fn farkas_certificate(&self, b: &RatMat) -> Option<RatMat> {
+        match self.feasibility(b) {
+            Feasibility::Feasible(_) => None,
+            Feasibility::Infeasible(y) => Some(y),
+        }
+    }
+}
+
+// A dense simplex tableau with `m` constraint rows followed by the objective 
+// row. The last column is the right hand side.
+struct Tableau {
+    rows: Vec<Vec<Rational>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn new(m: usize, nvars: usize) -> Self {
+        Tableau {
+            rows: vec![vec![Rational::zero(); nvars + 1]; m + 1],
+            basis: vec![0; m],
+        }
+    }
+
+    // Pivot until every reduced cost is nonnegative, using Bland's rule. The
+    // problem must be bounded.
+    fn solve(&mut self) {
+        let m = self.basis.len();
+        let rhs = self.rows[0].len() - 1;
+        loop {
+            check_interrupt();
+            let col = match (0..rhs).find(|&j| self.rows[m][j].sign() < 0) {
+                Some(j) => j,
+                None => return,
+            };
+            let mut best: Option<(usize, Rational)> = None;
+            for i in 0..m {
+                if self.rows[i][col].sign() > 0 {
+                    let ratio = &self.rows[i][rhs] / &self.rows[i][col];
+                    let better = match &best {
+                        None => true,
+                        Some((k, r)) => {
+                            ratio < *r || (ratio == *r && self.basis[i] < self.basis[*k])
+                        }
+                    };
+                    if better {
+                        best = Some((i, ratio));
+                    }
+                }
+            }
+            let (row, _) = best.expect("The simplex tableau is unbounded.");
+            self.pivot(row, col);
+        }
+    }
+
+    fn pivot(&mut self, row: usize, col: usize) {
+        let p = self.rows[row][col].clone();
+        for e in self.rows[row].iter_mut() {
+            *e /= &p;
+        }
+        let pivot_row = self.rows[row].clone();
+        for (i, r) in self.rows.iter_mut().enumerate() {
+            if i == row || r[col].is_zero() {
+                continue;
+            }
+            let f = r[col].clone();
+            for (e, pe) in r.iter_mut().zip(pivot_row.iter()) {
+                *e -= &f * pe;
+            }
+        }
+        self.basis[row] = col;
+    }
+}
@@ -16,10 +16,11 @@
  */
 
 use crate::*;
+use crate::util::fmpq_mat_swap_into;
 
 use flint_sys::{
     fmpz, 
-    //fmpq, 
+    fmpq, 
     fmpz_mat, 
     fmpq_mat
 };
@@ -89,6 +90,64 @@ impl_binop_unsafe! {
     */
 }
 
+impl_binop_unsafe! {
+    matrix
+    op_assign
+    RatMat, IntMat, RatMat
+
+    Add {add}
+    AddAssign {add_assign}
+    AssignAdd {assign_add}
+    fmpq_mat_fmpz_mat_add;
+
+    Sub {sub}
+    SubAssign {sub_assign}
+    AssignSub {assign_sub}
+    fmpq_mat_fmpz_mat_sub;
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    AssignMul {assign_mul}
+    fmpq_mat_fmpz_mat_mul;
+}
+
+impl_binop_unsafe! {
+    matrix
+    op_from
+    IntMat, RatMat, RatMat
+
+    Add {add}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    fmpz_mat_fmpq_mat_add;
+
+    Sub {sub}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    fmpz_mat_fmpq_mat_sub;
+
+    Mul {mul}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    fmpz_mat_fmpq_mat_mul;
+}
+
+impl_binop_unsafe! {
+    scalar_rhs
+    op_assign
+    RatMat, Rational, RatMat
+
+    Mul {mul}
+    MulAssign {mul_assign}
+    AssignMul {assign_mul}
+    fmpq_mat::fmpq_mat_scalar_mul_fmpq;
+
+    Div {div}
+    DivAssign {div_assign}
+    AssignDiv {assign_div}
+    fmpq_mat_scalar_div_fmpq;
+}
+
 impl_binop_unsafe! {
     scalar_rhs
     op_assign
@@ -177,6 +236,17 @@ impl_binop_unsafe! {
     fmpq_mat_fmpz_scalar_mul;
 }
 
+impl_binop_unsafe! {
+    scalar_lhs
+    op_from
+    Rational, RatMat, RatMat
+
+    Mul {mul}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    fmpq_mat_fmpq_scalar_mul;
+}
+
 impl_binop_unsafe! {
     scalar_lhs
     op_from
@@ -232,7 +302,6 @@ unsafe fn fmpq_mat_fmpz_scalar_mul(
     fmpq_mat::fmpq_mat_scalar_mul_fmpz(res, g, f);
 }
 
-/*
 #[inline]
 unsafe fn fmpq_mat_fmpq_scalar_mul(
     res: *mut fmpq_mat::fmpq_mat_struct,
@@ -240,7 +309,110 @@ unsafe fn fmpq_mat_fmpq_scalar_mul(
     g: *const fmpq_mat::fmpq_mat_struct,
 ) {
     fmpq_mat::fmpq_mat_scalar_mul_fmpq(res, g, f);
-}*/
+}
+
+#[inline]
+unsafe fn fmpq_mat_scalar_div_fmpq(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpq_mat::fmpq_mat_struct,
+    g: *const fmpq::fmpq,
+) {
+    assert!(fmpq::fmpq_is_zero(g) == 0, "Division by zero.");
+    let mut z = MaybeUninit::uninit();
+    fmpq::fmpq_init(z.as_mut_ptr());
+    fmpq::fmpq_inv(z.as_mut_ptr(), g);
+    fmpq_mat::fmpq_mat_scalar_mul_fmpq(res, f, z.as_ptr());
+    fmpq::fmpq_clear(z.as_mut_ptr());
+}
+
+// Call `op` with the integer matrix `x` converted to a temporary rational
+// matrix.
+#[inline]
+unsafe fn with_fmpq_mat<F>(x: *const fmpz_mat::fmpz_mat_struct, op: F)
+where
+    F: FnOnce(*const fmpq_mat::fmpq_mat_struct)
+{
+    let mut z = MaybeUninit::uninit();
+    fmpq_mat::fmpq_mat_init(z.as_mut_ptr(), (*x).r, (*x).c);
+    fmpq_mat::fmpq_mat_set_fmpz_mat(z.as_mut_ptr(), x);
+    op(z.as_ptr());
+    fmpq_mat::fmpq_mat_clear(z.as_mut_ptr());
+}
+
+// The results below are computed in a temporary and swapped into `res`, 
+// since `res` may alias an operand and need not have the right dimensions.
+#[inline]
+unsafe fn fmpq_mat_mul_swap(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpq_mat::fmpq_mat_struct,
+    g: *const fmpq_mat::fmpq_mat_struct,
+) {
+    assert_eq!((*f).c, (*g).r);
+    fmpq_mat_swap_into(res, (*f).r, (*g).c, |z| fmpq_mat::fmpq_mat_mul(z, f, g));
+}
+
+#[inline]
+unsafe fn fmpq_mat_fmpz_mat_add(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpq_mat::fmpq_mat_struct,
+    g: *const fmpz_mat::fmpz_mat_struct,
+) {
+    assert!((*f).r == (*g).r && (*f).c == (*g).c);
+    with_fmpq_mat(g, |g| {
+        fmpq_mat_swap_into(res, (*f).r, (*f).c, |z| fmpq_mat::fmpq_mat_add(z, f, g))
+    });
+}
+
+#[inline]
+unsafe fn fmpq_mat_fmpz_mat_sub(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpq_mat::fmpq_mat_struct,
+    g: *const fmpz_mat::fmpz_mat_struct,
+) {
+    assert!((*f).r == (*g).r && (*f).c == (*g).c);
+    with_fmpq_mat(g, |g| {
+        fmpq_mat_swap_into(res, (*f).r, (*f).c, |z| fmpq_mat::fmpq_mat_sub(z, f, g))
+    });
+}
+
+#[inline]
+unsafe fn fmpq_mat_fmpz_mat_mul(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpq_mat::fmpq_mat_struct,
+    g: *const fmpz_mat::fmpz_mat_struct,
+) {
+    with_fmpq_mat(g, |g| fmpq_mat_mul_swap(res, f, g));
+}
+
+#[inline]
+unsafe fn fmpz_mat_fmpq_mat_add(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpz_mat::fmpz_mat_struct,
+    g: *const fmpq_mat::fmpq_mat_struct,
+) {
+    fmpq_mat_fmpz_mat_add(res, g, f);
+}
+
+#[inline]
+unsafe fn fmpz_mat_fmpq_mat_sub(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpz_mat::fmpz_mat_struct,
+    g: *const fmpq_mat::fmpq_mat_struct,
+) {
+    assert!((*f).r == (*g).r && (*f).c == (*g).c);
+    with_fmpq_mat(f, |f| {
+        fmpq_mat_swap_into(res, (*g).r, (*g).c, |z| fmpq_mat::fmpq_mat_sub(z, f, g))
+    });
+}
+
+#[inline]
+unsafe fn fmpz_mat_fmpq_mat_mul(
+    res: *mut fmpq_mat::fmpq_mat_struct,
+    f: *const fmpz_mat::fmpz_mat_struct,
+    g: *const fmpq_mat::fmpq_mat_struct,
+) {
+    with_fmpq_mat(f, |f| fmpq_mat_mul_swap(res, f, g));
+}
 
 #[inline]
 unsafe fn fmpq_mat_ui_scalar_mul(
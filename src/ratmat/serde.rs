@@ -15,19 +15,21 @@
  *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
+use crate::{Rational, RatMat};
+use crate::util::read_matrix_entries;
 use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
 use serde::ser::{Serialize, SerializeSeq, Serializer};
+use std::fmt;
 
 impl Serialize for RatMat {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let entries = self.entries();
+        let entries = self.get_entries();
         let mut seq = serializer.serialize_seq(Some(entries.len() + 2))?;
-
-        seq.serialize_element(&self.nrows())?;
-        seq.serialize_element(&self.ncols())?;
+        seq.serialize_element(&self.nrows_si())?;
+        seq.serialize_element(&self.ncols_si())?;
         for e in entries.iter() {
             seq.serialize_element(e)?;
         }
@@ -43,15 +45,6 @@ impl RatMatVisitor {
     }
 }
 
-impl<'de> Deserialize<'de> for RatMat {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        deserializer.deserialize_seq(RatMatVisitor::new())
-    }
-}
-
 impl<'de> Visitor<'de> for RatMatVisitor {
     type Value = RatMat;
 
@@ -63,29 +56,46 @@ impl<'de> Visitor<'de> for RatMatVisitor {
     where
         A: SeqAccess<'de>,
     {
-        let mut entries: Vec<Rational> = Vec::with_capacity(
-            access.size_hint().unwrap_or(0));
-        let nrows: i64 = access.next_element()?.unwrap();
-        let ncols: i64 = access.next_element()?.unwrap();
+        let nrows: i64 = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let ncols: i64 = access
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let entries: Vec<Rational> = 
+            read_matrix_entries(&mut access, nrows, ncols, 2, &self)?;
 
-        while let Some(x) = access.next_element()? {
-            entries.push(x);
+        let mut res = RatMat::zero(nrows, ncols);
+        let c = res.ncols();
+        for (k, x) in entries.into_iter().enumerate() {
+            res.set_entry(k / c, k % c, x);
         }
-
-        let zm = RatMatSpace::init(nrows, ncols);
-        Ok(zm.new(&entries[..]))
+        Ok(res)
     }
 }
 
+impl<'de> Deserialize<'de> for RatMat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(RatMatVisitor::new())
+    }
+}
 
 #[cfg(test)]
-mod test {
+mod tests {
+    use crate::*;
+
     #[test]
     fn serde() {
-        let mr = RatMatSpace::init(2, 2);
-        let x = mr.new([1, 0, 0, 2]);
+        let x = RatMat::new([[1, 2], [0, 1], [-7, 3], [5, 1]], 2, 2);
         let ser = bincode::serialize(&x).unwrap();
         let y: RatMat = bincode::deserialize(&ser).unwrap();
         assert_eq!(x, y);
+        
+        let ser = serde_json::to_string(&x).unwrap();
+        let y: RatMat = serde_json::from_str(&ser).unwrap();
+        assert_eq!(x, y);
     }
 }
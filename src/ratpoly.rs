@@ -23,17 +23,18 @@ mod serde;
 
 use crate::{
     New,
+    FlintRand,
     Integer, 
     Rational, 
-    IntPoly
+    IntPoly,
+    RatMat
 };
-use flint_sys::fmpq_poly;
+use flint_sys::{fmpz_poly, fmpq_poly};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
-#[derive(Debug)]
 pub struct RatPoly {
     inner: fmpq_poly::fmpq_poly_struct,
 }
@@ -67,14 +68,37 @@ impl Default for RatPoly {
 }
 
 // TODO: output rational coeffs or 1/denominator times numerator?
+impl fmt::Debug for RatPoly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RatPoly").field(&format_args!("{}", self)).finish()
+    }
+}
+
 impl fmt::Display for RatPoly {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_str_radix(10))
+    }
+}
+
+impl RatPoly {
+    /// Convert the polynomial to a string with the coefficients in base 
+    /// `base`, in the same format as the `Display` implementation. Exponents
+    /// are always written in base 10.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([-10, 0, 255]);
+    /// assert_eq!(f.to_str_radix(16), "ff*x^2 - a");
+    /// assert_eq!(f.to_str_radix(10), f.to_string());
+    /// ```
+    pub fn to_str_radix(&self, base: u8) -> String {
         let deg = self.degree();
         if deg < 0 {
-            return write!(f, "0");
+            return "0".to_string();
         } else if deg == 0 {
-            return write!(f, "{}", self.get_coeff(0).to_string());
+            return self.get_coeff(0).to_str_radix(base);
         }
 
         let deg: usize = deg.try_into().unwrap();
@@ -95,7 +119,7 @@ impl fmt::Display for RatPoly {
 
             let abs = c.abs();
             if k == 0 {
-                out.push_str(&format!("{}{}", sign(s), abs));
+                out.push_str(&format!("{}{}", sign(s), abs.to_str_radix(base)));
             } else if k == deg {
                 if abs.is_one() && s > 0 {
                     if k == 1 {
@@ -111,26 +135,26 @@ impl fmt::Display for RatPoly {
                     }
                 } else {
                     if k == 1 {
-                        out.push_str(&format!("{}*x", c));
+                        out.push_str(&format!("{}*x", c.to_str_radix(base)));
                     } else {
-                        out.push_str(&format!("{}*x^{}", c, k));
+                        out.push_str(&format!("{}*x^{}", c.to_str_radix(base), k));
                     }
                 }
             } else if k == 1 {
                 if abs.is_one() {
                     out.push_str(&format!("{}x", sign(s)));
                 } else {
-                    out.push_str(&format!("{}{}*x", sign(s), abs));
+                    out.push_str(&format!("{}{}*x", sign(s), abs.to_str_radix(base)));
                 }
             } else {
                 if abs.is_one() {
                     out.push_str(&format!("{}x^{}", sign(s), k));
                 } else {
-                    out.push_str(&format!("{}{}*x^{}", sign(s), abs, k));
+                    out.push_str(&format!("{}{}*x^{}", sign(s), abs.to_str_radix(base), k));
                 }
             }
         }
-        write!(f, "{}", out)
+        out
     }
 }
 
@@ -187,6 +211,24 @@ impl RatPoly {
         unsafe { fmpq_poly::fmpq_poly_one(res.as_mut_ptr()); }
         res
     }
+
+    /// Return a random polynomial of length at most `len` with numerators and
+    /// denominators of up to `bits` bits.
+    pub fn randtest<S>(len: usize, bits: S, rng: &mut FlintRand) -> Self 
+    where
+        S: Into<u64>
+    {
+        let mut res = RatPoly::with_capacity(len);
+        unsafe {
+            fmpq_poly::fmpq_poly_randtest(
+                res.as_mut_ptr(), 
+                rng.as_mut_ptr(),
+                len.try_into().expect("Cannot convert length to a signed long."),
+                bits.into()
+            );
+        }
+        res
+    }
     
     #[inline]
     pub const fn as_ptr(&self) -> *const fmpq_poly::fmpq_poly_struct {
@@ -255,6 +297,24 @@ impl RatPoly {
     pub fn is_gen(&self) -> bool {
         unsafe { fmpq_poly::fmpq_poly_is_gen(self.as_ptr()) == 1}
     }
+
+    /// Return true if the leading coefficient is one.
+    #[inline]
+    pub fn is_monic(&self) -> bool {
+        unsafe { fmpq_poly::fmpq_poly_is_monic(self.as_ptr()) == 1}
+    }
+    
+    /// Return true if the polynomial has degree at most zero.
+    #[inline]
+    pub fn is_constant(&self) -> bool {
+        self.degree() <= 0
+    }
+    
+    /// Return true if all coefficients are integers.
+    #[inline]
+    pub fn is_integral(&self) -> bool {
+        self.denominator().is_one()
+    }
     
     #[inline]
     pub fn len(&self) -> usize {
@@ -354,4 +414,529 @@ impl RatPoly {
         }
         res
     }
+    
+    /// Return a pair `(f, d)` with `f` an integer polynomial and `d` a positive 
+    /// integer such that `self = f/d`, with `d` as small as possible.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([Rational::from([1, 2]), Rational::from([1, 3])]);
+    /// let (g, d) = f.clear_denominators();
+    /// assert_eq!(g, IntPoly::from([3, 2]));
+    /// assert_eq!(d, 6);
+    /// ```
+    #[inline]
+    pub fn clear_denominators(&self) -> (IntPoly, Integer) {
+        (self.numerator(), self.denominator())
+    }
+    
+    /// Return the primitive part of the polynomial, that is, the unique integer 
+    /// polynomial with content one and positive leading coefficient which is a 
+    /// rational multiple of `self`. The primitive part of zero is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([Rational::from([-2, 3]), Rational::from([-4, 5])]);
+    /// assert_eq!(f.primitive_part(), IntPoly::from([5, 6]));
+    /// ```
+    pub fn primitive_part(&self) -> IntPoly {
+        let mut res = self.numerator();
+        unsafe {
+            fmpz_poly::fmpz_poly_primitive_part(res.as_mut_ptr(), res.as_ptr());
+        }
+        res
+    }
+    
+    /// Return the pair `(r1, r2)` where `r1` is the number of distinct real 
+    /// roots and `r2` the number of pairs of distinct complex conjugate roots 
+    /// of the polynomial. The real roots are counted with a Sturm sequence. 
+    /// Panics if the polynomial is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // (x^2 - 2)^2 * (x^2 + 1)
+    /// let f = RatPoly::from([4, 0, 0, 0, -3, 0, 1]);
+    /// assert_eq!(f.signature(), (2, 1));
+    /// ```
+    pub fn signature(&self) -> (i64, i64) {
+        assert!(!self.is_zero());
+        let f = self.numerator();
+        let mut df = IntPoly::default();
+        let mut g = IntPoly::default();
+        let mut sqf = IntPoly::default();
+        let r1 = unsafe {
+            fmpz_poly::fmpz_poly_derivative(df.as_mut_ptr(), f.as_ptr());
+            fmpz_poly::fmpz_poly_gcd(g.as_mut_ptr(), f.as_ptr(), df.as_ptr());
+            fmpz_poly::fmpz_poly_div(sqf.as_mut_ptr(), f.as_ptr(), g.as_ptr());
+            fmpz_poly::fmpz_poly_num_real_roots_sturm(sqf.as_ptr())
+        };
+        (r1, (sqf.degree() - r1) / 2)
+    }
+
+    /// Return the product `self * other` truncated to its first `n` 
+    /// coefficients.
+    #[inline]
+    pub fn mullow<T: AsRef<RatPoly>>(&self, other: T, n: usize) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_mullow(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ref().as_ptr(),
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the power series inverse of `self` to precision `n`. Panics if 
+    /// the constant term is zero or `n` is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([2, -1]);
+    /// let g = f.inv_series(3);
+    /// assert_eq!(g, RatPoly::from([[1, 2], [1, 4], [1, 8]]));
+    /// assert_eq!(f.mullow(&g, 3), RatPoly::one());
+    /// ```
+    pub fn inv_series(&self, n: usize) -> RatPoly {
+        assert!(n > 0);
+        assert!(!self.get_coeff(0).is_zero(), "Constant term must be nonzero.");
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_inv_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the power series quotient `self / other` to precision `n`. 
+    /// Panics if the constant term of `other` is zero or `n` is zero.
+    pub fn div_series<T: AsRef<RatPoly>>(&self, other: T, n: usize) -> RatPoly {
+        let other = other.as_ref();
+        assert!(n > 0);
+        assert!(!other.get_coeff(0).is_zero(), "Constant term must be nonzero.");
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_div_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr(),
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the power series square root of `self` to precision `n`. 
+    /// Panics unless the constant term is one.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([1, 1]);
+    /// assert_eq!(f.sqrt_series(3), RatPoly::from([[1, 1], [1, 2], [-1, 8]]));
+    /// ```
+    pub fn sqrt_series(&self, n: usize) -> RatPoly {
+        assert!(self.get_coeff(0).is_one(), "Constant term must be one.");
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_sqrt_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the power series exponential of `self` to precision `n`. 
+    /// Panics unless the constant term is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = RatPoly::from([0, 1]);
+    /// assert_eq!(x.exp_series(4), RatPoly::from([[1, 1], [1, 1], [1, 2], [1, 6]]));
+    /// ```
+    pub fn exp_series(&self, n: usize) -> RatPoly {
+        assert!(self.get_coeff(0).is_zero(), "Constant term must be zero.");
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_exp_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the power series logarithm of `self` to precision `n`. Panics 
+    /// unless the constant term is one.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([1, 1]);
+    /// assert_eq!(f.log_series(4), RatPoly::from([[0, 1], [1, 1], [-1, 2], [1, 3]]));
+    /// ```
+    pub fn log_series(&self, n: usize) -> RatPoly {
+        assert!(self.get_coeff(0).is_one(), "Constant term must be one.");
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_log_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the composition `self(g(x))` of power series to precision `n`. 
+    /// Panics unless the constant term of `g` is zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([1, 1, 1]);
+    /// let g = RatPoly::from([0, 1, 1]);
+    /// assert_eq!(f.compose_series(&g, 4), RatPoly::from([1, 1, 2, 2]));
+    /// ```
+    pub fn compose_series<T: AsRef<RatPoly>>(&self, g: T, n: usize) -> RatPoly {
+        let g = g.as_ref();
+        assert!(g.get_coeff(0).is_zero(), "Constant term must be zero.");
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_compose_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                g.as_ptr(),
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Return the compositional inverse of `self` as a power series to 
+    /// precision `n`, that is, the series `g` with `self(g(x)) = x`. Panics 
+    /// unless the constant term is zero and the linear term is nonzero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([0, 1, 1]);
+    /// let g = f.revert_series(6);
+    /// assert_eq!(g, RatPoly::from([0, 1, -1, 2, -5, 14]));
+    /// assert_eq!(f.compose_series(&g, 6), RatPoly::from([0, 1]));
+    /// ```
+    pub fn revert_series(&self, n: usize) -> RatPoly {
+        assert!(self.get_coeff(0).is_zero(), "Constant term must be zero.");
+        assert!(!self.get_coeff(1).is_zero(), "Linear term must be nonzero.");
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_revert_series(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+        res
+    }
+
+    /// Truncate the polynomial to its first `n` coefficients.
+    #[inline]
+    pub fn truncate(&mut self, n: usize) {
+        unsafe {
+            fmpq_poly::fmpq_poly_truncate(
+                self.as_mut_ptr(), 
+                n.try_into().expect("Cannot convert length to a signed long.")
+            );
+        }
+    }
+
+    /// Evaluate the polynomial at `x`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([[1, 2], [0, 1], [1, 1]]);
+    /// assert_eq!(f.evaluate(&Rational::from([1, 2])), Rational::from([3, 4]));
+    /// ```
+    #[inline]
+    pub fn evaluate<T: AsRef<Rational>>(&self, x: T) -> Rational {
+        let mut res = Rational::zero();
+        unsafe {
+            fmpq_poly::fmpq_poly_evaluate_fmpq(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                x.as_ref().as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Evaluate the polynomial at each entry of the matrix `m`, returning the
+    /// matrix of values. This is not the matrix polynomial `f(m)`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([0, 0, 1]);
+    /// let m = RatMat::new([[1, 2], [3, 1]], 1, 2);
+    /// assert_eq!(f.evaluate_mat(&m), RatMat::new([[1, 4], [9, 1]], 1, 2));
+    /// ```
+    pub fn evaluate_mat(&self, m: &RatMat) -> RatMat {
+        let ys: Vec<Rational> = m.get_entries().iter().map(|x| self.evaluate(x)).collect();
+        RatMat::new(ys.as_slice(), m.nrows_si(), m.ncols_si())
+    }
+
+    /// Return the unique polynomial of degree less than `points.len()` 
+    /// passing through the given `(x, y)` points, or `None` if two points 
+    /// share an `x`-coordinate. Uses Newton's divided differences.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let points: Vec<(Rational, Rational)> = [(0, 1), (1, 2), (2, 5), (3, 10)]
+    ///     .into_iter()
+    ///     .map(|(x, y)| (Rational::from(x), Rational::from(y)))
+    ///     .collect();
+    /// assert_eq!(RatPoly::interpolate(&points), Some(RatPoly::from([1, 0, 1])));
+    ///
+    /// let points = [
+    ///     (Rational::from([1, 2]), Rational::from(1)),
+    ///     (Rational::from([1, 2]), Rational::from(2))
+    /// ];
+    /// assert_eq!(RatPoly::interpolate(&points), None);
+    /// ```
+    pub fn interpolate(points: &[(Rational, Rational)]) -> Option<RatPoly> {
+        if !distinct_abscissas(points) {
+            return None;
+        }
+        let n = points.len();
+        if n == 0 {
+            return Some(RatPoly::zero());
+        }
+
+        // divided differences in place: c[i] = f[x_0, ..., x_i]
+        let mut c: Vec<Rational> = points.iter().map(|(_, y)| y.clone()).collect();
+        for j in 1..n {
+            for i in (j..n).rev() {
+                c[i] = (&c[i] - &c[i - 1]) / (&points[i].0 - &points[i - j].0);
+            }
+        }
+
+        // expand the Newton form by Horner's rule
+        let mut res = RatPoly::from(&c[n - 1]);
+        let mut lin = RatPoly::from([0, 1]);
+        for k in (0..n - 1).rev() {
+            lin.set_coeff(0, -&points[k].0);
+            res = &(&res * &lin) + &c[k];
+        }
+        Some(res)
+    }
+
+    /// Return the Legendre polynomial `P_n(x)`, orthogonal on `[-1, 1]` and 
+    /// normalized by `P_n(1) = 1`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let p = RatPoly::legendre_p(2);
+    /// assert_eq!(p.get_coeff(0), Rational::from([-1, 2]));
+    /// assert_eq!(p.get_coeff(2), Rational::from([3, 2]));
+    /// assert_eq!(RatPoly::legendre_p(7).evaluate(Rational::from(1)), 1);
+    /// ```
+    pub fn legendre_p(n: u64) -> Self {
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_legendre_p(res.as_mut_ptr(), n);
+        }
+        res
+    }
+
+    /// Evaluate the interpolating polynomial of the given `(x, y)` points at
+    /// `x` using the barycentric Lagrange formula, without computing its
+    /// coefficients. Returns `None` if two points share an `x`-coordinate or
+    /// there are no points.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let points: Vec<(Rational, Rational)> = [(0, 1), (1, 2), (2, 5)]
+    ///     .into_iter()
+    ///     .map(|(x, y)| (Rational::from(x), Rational::from(y)))
+    ///     .collect();
+    /// let x = Rational::from([1, 2]);
+    /// assert_eq!(RatPoly::barycentric_evaluate(&points, &x), Some(Rational::from([5, 4])));
+    /// ```
+    pub fn barycentric_evaluate(
+        points: &[(Rational, Rational)], 
+        x: &Rational
+    ) -> Option<Rational> {
+        if points.is_empty() || !distinct_abscissas(points) {
+            return None;
+        }
+        if let Some((_, y)) = points.iter().find(|(xj, _)| xj == x) {
+            return Some(y.clone());
+        }
+
+        let mut num = Rational::zero();
+        let mut den = Rational::zero();
+        for (j, (xj, yj)) in points.iter().enumerate() {
+            let mut w = x - xj;
+            for (k, (xk, _)) in points.iter().enumerate() {
+                if k != j {
+                    w *= xj - xk;
+                }
+            }
+            let t = Rational::one() / w;
+            num += &t * yj;
+            den += t;
+        }
+        Some(num / den)
+    }
+
+    /// Return the resultant of `self` and `other`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([-2, 0, 1]);
+    /// let g = RatPoly::from([[-1, 2], [1, 1]]);
+    /// assert_eq!(f.resultant(&g), Rational::from([-7, 4]));
+    /// ```
+    #[inline]
+    pub fn resultant<T: AsRef<RatPoly>>(&self, other: T) -> Rational {
+        let mut res = Rational::zero();
+        unsafe {
+            fmpq_poly::fmpq_poly_resultant(
+                res.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ref().as_ptr()
+            );
+        }
+        res
+    }
+
+    /// Return the discriminant of the polynomial. The discriminant of a 
+    /// constant polynomial is zero.
+    #[inline]
+    pub fn discriminant(&self) -> Rational {
+        let mut res = Rational::zero();
+        unsafe { fmpq_poly::fmpq_poly_discriminant(res.as_mut_ptr(), self.as_ptr()); }
+        res
+    }
+
+    /// Return the subresultant polynomial remainder sequence of `self` and 
+    /// `other`, computed as for [IntPoly::subresultants] with exact rational 
+    /// arithmetic.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([-1, 0, 1]);
+    /// let g = RatPoly::from([[1, 2], [1, 1]]);
+    /// let prs = f.subresultants(&g);
+    /// assert_eq!(prs.last().unwrap(), &RatPoly::from([[-3, 4]]));
+    /// ```
+    pub fn subresultants<T: AsRef<RatPoly>>(&self, other: T) -> Vec<RatPoly> {
+        crate::prs::subresultants(self, other.as_ref())
+    }
+
+    /// Return the monic greatest common divisor of `self` and `other`. The 
+    /// gcd of two zero polynomials is zero.
+    #[inline]
+    pub fn gcd<T: AsRef<RatPoly>>(&self, other: T) -> RatPoly {
+        let mut res = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_gcd(res.as_mut_ptr(), self.as_ptr(), other.as_ref().as_ptr());
+        }
+        res
+    }
+
+    /// Return `(g, s, t)` where `g` is the monic gcd of `self` and `other` and 
+    /// `s*self + t*other = g`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([0, 2]);
+    /// let g = RatPoly::from([1, 1]);
+    /// let (d, s, t) = f.xgcd(&g);
+    /// assert_eq!(d, RatPoly::one());
+    /// assert_eq!(s * &f + t * &g, d);
+    /// ```
+    pub fn xgcd<T: AsRef<RatPoly>>(&self, other: T) -> (RatPoly, RatPoly, RatPoly) {
+        let mut d = RatPoly::default();
+        let mut s = RatPoly::default();
+        let mut t = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_xgcd(
+                d.as_mut_ptr(), 
+                s.as_mut_ptr(), 
+                t.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ref().as_ptr()
+            );
+        }
+        (d, s, t)
+    }
+
+    /// Return the inverse of `self` modulo `modulus`, or `None` if they are 
+    /// not coprime. Panics if `modulus` is constant.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f = RatPoly::from([1, 1]);
+    /// let m = RatPoly::from([1, 0, 1]);
+    /// let s = f.invmod(&m).unwrap();
+    /// assert_eq!(s, RatPoly::from([[1, 2], [-1, 2]]));
+    /// assert!(f.invmod(RatPoly::from([-1, 0, 1])).is_none());
+    /// ```
+    pub fn invmod<T: AsRef<RatPoly>>(&self, modulus: T) -> Option<RatPoly> {
+        let modulus = modulus.as_ref();
+        assert!(modulus.degree() > 0, "Modulus must be nonconstant.");
+        let (d, s, _) = self.xgcd(modulus);
+        if d.is_one() {
+            Some(s.divrem(modulus).1)
+        } else {
+            None
+        }
+    }
+
+    /// Return `(q, r)` with `self = q*other + r` and `deg(r) < deg(other)`. 
+    /// Panics if `other` is zero.
+    pub fn divrem<T: AsRef<RatPoly>>(&self, other: T) -> (RatPoly, RatPoly) {
+        let other = other.as_ref();
+        assert!(!other.is_zero(), "Division by zero.");
+        let mut q = RatPoly::default();
+        let mut r = RatPoly::default();
+        unsafe {
+            fmpq_poly::fmpq_poly_divrem(
+                q.as_mut_ptr(), 
+                r.as_mut_ptr(), 
+                self.as_ptr(), 
+                other.as_ptr()
+            );
+        }
+        (q, r)
+    }
+}
+
+// Whether the `x`-coordinates of the points are pairwise distinct.
+fn distinct_abscissas(points: &[(Rational, Rational)]) -> bool {
+    let mut xs: Vec<&Rational> = points.iter().map(|(x, _)| x).collect();
+    xs.sort();
+    xs.windows(2).all(|w| w[0] != w[1])
 }
@@ -16,9 +16,31 @@
  */
 
 use crate::*;
+use crate::Error::ParsePolyError;
+use std::str::FromStr;
 use flint_sys::fmpq_poly;
 
 
+impl FromStr for RatPoly {
+    type Err = Error;
+    /// Parse a polynomial with rational coefficients, either as a sum of terms
+    /// in a single variable like `1/2*x^2 - 3/4` or as a list of coefficients
+    /// `[-3/4, 0, 1/2]` starting with the constant term.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let f: RatPoly = "1/2*x^2 - 3/4".parse().unwrap();
+    /// assert_eq!(f, RatPoly::from([[-3, 4], [0, 1], [1, 2]]));
+    /// assert_eq!("[-3/4, 0, 1/2]".parse::<RatPoly>().unwrap(), f);
+    /// assert_eq!(f.to_string().parse::<RatPoly>().unwrap(), f);
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let coeffs = parse::poly(s).map_err(ParsePolyError)?;
+        Ok(RatPoly::from(coeffs.as_slice()))
+    }
+}
+
 impl_from_unsafe! {
     None
     RatPoly, u64 {usize u64 u32 u16 u8}
@@ -73,6 +95,23 @@ impl_from! {
     }
 }
 
+impl_from! {
+    RatPoly, NumFldElem
+    {
+        fn from(x: &NumFldElem) -> RatPoly {
+            let mut res = RatPoly::default();
+            unsafe {
+                antic_sys::nf_elem::nf_elem_get_fmpq_poly(
+                    res.as_mut_ptr(), 
+                    x.as_ptr(), 
+                    x.ctx_as_ptr()
+                );
+            }
+            res
+        }
+    }
+}
+
 impl<T, const CAP: usize> From<[T; CAP]> for RatPoly
 where
     T: Into<Rational>
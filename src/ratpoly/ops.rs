@@ -362,6 +362,27 @@ impl_binop_unsafe! {
     fmpq_poly_si_scalar_mul;
 }
 
+impl_binop_unsafe! {
+    None
+    op_from
+    Rational, RatPoly, RatPoly
+   
+    Add {add}
+    AddFrom {add_from}
+    AssignAdd {assign_add}
+    fmpq_poly_fmpq_add;
+
+    Sub {sub}
+    SubFrom {sub_from}
+    AssignSub {assign_sub}
+    fmpq_poly::fmpq_poly_fmpq_sub;
+    
+    Mul {mul}
+    MulFrom {mul_from}
+    AssignMul {assign_mul}
+    fmpq_poly_fmpq_scalar_mul;
+}
+
 impl_binop_unsafe! {
     None
     op_from
@@ -676,6 +697,26 @@ unsafe fn fmpq_poly_fmpz_add(
     fmpq_poly::fmpq_poly_add_fmpz(res, g, f);
 }
 
+#[inline]
+unsafe fn fmpq_poly_fmpq_add(
+    res: *mut fmpq_poly::fmpq_poly_struct,
+    f: *const fmpq::fmpq,
+    g: *const fmpq_poly::fmpq_poly_struct,
+    )
+{
+    fmpq_poly::fmpq_poly_add_fmpq(res, g, f);
+}
+
+#[inline]
+unsafe fn fmpq_poly_fmpq_scalar_mul(
+    res: *mut fmpq_poly::fmpq_poly_struct,
+    f: *const fmpq::fmpq,
+    g: *const fmpq_poly::fmpq_poly_struct,
+    )
+{
+    fmpq_poly::fmpq_poly_scalar_mul_fmpq(res, g, f);
+}
+
 #[inline]
 unsafe fn fmpq_poly_fmpz_scalar_mul(
     res: *mut fmpq_poly::fmpq_poly_struct,
@@ -21,3 +21,14 @@ pub use arb::*;
 pub mod arf;
 pub mod mag;
 
+mod binsplit;
+pub use binsplit::*;
+
+mod hypgeom;
+
+mod field;
+pub use field::RealField;
+
+mod sample;
+pub use sample::sample_function;
+
@@ -18,22 +18,25 @@
 mod ops;
 mod conv;
 
-use crate::{New, arf::Arf, mag::Mag};
+use crate::{Error, Integer, New, Rational, Result, arf::Arf, mag::Mag};
 use arb_sys::{
     arb::*,
     arf::arf_set,
     mag::mag_set
 };
 
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
-#[derive(Debug)]
+/// A real ball: a midpoint and a radius enclosing the exact value. Each 
+/// ball carries the working precision in bits used for arithmetic on it, see
+/// [RealField](crate::RealField).
 pub struct Arb {
     pub(crate) inner: arb_struct,
+    pub(crate) prec: u64,
 }
 
 pub type Real = Arb;
@@ -51,6 +54,7 @@ impl Clone for Real {
         unsafe {
             arb_set(res.as_mut_ptr(), self.as_ptr());
         }
+        res.prec = self.prec;
         res
     }
 }
@@ -66,17 +70,30 @@ impl Default for Real {
     }
 }
 
+impl fmt::Debug for Real {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Real")
+            .field("value", &format_args!("{}", self))
+            .field("prec", &self.prec)
+            .finish()
+    }
+}
+
 impl fmt::Display for Real {
-    #[inline]
+    /// Print the ball in decimal as `[m +/- r]`, or just `m` when it is
+    /// exact. The number of significant digits of the midpoint can be set
+    /// with the formatter precision or width, e.g. `{:.30}` or `{:30}`; by
+    /// default it is enough to show all bits of the midpoint.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        unsafe {
-            let mut n = self.bits() as f64;
-            n *= 0.30102999566398119521; // log_10(2)
-            let digits = n.ceil() as i64 + 1;
-            let c_str = CStr::from_ptr(arb_get_str(self.as_ptr(), digits, 0));
-            let s = c_str.to_str().unwrap();
-            write!(f, "{}", s)
-        }
+        let digits = match f.precision().or_else(|| f.width()) {
+            Some(d) => d,
+            None => {
+                let mut n = self.bits() as f64;
+                n *= 0.30102999566398119521; // log_10(2)
+                n.ceil() as usize + 1
+            }
+        };
+        write!(f, "{}", self.to_decimal_string(digits))
     }
 }
 
@@ -111,6 +128,10 @@ impl New<&Real> for Real {
 }
 
 impl Real {
+    /// The working precision in bits of balls not created through a
+    /// [RealField](crate::RealField).
+    pub const DEFAULT_PREC: u64 = 128;
+
     #[inline]
     pub fn zero() -> Self {
         Real::default()
@@ -123,6 +144,65 @@ impl Real {
         res
     }
 
+    /// Return an enclosure of `pi` computed to `prec` bits.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let pi = Real::pi(128);
+    /// assert_eq!(pi.prec(), 128);
+    /// assert!(pi.rel_accuracy_bits() >= 126);
+    /// assert!(pi.certainly_gt(&Real::from(3.14159)));
+    /// assert!(pi.certainly_lt(&Real::from(3.1416)));
+    /// ```
+    #[inline]
+    pub fn pi(prec: u64) -> Self {
+        Real::constant(prec, arb_const_pi)
+    }
+
+    /// Return an enclosure of Euler's number `e = exp(1)` computed to `prec`
+    /// bits.
+    #[inline]
+    pub fn e(prec: u64) -> Self {
+        Real::constant(prec, arb_const_e)
+    }
+
+    /// Return an enclosure of the Euler-Mascheroni constant `0.5772...`
+    /// computed to `prec` bits.
+    #[inline]
+    pub fn euler_gamma(prec: u64) -> Self {
+        Real::constant(prec, arb_const_euler)
+    }
+
+    /// Return an enclosure of `log(2)` computed to `prec` bits.
+    #[inline]
+    pub fn log2(prec: u64) -> Self {
+        Real::constant(prec, arb_const_log2)
+    }
+
+    /// Return an enclosure of Catalan's constant `0.9159...` computed to
+    /// `prec` bits.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let g = Real::catalan(64);
+    /// assert!(Real::from_str_prec("0.915965594177219 +/- 1e-15", 64).unwrap().contains(&g));
+    /// ```
+    #[inline]
+    pub fn catalan(prec: u64) -> Self {
+        Real::constant(prec, arb_const_catalan)
+    }
+
+    // Evaluate one of Arb's arb_const_* functions.
+    fn constant(prec: u64, f: unsafe extern "C" fn(*mut arb_struct, i64)) -> Self {
+        let mut res = Real::default();
+        res.set_prec(prec);
+        let prec = prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe { f(res.as_mut_ptr(), prec); }
+        res
+    }
+
     #[inline]
     pub fn zero_assign(&mut self) {
         unsafe {
@@ -163,7 +243,7 @@ impl Real {
 
     #[inline]
     pub const unsafe fn from_raw(inner: arb_struct) -> Self {
-        Real { inner }
+        Real { inner, prec: Real::DEFAULT_PREC }
     }
 
     #[inline]
@@ -173,21 +253,39 @@ impl Real {
         ret
     }
 
+    /// Return the working precision in bits used for arithmetic with the 
+    /// ball. Operations on two balls use the larger of their precisions.
+    #[inline]
+    pub fn prec(&self) -> u64 {
+        self.prec
+    }
+
+    /// Set the working precision in bits. This does not round the ball.
+    #[inline]
+    pub fn set_prec(&mut self, prec: u64) {
+        assert!(prec >= 2, "The precision must be at least two bits.");
+        self.prec = prec;
+    }
+
     #[inline]
     pub fn bits(&self) -> i64 {
         unsafe { arb_bits(self.as_ptr()) }
     }
 
+    /// Return the midpoint of the ball as an exact ball.
     pub fn midpoint(&self) -> Self {
         let mut res = Real::default();
+        res.prec = self.prec;
         unsafe {
             arb_get_mid_arb(res.as_mut_ptr(), self.as_ptr());
         }
         res
     }
 
+    /// Return the radius of the ball as an exact ball.
     pub fn radius(&self) -> Self {
         let mut res = Real::default();
+        res.prec = self.prec;
         unsafe {
             arb_get_rad_arb(res.as_mut_ptr(), self.as_ptr());
         }
@@ -209,4 +307,319 @@ impl Real {
         }
         res
     }
+
+    /// Return true if the ball contains the ball `other`.
+    #[inline]
+    pub fn contains(&self, other: &Real) -> bool {
+        unsafe { arb_contains(self.as_ptr(), other.as_ptr()) != 0 }
+    }
+
+    /// Return true if the balls have a point in common.
+    #[inline]
+    pub fn overlaps(&self, other: &Real) -> bool {
+        unsafe { arb_overlaps(self.as_ptr(), other.as_ptr()) != 0 }
+    }
+
+    /// Return true if the radius is zero.
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        unsafe { arb_is_exact(self.as_ptr()) != 0 }
+    }
+
+    /// Return true if both the midpoint and the radius are finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        unsafe { arb_is_finite(self.as_ptr()) != 0 }
+    }
+
+    /// Widen the ball by `|err|`, that is, add the upper bound of `|err|` to
+    /// the radius. This is how a bound on a truncation error is attached to
+    /// an approximation.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let mut x = Real::from(1);
+    /// x.add_error(&Real::from_str_prec("0.125", 64).unwrap());
+    /// assert!(!x.is_exact());
+    /// assert!(x.contains(&Real::from(1.125)));
+    /// assert!(!x.contains(&Real::from(1.25)));
+    /// ```
+    #[inline]
+    pub fn add_error(&mut self, err: &Real) {
+        unsafe { arb_add_error(self.as_mut_ptr(), err.as_ptr()); }
+    }
+
+    /// Widen the ball by `2^e`.
+    #[inline]
+    pub fn add_error_2exp(&mut self, e: i64) {
+        unsafe { arb_add_error_2exp_si(self.as_mut_ptr(), e); }
+    }
+
+    /// Return true if every point of the ball is less than every point of 
+    /// `other`. Unlike `<`, the negation of this does not imply `>=`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Real::from_str_prec("[1 +/- 0.5]", 64).unwrap();
+    /// let y = Real::from_str_prec("[1.75 +/- 0.5]", 64).unwrap();
+    /// assert!(!x.certainly_lt(&y) && !x.certainly_gt(&y));
+    /// assert!(x.certainly_lt(&Real::from(2)));
+    /// ```
+    #[inline]
+    pub fn certainly_lt(&self, other: &Real) -> bool {
+        unsafe { arb_lt(self.as_ptr(), other.as_ptr()) != 0 }
+    }
+
+    /// Return true if every point of the ball is at most every point of 
+    /// `other`.
+    #[inline]
+    pub fn certainly_le(&self, other: &Real) -> bool {
+        unsafe { arb_le(self.as_ptr(), other.as_ptr()) != 0 }
+    }
+
+    /// Return true if every point of the ball is greater than every point of
+    /// `other`.
+    #[inline]
+    pub fn certainly_gt(&self, other: &Real) -> bool {
+        unsafe { arb_gt(self.as_ptr(), other.as_ptr()) != 0 }
+    }
+
+    /// Return true if every point of the ball is at least every point of 
+    /// `other`.
+    #[inline]
+    pub fn certainly_ge(&self, other: &Real) -> bool {
+        unsafe { arb_ge(self.as_ptr(), other.as_ptr()) != 0 }
+    }
+
+    /// Return true if both balls are the same exact point.
+    #[inline]
+    pub fn certainly_eq(&self, other: &Real) -> bool {
+        unsafe { arb_eq(self.as_ptr(), other.as_ptr()) != 0 }
+    }
+
+    /// Return true if the balls do not overlap.
+    #[inline]
+    pub fn certainly_ne(&self, other: &Real) -> bool {
+        unsafe { arb_ne(self.as_ptr(), other.as_ptr()) != 0 }
+    }
+
+    /// Return the relative accuracy of the ball in bits, roughly the number 
+    /// of correct leading bits of the midpoint.
+    #[inline]
+    pub fn rel_accuracy_bits(&self) -> i64 {
+        unsafe { arb_rel_accuracy_bits(self.as_ptr()) }
+    }
+
+    /// Return a ball containing `self` whose midpoint is rounded to at most 
+    /// `prec` bits and then stripped of the bits not justified by the radius,
+    /// so that the midpoint is no more precise than the ball.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let mut x = Real::from_str_prec("3.14159265358979323846", 128).unwrap();
+    /// x.add_error_2exp(-20);
+    /// let y = x.trim(128);
+    /// assert!(y.contains(&x));
+    /// assert!(y.midpoint().bits() < x.midpoint().bits());
+    /// ```
+    pub fn trim(&self, prec: u64) -> Real {
+        let mut res = Real::default();
+        res.prec = self.prec;
+        let prec = prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe {
+            arb_set_round(res.as_mut_ptr(), self.as_ptr(), prec);
+            arb_trim(res.as_mut_ptr(), res.as_ptr());
+        }
+        res
+    }
+
+    /// Parse a decimal or `[m +/- r]` ball (also accepting `m +/- r` and
+    /// `m ± r`) at `prec` bits of working precision. The result contains the
+    /// exact value of the input.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Real::from_str_prec("[3.14159 +/- 1e-5]", 64).unwrap();
+    /// assert!(x.contains(&Real::from_str_prec("3.14159", 64).unwrap()));
+    /// let y = Real::from_str_prec("2.5 ± 0.5", 64).unwrap();
+    /// assert_eq!(y.midpoint(), Real::from(2.5));
+    /// assert!(Real::from_str_prec("pi", 64).is_err());
+    /// ```
+    pub fn from_str_prec(s: &str, prec: u64) -> Result<Real> {
+        let s = s.trim().replace('±', "+/-");
+        let c_str = CString::new(s.as_str())
+            .map_err(|_| Error::Msg("String contains 0 byte.".to_string()))?;
+        let mut res = Real::default();
+        res.set_prec(prec);
+        let prec = prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe {
+            if arb_set_str(res.as_mut_ptr(), c_str.as_ptr(), prec) == 0 {
+                Ok(res)
+            } else {
+                Err(Error::ConversionError {
+                    val: s,
+                    in_type: "&str".to_string(),
+                    out_type: "Real".to_string(),
+                })
+            }
+        }
+    }
+
+    /// Return a decimal string for the ball with at most `digits`
+    /// significant digits in the midpoint. The radius is widened as needed,
+    /// so parsing the string gives a ball containing this one.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Real::from_str_prec("0.1", 64).unwrap();
+    /// let s = x.to_decimal_string(10);
+    /// assert!(s.starts_with("[0.1000000000 +/- "));
+    /// assert!(Real::from_str_prec(&s, 64).unwrap().contains(&x));
+    /// ```
+    pub fn to_decimal_string(&self, digits: usize) -> String {
+        let digits = digits.try_into().expect("Cannot convert digits to a signed long.");
+        unsafe {
+            let ptr = arb_get_str(self.as_ptr(), digits, 0);
+            let res = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+            flint_sys::flint::flint_free(ptr as *mut libc::c_void);
+            res
+        }
+    }
+    // Apply an Arb rounding function and return the integer if the rounded
+    // ball is a single integer. The working precision is at least the bits of
+    // the midpoint so exact balls round exactly.
+    fn round_with(
+        &self,
+        f: unsafe extern "C" fn(*mut arb_struct, *const arb_struct, i64)
+    ) -> Option<Integer> {
+        let prec: i64 = self.prec.try_into().expect("Cannot convert precision to a signed long.");
+        let prec = prec.max(self.bits()) + 2;
+        let mut tmp = Real::default();
+        let mut res = Integer::default();
+        unsafe {
+            f(tmp.as_mut_ptr(), self.as_ptr(), prec);
+            if arb_get_unique_fmpz(res.as_mut_ptr(), tmp.as_ptr()) != 0 {
+                Some(res)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Return the floor of every point of the ball if it is the same integer,
+    /// otherwise `None`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Real::from_str_prec("2.75 +/- 0.1", 64).unwrap();
+    /// assert_eq!(x.floor().unwrap(), 2);
+    /// assert_eq!(x.ceil().unwrap(), 3);
+    /// assert_eq!(x.round().unwrap(), 3);
+    ///
+    /// // the ball straddles 3
+    /// let y = Real::from_str_prec("2.95 +/- 0.1", 64).unwrap();
+    /// assert!(y.floor().is_none());
+    /// assert_eq!(y.round().unwrap(), 3);
+    /// ```
+    #[inline]
+    pub fn floor(&self) -> Option<Integer> {
+        self.round_with(arb_floor)
+    }
+
+    /// Return the ceiling of every point of the ball if it is the same
+    /// integer, otherwise `None`.
+    #[inline]
+    pub fn ceil(&self) -> Option<Integer> {
+        self.round_with(arb_ceil)
+    }
+
+    /// Return the nearest integer to every point of the ball if it is the
+    /// same integer, otherwise `None`. Halfway cases are rounded up.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// assert_eq!(Real::from(2.5).round(), Some(Integer::from(3)));
+    /// let x = Integer::from(2).pow(200u8) + 1;
+    /// assert_eq!(Real::from(&x).round(), Some(x));
+    /// ```
+    #[inline]
+    pub fn round(&self) -> Option<Integer> {
+        self.round_with(arb_round_half_up)
+    }
+
+    /// Return the simplest rational number, the one with smallest denominator,
+    /// within distance `eps` of every point of the ball, or `None` if the ball
+    /// is wider than `2*eps` or not finite.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Real::pi(128);
+    /// let eps = Rational::from([1, 1000]);
+    /// assert_eq!(x.to_rational_within(&eps).unwrap(), Rational::from([201, 64]));
+    /// let eps = Rational::from([1, 1000000]);
+    /// assert_eq!(x.to_rational_within(&eps).unwrap(), Rational::from([355, 113]));
+    ///
+    /// let wide = Real::from_str_prec("0.5 +/- 0.25", 64).unwrap();
+    /// assert!(wide.to_rational_within(&Rational::from([1, 10])).is_none());
+    /// ```
+    pub fn to_rational_within(&self, eps: &Rational) -> Option<Rational> {
+        assert!(eps.sign() >= 0, "The tolerance must be nonnegative.");
+        if !self.is_finite() {
+            return None;
+        }
+        let prec: i64 = self.prec.try_into().expect("Cannot convert precision to a signed long.");
+        let mut lo = Arf::default();
+        let mut hi = Arf::default();
+        unsafe {
+            // outward rounding keeps the bounds valid
+            arb_get_lbound_arf(lo.as_mut_ptr(), self.as_ptr(), prec);
+            arb_get_ubound_arf(hi.as_mut_ptr(), self.as_ptr(), prec);
+        }
+        let left = arf_to_rational(&hi) - eps;
+        let right = arf_to_rational(&lo) + eps;
+        if left > right {
+            return None;
+        }
+        let mut res = Rational::default();
+        unsafe {
+            flint_sys::fmpq::fmpq_simplest_between(
+                res.as_mut_ptr(),
+                left.as_ptr(),
+                right.as_ptr()
+            );
+        }
+        Some(res)
+    }
+}
+
+// The exact value `m*2^e` of a finite Arf.
+fn arf_to_rational(x: &Arf) -> Rational {
+    let (m, e) = x.mantissa_exponent();
+    let mut res = Rational::from(m);
+    let shift = e.abs().get_ui().expect("Exponent too large.");
+    unsafe {
+        if e.sign() >= 0 {
+            flint_sys::fmpq::fmpq_mul_2exp(res.as_mut_ptr(), res.as_ptr(), shift);
+        } else {
+            flint_sys::fmpq::fmpq_div_2exp(res.as_mut_ptr(), res.as_ptr(), shift);
+        }
+    }
+    res
+}
+
+// Set `res` to floor(x + 1/2), computed as floor((2x + 1)/2) so that the only
+// inexact step is the addition at precision `prec`.
+unsafe extern "C" fn arb_round_half_up(res: *mut arb_struct, x: *const arb_struct, prec: i64) {
+    arb_mul_2exp_si(res, x, 1);
+    arb_add_si(res, res, 1, prec);
+    arb_mul_2exp_si(res, res, -1);
+    arb_floor(res, res, prec);
 }
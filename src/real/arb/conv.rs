@@ -17,6 +17,7 @@
 
 use crate::{*, arf::Arf};
 use arb_sys::arb::*;
+use std::str::FromStr;
 
 impl_assign_unsafe! {
     None
@@ -83,3 +84,78 @@ impl_from_unsafe! {
     Real, Arf
     arb_set_arf
 }
+
+impl_from! {
+    Real, Rational
+    {
+        fn from(src: &Rational) -> Real {
+            let mut res = Real::default();
+            let prec = res.prec.try_into().expect(
+                "Cannot convert precision to a signed long.");
+            unsafe { arb_set_fmpq(res.as_mut_ptr(), src.as_ptr(), prec); }
+            res
+        }
+    }
+}
+
+impl_from! {
+    Real, IntMod
+    {
+        fn from(src: &IntMod) -> Real {
+            Real::from(Integer::from(src))
+        }
+    }
+}
+
+impl Integer {
+    /// Return a ball enclosing the integer, rounded to `prec` bits. The ball
+    /// carries `prec` as its working precision.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Integer::from(10).pow(40u64);
+    /// assert!(x.to_real(256).is_exact());
+    /// let y = x.to_real(32);
+    /// assert!(!y.is_exact() && y.contains(&x.to_real(256)));
+    /// ```
+    pub fn to_real(&self, prec: u64) -> Real {
+        let mut res = Real::default();
+        res.set_prec(prec);
+        let prec = prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe { arb_set_round_fmpz(res.as_mut_ptr(), self.as_ptr(), prec); }
+        res
+    }
+}
+
+impl Rational {
+    /// Return a ball enclosing the rational number, rounded to `prec` bits.
+    /// The ball carries `prec` as its working precision.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let x = Rational::from([1, 3]).to_real(64);
+    /// assert_eq!(x.prec(), 64);
+    /// assert!(x.certainly_gt(&Real::from(0.333)) && x.certainly_lt(&Real::from(0.334)));
+    /// ```
+    pub fn to_real(&self, prec: u64) -> Real {
+        let mut res = Real::default();
+        res.set_prec(prec);
+        let prec = prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe { arb_set_fmpq(res.as_mut_ptr(), self.as_ptr(), prec); }
+        res
+    }
+}
+
+impl FromStr for Real {
+    type Err = Error;
+    /// Parse a decimal or `[m +/- r]` ball at a working precision large 
+    /// enough for the number of digits given. See [Real::from_str_prec].
+    fn from_str(s: &str) -> Result<Self> {
+        let ndigits = s.chars().filter(|c| c.is_ascii_digit()).count() as u64;
+        // log_2(10) < 3.33 bits per digit, plus some guard bits
+        let prec = (ndigits * 333 / 100 + 16).max(64);
+        Real::from_str_prec(s, prec)
+    }
+}
@@ -21,6 +21,7 @@ use arb_sys::arb::*;
 //use libc::{c_long, c_ulong};
 
 use std::cmp::Ordering::{self, Equal, Greater, Less};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 //use std::mem::MaybeUninit;
 
 // TODO:
@@ -608,3 +609,13 @@ unsafe fn fmpz_si_div(res: *mut fmpq::fmpq, f: c_long, g: *const fmpz::fmpz) {
     fmpz::fmpz_clear(z.as_mut_ptr());
 }
 */
+
+impl_ball_ops! {
+    Real
+    arb_neg;
+
+    Add {add} AddAssign {add_assign} arb_add;
+    Sub {sub} SubAssign {sub_assign} arb_sub;
+    Mul {mul} MulAssign {mul_assign} arb_mul;
+    Div {div} DivAssign {div_assign} arb_div;
+}
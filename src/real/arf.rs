@@ -27,7 +27,6 @@ use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
-#[derive(Debug)]
 pub struct Arf {
     pub(crate) inner: arf_struct,
 }
@@ -58,6 +57,12 @@ impl Default for Arf {
     }
 }
 
+impl fmt::Debug for Arf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Arf").field(&format_args!("{}", self)).finish()
+    }
+}
+
 impl fmt::Display for Arf {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         unsafe {
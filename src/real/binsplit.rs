@@ -0,0 +1,188 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{check_interrupt, tuning, Integer, Pow, Rational, Real};
+use arb_sys::arb::*;
+
+// Compute P(a, b), Q(a, b) and T(a, b) for the terms `a <= k < b`, where the 
+// factors `p(0)` and `q(0)` are taken to be one.
+fn bsplit<P, Q, R>(p: &P, q: &Q, r: &R, a: u64, b: u64) -> (Integer, Integer, Integer)
+where
+    P: Fn(u64) -> Integer,
+    Q: Fn(u64) -> Integer,
+    R: Fn(u64) -> Integer,
+{
+    if b - a <= tuning::binsplit_basecase() {
+        // sum the range term by term: T(a, k + 1) = T(a, k) q(k) + P(a, k + 1) r(k)
+        let (mut pk, mut qk, mut tk) = if a == 0 {
+            (Integer::one(), Integer::one(), r(0))
+        } else {
+            let pa = p(a);
+            let t = &pa * r(a);
+            (pa, q(a), t)
+        };
+        for k in a + 1..b {
+            pk *= p(k);
+            tk = tk * q(k) + &pk * r(k);
+            qk *= q(k);
+        }
+        (pk, qk, tk)
+    } else {
+        check_interrupt();
+        let m = a + (b - a) / 2;
+        let (p1, q1, t1) = bsplit(p, q, r, a, m);
+        let (p2, q2, t2) = bsplit(p, q, r, m, b);
+        let t = &t1 * &q2 + &p1 * &t2;
+        (p1 * p2, q1 * q2, t)
+    }
+}
+
+/// Evaluate the partial sum of the hypergeometric-type series
+/// `S = sum_{k=0}^{n-1} r(k) * (p(1)...p(k))/(q(1)...q(k))` exactly using 
+/// binary splitting. Series with rational term ratios can be handled by 
+/// moving denominators of `p` into `q` and vice versa.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// // sum_{k=0}^{3} 1/k! = 8/3
+/// let s = binary_splitting(
+///     |_| Integer::one(), 
+///     |k| Integer::from(k), 
+///     |_| Integer::one(), 
+///     4
+/// );
+/// assert_eq!(s, Rational::from([8, 3]));
+/// ```
+pub fn binary_splitting<P, Q, R>(p: P, q: Q, r: R, n: u64) -> Rational
+where
+    P: Fn(u64) -> Integer,
+    Q: Fn(u64) -> Integer,
+    R: Fn(u64) -> Integer,
+{
+    if n == 0 {
+        return Rational::zero();
+    }
+    let (_, q, t) = bsplit(&p, &q, &r, 0, n);
+    Rational::from([t, q])
+}
+
+/// Evaluate the series of [binary_splitting] to `prec` bits as a certified 
+/// [Real] ball. The caller supplies `tail_exp` such that the truncation error 
+/// satisfies `|sum_{k>=n} ...| <= 2^tail_exp`, which is added to the radius.
+pub fn binary_splitting_real<P, Q, R>(
+    p: P, 
+    q: Q, 
+    r: R, 
+    n: u64, 
+    tail_exp: i64, 
+    prec: u64
+) -> Real
+where
+    P: Fn(u64) -> Integer,
+    Q: Fn(u64) -> Integer,
+    R: Fn(u64) -> Integer,
+{
+    let prec = prec.try_into().expect("Cannot convert precision to a signed long.");
+    let mut res = Real::default();
+    if n > 0 {
+        let (_, q, t) = bsplit(&p, &q, &r, 0, n);
+        unsafe { arb_fmpz_div_fmpz(res.as_mut_ptr(), t.as_ptr(), q.as_ptr(), prec); }
+    }
+    unsafe { arb_add_error_2exp_si(res.as_mut_ptr(), tail_exp); }
+    res.set_prec(prec as u64);
+    res
+}
+
+/// Compute `e = sum 1/k!` to `prec` bits by binary splitting.
+pub fn e_binary_splitting(prec: u64) -> Real {
+    // choose n with n! > 2^(prec + 2); the tail is at most 2/n!
+    let mut n = 1u64;
+    let mut log_fac = 0f64;
+    while log_fac < (prec + 2) as f64 {
+        n += 1;
+        log_fac += (n as f64).log2();
+    }
+    let tail_exp = 2 - log_fac.floor() as i64;
+    binary_splitting_real(
+        |_| Integer::one(), 
+        Integer::from, 
+        |_| Integer::one(), 
+        n + 1, 
+        tail_exp, 
+        prec
+    )
+}
+
+/// Compute `pi` to `prec` bits using the Chudnovsky series evaluated by 
+/// binary splitting.
+pub fn pi_chudnovsky(prec: u64) -> Real {
+    // Each term gains more than 47 bits: |p(k)/q(k)| < 2^-47, and 
+    // r(k) < 2^30 (k + 1), so the tail after n terms is below 
+    // 2^(31 - 47n) (n + 1).
+    let n = prec / 47 + 2;
+    let tail_exp = 31 + (64 - (n + 1).leading_zeros()) as i64 - 47 * n as i64;
+    let s = binary_splitting_real(
+        |k| {
+            let k = Integer::from(k);
+            -((&k * 6u64 - 5u64) * (&k * 2u64 - 1u64) * (&k * 6u64 - 1u64))
+        },
+        |k| Integer::from(k).pow(3u64) * 10939058860032000u64,
+        |k| Integer::from(k) * 545140134u64 + 13591409u64,
+        n,
+        tail_exp,
+        prec + 16
+    );
+
+    // pi = 426880 sqrt(10005) / s
+    let prec = prec.try_into().expect("Cannot convert precision to a signed long.");
+    let mut res = Real::default();
+    unsafe {
+        arb_sqrt_ui(res.as_mut_ptr(), 10005, prec);
+        arb_mul_ui(res.as_mut_ptr(), res.as_ptr(), 426880, prec);
+        arb_div(res.as_mut_ptr(), res.as_ptr(), s.as_ptr(), prec);
+    }
+    res.set_prec(prec as u64);
+    res
+}
+
+/// Compute Apéry's constant `zeta(3)` to `prec` bits using the 
+/// Amdeberhan-Zeilberger series 
+/// `zeta(3) = 1/64 sum_k (-1)^k (205k^2 + 250k + 77) (k!)^10/((2k+1)!)^5`.
+pub fn zeta3_binary_splitting(prec: u64) -> Real {
+    // The series alternates with terms decreasing by a factor of at least 
+    // 2^10, and r(k) < 2^10 (k + 1)^2, so the tail is below 
+    // 2^(10 - 10n) (n + 1)^2.
+    let n = prec / 10 + 2;
+    let tail_exp = 10 + 2 * (64 - (n + 1).leading_zeros()) as i64 - 10 * n as i64;
+    let s = binary_splitting_real(
+        |k| -Integer::from(k).pow(5u64),
+        |k| (Integer::from(k) * 2u64 + 1u64).pow(5u64) * 32u64,
+        |k| {
+            let k = Integer::from(k);
+            &k * &k * 205u64 + &k * 250u64 + 77u64
+        },
+        n,
+        tail_exp,
+        prec
+    );
+    
+    let mut res = Real::default();
+    unsafe { arb_mul_2exp_si(res.as_mut_ptr(), s.as_ptr(), -6); }
+    res.set_prec(prec);
+    res
+}
@@ -0,0 +1,119 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{Real, Result};
+use arb_sys::arb::*;
+
+/// The real numbers at a fixed working precision. Elements created through 
+/// the field carry its precision, so arithmetic on them does not need a 
+/// precision argument; an operation on two balls of different precisions 
+/// uses the larger one.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let rr = RealField::init(256);
+/// let x = rr.new(2) / rr.new(3);
+/// assert_eq!(x.prec(), 256);
+/// assert!((&x * &rr.new(3)).contains(&rr.new(2)));
+///
+/// let y = &x + &RealField::init(64).one();
+/// assert_eq!(y.prec(), 256);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RealField {
+    prec: u64,
+}
+
+impl Default for RealField {
+    #[inline]
+    fn default() -> Self {
+        RealField::init(Real::DEFAULT_PREC)
+    }
+}
+
+impl RealField {
+    /// Return the field of real balls with `prec` bits of working precision.
+    #[inline]
+    pub fn init(prec: u64) -> Self {
+        assert!(prec >= 2, "The precision must be at least two bits.");
+        RealField { prec }
+    }
+
+    #[inline]
+    pub fn prec(&self) -> u64 {
+        self.prec
+    }
+
+    /// Return `x` rounded to the precision of the field.
+    pub fn new<T: Into<Real>>(&self, x: T) -> Real {
+        let mut res = x.into();
+        res.set_prec(self.prec);
+        let prec = self.prec.try_into().expect("Cannot convert precision to a signed long.");
+        unsafe { arb_set_round(res.as_mut_ptr(), res.as_ptr(), prec); }
+        res
+    }
+
+    #[inline]
+    pub fn zero(&self) -> Real {
+        self.new(Real::zero())
+    }
+
+    #[inline]
+    pub fn one(&self) -> Real {
+        self.new(Real::one())
+    }
+
+    /// Parse a decimal or `[m +/- r]` ball at the precision of the field. See
+    /// [Real::from_str_prec].
+    #[inline]
+    pub fn parse(&self, s: &str) -> Result<Real> {
+        Real::from_str_prec(s, self.prec)
+    }
+
+    /// Return an enclosure of `pi` at the precision of the field.
+    #[inline]
+    pub fn pi(&self) -> Real {
+        Real::pi(self.prec)
+    }
+
+    /// Return an enclosure of `e = exp(1)` at the precision of the field.
+    #[inline]
+    pub fn e(&self) -> Real {
+        Real::e(self.prec)
+    }
+
+    /// Return an enclosure of the Euler-Mascheroni constant at the precision
+    /// of the field.
+    #[inline]
+    pub fn euler_gamma(&self) -> Real {
+        Real::euler_gamma(self.prec)
+    }
+
+    /// Return an enclosure of `log(2)` at the precision of the field.
+    #[inline]
+    pub fn log2(&self) -> Real {
+        Real::log2(self.prec)
+    }
+
+    /// Return an enclosure of Catalan's constant at the precision of the 
+    /// field.
+    #[inline]
+    pub fn catalan(&self) -> Real {
+        Real::catalan(self.prec)
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+use crate::Real;
+use arb_sys::arb_hypgeom::*;
+
+impl Real {
+    /// Return the Gauss hypergeometric function `2F1(a, b; c; z)` at 
+    /// `z = self`, or the regularized function `2F1(a, b; c; z)/Gamma(c)` if
+    /// `regularized` is true. The result is a rigorous enclosure, and is not
+    /// finite on the branch cut `z >= 1`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // 2F1(1, 1; 2; z) = -log(1 - z)/z
+    /// let one = Real::one();
+    /// let z = Real::from(0.5);
+    /// let f = z.hypgeom_2f1(&one, &one, &Real::from(2), false);
+    /// assert!(f.overlaps(&(&Real::log2(128) * &Real::from(2))));
+    /// ```
+    pub fn hypgeom_2f1(&self, a: &Real, b: &Real, c: &Real, regularized: bool) -> Real {
+        let prec = self.prec.max(a.prec).max(b.prec).max(c.prec);
+        eval_prec!(Real, prec, |res, prec| {
+            arb_hypgeom_2f1(
+                res.as_mut_ptr(),
+                a.as_ptr(),
+                b.as_ptr(),
+                c.as_ptr(),
+                self.as_ptr(),
+                regularized.into(),
+                prec
+            )
+        })
+    }
+
+    /// Return the Legendre function of the first kind `P_n^m(z)` at 
+    /// `z = self`. If `ferrers` is true this uses the Ferrers convention, 
+    /// defined for `-1 < z < 1`, otherwise the convention defined for 
+    /// `z > 1`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // P_2(z) = (3z^2 - 1)/2
+    /// let z = Real::from(0.5);
+    /// let p = z.legendre_p(&Real::from(2), &Real::zero(), true);
+    /// assert!(p.contains(&Real::from(-0.125)));
+    /// ```
+    pub fn legendre_p(&self, n: &Real, m: &Real, ferrers: bool) -> Real {
+        let kind = if ferrers { 0 } else { 1 };
+        eval_prec!(Real, self.prec.max(n.prec).max(m.prec), |res, prec| {
+            arb_hypgeom_legendre_p(
+                res.as_mut_ptr(),
+                n.as_ptr(),
+                m.as_ptr(),
+                self.as_ptr(),
+                kind,
+                prec
+            )
+        })
+    }
+
+    /// Return the Legendre function of the second kind `Q_n^m(z)` at 
+    /// `z = self`, with the conventions of [Real::legendre_p].
+    pub fn legendre_q(&self, n: &Real, m: &Real, ferrers: bool) -> Real {
+        let kind = if ferrers { 0 } else { 1 };
+        eval_prec!(Real, self.prec.max(n.prec).max(m.prec), |res, prec| {
+            arb_hypgeom_legendre_q(
+                res.as_mut_ptr(),
+                n.as_ptr(),
+                m.as_ptr(),
+                self.as_ptr(),
+                kind,
+                prec
+            )
+        })
+    }
+
+    /// Return the Hermite function `H_nu(z)` at `z = self`, the physicists'
+    /// Hermite polynomial when `nu` is a nonnegative integer.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // H_3(z) = 8z^3 - 12z
+    /// let h = Real::from(0.5).hermite_h(&Real::from(3));
+    /// assert!(h.contains(&Real::from(-5)));
+    /// ```
+    pub fn hermite_h(&self, nu: &Real) -> Real {
+        eval_prec!(Real, self.prec.max(nu.prec), |res, prec| {
+            arb_hypgeom_hermite_h(res.as_mut_ptr(), nu.as_ptr(), self.as_ptr(), prec)
+        })
+    }
+
+    /// Return the generalized Laguerre function `L_n^m(z)` at `z = self`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // L_2(z) = (z^2 - 4z + 2)/2
+    /// let l = Real::from(0.5).laguerre_l(&Real::from(2), &Real::zero());
+    /// assert!(l.contains(&Real::from(0.125)));
+    /// ```
+    pub fn laguerre_l(&self, n: &Real, m: &Real) -> Real {
+        eval_prec!(Real, self.prec.max(n.prec).max(m.prec), |res, prec| {
+            arb_hypgeom_laguerre_l(
+                res.as_mut_ptr(),
+                n.as_ptr(),
+                m.as_ptr(),
+                self.as_ptr(),
+                prec
+            )
+        })
+    }
+
+    /// Return the Chebyshev function of the first kind `T_nu(z)` at 
+    /// `z = self`.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let z = Real::from(0.5);
+    /// assert!(z.chebyshev_t(&Real::from(3)).contains(&Real::from(-1)));
+    /// assert!(z.chebyshev_u(&Real::from(2)).contains(&Real::zero()));
+    /// ```
+    pub fn chebyshev_t(&self, nu: &Real) -> Real {
+        eval_prec!(Real, self.prec.max(nu.prec), |res, prec| {
+            arb_hypgeom_chebyshev_t(res.as_mut_ptr(), nu.as_ptr(), self.as_ptr(), prec)
+        })
+    }
+
+    /// Return the Chebyshev function of the second kind `U_nu(z)` at 
+    /// `z = self`.
+    pub fn chebyshev_u(&self, nu: &Real) -> Real {
+        eval_prec!(Real, self.prec.max(nu.prec), |res, prec| {
+            arb_hypgeom_chebyshev_u(res.as_mut_ptr(), nu.as_ptr(), self.as_ptr(), prec)
+        })
+    }
+}
@@ -28,7 +28,6 @@ use std::hash::{Hash, Hasher};
 use std::mem::{ManuallyDrop, MaybeUninit};
 
 
-#[derive(Debug)]
 pub struct Mag {
     pub(crate) inner: mag_struct,
 }
@@ -60,6 +59,12 @@ impl Default for Mag {
 }
 
 // TODO: use mag_fprint_d
+impl fmt::Debug for Mag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Mag").field(&format_args!("{}", self)).finish()
+    }
+}
+
 impl fmt::Display for Mag {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -0,0 +1,79 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{Real, RealField, arf::Arf};
+use arb_sys::{arb::*, arf::*};
+
+/// Evaluate `f` at `n` equally spaced points `x_i = a + i(b - a)/(n - 1)` of 
+/// the interval `(a, b)` with working precision `prec`, for plotting. 
+/// Returns the midpoints of the values `f(x_i)` rounded to `f64`, together 
+/// with `(lower, upper)` error bars that are rounded outwards, so the exact 
+/// value of `f` at the sample point is guaranteed to lie between them. 
+/// The bars are infinite where `f` returns a ball that is not finite, for 
+/// example near a pole. Panics if `n < 2`.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// // x^2/3 on [0, 2]
+/// let three = Real::from(3);
+/// let (mid, bars) = sample_function(|x| &(x * x) / &three, (0.0, 2.0), 5, 64);
+/// assert_eq!(mid.len(), 5);
+/// assert_eq!(mid[0], 0.0);
+/// for (y, (lo, hi)) in mid.iter().zip(&bars) {
+///     assert!(lo <= y && y <= hi);
+/// }
+/// // the value 1/3 at x = 1 is not a double, but it is enclosed
+/// let (lo, hi) = bars[2];
+/// assert!(lo < hi && 3.0 * lo <= 1.0 && 1.0 <= 3.0 * hi);
+/// ```
+pub fn sample_function<F>(
+    mut f: F,
+    interval: (f64, f64),
+    n: usize,
+    prec: u64
+) -> (Vec<f64>, Vec<(f64, f64)>)
+where
+    F: FnMut(&Real) -> Real
+{
+    assert!(n >= 2, "At least two sample points are needed.");
+    let field = RealField::init(prec);
+    let a = field.new(interval.0);
+    let b = field.new(interval.1);
+    let last: i64 = (n - 1).try_into().expect("Cannot convert usize to a signed long.");
+    let step = &(&b - &a) / &field.new(last);
+    let prec_si: i64 = prec.try_into().expect("Cannot convert precision to a signed long.");
+
+    let mut mid = Vec::with_capacity(n);
+    let mut bars = Vec::with_capacity(n);
+    for i in 0..=last {
+        let x = &a + &(&step * &field.new(i));
+        let y = f(&x);
+        let mut lo = Arf::default();
+        let mut hi = Arf::default();
+        unsafe {
+            arb_get_lbound_arf(lo.as_mut_ptr(), y.as_ptr(), prec_si);
+            arb_get_ubound_arf(hi.as_mut_ptr(), y.as_ptr(), prec_si);
+            mid.push(arf_get_d(&y.inner.mid, ARF_RND_NEAR));
+            bars.push((
+                arf_get_d(lo.as_ptr(), ARF_RND_FLOOR),
+                arf_get_d(hi.as_ptr(), ARF_RND_CEIL)
+            ));
+        }
+    }
+    (mid, bars)
+}
@@ -0,0 +1,469 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Human-readable serde representations for use with `#[serde(with = ...)]`,
+//! for exchanging values with services written in other languages. The
+//! default `Serialize` impls are compact but opaque (integers are sequences
+//! of limbs); the modules here instead use
+//!
+//! * decimal strings for [Integer], e.g. `"-12345678901234567890"`,
+//! * `{"num": "1", "den": "3"}` objects for [Rational],
+//! * arrays of coefficients, constant term first, for [IntPoly] and
+//!   [RatPoly],
+//! * `{"nrows": 2, "ncols": 2, "rows": [...]}` objects holding an array of
+//!   rows for [IntMat] and [RatMat].
+//!
+//! Decimal strings avoid the loss of precision JavaScript numbers would
+//! cause, but plain JSON integers are also accepted when deserializing.
+//! Deserialization relies on `deserialize_any`, so these representations
+//! are meant for self-describing formats like JSON rather than bincode. The
+//! dimensions of a matrix are written explicitly, so matrices with no rows or
+//! no columns keep their shape.
+//!
+//! ```
+//! use inertia_core::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Record {
+//!     #[serde(with = "serde_readable::integer")]
+//!     n: Integer,
+//!     #[serde(with = "serde_readable::rational")]
+//!     q: Rational,
+//!     #[serde(with = "serde_readable::intmat")]
+//!     m: IntMat,
+//! }
+//!
+//! let r = Record {
+//!     n: "18446744073709551616".parse().unwrap(),
+//!     q: Rational::from([-1, 3]),
+//!     m: IntMat::new([1, 2, 3, 4], 2, 2),
+//! };
+//! let json = serde_json::to_string(&r).unwrap();
+//! assert_eq!(
+//!     json,
+//!     concat!(
+//!         r#"{"n":"18446744073709551616","q":{"num":"-1","den":"3"},"#,
+//!         r#""m":{"nrows":2,"ncols":2,"rows":[["1","2"],["3","4"]]}}"#
+//!     )
+//! );
+//! assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), r);
+//! ```
+
+use crate::{Integer, IntMat, IntPoly, Rational, RatMat, RatPoly};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+// An Integer written as a decimal string.
+struct Dec<'a>(&'a Integer);
+
+impl Serialize for Dec<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self.0)
+    }
+}
+
+// An Integer read from a decimal string or a JSON integer.
+struct DecOwned(Integer);
+
+struct DecVisitor {}
+
+impl<'de> Visitor<'de> for DecVisitor {
+    type Value = DecOwned;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an integer or a string of decimal digits")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let x: Integer = s
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(s), &self))?;
+        Ok(DecOwned(x))
+    }
+
+    fn visit_i64<E>(self, x: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(DecOwned(Integer::from(x)))
+    }
+
+    fn visit_u64<E>(self, x: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(DecOwned(Integer::from(x)))
+    }
+}
+
+impl<'de> Deserialize<'de> for DecOwned {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(DecVisitor {})
+    }
+}
+
+// A Rational written as a `{"num", "den"}` object.
+struct Frac<'a>(&'a Rational);
+
+impl Serialize for Frac<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Rational", 2)?;
+        state.serialize_field("num", &Dec(&self.0.numerator()))?;
+        state.serialize_field("den", &Dec(&self.0.denominator()))?;
+        state.end()
+    }
+}
+
+struct FracOwned(Rational);
+
+struct FracVisitor {}
+
+impl<'de> Visitor<'de> for FracVisitor {
+    type Value = FracOwned;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object with fields num and den")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut num = None;
+        let mut den = None;
+        while let Some(key) = access.next_key::<String>()? {
+            match key.as_str() {
+                "num" => num = Some(access.next_value::<DecOwned>()?.0),
+                "den" => den = Some(access.next_value::<DecOwned>()?.0),
+                _ => return Err(de::Error::unknown_field(&key, &["num", "den"])),
+            }
+        }
+        let num = num.ok_or_else(|| de::Error::missing_field("num"))?;
+        let den = den.ok_or_else(|| de::Error::missing_field("den"))?;
+        if den.is_zero() {
+            return Err(de::Error::custom("zero denominator"));
+        }
+        Ok(FracOwned(Rational::from([num, den])))
+    }
+}
+
+impl<'de> Deserialize<'de> for FracOwned {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(FracVisitor {})
+    }
+}
+
+// Write a matrix as a `{"nrows", "ncols", "rows"}` object, mapping each
+// entry with `f`.
+fn serialize_rows<'a, S, T, U, F>(
+    nrows: i64,
+    ncols: i64,
+    entries: &'a [T],
+    f: F,
+    serializer: S
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    U: Serialize,
+    F: Fn(&'a T) -> U,
+{
+    let (r, c) = (nrows as usize, ncols as usize);
+    let rows: Vec<Vec<U>> = (0..r)
+        .map(|i| entries[i * c..(i + 1) * c].iter().map(&f).collect())
+        .collect();
+    let mut state = serializer.serialize_struct("Matrix", 3)?;
+    state.serialize_field("nrows", &nrows)?;
+    state.serialize_field("ncols", &ncols)?;
+    state.serialize_field("rows", &rows)?;
+    state.end()
+}
+
+// A matrix read from a `{"nrows", "ncols", "rows"}` object, with the rows 
+// checked against the dimensions.
+struct MatOwned<T>(i64, i64, Vec<Vec<T>>);
+
+struct MatVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for MatVisitor<T> {
+    type Value = MatOwned<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object with fields nrows, ncols and rows")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        const FIELDS: &[&str] = &["nrows", "ncols", "rows"];
+        let mut nrows = None;
+        let mut ncols = None;
+        let mut rows = None;
+        while let Some(key) = access.next_key::<String>()? {
+            match key.as_str() {
+                "nrows" => nrows = Some(access.next_value::<i64>()?),
+                "ncols" => ncols = Some(access.next_value::<i64>()?),
+                "rows" => rows = Some(access.next_value::<Vec<Vec<T>>>()?),
+                _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+        let nrows = nrows.ok_or_else(|| de::Error::missing_field("nrows"))?;
+        let ncols = ncols.ok_or_else(|| de::Error::missing_field("ncols"))?;
+        let rows = rows.ok_or_else(|| de::Error::missing_field("rows"))?;
+        
+        let fits = |n: i64, len: usize| usize::try_from(n).map_or(false, |n| n == len);
+        if !fits(nrows, rows.len()) || !rows.iter().all(|r| fits(ncols, r.len())) {
+            return Err(de::Error::custom("rows do not match the matrix dimensions"));
+        }
+        Ok(MatOwned(nrows, ncols, rows))
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for MatOwned<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MatVisitor(PhantomData))
+    }
+}
+
+/// Serialize an [Integer] as a decimal string.
+pub mod integer {
+    use super::*;
+
+    pub fn serialize<S>(x: &Integer, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Dec(x).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Integer, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(DecOwned::deserialize(deserializer)?.0)
+    }
+}
+
+/// Serialize a [Rational] as a `{"num": ..., "den": ...}` object of decimal
+/// strings.
+pub mod rational {
+    use super::*;
+
+    pub fn serialize<S>(x: &Rational, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Frac(x).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Rational, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(FracOwned::deserialize(deserializer)?.0)
+    }
+}
+
+/// Serialize an [IntPoly] as an array of decimal strings, constant term
+/// first.
+pub mod intpoly {
+    use super::*;
+
+    pub fn serialize<S>(f: &IntPoly, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let coeffs = f.get_coeffs();
+        serializer.collect_seq(coeffs.iter().map(Dec))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<IntPoly, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let coeffs = Vec::<DecOwned>::deserialize(deserializer)?;
+        let mut res = IntPoly::zero();
+        for (i, c) in coeffs.iter().enumerate() {
+            res.set_coeff(i, &c.0);
+        }
+        Ok(res)
+    }
+}
+
+/// Serialize a [RatPoly] as an array of `{"num", "den"}` objects, constant
+/// term first.
+pub mod ratpoly {
+    use super::*;
+
+    pub fn serialize<S>(f: &RatPoly, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let coeffs = f.get_coeffs();
+        serializer.collect_seq(coeffs.iter().map(Frac))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RatPoly, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let coeffs = Vec::<FracOwned>::deserialize(deserializer)?;
+        let mut res = RatPoly::zero();
+        for (i, c) in coeffs.iter().enumerate() {
+            res.set_coeff(i, &c.0);
+        }
+        Ok(res)
+    }
+}
+
+/// Serialize an [IntMat] as its dimensions and an array of rows of decimal 
+/// strings.
+pub mod intmat {
+    use super::*;
+
+    pub fn serialize<S>(m: &IntMat, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let entries = m.get_entries();
+        serialize_rows(m.nrows_si(), m.ncols_si(), &entries, Dec, serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<IntMat, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let MatOwned(nrows, ncols, rows) = MatOwned::<DecOwned>::deserialize(deserializer)?;
+        let mut res = IntMat::zero(nrows, ncols);
+        for (i, row) in rows.iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                res.set_entry(i, j, &x.0);
+            }
+        }
+        Ok(res)
+    }
+}
+
+/// Serialize a [RatMat] as its dimensions and an array of rows of 
+/// `{"num", "den"}` objects.
+pub mod ratmat {
+    use super::*;
+
+    pub fn serialize<S>(m: &RatMat, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let entries = m.get_entries();
+        serialize_rows(m.nrows_si(), m.ncols_si(), &entries, Frac, serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RatMat, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let MatOwned(nrows, ncols, rows) = MatOwned::<FracOwned>::deserialize(deserializer)?;
+        let mut res = RatMat::zero(nrows, ncols);
+        for (i, row) in rows.iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                res.set_entry(i, j, &x.0);
+            }
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Values {
+        #[serde(with = "super::integer")]
+        n: Integer,
+        #[serde(with = "super::ratpoly")]
+        f: RatPoly,
+        #[serde(with = "super::ratmat")]
+        m: RatMat,
+        #[serde(with = "super::intpoly")]
+        g: IntPoly,
+    }
+
+    #[test]
+    fn serde_readable() {
+        let x = Values {
+            n: Integer::from(-42),
+            f: RatPoly::from([[1, 2], [0, 1], [-3, 4]]),
+            m: RatMat::new([[1, 2], [3, 1], [0, 1], [-5, 7]], 2, 2),
+            g: IntPoly::from([1, 0, -1]),
+        };
+        let ser = serde_json::to_string(&x).unwrap();
+        let y: Values = serde_json::from_str(&ser).unwrap();
+        assert_eq!(x, y);
+
+        let y: Values = serde_json::from_str(
+            r#"{"n": -42, "f": [{"num": 1, "den": 2}, {"num": "0", "den": "1"},
+                {"den": "4", "num": "-3"}], "m": {"nrows": 2, "ncols": 2, "rows":
+                [[{"num": 1, "den": 2}, {"num": 3, "den": 1}],
+                [{"num": 0, "den": 1}, {"num": -5, "den": 7}]]}, "g": ["1", "0", "-1"]}"#
+        ).unwrap();
+        assert_eq!(x, y);
+
+        assert!(serde_json::from_str::<Values>(r#"{"n": "1x"}"#).is_err());
+        assert!(serde_json::from_str::<Values>(r#"{"n": "--5"}"#).is_err());
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Shape {
+        #[serde(with = "super::intmat")]
+        m: IntMat,
+    }
+
+    #[test]
+    fn serde_readable_empty_matrix() {
+        for (r, c) in [(3, 0), (0, 3), (0, 0)] {
+            let x = Shape { m: IntMat::zero(r, c) };
+            let ser = serde_json::to_string(&x).unwrap();
+            let y: Shape = serde_json::from_str(&ser).unwrap();
+            assert_eq!(x, y);
+        }
+
+        let bad = r#"{"m": {"nrows": 2, "ncols": 1, "rows": [["1"]]}}"#;
+        assert!(serde_json::from_str::<Shape>(bad).is_err());
+    }
+}
@@ -0,0 +1,286 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::{IntPoly, RatPoly};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A truncated power series `f + O(x^prec)` with coefficients in the ring of
+/// the polynomial type `T`, either [IntPoly] or [RatPoly]. The polynomial is
+/// always kept truncated to `prec` coefficients and arithmetic returns a
+/// series of the smaller of the two precisions, so precision loss is
+/// explicit.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// // the generating function of the Catalan numbers is 2/(1 + sqrt(1 - 4x))
+/// let one = Series::new(RatPoly::one(), 6);
+/// let f = Series::new(RatPoly::from([1, -4]), 6);
+/// let c = &Series::new(RatPoly::from([2]), 6) / &(&one + &f.sqrt());
+/// assert_eq!(c.poly(), &RatPoly::from([1, 1, 2, 5, 14, 42]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Series<T> {
+    poly: T,
+    prec: usize,
+}
+
+impl<T> Series<T> {
+    /// Return the precision `n` of the series `f + O(x^n)`.
+    #[inline]
+    pub fn prec(&self) -> usize {
+        self.prec
+    }
+
+    /// Borrow the truncated polynomial `f` of the series `f + O(x^n)`.
+    #[inline]
+    pub fn poly(&self) -> &T {
+        &self.poly
+    }
+
+    /// Return the truncated polynomial `f` of the series `f + O(x^n)`.
+    #[inline]
+    pub fn into_poly(self) -> T {
+        self.poly
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Series<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + O(x^{})", self.poly, self.prec)
+    }
+}
+
+/// The polynomial types that can back a [Series].
+pub trait SeriesPoly: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn truncate(&mut self, n: usize);
+    fn mullow(&self, other: &Self, n: usize) -> Self;
+    fn inv_series(&self, n: usize) -> Self;
+    fn div_series(&self, other: &Self, n: usize) -> Self;
+}
+
+impl<T: SeriesPoly> Series<T> {
+    /// Return the series `poly + O(x^prec)`, truncating `poly`. Panics if 
+    /// `prec` is zero.
+    pub fn new(mut poly: T, prec: usize) -> Self {
+        assert!(prec > 0, "The precision must be positive.");
+        poly.truncate(prec);
+        Series { poly, prec }
+    }
+
+    /// Return the zero series to precision `prec`. Panics if `prec` is zero.
+    #[inline]
+    pub fn zero(prec: usize) -> Self {
+        Series::new(T::zero(), prec)
+    }
+
+    /// Return the series `1 + O(x^prec)`.
+    #[inline]
+    pub fn one(prec: usize) -> Self {
+        Series::new(T::one(), prec)
+    }
+
+    /// Return the series truncated to the smaller precision `prec`. Panics if
+    /// `prec` is zero or exceeds the current precision.
+    pub fn truncate(&self, prec: usize) -> Self {
+        assert!(prec <= self.prec);
+        Series::new(self.poly.clone(), prec)
+    }
+
+    /// Return the multiplicative inverse of the series. See
+    /// [IntPoly::inv_series] and [RatPoly::inv_series] for the conditions on
+    /// the constant term.
+    #[inline]
+    pub fn inv(&self) -> Self {
+        Series { poly: self.poly.inv_series(self.prec), prec: self.prec }
+    }
+}
+
+macro_rules! impl_series {
+    ($poly:ident) => {
+        impl SeriesPoly for $poly {
+            #[inline]
+            fn zero() -> Self {
+                $poly::zero()
+            }
+            #[inline]
+            fn one() -> Self {
+                $poly::one()
+            }
+            #[inline]
+            fn truncate(&mut self, n: usize) {
+                $poly::truncate(self, n)
+            }
+            #[inline]
+            fn mullow(&self, other: &Self, n: usize) -> Self {
+                $poly::mullow(self, other, n)
+            }
+            #[inline]
+            fn inv_series(&self, n: usize) -> Self {
+                $poly::inv_series(self, n)
+            }
+            #[inline]
+            fn div_series(&self, other: &Self, n: usize) -> Self {
+                $poly::div_series(self, other, n)
+            }
+        }
+
+        impl Neg for &Series<$poly> {
+            type Output = Series<$poly>;
+            #[inline]
+            fn neg(self) -> Series<$poly> {
+                Series { poly: -&self.poly, prec: self.prec }
+            }
+        }
+
+        impl Add for &Series<$poly> {
+            type Output = Series<$poly>;
+            #[inline]
+            fn add(self, rhs: &Series<$poly>) -> Series<$poly> {
+                Series::new(&self.poly + &rhs.poly, self.prec.min(rhs.prec))
+            }
+        }
+
+        impl Sub for &Series<$poly> {
+            type Output = Series<$poly>;
+            #[inline]
+            fn sub(self, rhs: &Series<$poly>) -> Series<$poly> {
+                Series::new(&self.poly - &rhs.poly, self.prec.min(rhs.prec))
+            }
+        }
+
+        impl Mul for &Series<$poly> {
+            type Output = Series<$poly>;
+            #[inline]
+            fn mul(self, rhs: &Series<$poly>) -> Series<$poly> {
+                let prec = self.prec.min(rhs.prec);
+                Series { poly: $poly::mullow(&self.poly, &rhs.poly, prec), prec }
+            }
+        }
+
+        impl Div for &Series<$poly> {
+            type Output = Series<$poly>;
+            /// Power series division. See [IntPoly::div_series] and
+            /// [RatPoly::div_series] for the conditions on the constant term
+            /// of the divisor.
+            #[inline]
+            fn div(self, rhs: &Series<$poly>) -> Series<$poly> {
+                let prec = self.prec.min(rhs.prec);
+                Series { poly: $poly::div_series(&self.poly, &rhs.poly, prec), prec }
+            }
+        }
+    };
+}
+
+impl_series!(IntPoly);
+impl_series!(RatPoly);
+
+/// A truncated power series over the rationals.
+pub type RatSeries = Series<RatPoly>;
+
+impl Series<IntPoly> {
+    /// Return the square root of the series if it has integer coefficients,
+    /// otherwise `None`.
+    #[inline]
+    pub fn sqrt(&self) -> Option<Self> {
+        self.poly.sqrt_series(self.prec).map(|poly| Series { poly, prec: self.prec })
+    }
+}
+
+impl Series<RatPoly> {
+    /// Return the square root of a series with constant term one.
+    #[inline]
+    pub fn sqrt(&self) -> Self {
+        Series { poly: self.poly.sqrt_series(self.prec), prec: self.prec }
+    }
+
+    /// Return the exponential of a series with constant term zero.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // exp(log(1 + x)) = 1 + x
+    /// let f = Series::new(RatPoly::from([1, 1]), 8);
+    /// assert_eq!(f.log().exp(), f);
+    /// ```
+    #[inline]
+    pub fn exp(&self) -> Self {
+        Series { poly: self.poly.exp_series(self.prec), prec: self.prec }
+    }
+
+    /// Return the logarithm of a series with constant term one.
+    #[inline]
+    pub fn log(&self) -> Self {
+        Series { poly: self.poly.log_series(self.prec), prec: self.prec }
+    }
+
+    /// Return the composition `self(g)` of the series with a series `g` with
+    /// constant term zero, to the smaller of the two precisions.
+    #[inline]
+    pub fn compose(&self, g: &Self) -> Self {
+        let prec = self.prec.min(g.prec);
+        Series { poly: self.poly.compose_series(&g.poly, prec), prec }
+    }
+
+    /// Return the compositional inverse of a series with constant term zero
+    /// and nonzero linear term.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// // the inverse of x/(1 - x) is x/(1 + x)
+    /// let f = RatSeries::new(RatPoly::from([0, 1, 1, 1, 1, 1]), 6);
+    /// let g = f.revert();
+    /// assert_eq!(g.poly(), &RatPoly::from([0, 1, -1, 1, -1, 1]));
+    /// assert_eq!(f.compose(&g), RatSeries::new(RatPoly::from([0, 1]), 6));
+    /// ```
+    #[inline]
+    pub fn revert(&self) -> Self {
+        Series { poly: self.poly.revert_series(self.prec), prec: self.prec }
+    }
+}
+
+impl From<&Series<IntPoly>> for Series<RatPoly> {
+    #[inline]
+    fn from(x: &Series<IntPoly>) -> Series<RatPoly> {
+        Series { poly: RatPoly::from(x.poly()), prec: x.prec }
+    }
+}
+
+impl From<Series<IntPoly>> for Series<RatPoly> {
+    #[inline]
+    fn from(x: Series<IntPoly>) -> Series<RatPoly> {
+        Series::from(&x)
+    }
+}
+
+impl From<&Series<RatPoly>> for RatPoly {
+    #[inline]
+    fn from(x: &Series<RatPoly>) -> RatPoly {
+        x.poly.clone()
+    }
+}
+
+impl From<Series<RatPoly>> for RatPoly {
+    #[inline]
+    fn from(x: Series<RatPoly>) -> RatPoly {
+        x.poly
+    }
+}
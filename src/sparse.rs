@@ -0,0 +1,513 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use flint_sys::fmpz;
+
+// Sort the triplets by position, add up duplicates and drop zeros, returning
+// the compressed sparse row arrays.
+fn compress(
+    nrows: usize,
+    ncols: usize,
+    mut entries: Vec<(usize, usize, Integer)>,
+    reduce: impl Fn(Integer) -> Integer
+) -> (Vec<usize>, Vec<usize>, Vec<Integer>) {
+    entries.sort_by_key(|&(i, j, _)| (i, j));
+
+    let mut row_ptr = vec![0; nrows + 1];
+    let mut cols = Vec::with_capacity(entries.len());
+    let mut vals: Vec<Integer> = Vec::with_capacity(entries.len());
+    let mut rows = Vec::with_capacity(entries.len());
+    for (i, j, x) in entries {
+        assert!(i < nrows && j < ncols);
+        if rows.last() == Some(&i) && cols.last() == Some(&j) {
+            let last = vals.last_mut().unwrap();
+            *last = &*last + &x;
+        } else {
+            rows.push(i);
+            cols.push(j);
+            vals.push(x);
+        }
+    }
+
+    let (mut c, mut v) = (Vec::with_capacity(cols.len()), Vec::with_capacity(vals.len()));
+    for ((i, j), x) in rows.into_iter().zip(cols).zip(vals) {
+        let x = reduce(x);
+        if !x.is_zero() {
+            row_ptr[i + 1] += 1;
+            c.push(j);
+            v.push(x);
+        }
+    }
+    for i in 0..nrows {
+        row_ptr[i + 1] += row_ptr[i];
+    }
+    (row_ptr, c, v)
+}
+
+/// A sparse integer matrix in compressed sparse row (CSR) format. Only the 
+/// nonzero entries are stored, sorted by row and then by column, so memory 
+/// and the cost of a matrix-vector product are proportional to the number 
+/// of nonzero entries.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let a = SparseIntMat::from_triplets(2, 3, vec![(0, 0, 2), (1, 2, -1), (0, 0, 3)]);
+/// assert_eq!(a.nnz(), 2);
+/// assert_eq!(a.get(0, 0), 5);
+/// assert_eq!(a.mul_vec(&[Integer::from(1), Integer::from(7), Integer::from(4)]), 
+///     vec![Integer::from(5), Integer::from(-4)]);
+/// assert_eq!(IntMat::from(&a), IntMat::new([5, 0, 0, 0, 0, -1], 2, 3));
+/// assert_eq!(SparseIntMat::from(IntMat::from(&a)), a);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseIntMat {
+    nrows: usize,
+    ncols: usize,
+    // the entries of row `i` are at positions `row_ptr[i]..row_ptr[i + 1]`
+    row_ptr: Vec<usize>,
+    cols: Vec<usize>,
+    vals: Vec<Integer>,
+}
+
+impl SparseIntMat {
+    /// Return the `nrows` by `ncols` zero matrix.
+    #[inline]
+    pub fn zero(nrows: usize, ncols: usize) -> SparseIntMat {
+        SparseIntMat { 
+            nrows, 
+            ncols, 
+            row_ptr: vec![0; nrows + 1], 
+            cols: vec![], 
+            vals: vec![] 
+        }
+    }
+
+    /// Return the matrix with entries given as `(row, column, value)` 
+    /// triplets in any order. Values given for the same position are added.
+    pub fn from_triplets<I, T>(nrows: usize, ncols: usize, entries: I) -> SparseIntMat
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+        T: Into<Integer>
+    {
+        let entries = entries.into_iter().map(|(i, j, x)| (i, j, x.into())).collect();
+        let (row_ptr, cols, vals) = compress(nrows, ncols, entries, |x| x);
+        SparseIntMat { nrows, ncols, row_ptr, cols, vals }
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Return the number of nonzero entries.
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.vals.len()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.vals.is_empty()
+    }
+
+    /// Return the `(i, j)`-th entry of the matrix.
+    pub fn get(&self, i: usize, j: usize) -> Integer {
+        assert!(i < self.nrows && j < self.ncols);
+        let (start, end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+        match self.cols[start..end].binary_search(&j) {
+            Ok(k) => self.vals[start + k].clone(),
+            Err(_) => Integer::zero(),
+        }
+    }
+
+    /// Iterate over the nonzero entries `(j, x)` of row `i`, in increasing 
+    /// order of the column.
+    pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, &Integer)> + '_ {
+        assert!(i < self.nrows);
+        let (start, end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+        self.cols[start..end].iter().copied().zip(&self.vals[start..end])
+    }
+
+    /// Iterate over the nonzero entries as `(row, column, value)` triplets.
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, &Integer)> + '_ {
+        (0..self.nrows).flat_map(move |i| self.row(i).map(move |(j, x)| (i, j, x)))
+    }
+
+    /// Return the transpose of the matrix.
+    pub fn transpose(&self) -> SparseIntMat {
+        let entries = self.triplets().map(|(i, j, x)| (j, i, x.clone())).collect();
+        let (row_ptr, cols, vals) = compress(self.ncols, self.nrows, entries, |x| x);
+        SparseIntMat { nrows: self.ncols, ncols: self.nrows, row_ptr, cols, vals }
+    }
+
+    /// Return the product `Ax` of the matrix and the vector `x`.
+    pub fn mul_vec(&self, x: &[Integer]) -> Vec<Integer> {
+        assert_eq!(x.len(), self.ncols);
+        (0..self.nrows).map(|i| {
+            let mut acc = Integer::zero();
+            for (j, a) in self.row(i) {
+                acc.addmul_assign(a, &x[j]);
+            }
+            acc
+        }).collect()
+    }
+
+    /// Return the product `A^T y` of the transpose of the matrix and the 
+    /// vector `y`, without forming the transpose.
+    pub fn mul_vec_transpose(&self, y: &[Integer]) -> Vec<Integer> {
+        assert_eq!(y.len(), self.nrows);
+        let mut res = vec![Integer::zero(); self.ncols];
+        for (i, j, a) in self.triplets() {
+            res[j].addmul_assign(a, &y[i]);
+        }
+        res
+    }
+
+    /// Reduce the entries modulo the modulus of `ctx`.
+    pub fn reduce(&self, ctx: &IntModCtx) -> SparseIntModMat {
+        SparseIntModMat::from_triplets(
+            self.nrows, 
+            self.ncols, 
+            self.triplets().map(|(i, j, x)| (i, j, x.clone())), 
+            ctx
+        )
+    }
+}
+
+impl_from! {
+    SparseIntMat, IntMat
+    {
+        fn from(x: &IntMat) -> SparseIntMat {
+            let mut entries = vec![];
+            for i in 0..x.nrows() {
+                for j in 0..x.ncols() {
+                    let e = x.entry(i, j);
+                    if !e.is_zero() {
+                        entries.push((i, j, e.clone()));
+                    }
+                }
+            }
+            SparseIntMat::from_triplets(x.nrows(), x.ncols(), entries)
+        }
+    }
+}
+
+impl_from! {
+    IntMat, SparseIntMat
+    {
+        fn from(x: &SparseIntMat) -> IntMat {
+            let conv = |n: usize| -> i64 {
+                n.try_into().expect("Cannot convert length to a signed long.")
+            };
+            let mut res = IntMat::zero(conv(x.nrows), conv(x.ncols));
+            for (i, j, e) in x.triplets() {
+                res.set_entry(i, j, e);
+            }
+            res
+        }
+    }
+}
+
+/// A sparse matrix over the integers mod `n` in compressed sparse row (CSR) 
+/// format, see [SparseIntMat]. The stored entries are the nonzero reduced 
+/// representatives.
+///
+/// For a prime modulus, [kernel_vector](SparseIntModMat::kernel_vector) 
+/// finds nonzero solutions of `Ax = 0` using only matrix-vector products, 
+/// which is what the linear algebra step of sieving algorithms needs.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseIntModMat {
+    nrows: usize,
+    ncols: usize,
+    row_ptr: Vec<usize>,
+    cols: Vec<usize>,
+    vals: Vec<Integer>,
+    ctx: IntModCtx,
+}
+
+impl SparseIntModMat {
+    /// Return the `nrows` by `ncols` zero matrix.
+    #[inline]
+    pub fn zero(nrows: usize, ncols: usize, ctx: &IntModCtx) -> SparseIntModMat {
+        SparseIntModMat { 
+            nrows, 
+            ncols, 
+            row_ptr: vec![0; nrows + 1], 
+            cols: vec![], 
+            vals: vec![],
+            ctx: ctx.clone()
+        }
+    }
+
+    /// Return the matrix with entries given as `(row, column, value)` 
+    /// triplets in any order. Values given for the same position are added 
+    /// and the sums reduced.
+    pub fn from_triplets<I, T>(
+        nrows: usize, 
+        ncols: usize, 
+        entries: I, 
+        ctx: &IntModCtx
+    ) -> SparseIntModMat
+    where
+        I: IntoIterator<Item = (usize, usize, T)>,
+        T: Into<Integer>
+    {
+        let entries = entries.into_iter().map(|(i, j, x)| (i, j, x.into())).collect();
+        let (row_ptr, cols, vals) = compress(
+            nrows, 
+            ncols, 
+            entries, 
+            |x| Integer::from(ctx.element(x))
+        );
+        SparseIntModMat { nrows, ncols, row_ptr, cols, vals, ctx: ctx.clone() }
+    }
+
+    #[inline]
+    pub fn context(&self) -> &IntModCtx {
+        &self.ctx
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> Integer {
+        self.ctx.modulus()
+    }
+
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Return the number of nonzero entries.
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.vals.len()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.vals.is_empty()
+    }
+
+    /// Return the `(i, j)`-th entry of the matrix.
+    pub fn get(&self, i: usize, j: usize) -> IntMod {
+        assert!(i < self.nrows && j < self.ncols);
+        let (start, end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+        match self.cols[start..end].binary_search(&j) {
+            Ok(k) => self.ctx.element(self.vals[start + k].clone()),
+            Err(_) => self.ctx.zero(),
+        }
+    }
+
+    /// Iterate over the nonzero entries `(j, x)` of row `i`, in increasing 
+    /// order of the column, as reduced representatives.
+    pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, &Integer)> + '_ {
+        assert!(i < self.nrows);
+        let (start, end) = (self.row_ptr[i], self.row_ptr[i + 1]);
+        self.cols[start..end].iter().copied().zip(&self.vals[start..end])
+    }
+
+    /// Iterate over the nonzero entries as `(row, column, value)` triplets of 
+    /// reduced representatives.
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, &Integer)> + '_ {
+        (0..self.nrows).flat_map(move |i| self.row(i).map(move |(j, x)| (i, j, x)))
+    }
+
+    /// Return the transpose of the matrix.
+    pub fn transpose(&self) -> SparseIntModMat {
+        SparseIntModMat::from_triplets(
+            self.ncols, 
+            self.nrows, 
+            self.triplets().map(|(i, j, x)| (j, i, x.clone())), 
+            &self.ctx
+        )
+    }
+
+    /// Return the product `Ax` of the matrix and the vector `x`.
+    pub fn mul_vec(&self, x: &[IntMod]) -> Vec<IntMod> {
+        assert_eq!(x.len(), self.ncols);
+        (0..self.nrows).map(|i| {
+            let mut acc = Integer::zero();
+            for (j, a) in self.row(i) {
+                assert_eq!(x[j].context(), &self.ctx);
+                unsafe { fmpz::fmpz_addmul(acc.as_mut_ptr(), a.as_ptr(), x[j].as_ptr()); }
+            }
+            self.ctx.element(acc)
+        }).collect()
+    }
+
+    /// Return the product `A^T y` of the transpose of the matrix and the 
+    /// vector `y`, without forming the transpose.
+    pub fn mul_vec_transpose(&self, y: &[IntMod]) -> Vec<IntMod> {
+        assert_eq!(y.len(), self.nrows);
+        let mut acc = vec![Integer::zero(); self.ncols];
+        for (i, j, a) in self.triplets() {
+            assert_eq!(y[i].context(), &self.ctx);
+            unsafe { fmpz::fmpz_addmul(acc[j].as_mut_ptr(), a.as_ptr(), y[i].as_ptr()); }
+        }
+        acc.into_iter().map(|x| self.ctx.element(x)).collect()
+    }
+
+    // The square operator whose kernel is searched: the matrix padded with
+    // zero rows when it is wide, and A^T A when it is tall.
+    fn apply_square(&self, x: &[IntMod]) -> Vec<IntMod> {
+        if self.nrows <= self.ncols {
+            let mut y = self.mul_vec(x);
+            y.resize(self.ncols, self.ctx.zero());
+            y
+        } else {
+            self.mul_vec_transpose(&self.mul_vec(x))
+        }
+    }
+
+    /// Find a nonzero vector `x` with `Ax = 0` using Wiedemann's algorithm, 
+    /// which needs `O(n)` sparse matrix-vector products and `O(n)` vectors 
+    /// of storage for `n` columns, never forming a dense matrix. The modulus 
+    /// must be prime.
+    ///
+    /// The algorithm is randomized: `None` means that no kernel vector was 
+    /// found within a few attempts, which for a large field almost surely 
+    /// means that the kernel is trivial. Over small fields, such as `F_2`, 
+    /// a single attempt fails more often, so callers that know the kernel 
+    /// is nontrivial should retry. Repeated calls return random kernel 
+    /// vectors, so a basis of a small kernel can be built by calling this 
+    /// until enough independent vectors are found.
+    ///
+    /// ```
+    /// use inertia_core::*;
+    ///
+    /// let ctx = IntModCtx::new(101);
+    /// let a = SparseIntModMat::from_triplets(3, 4, vec![
+    ///     (0, 0, 1), (0, 1, 2),
+    ///     (1, 1, 1), (1, 2, 5),
+    ///     (2, 2, 1), (2, 3, 7),
+    /// ], &ctx);
+    /// let mut rng = FlintRand::new();
+    /// let x = a.kernel_vector(&mut rng).unwrap();
+    /// assert!(x.iter().any(|e| !e.is_zero()));
+    /// assert!(a.mul_vec(&x).iter().all(|e| e.is_zero()));
+    /// ```
+    pub fn kernel_vector(&self, rng: &mut FlintRand) -> Option<Vec<IntMod>> {
+        assert!(self.modulus().is_prime());
+        let n = self.ncols;
+        if n == 0 {
+            return None;
+        }
+        for _ in 0..4 {
+            if let Some(x) = self.wiedemann(rng) {
+                return Some(x);
+            }
+        }
+        None
+    }
+
+    // A single attempt of Wiedemann's algorithm for the square operator B:
+    // the minimal polynomial f = x^k g, g(0) != 0, of a random projection of
+    // the Krylov sequence of Bx annihilates Bx with high probability, so 
+    // B^(k+1) g(B) x = 0 and the last nonzero vector of g(B) x, B g(B) x, ... 
+    // lies in the kernel.
+    fn wiedemann(&self, rng: &mut FlintRand) -> Option<Vec<IntMod>> {
+        let n = self.ncols;
+        let ctx = &self.ctx;
+        let x: Vec<IntMod> = (0..n).map(|_| IntMod::randm(ctx, rng)).collect();
+        let u: Vec<IntMod> = (0..n).map(|_| IntMod::randm(ctx, rng)).collect();
+
+        let mut v = self.apply_square(&x);
+        let mut seq = Vec::with_capacity(2 * n);
+        for _ in 0..2 * n {
+            check_interrupt();
+            seq.push(IntMod::dot(&u, &v));
+            v = self.apply_square(&v);
+        }
+
+        let f = minimal_recurrence(&seq);
+        let f: Vec<IntMod> = (0..=f.degree() as usize).map(|j| f.get_coeff(j)).collect();
+        let k = f.iter().take_while(|a| a.is_zero()).count();
+        let g = &f[k..];
+
+        // w = g(B) x by Horner's rule
+        let mut w: Vec<IntMod> = x.iter().map(|e| e * &g[g.len() - 1]).collect();
+        for gj in g[..g.len() - 1].iter().rev() {
+            w = self.apply_square(&w);
+            for (wi, xi) in w.iter_mut().zip(&x) {
+                *wi = &*wi + &(xi * gj);
+            }
+        }
+
+        for _ in 0..=k + 1 {
+            if w.iter().all(|e| e.is_zero()) {
+                return None;
+            }
+            let z = self.apply_square(&w);
+            if z.iter().all(|e| e.is_zero()) {
+                // A^T A w = 0 does not imply Aw = 0 over a finite field
+                return if self.mul_vec(&w).iter().all(|e| e.is_zero()) {
+                    Some(w)
+                } else {
+                    None
+                };
+            }
+            w = z;
+        }
+        None
+    }
+}
+
+impl_from! {
+    SparseIntModMat, IntModMat
+    {
+        fn from(x: &IntModMat) -> SparseIntModMat {
+            let mut entries = vec![];
+            for i in 0..x.nrows() {
+                for j in 0..x.ncols() {
+                    let e = x.entry(i, j);
+                    if !e.is_zero() {
+                        entries.push((i, j, e.clone()));
+                    }
+                }
+            }
+            SparseIntModMat::from_triplets(x.nrows(), x.ncols(), entries, x.context())
+        }
+    }
+}
+
+impl_from! {
+    IntModMat, SparseIntModMat
+    {
+        fn from(x: &SparseIntModMat) -> IntModMat {
+            let conv = |n: usize| -> i64 {
+                n.try_into().expect("Cannot convert length to a signed long.")
+            };
+            let mut res = IntModMat::zero(conv(x.nrows), conv(x.ncols), &x.ctx);
+            for (i, j, e) in x.triplets() {
+                res.set_entry(i, j, x.ctx.element(e.clone()));
+            }
+            res
+        }
+    }
+}
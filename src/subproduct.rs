@@ -0,0 +1,179 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+
+/// A subproduct tree over a set of moduli `m_0, ..., m_{k-1}` in a polynomial
+/// ring over a finite field, supporting simultaneous reduction of a 
+/// polynomial modulo all of the `m_i` and the inverse Chinese remaindering, 
+/// both in quasi-linear time in the total degree.
+///
+/// The first level of the tree holds the moduli and each following level the
+/// products of adjacent pairs, with an unpaired last node carried up 
+/// unchanged, so the root is the product of all moduli.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let ctx = IntModCtx::new(101);
+/// let moduli: Vec<IntModPoly> = (0..5)
+///     .map(|a| IntModPoly::new([-a, 0, 1], &ctx))
+///     .collect();
+/// let tree = SubproductTree::new(&moduli);
+///
+/// let f = IntModPoly::new([7, 1, 0, 3, 0, 0, 5, 2, 9], &ctx);
+/// let rems = tree.multi_mod(&f);
+/// assert_eq!(rems[0], f.divrem(&moduli[0]).1);
+/// assert_eq!(tree.multi_crt(&rems), f);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SubproductTree<P> {
+    tree: Vec<Vec<P>>,
+}
+
+/// The polynomial types that can back a [SubproductTree]. The methods are 
+/// prefixed so that they do not shadow the inherent methods and operator 
+/// traits of the polynomial types when the trait is in scope.
+pub trait TreePoly: Clone {
+    fn tree_degree(&self) -> i64;
+    fn tree_mul(&self, other: &Self) -> Self;
+    fn tree_add(&self, other: &Self) -> Self;
+    fn tree_sub(&self, other: &Self) -> Self;
+    fn tree_rem(&self, other: &Self) -> Self;
+    fn tree_invmod(&self, modulus: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_tree_poly {
+    ($poly:ident) => {
+        impl TreePoly for $poly {
+            #[inline]
+            fn tree_degree(&self) -> i64 {
+                $poly::degree(self)
+            }
+            #[inline]
+            fn tree_mul(&self, other: &Self) -> Self {
+                self * other
+            }
+            #[inline]
+            fn tree_add(&self, other: &Self) -> Self {
+                self + other
+            }
+            #[inline]
+            fn tree_sub(&self, other: &Self) -> Self {
+                self - other
+            }
+            #[inline]
+            fn tree_rem(&self, other: &Self) -> Self {
+                $poly::divrem(self, other).1
+            }
+            #[inline]
+            fn tree_invmod(&self, modulus: &Self) -> Option<Self> {
+                $poly::invmod(self, modulus)
+            }
+        }
+    };
+}
+
+impl_tree_poly!(IntModPoly);
+impl_tree_poly!(FinFldPoly);
+
+impl<P: TreePoly> SubproductTree<P> {
+    /// Build the subproduct tree of the given moduli. Panics if there are no
+    /// moduli or one of them is constant.
+    pub fn new(moduli: &[P]) -> Self {
+        assert!(!moduli.is_empty(), "A subproduct tree needs at least one modulus.");
+        assert!(
+            moduli.iter().all(|m| m.tree_degree() > 0),
+            "Moduli must be nonconstant."
+        );
+        let mut tree = vec![moduli.to_vec()];
+        while tree[tree.len() - 1].len() > 1 {
+            check_interrupt();
+            let next = tree[tree.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a.tree_mul(b),
+                    [a] => a.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            tree.push(next);
+        }
+        SubproductTree { tree }
+    }
+
+    /// Return the moduli at the leaves of the tree.
+    #[inline]
+    pub fn moduli(&self) -> &[P] {
+        &self.tree[0]
+    }
+
+    /// Return the product of all moduli, at the root of the tree.
+    #[inline]
+    pub fn product(&self) -> &P {
+        &self.tree[self.tree.len() - 1][0]
+    }
+
+    /// Return the remainders of `f` modulo each of the moduli, computed by 
+    /// reducing down the tree.
+    pub fn multi_mod(&self, f: &P) -> Vec<P> {
+        let mut rems = vec![f.tree_rem(self.product())];
+        for level in self.tree.iter().rev().skip(1) {
+            check_interrupt();
+            rems = level
+                .iter()
+                .enumerate()
+                .map(|(i, m)| rems[i / 2].tree_rem(m))
+                .collect();
+        }
+        rems
+    }
+
+    /// Return the unique polynomial of degree less than the degree of the 
+    /// product of the moduli which is congruent to `residues[i]` modulo the 
+    /// `i`-th modulus for every `i`, combining the residues pairwise up the
+    /// tree. Panics if the number of residues differs from the number of
+    /// moduli or the moduli are not pairwise coprime.
+    pub fn multi_crt(&self, residues: &[P]) -> P {
+        assert_eq!(residues.len(), self.moduli().len());
+        let mut vals: Vec<P> = residues
+            .iter()
+            .zip(self.moduli())
+            .map(|(r, m)| r.tree_rem(m))
+            .collect();
+        for level in self.tree.iter().take(self.tree.len() - 1) {
+            check_interrupt();
+            vals = vals
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(v, m)| match (v, m) {
+                    // f = va + a*((vb - va)/a mod b)
+                    ([va, vb], [a, b]) => {
+                        let inv = a.tree_rem(b)
+                            .tree_invmod(b)
+                            .expect("Moduli must be pairwise coprime.");
+                        let t = vb.tree_sub(&va.tree_rem(b)).tree_mul(&inv).tree_rem(b);
+                        va.tree_add(&a.tree_mul(&t))
+                    }
+                    ([va], [_]) => va.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        vals.pop().unwrap()
+    }
+}
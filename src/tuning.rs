@@ -0,0 +1,126 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! Thresholds at which the crate's own Rust-driven algorithms switch 
+//! strategy. FLINT and Arb select their internal algorithms themselves; only 
+//! the loops implemented in this crate are tuned here.
+//!
+//! In particular the cutoffs for Integer arithmetic, IntMat determinants, 
+//! Hermite forms and LLL, IntPoly multiplication and Arb function evaluation 
+//! are fixed when FLINT and Arb are built. Their benchmarks report scaling 
+//! and the crossover points of the underlying algorithms, but there is 
+//! nothing to set for them here.
+//!
+//! The defaults were picked from the Criterion benchmarks in `benches/`, 
+//! which can be rerun on a given machine with
+//!
+//! ```text
+//! cargo bench --features bench
+//! ```
+//!
+//! The values are global and can be changed at runtime. They only affect 
+//! performance, never results.
+//!
+//! ```
+//! use inertia_core::*;
+//!
+//! let s = binary_splitting(|_| Integer::one(), Integer::from, |_| Integer::one(), 20);
+//! tuning::set_binsplit_basecase(1);
+//! let t = binary_splitting(|_| Integer::one(), Integer::from, |_| Integer::one(), 20);
+//! tuning::set_binsplit_basecase(tuning::DEFAULT_BINSPLIT_BASECASE);
+//! assert_eq!(s, t);
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Default of [binsplit_basecase].
+pub const DEFAULT_BINSPLIT_BASECASE: u64 = 16;
+
+static BINSPLIT_BASECASE: AtomicU64 = AtomicU64::new(DEFAULT_BINSPLIT_BASECASE);
+
+/// The number of terms below which [binary_splitting](crate::binary_splitting) 
+/// and the constants built on it sum a range term by term instead of 
+/// splitting it further.
+#[inline]
+pub fn binsplit_basecase() -> u64 {
+    BINSPLIT_BASECASE.load(Ordering::Relaxed)
+}
+
+/// Set the value returned by [binsplit_basecase]. Values below one are 
+/// treated as one.
+#[inline]
+pub fn set_binsplit_basecase(n: u64) {
+    BINSPLIT_BASECASE.store(n.max(1), Ordering::Relaxed);
+}
+
+/// Default of [fixed_base_window].
+pub const DEFAULT_FIXED_BASE_WINDOW: u64 = 4;
+
+static FIXED_BASE_WINDOW: AtomicU64 = AtomicU64::new(DEFAULT_FIXED_BASE_WINDOW);
+
+/// The window size in bits used by [FixedBasePow::new](crate::FixedBasePow::new).
+#[inline]
+pub fn fixed_base_window() -> u64 {
+    FIXED_BASE_WINDOW.load(Ordering::Relaxed)
+}
+
+/// Set the value returned by [fixed_base_window]. Values are clamped to 
+/// between 1 and 15 bits.
+#[inline]
+pub fn set_fixed_base_window(w: u64) {
+    FIXED_BASE_WINDOW.store(w.clamp(1, 15), Ordering::Relaxed);
+}
+
+/// Default of [multi_exp_pippenger_cutoff].
+pub const DEFAULT_MULTI_EXP_PIPPENGER_CUTOFF: u64 = 32;
+
+static MULTI_EXP_PIPPENGER_CUTOFF: AtomicU64 = 
+    AtomicU64::new(DEFAULT_MULTI_EXP_PIPPENGER_CUTOFF);
+
+/// The number of bases from which [IntMod::multi_exp](crate::IntMod::multi_exp)
+/// and [FinFldElem::multi_exp](crate::FinFldElem::multi_exp) switch from 
+/// Straus' method to Pippenger's.
+#[inline]
+pub fn multi_exp_pippenger_cutoff() -> u64 {
+    MULTI_EXP_PIPPENGER_CUTOFF.load(Ordering::Relaxed)
+}
+
+/// Set the value returned by [multi_exp_pippenger_cutoff].
+#[inline]
+pub fn set_multi_exp_pippenger_cutoff(n: u64) {
+    MULTI_EXP_PIPPENGER_CUTOFF.store(n, Ordering::Relaxed);
+}
+
+/// Default of [multipoint_eval_cutoff].
+pub const DEFAULT_MULTIPOINT_EVAL_CUTOFF: u64 = 8;
+
+static MULTIPOINT_EVAL_CUTOFF: AtomicU64 = 
+    AtomicU64::new(DEFAULT_MULTIPOINT_EVAL_CUTOFF);
+
+/// The number of points and the degree from which 
+/// [IntPoly::evaluate_vec](crate::IntPoly::evaluate_vec) reduces down a 
+/// subproduct tree instead of evaluating at each point separately.
+#[inline]
+pub fn multipoint_eval_cutoff() -> u64 {
+    MULTIPOINT_EVAL_CUTOFF.load(Ordering::Relaxed)
+}
+
+/// Set the value returned by [multipoint_eval_cutoff].
+#[inline]
+pub fn set_multipoint_eval_cutoff(n: u64) {
+    MULTIPOINT_EVAL_CUTOFF.store(n, Ordering::Relaxed);
+}
@@ -0,0 +1,678 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use crate::*;
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+macro_rules! impl_vector {
+    ($vec:ident, $elem:ident, $mat:ident) => {
+        impl $vec {
+            /// Return a vector with the given entries.
+            pub fn new<T, I>(src: I) -> Self
+            where
+                T: Into<$elem>,
+                I: IntoIterator<Item = T>,
+            {
+                $vec { entries: src.into_iter().map(|x| x.into()).collect() }
+            }
+
+            /// Return the zero vector of length `n`.
+            #[inline]
+            pub fn zero(n: usize) -> Self {
+                $vec { entries: vec![$elem::zero(); n] }
+            }
+
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.entries.len()
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.entries.is_empty()
+            }
+
+            #[inline]
+            pub fn is_zero(&self) -> bool {
+                self.entries.iter().all(|x| x.is_zero())
+            }
+
+            /// Borrow the entries of the vector.
+            #[inline]
+            pub fn as_slice(&self) -> &[$elem] {
+                &self.entries
+            }
+
+            /// Mutably borrow the entries of the vector.
+            #[inline]
+            pub fn as_mut_slice(&mut self) -> &mut [$elem] {
+                &mut self.entries
+            }
+
+            /// Return the entries of the vector.
+            #[inline]
+            pub fn into_vec(self) -> Vec<$elem> {
+                self.entries
+            }
+
+            /// Iterate over the entries of the vector.
+            #[inline]
+            pub fn iter(&self) -> std::slice::Iter<'_, $elem> {
+                self.entries.iter()
+            }
+
+            /// Return the scalar product of two vectors of the same length.
+            pub fn dot(&self, other: &$vec) -> $elem {
+                assert_eq!(self.len(), other.len(), "The vectors have different lengths.");
+                let mut res = $elem::zero();
+                for (x, y) in self.iter().zip(other.iter()) {
+                    res += x * y;
+                }
+                res
+            }
+
+            /// Return the squared Euclidean norm `sum x_i^2`.
+            #[inline]
+            pub fn norm2_squared(&self) -> $elem {
+                self.dot(self)
+            }
+
+            /// Return the 1-norm `sum |x_i|`.
+            pub fn l1_norm(&self) -> $elem {
+                let mut res = $elem::zero();
+                for x in self.iter() {
+                    res += x.abs();
+                }
+                res
+            }
+
+            /// Return the infinity-norm `max |x_i|`, zero for the empty vector.
+            pub fn linf_norm(&self) -> $elem {
+                self.iter().map(|x| x.abs()).fold($elem::zero(), |m, x| {
+                    if x > m { x } else { m }
+                })
+            }
+
+            /// Set `self` to `self + a x`.
+            pub fn axpy(&mut self, a: &$elem, x: &$vec) {
+                assert_eq!(self.len(), x.len(), "The vectors have different lengths.");
+                for (y, x) in self.entries.iter_mut().zip(x.iter()) {
+                    *y += a * x;
+                }
+            }
+
+            /// Return the elementwise (Hadamard) product of two vectors.
+            pub fn mul_elementwise(&self, other: &$vec) -> $vec {
+                assert_eq!(self.len(), other.len(), "The vectors have different lengths.");
+                $vec { entries: self.iter().zip(other.iter()).map(|(x, y)| x * y).collect() }
+            }
+
+            /// Return the vector as a `1 x n` matrix.
+            pub fn to_row(&self) -> $mat {
+                let n = self.len().try_into().expect("Cannot convert length to a signed long.");
+                let mut res = $mat::zero(1, n);
+                for (j, x) in self.iter().enumerate() {
+                    res.set_entry(0, j, x);
+                }
+                res
+            }
+
+            /// Return the vector as an `n x 1` matrix.
+            pub fn to_col(&self) -> $mat {
+                let n = self.len().try_into().expect("Cannot convert length to a signed long.");
+                let mut res = $mat::zero(n, 1);
+                for (i, x) in self.iter().enumerate() {
+                    res.set_entry(i, 0, x);
+                }
+                res
+            }
+        }
+
+        impl $mat {
+            /// Return the `i`-th row of the matrix as a vector.
+            pub fn row_vec(&self, i: usize) -> $vec {
+                assert!(i < self.nrows());
+                $vec { entries: (0..self.ncols()).map(|j| self.get_entry(i, j)).collect() }
+            }
+
+            /// Return the `j`-th column of the matrix as a vector.
+            pub fn col_vec(&self, j: usize) -> $vec {
+                assert!(j < self.ncols());
+                $vec { entries: (0..self.nrows()).map(|i| self.get_entry(i, j)).collect() }
+            }
+
+            /// Return the matrix-vector product `A x`.
+            pub fn mul_vec(&self, x: &$vec) -> $vec {
+                assert_eq!(self.ncols(), x.len(), "The dimensions do not match.");
+                let mut res = $vec::zero(self.nrows());
+                for (i, y) in res.entries.iter_mut().enumerate() {
+                    for (j, xj) in x.iter().enumerate() {
+                        *y += self.entry(i, j) * xj;
+                    }
+                }
+                res
+            }
+        }
+
+        impl fmt::Display for $vec {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "[")?;
+                for (i, x) in self.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", x)?;
+                }
+                write!(f, "]")
+            }
+        }
+
+        impl From<Vec<$elem>> for $vec {
+            #[inline]
+            fn from(entries: Vec<$elem>) -> $vec {
+                $vec { entries }
+            }
+        }
+
+        impl<T: Into<$elem>, const N: usize> From<[T; N]> for $vec {
+            #[inline]
+            fn from(src: [T; N]) -> $vec {
+                $vec::new(src)
+            }
+        }
+
+        impl From<$vec> for Vec<$elem> {
+            #[inline]
+            fn from(x: $vec) -> Vec<$elem> {
+                x.entries
+            }
+        }
+
+        impl<T: Into<$elem>> FromIterator<T> for $vec {
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> $vec {
+                $vec::new(iter)
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $vec {
+            type Item = &'a $elem;
+            type IntoIter = std::slice::Iter<'a, $elem>;
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.entries.iter()
+            }
+        }
+
+        impl AsRef<[$elem]> for $vec {
+            #[inline]
+            fn as_ref(&self) -> &[$elem] {
+                &self.entries
+            }
+        }
+
+        // The derived `Eq` and `Hash` are those of the entries.
+        impl Borrow<[$elem]> for $vec {
+            #[inline]
+            fn borrow(&self) -> &[$elem] {
+                &self.entries
+            }
+        }
+
+        impl Index<usize> for $vec {
+            type Output = $elem;
+            #[inline]
+            fn index(&self, i: usize) -> &$elem {
+                &self.entries[i]
+            }
+        }
+
+        impl IndexMut<usize> for $vec {
+            #[inline]
+            fn index_mut(&mut self, i: usize) -> &mut $elem {
+                &mut self.entries[i]
+            }
+        }
+
+        impl Neg for &$vec {
+            type Output = $vec;
+            #[inline]
+            fn neg(self) -> $vec {
+                $vec { entries: self.iter().map(|x| -x).collect() }
+            }
+        }
+
+        impl Add for &$vec {
+            type Output = $vec;
+            fn add(self, rhs: &$vec) -> $vec {
+                assert_eq!(self.len(), rhs.len(), "The vectors have different lengths.");
+                $vec { entries: self.iter().zip(rhs.iter()).map(|(x, y)| x + y).collect() }
+            }
+        }
+
+        impl Sub for &$vec {
+            type Output = $vec;
+            fn sub(self, rhs: &$vec) -> $vec {
+                assert_eq!(self.len(), rhs.len(), "The vectors have different lengths.");
+                $vec { entries: self.iter().zip(rhs.iter()).map(|(x, y)| x - y).collect() }
+            }
+        }
+
+        impl AddAssign<&$vec> for $vec {
+            fn add_assign(&mut self, rhs: &$vec) {
+                assert_eq!(self.len(), rhs.len(), "The vectors have different lengths.");
+                for (x, y) in self.entries.iter_mut().zip(rhs.iter()) {
+                    *x += y;
+                }
+            }
+        }
+
+        impl SubAssign<&$vec> for $vec {
+            fn sub_assign(&mut self, rhs: &$vec) {
+                assert_eq!(self.len(), rhs.len(), "The vectors have different lengths.");
+                for (x, y) in self.entries.iter_mut().zip(rhs.iter()) {
+                    *x -= y;
+                }
+            }
+        }
+
+        impl Mul<&$elem> for &$vec {
+            type Output = $vec;
+            #[inline]
+            fn mul(self, rhs: &$elem) -> $vec {
+                $vec { entries: self.iter().map(|x| x * rhs).collect() }
+            }
+        }
+
+        impl Mul<&$vec> for &$elem {
+            type Output = $vec;
+            #[inline]
+            fn mul(self, rhs: &$vec) -> $vec {
+                rhs * self
+            }
+        }
+    };
+}
+
+/// A vector of integers, for algorithms that work with vectors rather than
+/// `1 x n` or `n x 1` matrices. The entries are stored in a plain [Vec].
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let x = IntVec::from([1, -2, 3]);
+/// let y = IntVec::from([4, 5, 6]);
+/// assert_eq!(x.dot(&y), 12);
+/// assert_eq!(x.norm2_squared(), 14);
+/// assert_eq!(x.l1_norm(), 6);
+/// assert_eq!(x.linf_norm(), 3);
+/// assert_eq!(&x + &y, IntVec::from([5, 3, 9]));
+/// assert_eq!(&x * &Integer::from(2), IntVec::from([2, -4, 6]));
+///
+/// let mut z = y.clone();
+/// z.axpy(&Integer::from(-2), &x);
+/// assert_eq!(z, IntVec::from([2, 9, 0]));
+///
+/// let m = IntMat::new([1, 0, 1, 0, 1, 1], 2, 3);
+/// assert_eq!(m.mul_vec(&x), IntVec::from([4, 1]));
+/// assert_eq!(x.to_col(), IntMat::new([1, -2, 3], 3, 1));
+/// assert_eq!(m.row_vec(1), IntVec::from([0, 1, 1]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntVec {
+    entries: Vec<Integer>,
+}
+
+/// A vector of rationals, see [IntVec].
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let x = RatVec::from([[1, 2], [-1, 3]]);
+/// assert_eq!(x.norm2_squared(), Rational::from([13, 36]));
+/// assert_eq!(x.to_row(), RatMat::new([[1, 2], [-1, 3]], 1, 2));
+///
+/// let (v, d) = x.clear_denominators();
+/// assert_eq!(v, IntVec::from([3, -2]));
+/// assert_eq!(d, 6);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RatVec {
+    entries: Vec<Rational>,
+}
+
+impl_vector!(IntVec, Integer, IntMat);
+impl_vector!(RatVec, Rational, RatMat);
+
+impl IntVec {
+    /// Return the content of the vector, the nonnegative gcd of its entries.
+    pub fn content(&self) -> Integer {
+        self.iter().fold(Integer::zero(), |g, x| g.gcd(x))
+    }
+
+    /// Reduce the entries of the vector modulo the modulus of `ctx`.
+    pub fn reduce_mod(&self, ctx: &IntModCtx) -> ModVec {
+        ModVec::new(self.iter().cloned(), ctx)
+    }
+}
+
+impl RatVec {
+    /// Return `(d x, d)` where `d` is the least common multiple of the
+    /// denominators of the entries.
+    pub fn clear_denominators(&self) -> (IntVec, Integer) {
+        let d = self.iter().fold(Integer::one(), |d, x| d.lcm(x.denominator()));
+        let v = self.iter().map(|x| x.numerator() * d.fdiv_q(x.denominator())).collect();
+        (v, d)
+    }
+}
+
+impl From<&IntVec> for RatVec {
+    #[inline]
+    fn from(x: &IntVec) -> RatVec {
+        RatVec { entries: x.iter().map(Rational::from).collect() }
+    }
+}
+
+impl From<IntVec> for RatVec {
+    #[inline]
+    fn from(x: IntVec) -> RatVec {
+        RatVec::from(&x)
+    }
+}
+
+/// A vector of integers modulo `n`. Unlike [IntVec] the vector carries its
+/// context, so that the empty vector and zero vectors know their ring.
+///
+/// ```
+/// use inertia_core::*;
+///
+/// let zn = IntModCtx::new(7);
+/// let x = ModVec::new([1, 2, 3], &zn);
+/// let y = ModVec::new([4, 5, 6], &zn);
+/// assert_eq!(x.dot(&y), zn.element(32));
+/// assert_eq!(&x + &y, ModVec::new([5, 0, 2], &zn));
+/// assert_eq!(x.to_row(), IntMat::new([1, 2, 3], 1, 3).reduce_mod(&zn));
+/// assert_eq!(x.lift(), IntVec::from([1, 2, 3]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModVec {
+    entries: Vec<IntMod>,
+    ctx: IntModCtx,
+}
+
+impl ModVec {
+    /// Return the vector with the given entries reduced modulo the modulus of
+    /// `ctx`.
+    pub fn new<T, I>(src: I, ctx: &IntModCtx) -> Self
+    where
+        T: Into<Integer>,
+        I: IntoIterator<Item = T>,
+    {
+        ModVec { entries: src.into_iter().map(|x| ctx.element(x)).collect(), ctx: ctx.clone() }
+    }
+
+    /// Return the vector with the given entries, which must lie in `ctx`.
+    pub fn from_entries(entries: Vec<IntMod>, ctx: &IntModCtx) -> Self {
+        assert!(entries.iter().all(|x| x.context() == ctx));
+        ModVec { entries, ctx: ctx.clone() }
+    }
+
+    /// Return the zero vector of length `n`.
+    #[inline]
+    pub fn zero(n: usize, ctx: &IntModCtx) -> Self {
+        ModVec { entries: vec![ctx.zero(); n], ctx: ctx.clone() }
+    }
+
+    #[inline]
+    pub fn context(&self) -> &IntModCtx {
+        &self.ctx
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.entries.iter().all(|x| x.is_zero())
+    }
+
+    /// Borrow the entries of the vector.
+    #[inline]
+    pub fn as_slice(&self) -> &[IntMod] {
+        &self.entries
+    }
+
+    /// Return the entries of the vector.
+    #[inline]
+    pub fn into_vec(self) -> Vec<IntMod> {
+        self.entries
+    }
+
+    /// Iterate over the entries of the vector.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, IntMod> {
+        self.entries.iter()
+    }
+
+    /// Set the `i`-th entry of the vector.
+    pub fn set_entry<T: AsRef<IntMod>>(&mut self, i: usize, e: T) {
+        let e = e.as_ref();
+        assert_eq!(&self.ctx, e.context());
+        self.entries[i] = e.clone();
+    }
+
+    /// Return the scalar product of two vectors of the same length, reducing
+    /// once at the end, see [IntMod::dot].
+    pub fn dot(&self, other: &ModVec) -> IntMod {
+        assert_eq!(self.ctx, other.ctx);
+        assert_eq!(self.len(), other.len(), "The vectors have different lengths.");
+        if self.is_empty() {
+            self.ctx.zero()
+        } else {
+            IntMod::dot(&self.entries, &other.entries)
+        }
+    }
+
+    /// Set `self` to `self + a x`.
+    pub fn axpy(&mut self, a: &IntMod, x: &ModVec) {
+        assert!(self.ctx == x.ctx && &self.ctx == a.context());
+        assert_eq!(self.len(), x.len(), "The vectors have different lengths.");
+        for (y, x) in self.entries.iter_mut().zip(x.iter()) {
+            *y += a * x;
+        }
+    }
+
+    /// Return the elementwise (Hadamard) product of two vectors.
+    pub fn mul_elementwise(&self, other: &ModVec) -> ModVec {
+        assert_eq!(self.ctx, other.ctx);
+        assert_eq!(self.len(), other.len(), "The vectors have different lengths.");
+        ModVec { 
+            entries: self.iter().zip(other.iter()).map(|(x, y)| x * y).collect(), 
+            ctx: self.ctx.clone() 
+        }
+    }
+
+    /// Return the vector of the reduced representatives in `[0, n)` of the
+    /// entries.
+    pub fn lift(&self) -> IntVec {
+        self.iter().map(Integer::from).collect()
+    }
+
+    /// Return the vector as a `1 x n` matrix.
+    pub fn to_row(&self) -> IntModMat {
+        let n = self.len().try_into().expect("Cannot convert length to a signed long.");
+        let mut res = IntModMat::zero(1, n, &self.ctx);
+        for (j, x) in self.iter().enumerate() {
+            res.set_entry(0, j, x);
+        }
+        res
+    }
+
+    /// Return the vector as an `n x 1` matrix.
+    pub fn to_col(&self) -> IntModMat {
+        let n = self.len().try_into().expect("Cannot convert length to a signed long.");
+        let mut res = IntModMat::zero(n, 1, &self.ctx);
+        for (i, x) in self.iter().enumerate() {
+            res.set_entry(i, 0, x);
+        }
+        res
+    }
+}
+
+impl IntModMat {
+    /// Return the `i`-th row of the matrix as a vector.
+    pub fn row_vec(&self, i: usize) -> ModVec {
+        assert!(i < self.nrows());
+        let entries = (0..self.ncols()).map(|j| self.get_entry(i, j)).collect();
+        ModVec { entries, ctx: self.context().clone() }
+    }
+
+    /// Return the `j`-th column of the matrix as a vector.
+    pub fn col_vec(&self, j: usize) -> ModVec {
+        assert!(j < self.ncols());
+        let entries = (0..self.nrows()).map(|i| self.get_entry(i, j)).collect();
+        ModVec { entries, ctx: self.context().clone() }
+    }
+
+    /// Return the matrix-vector product `A x`.
+    pub fn mul_vec(&self, x: &ModVec) -> ModVec {
+        assert_eq!(self.context(), x.context());
+        assert_eq!(self.ncols(), x.len(), "The dimensions do not match.");
+        let mut res = ModVec::zero(self.nrows(), x.context());
+        for (i, y) in res.entries.iter_mut().enumerate() {
+            let mut acc = Integer::zero();
+            for (j, xj) in x.iter().enumerate() {
+                acc.addmul_assign(self.entry(i, j), &Integer::from(xj));
+            }
+            *y = x.ctx.element(acc);
+        }
+        res
+    }
+}
+
+impl fmt::Display for ModVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, x) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", x)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl From<ModVec> for Vec<IntMod> {
+    #[inline]
+    fn from(x: ModVec) -> Vec<IntMod> {
+        x.entries
+    }
+}
+
+impl<'a> IntoIterator for &'a ModVec {
+    type Item = &'a IntMod;
+    type IntoIter = std::slice::Iter<'a, IntMod>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+impl AsRef<[IntMod]> for ModVec {
+    #[inline]
+    fn as_ref(&self) -> &[IntMod] {
+        &self.entries
+    }
+}
+
+impl Index<usize> for ModVec {
+    type Output = IntMod;
+    #[inline]
+    fn index(&self, i: usize) -> &IntMod {
+        &self.entries[i]
+    }
+}
+
+impl Neg for &ModVec {
+    type Output = ModVec;
+    #[inline]
+    fn neg(self) -> ModVec {
+        ModVec { entries: self.iter().map(|x| -x).collect(), ctx: self.ctx.clone() }
+    }
+}
+
+impl Add for &ModVec {
+    type Output = ModVec;
+    fn add(self, rhs: &ModVec) -> ModVec {
+        assert_eq!(self.ctx, rhs.ctx);
+        assert_eq!(self.len(), rhs.len(), "The vectors have different lengths.");
+        ModVec { 
+            entries: self.iter().zip(rhs.iter()).map(|(x, y)| x + y).collect(), 
+            ctx: self.ctx.clone() 
+        }
+    }
+}
+
+impl Sub for &ModVec {
+    type Output = ModVec;
+    fn sub(self, rhs: &ModVec) -> ModVec {
+        assert_eq!(self.ctx, rhs.ctx);
+        assert_eq!(self.len(), rhs.len(), "The vectors have different lengths.");
+        ModVec { 
+            entries: self.iter().zip(rhs.iter()).map(|(x, y)| x - y).collect(), 
+            ctx: self.ctx.clone() 
+        }
+    }
+}
+
+impl AddAssign<&ModVec> for ModVec {
+    fn add_assign(&mut self, rhs: &ModVec) {
+        assert_eq!(self.ctx, rhs.ctx);
+        assert_eq!(self.len(), rhs.len(), "The vectors have different lengths.");
+        for (x, y) in self.entries.iter_mut().zip(rhs.iter()) {
+            *x += y;
+        }
+    }
+}
+
+impl SubAssign<&ModVec> for ModVec {
+    fn sub_assign(&mut self, rhs: &ModVec) {
+        assert_eq!(self.ctx, rhs.ctx);
+        assert_eq!(self.len(), rhs.len(), "The vectors have different lengths.");
+        for (x, y) in self.entries.iter_mut().zip(rhs.iter()) {
+            *x -= y;
+        }
+    }
+}
+
+impl Mul<&IntMod> for &ModVec {
+    type Output = ModVec;
+    fn mul(self, rhs: &IntMod) -> ModVec {
+        assert_eq!(&self.ctx, rhs.context());
+        ModVec { entries: self.iter().map(|x| x * rhs).collect(), ctx: self.ctx.clone() }
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// Conformance tests against outputs recorded from Pari/GP and Sage. Each file
+// in `tests/golden` holds one case per line with `|` separated fields; lines
+// starting with `#` are comments. Run with
+//
+//     cargo test --features golden --test golden
+
+use inertia_core::*;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+// Return the `|` separated fields of each case in a fixture file.
+fn cases(name: &str) -> Vec<Vec<String>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(name);
+    let text = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Cannot read {}: {}", path.display(), e));
+    let res: Vec<Vec<String>> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split('|').map(|s| s.trim().to_string()).collect())
+        .collect();
+    assert!(!res.is_empty(), "No cases in {}.", name);
+    res
+}
+
+// Parse a field with the crate's `FromStr` impls, which accept the Pari/GP 
+// syntax of the fixtures.
+fn parse<T: FromStr<Err = Error>>(s: &str) -> T {
+    s.parse().unwrap_or_else(|e| panic!("Cannot parse {:?}: {}", s, e))
+}
+
+#[test]
+fn intpoly_factor() {
+    for case in cases("intpoly_factor.txt") {
+        let f: IntPoly = parse(&case[0]);
+        let (content, mut factors) = f.factor();
+        assert_eq!(content, parse::<Integer>(&case[1]), "content of {}", f);
+
+        let mut expected: Vec<(IntPoly, u64)> = case[2]
+            .split('(')
+            .skip(1)
+            .map(|s| {
+                let (g, e) = s.trim().split_once(")^").unwrap();
+                (parse(g), e.parse().unwrap())
+            })
+            .collect();
+        factors.sort_by_key(|(g, e)| (g.to_string(), *e));
+        expected.sort_by_key(|(g, e)| (g.to_string(), *e));
+        assert_eq!(factors, expected, "factorization of {}", f);
+    }
+}
+
+#[test]
+fn intmat_hnf() {
+    for case in cases("intmat_hnf.txt") {
+        let a: IntMat = parse(&case[0]);
+        assert_eq!(a.hnf(), parse::<IntMat>(&case[1]), "HNF of {}", case[0]);
+    }
+}
+
+#[test]
+fn class_numbers() {
+    for case in cases("class_numbers.txt") {
+        let d: i64 = case[0].parse().unwrap();
+        let (h, _) = class_group(d);
+        assert_eq!(h, case[1].parse::<u64>().unwrap(), "class number of {}", d);
+    }
+}
+
+#[test]
+fn constants() {
+    let prec = 400;
+    for case in cases("constants.txt") {
+        let digits = case[1].split_once('.').unwrap().1.len();
+        let expected = format!("{} +/- 1e-{}", case[1], digits);
+        let expected = Real::from_str_prec(&expected, prec).unwrap();
+        let values = match case[0].as_str() {
+            "pi" => vec![RealField::init(prec).pi(), pi_chudnovsky(prec)],
+            "e" => vec![e_binary_splitting(prec)],
+            "zeta3" => vec![zeta3_binary_splitting(prec)],
+            name => panic!("Unknown constant {}.", name),
+        };
+        for x in values {
+            assert!(expected.contains(&x), "{} = {}", case[0], x);
+        }
+    }
+}
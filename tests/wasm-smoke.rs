@@ -0,0 +1,65 @@
+/*
+ *  Copyright (C) 2021 William Youmans
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  You should have received a copy of the GNU General Public License
+ *  along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+// A small end-to-end check of the exact types that is cheap enough to run on 
+// every target, in particular `wasm32-unknown-emscripten` under node:
+//
+//     cargo test --target wasm32-unknown-emscripten --test wasm-smoke
+
+use inertia_core::*;
+
+#[test]
+fn integer_arithmetic() {
+    let x: Integer = "123456789012345678901234567890".parse().unwrap();
+    let y = Integer::from(987654321u64);
+    let z = &x * &y;
+    assert_eq!(z.fdiv_q(&y), x);
+    assert_eq!(Integer::factorial(20u32), 2432902008176640000u64);
+    assert!(Integer::from(1000003).is_prime());
+}
+
+#[test]
+fn rational_arithmetic() {
+    let a = Rational::from([1, 3]);
+    let b = Rational::from([1, 6]);
+    assert_eq!(&a + &b, Rational::from([1, 2]));
+    assert_eq!(a.to_string(), "1/3");
+}
+
+#[test]
+fn polynomials_and_matrices() {
+    let f = IntPoly::from([1, 1]);
+    let g = &f * &f;
+    assert_eq!(g, IntPoly::from([1, 2, 1]));
+    assert_eq!(g.gcd(&f), f);
+
+    let m = IntMat::new([2, 1, 1, 1], 2, 2);
+    assert_eq!(m.det(), 1);
+    assert_eq!(m.pow(3), &(&m * &m) * &m);
+}
+
+#[test]
+fn modular_and_random() {
+    let zn = IntModCtx::new(7);
+    let x = IntMod::new(10, &zn);
+    assert_eq!(x, 3);
+
+    let mut rng = FlintRand::with_seed(1);
+    let a = Integer::randtest(64u32, &mut rng);
+    let mut rng = FlintRand::with_seed(1);
+    assert_eq!(Integer::randtest(64u32, &mut rng), a);
+}